@@ -1,9 +1,13 @@
-use iai_callgrind::{library_benchmark, library_benchmark_group, main};
+use iai_callgrind::{
+    library_benchmark, library_benchmark_group, main, Dhat, LibraryBenchmarkConfig,
+};
 use std::fs::read;
 use std::hint::black_box;
 use std::path::Path;
 use tf_demo_parser::demo::data::DemoTick;
+use tf_demo_parser::demo::message::packetentities::PacketEntitiesView;
 use tf_demo_parser::demo::message::Message;
+use tf_demo_parser::demo::packet::datatable::{flatten_send_tables, ParseSendTable, ServerClass};
 use tf_demo_parser::demo::parser::analyser::Analyser;
 use tf_demo_parser::demo::parser::gamestateanalyser::GameStateAnalyser;
 use tf_demo_parser::demo::parser::MessageHandler;
@@ -27,6 +31,78 @@ impl MessageHandler for AllMessages {
     }
 }
 
+/// Handles entity updates as messages
+struct OwnedEntities;
+
+impl MessageHandler for OwnedEntities {
+    type Output = bool;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        message_type == MessageType::PacketEntities
+    }
+
+    fn handle_message(&mut self, message: &Message, _tick: DemoTick, _parser_state: &ParserState) {
+        black_box(message);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        black_box(true)
+    }
+}
+
+/// Handles entity updates borrowed from the parser's entity buffer
+struct BorrowedEntities;
+
+impl MessageHandler for BorrowedEntities {
+    type Output = bool;
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        false
+    }
+
+    fn does_handle_borrowed_entities() -> bool {
+        true
+    }
+
+    fn handle_entities(
+        &mut self,
+        entities: &PacketEntitiesView,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        black_box(entities);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        black_box(true)
+    }
+}
+
+#[derive(Default)]
+struct DataTables(Vec<ParseSendTable>, Vec<ServerClass>);
+
+impl MessageHandler for DataTables {
+    type Output = (Vec<ParseSendTable>, Vec<ServerClass>);
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        false
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _parser_state: &ParserState,
+    ) {
+        self.0 = tables.to_vec();
+        self.1 = server_classes.to_vec();
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        (self.0, self.1)
+    }
+}
+
 fn read_file<P: AsRef<Path>>(path: P) -> Demo<'static> {
     let data = read(path).unwrap();
     Demo::owned(data)
@@ -49,9 +125,29 @@ fn parse<A: MessageHandler>(parser: DemoParser<A>) {
     black_box(parser.parse().unwrap());
 }
 
+#[library_benchmark(setup = get_parser, config = LibraryBenchmarkConfig::default().tool(Dhat::default()))]
+#[bench::owned_small("test_data/small.dem", OwnedEntities)]
+#[bench::owned_gully("test_data/gully.dem", OwnedEntities)]
+#[bench::borrowed_small("test_data/small.dem", BorrowedEntities)]
+#[bench::borrowed_gully("test_data/gully.dem", BorrowedEntities)]
+fn entities<A: MessageHandler>(parser: DemoParser<A>) {
+    black_box(parser.parse().unwrap());
+}
+
+fn get_data_tables<P: AsRef<Path>>(path: P) -> (Vec<ParseSendTable>, Vec<ServerClass>) {
+    get_parser(path, DataTables::default()).parse().unwrap().1
+}
+
+#[library_benchmark(setup = get_data_tables)]
+#[bench::small("test_data/small.dem")]
+#[bench::gully("test_data/gully.dem")]
+fn flatten((tables, server_classes): (Vec<ParseSendTable>, Vec<ServerClass>)) {
+    black_box(flatten_send_tables(&tables, &server_classes).unwrap());
+}
+
 library_benchmark_group!(
     name = bench_parse;
-    benchmarks = parse
+    benchmarks = parse, entities, flatten
 );
 
 main!(library_benchmark_groups = bench_parse);
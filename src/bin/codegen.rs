@@ -3,7 +3,9 @@ use prettyplease::unparse;
 use std::env;
 use std::fs;
 use syn::{parse2, File};
+use tf_demo_parser::codegen::entityviews::{generate_entity_views, DEFAULT_VIEW_CLASSES};
 use tf_demo_parser::codegen::gameevent::generate_game_events;
+use tf_demo_parser::codegen::itemschema::generate_item_schema;
 use tf_demo_parser::codegen::propnames::generate_prop_names;
 use tf_demo_parser::Demo;
 
@@ -17,10 +19,11 @@ fn main() -> std::result::Result<(), MainError> {
     }
     let path = args[1].clone();
     let file = fs::read(path).expect("Unable to read file");
-    let demo = Demo::new(&file);
     let tokens = match args.get(2).map(|s| s.as_str()) {
-        None | Some("events") => generate_game_events(demo),
-        Some("props") => generate_prop_names(demo),
+        None | Some("events") => generate_game_events(Demo::new(&file)),
+        Some("props") => generate_prop_names(Demo::new(&file)),
+        Some("views") => generate_entity_views(Demo::new(&file), DEFAULT_VIEW_CLASSES),
+        Some("items") => generate_item_schema(&String::from_utf8_lossy(&file)),
         _ => panic!("unsupported"),
     };
     let file = parse2::<File>(tokens)?;
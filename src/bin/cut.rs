@@ -0,0 +1,27 @@
+use std::env;
+use std::fs;
+
+use main_error::MainError;
+use tf_demo_parser::demo::edit::cut_demo;
+use tf_demo_parser::Demo;
+
+fn main() -> Result<(), MainError> {
+    #[cfg(feature = "better-panic")]
+    better_panic::install();
+
+    #[cfg(feature = "trace")]
+    tracing_subscriber::fmt::init();
+
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 5 {
+        println!("usage: {} <demo> <output> <start tick> <end tick>", args[0]);
+        return Ok(());
+    }
+    let start: u32 = args[3].parse()?;
+    let end: u32 = args[4].parse()?;
+    let file = fs::read(&args[1])?;
+    let demo = Demo::new(&file);
+    let clip = cut_demo(&demo, start.into(), end.into())?;
+    fs::write(&args[2], clip)?;
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use std::env;
+use std::fs;
+
+use main_error::MainError;
+use tf_demo_parser::demo::diff::{diff_demos, DiffOptions};
+use tf_demo_parser::Demo;
+
+fn main() -> Result<(), MainError> {
+    #[cfg(feature = "better-panic")]
+    better_panic::install();
+
+    #[cfg(feature = "trace")]
+    tracing_subscriber::fmt::init();
+
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 3 {
+        println!(
+            "usage: {} <left demo> <right demo> [--ignore-header] [--ignore-view-angles] [--ignore-sequence] [--max <count>]",
+            args[0]
+        );
+        return Ok(());
+    }
+    let mut options = DiffOptions::default();
+    let mut flags = args[3..].iter();
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--ignore-header" => options.ignore_header = true,
+            "--ignore-view-angles" => options.ignore_view_angles = true,
+            "--ignore-sequence" => options.ignore_sequence = true,
+            "--max" => {
+                let max = flags.next().ok_or("missing count for --max")?;
+                options.max_differences = Some(max.parse()?);
+            }
+            flag => return Err(format!("unknown option {flag}").into()),
+        }
+    }
+
+    let left = fs::read(&args[1])?;
+    let right = fs::read(&args[2])?;
+    let differences = diff_demos(&Demo::new(&left), &Demo::new(&right), &options)?;
+    for difference in &differences {
+        println!("{}", difference);
+    }
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use std::env;
+use std::fs;
+
+use main_error::MainError;
+use tf_demo_parser::demo::edit::merge_demos;
+use tf_demo_parser::Demo;
+
+fn main() -> Result<(), MainError> {
+    #[cfg(feature = "better-panic")]
+    better_panic::install();
+
+    #[cfg(feature = "trace")]
+    tracing_subscriber::fmt::init();
+
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 4 {
+        println!("usage: {} <output> <demo> <demo>...", args[0]);
+        return Ok(());
+    }
+    let files = args[2..]
+        .iter()
+        .map(fs::read)
+        .collect::<Result<Vec<_>, _>>()?;
+    let demos: Vec<_> = files.iter().map(|file| Demo::new(file)).collect();
+    let merged = merge_demos(&demos)?;
+    fs::write(&args[1], merged)?;
+    Ok(())
+}
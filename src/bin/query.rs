@@ -0,0 +1,38 @@
+use std::env;
+use std::fs;
+
+use main_error::MainError;
+use tf_demo_parser::demo::parser::query::{Query, QueryAnalyser};
+use tf_demo_parser::{Demo, DemoParser};
+
+#[cfg(feature = "jemallocator")]
+#[global_allocator]
+static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
+
+fn main() -> Result<(), MainError> {
+    #[cfg(feature = "better-panic")]
+    better_panic::install();
+
+    #[cfg(feature = "trace")]
+    tracing_subscriber::fmt::init();
+
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 3 {
+        println!("usage: {} <demo> <query>", args[0]);
+        println!(
+            r#"example: {} demo.dem 'class == "CTFPlayer" && prop("DT_BasePlayer.m_iHealth") < 50'"#,
+            args[0]
+        );
+        return Ok(());
+    }
+    let query: Query = args[2].parse()?;
+    let file = fs::read(&args[1])?;
+    let demo = Demo::new(&file);
+    let analyser = QueryAnalyser::with_callback(query, |query_match| {
+        if let Ok(json) = serde_json::to_string(&query_match) {
+            println!("{json}");
+        }
+    });
+    DemoParser::new_with_analyser(demo.get_stream(), analyser).parse()?;
+    Ok(())
+}
@@ -1,17 +1,34 @@
-#![allow(unused_imports)]
-
 use std::env;
 use std::fs;
 
-use bitbuffer::{BitError, BitRead, BitWrite, BitWriteStream, LittleEndian};
 use main_error::MainError;
-use tf_demo_parser::demo::data::DemoTick;
-use tf_demo_parser::demo::header::Header;
-use tf_demo_parser::demo::message::{setconvar::SetConVarMessage, Message, NetTickMessage};
-use tf_demo_parser::demo::packet::stop::StopPacket;
-use tf_demo_parser::demo::packet::{Packet, PacketType};
-use tf_demo_parser::demo::parser::{DemoHandler, Encode, RawPacketStream};
-use tf_demo_parser::{Demo, ParseError};
+use tf_demo_parser::demo::edit::rewrite::{
+    drop_messages, drop_packets, patch_array_sizes, strip_pov,
+};
+use tf_demo_parser::demo::edit::{AnonymizeOptions, Anonymizer, DemoRewriter, PovConverter};
+use tf_demo_parser::demo::message::MessageType;
+use tf_demo_parser::demo::packet::PacketType;
+use tf_demo_parser::Demo;
+
+/// Unless `--raw` is passed, the view angles are removed, the demo is marked as stv,
+/// the player and building arrays are increased for 100 player servers and all console
+/// commands are removed.
+const FLAGS: &[(&str, &str)] = &[
+    (
+        "--raw",
+        "don't strip the pov, patch the array sizes or remove console commands",
+    ),
+    (
+        "--pov=<user id>",
+        "convert an stv demo to the point of view of a player, implies not stripping the pov",
+    ),
+    ("--drop-user-commands", "remove all user commands"),
+    ("--drop-sounds", "remove all sounds"),
+    ("--drop-decals", "remove all decals"),
+    ("--anonymize", "replace player names and steam ids"),
+    ("--drop-chat", "remove chat messages, implies --anonymize"),
+    ("--drop-voice", "remove voice data, implies --anonymize"),
+];
 
 fn main() -> Result<(), MainError> {
     #[cfg(feature = "trace")]
@@ -20,123 +37,72 @@ fn main() -> Result<(), MainError> {
     #[cfg(feature = "better-panic")]
     better_panic::install();
 
-    let args: Vec<_> = env::args().collect();
+    let (flags, args): (Vec<_>, Vec<_>) = env::args().partition(|arg| arg.starts_with("--"));
     if args.len() < 3 {
-        println!("2 argument required");
+        println!("usage: {} <demo> <output> [flags]", args[0]);
+        println!(
+            "by default the pov is stripped, the array sizes are patched and console commands are removed"
+        );
+        for (flag, description) in FLAGS {
+            println!("  {flag:<26}{description}");
+        }
+        return Ok(());
+    }
+    if let Some(unknown) = flags.iter().find(|flag| {
+        !FLAGS
+            .iter()
+            .any(|(known, _)| flag_name(known) == flag_name(flag))
+    }) {
+        println!("unknown flag {unknown}");
         return Ok(());
     }
+    let has_flag = |flag: &str| flags.iter().any(|arg| arg == flag);
+
     let path = args[1].clone();
     let out_path = args[2].clone();
     let file = fs::read(path)?;
-    let strip_pov = true;
-
-    let mut out_buffer = Vec::with_capacity(file.len());
-    {
-        let mut out_stream = BitWriteStream::new(&mut out_buffer, LittleEndian);
-
-        let demo = Demo::new(&file);
-        let mut stream = demo.get_stream();
-        let mut header = Header::read(&mut stream)?;
-        let mut packets = RawPacketStream::new(stream.clone());
-
-        // demos that are closed unexpectedly have no length set
-        if header.ticks == 0 {
-            header_fixup(&mut header, packets.clone())?;
-        }
-        header.write(&mut out_stream)?;
-
-        let mut handler = DemoHandler::default();
-        let mut encode_handler = DemoHandler::default();
-
-        let mut has_stop = false;
-        let mut last_tick = DemoTick::default();
-
-        while let Some(packet) = packets.next(&handler.state_handler)? {
-            last_tick = packet.tick();
-            if packet.packet_type() == PacketType::Stop {
-                has_stop = true;
-            }
-
-            let mut encode_packet = packet.clone();
-            match &mut encode_packet {
-                Packet::DataTables(tables_packet) => {
-                    for table in tables_packet.tables.iter_mut() {
-                        for prop in table.props.iter_mut() {
-                            match (table.name.as_str(), prop.name.as_str()) {
-                                ("DT_ObjectDispenser", "\"healing_array\"") => {
-                                    prop.element_count = Some(101);
-                                }
-                                ("DT_Team", "\"player_array\"") => {
-                                    prop.element_count = Some(101);
-                                }
-                                ("DT_TFTeam", "\"team_object_array\"") => {
-                                    prop.element_count = Some(606);
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                }
-                Packet::Signon(message_packet) | Packet::Message(message_packet) if strip_pov => {
-                    message_packet.meta.view_angles = Default::default();
-                    message_packet.messages.iter_mut().for_each(|msg| {
-                        if let Message::ServerInfo(info) = msg {
-                            info.stv = true;
-                        }
-                    });
-                }
-                _ => {}
-            }
-
-            if encode_packet.packet_type() != PacketType::ConsoleCmd {
-                encode_packet
-                    .encode(&mut out_stream, &encode_handler.state_handler)
-                    .unwrap();
-            }
-
-            handler.handle_packet(packet).unwrap();
-            encode_handler.handle_packet(encode_packet).unwrap();
-        }
+    let demo = Demo::new(&file);
 
-        if packets.incomplete {
-            eprintln!("Warning: truncated demo");
-        }
+    let raw = has_flag("--raw");
+    let pov_user = flags.iter().find_map(|flag| flag.strip_prefix("--pov="));
 
-        if !has_stop {
-            Packet::Stop(StopPacket { tick: last_tick })
-                .encode(&mut out_stream, &encode_handler.state_handler)
-                .unwrap();
-        }
+    let mut rewriter = DemoRewriter::new();
+    if !raw && pov_user.is_none() {
+        rewriter = rewriter.with_packet_hook(strip_pov);
+    }
+    if let Some(user_id) = pov_user {
+        let user_id: u32 = user_id.parse()?;
+        rewriter = rewriter.with_pov_converter(PovConverter::new(&demo, user_id.into())?);
+    }
+    if !raw {
+        rewriter = rewriter
+            .with_packet_hook(patch_array_sizes)
+            .with_packet_hook(drop_packets(PacketType::ConsoleCmd));
+    }
+    if has_flag("--drop-user-commands") {
+        rewriter = rewriter.with_packet_hook(drop_packets(PacketType::UserCmd));
+    }
+    if has_flag("--drop-sounds") {
+        rewriter = rewriter.with_message_hook(drop_messages(MessageType::ParseSounds));
+    }
+    if has_flag("--drop-decals") {
+        rewriter = rewriter.with_message_hook(drop_messages(MessageType::BspDecal));
+    }
+    // dropping chat or voice only makes sense when publishing anonymized demos
+    if has_flag("--anonymize") || has_flag("--drop-chat") || has_flag("--drop-voice") {
+        rewriter = rewriter.with_anonymizer(Anonymizer::new(AnonymizeOptions {
+            drop_chat: has_flag("--drop-chat"),
+            drop_voice: has_flag("--drop-voice"),
+        }));
     }
 
-    fs::write(out_path, out_buffer)?;
+    let output = rewriter.rewrite(&demo)?;
+    fs::write(out_path, output)?;
 
     Ok(())
 }
 
-fn header_fixup(header: &mut Header, mut packets: RawPacketStream) -> Result<(), MainError> {
-    let mut ticks = 0;
-    let mut handler = DemoHandler::default();
-    let mut tickrate = 66;
-
-    while let Some(packet) = packets.next(&handler.state_handler)? {
-        ticks = packet.tick().into();
-
-        if let Packet::Signon(message_packet) = &packet {
-            for message in &message_packet.messages {
-                if let Message::SetConVar(SetConVarMessage { vars, .. }) = message {
-                    for cvar in vars {
-                        if cvar.key == "sv_minupdaterate" {
-                            tickrate = cvar.value.parse().expect("invalid sv_minupdaterate");
-                        }
-                    }
-                }
-            }
-        }
-        handler.handle_packet(packet).unwrap();
-    }
-
-    header.ticks = ticks;
-    header.duration = ticks as f32 / tickrate as f32;
-    Ok(())
+/// The name of a flag, without its value
+fn flag_name(flag: &str) -> &str {
+    flag.split_once('=').map_or(flag, |(name, _)| name)
 }
@@ -0,0 +1,222 @@
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::parser::MessageHandler;
+use crate::demo::sendprop::{SendPropDefinition, SendPropParseDefinition};
+use crate::{Demo, DemoParser, MessageType, ParserState};
+use inflector::Inflector;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::collections::HashSet;
+
+/// The server classes to generate views for by default
+pub const DEFAULT_VIEW_CLASSES: &[&str] = &[
+    "CTFPlayer",
+    "CTFPlayerResource",
+    "CTFTeam",
+    "CTFGameRulesProxy",
+    "CObjectSentrygun",
+    "CObjectDispenser",
+    "CObjectTeleporter",
+    "CWeaponMedigun",
+    "CTFProjectile_Rocket",
+    "CTFGrenadePipebombProjectile",
+];
+
+const KEYWORDS: &[&str] = &[
+    "as", "box", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "new", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "async", "await", "dyn",
+];
+
+struct ClassInfo {
+    name: String,
+    props: Vec<SendPropDefinition>,
+}
+
+struct ClassAnalyzer {
+    class_names: Vec<String>,
+    classes: Vec<ClassInfo>,
+}
+
+impl MessageHandler for ClassAnalyzer {
+    type Output = Vec<ClassInfo>;
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        false
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _state: &ParserState,
+    ) {
+        self.classes = self
+            .class_names
+            .iter()
+            .filter_map(|name| {
+                let class = server_classes
+                    .iter()
+                    .find(|class| class.name.as_str() == name)?;
+                let table = parse_tables
+                    .iter()
+                    .find(|table| table.name == class.data_table)?;
+                Some(ClassInfo {
+                    name: name.clone(),
+                    props: table.flatten_props(parse_tables).ok()?,
+                })
+            })
+            .collect();
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.classes
+    }
+}
+
+/// Strip the `m_` and type prefixes from a prop name, `m_flMaxspeed` becomes `maxspeed`
+fn get_accessor_name(table_name: &str, prop_name: &str) -> String {
+    // elements of arrays are named by their index, use the name of the array instead
+    if prop_name.chars().all(|c| c.is_ascii_digit()) {
+        return format!("{}_{}", get_accessor_name("", table_name), prop_name);
+    }
+
+    let name = prop_name.trim_start_matches("m_");
+    let type_prefix = name.chars().take_while(|c| c.is_ascii_lowercase()).count();
+    let stripped = match name.get(type_prefix..) {
+        Some(rest) if type_prefix <= 3 && rest.starts_with(|c: char| c.is_ascii_uppercase()) => {
+            rest
+        }
+        _ => name,
+    };
+    let snake = stripped.to_snake_case();
+    if snake.is_empty()
+        || snake.starts_with(|c: char| c.is_ascii_digit())
+        || KEYWORDS.contains(&snake.as_str())
+    {
+        name.to_snake_case()
+    } else {
+        snake
+    }
+}
+
+fn get_return_type(prop_name: &str, definition: &SendPropParseDefinition) -> TokenStream {
+    match definition {
+        SendPropParseDefinition::NormalVarInt { .. }
+        | SendPropParseDefinition::UnsignedInt { .. }
+        | SendPropParseDefinition::Int { .. }
+            if prop_name.starts_with("m_h") =>
+        {
+            quote!(Handle)
+        }
+        SendPropParseDefinition::UnsignedInt { bit_count: 1, .. } => quote!(bool),
+        SendPropParseDefinition::NormalVarInt { .. }
+        | SendPropParseDefinition::UnsignedInt { .. }
+        | SendPropParseDefinition::Int { .. } => quote!(i64),
+        SendPropParseDefinition::Float { .. } => quote!(f32),
+        SendPropParseDefinition::String { .. } => quote!(&'a str),
+        SendPropParseDefinition::Vector { .. } => quote!(Vector),
+        SendPropParseDefinition::VectorXY { .. } => quote!(VectorXY),
+        SendPropParseDefinition::Array { .. } => quote!(&'a [SendPropValue]),
+    }
+}
+
+fn generate_view(class: &ClassInfo) -> TokenStream {
+    let span = Span::call_site();
+    let class_name = &class.name;
+    let view_name = Ident::new(&format!("{}View", class.name.replace('_', "")), span);
+
+    let mut seen_identifiers = HashSet::new();
+    let props: Vec<_> = class
+        .props
+        .iter()
+        .filter(|prop| seen_identifiers.insert(prop.identifier))
+        .filter_map(|prop| {
+            let (table_name, prop_name) = prop.identifier.names()?;
+            Some((
+                table_name.to_string(),
+                prop_name.to_string(),
+                &prop.parse_definition,
+            ))
+        })
+        .collect();
+
+    let mut used_names = HashSet::new();
+    let accessors = props
+        .iter()
+        .map(|(table_name, prop_name, definition)| {
+            let mut name = get_accessor_name(table_name, prop_name);
+            if !used_names.insert(name.clone()) {
+                let table = table_name.trim_start_matches("DT_").to_snake_case();
+                name = format!("{table}_{name}");
+                let base = name.clone();
+                let mut suffix = 2;
+                while !used_names.insert(name.clone()) {
+                    name = format!("{base}_{suffix}");
+                    suffix += 1;
+                }
+            }
+            let accessor = Ident::new(&name, span);
+            let ty = get_return_type(prop_name, definition);
+            let doc = format!(" `{table_name}.{prop_name}`");
+            quote!(
+                #[doc = #doc]
+                pub fn #accessor(&self) -> Option<#ty> {
+                    get_prop(self.entity, SendPropIdentifier::new(#table_name, #prop_name))
+                }
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let struct_doc = format!(" Typed view of the props of a `{class_name}` entity");
+
+    quote!(
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone, Copy)]
+        pub struct #view_name<'a, E: EntityProps + ?Sized> {
+            entity: &'a E,
+        }
+
+        impl<'a, E: EntityProps + ?Sized> #view_name<'a, E> {
+            pub const CLASS_NAME: &'static str = #class_name;
+
+            /// Create a view without checking the class of the entity
+            pub fn new(entity: &'a E) -> Self {
+                #view_name { entity }
+            }
+
+            /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+            pub fn try_new(entity: &'a E) -> Option<Self> {
+                matches_class(entity, Self::CLASS_NAME).then_some(#view_name { entity })
+            }
+
+            #(#accessors)*
+        }
+    )
+}
+
+pub fn generate_entity_views(demo: Demo, classes: &[&str]) -> TokenStream {
+    let analyzer = ClassAnalyzer {
+        class_names: classes.iter().map(|class| class.to_string()).collect(),
+        classes: Vec::new(),
+    };
+    #[allow(clippy::unwrap_used)]
+    let (_, classes) = DemoParser::new_with_analyser(demo.get_stream(), analyzer)
+        .parse()
+        .unwrap();
+
+    let imports = quote!(
+        use super::entityviews::{get_prop, matches_class, EntityProps};
+        use crate::demo::data::game_state::Handle;
+        use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
+        use crate::demo::vector::{Vector, VectorXY};
+    );
+
+    let views = classes.iter().map(generate_view);
+
+    quote!(
+        #imports
+
+        #(#views)*
+    )
+}
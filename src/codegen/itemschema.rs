@@ -0,0 +1,415 @@
+use crate::demo::data::items::{ItemQuality, ItemSlot};
+use crate::demo::parser::analyser::Class;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::collections::BTreeMap;
+
+/// Minimal parser for valve's KeyValues format as used by `items_game.txt`
+#[derive(Debug, Clone)]
+enum KeyValue {
+    String(String),
+    Object(Vec<(String, KeyValue)>),
+}
+
+impl KeyValue {
+    fn get(&self, key: &str) -> Option<&KeyValue> {
+        match self {
+            KeyValue::Object(children) => children
+                .iter()
+                .rev()
+                .find(|(child_key, _)| child_key.eq_ignore_ascii_case(key))
+                .map(|(_, value)| value),
+            KeyValue::String(_) => None,
+        }
+    }
+
+    fn get_str(&self, key: &str) -> Option<&str> {
+        match self.get(key)? {
+            KeyValue::String(value) => Some(value),
+            KeyValue::Object(_) => None,
+        }
+    }
+
+    fn children(&self) -> &[(String, KeyValue)] {
+        match self {
+            KeyValue::Object(children) => children,
+            KeyValue::String(_) => &[],
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let mut token = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                token.push(escaped);
+                            }
+                        }
+                        _ => token.push(c),
+                    }
+                }
+                tokens.push(token);
+            }
+            '{' | '}' => tokens.push(c.to_string()),
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            // platform conditionals
+            '[' => for _ in chars.by_ref().take_while(|c| *c != ']') {},
+            c if c.is_whitespace() => {}
+            c => {
+                let mut token = c.to_string();
+                while let Some(c) = chars.peek() {
+                    if c.is_whitespace() || *c == '"' || *c == '{' || *c == '}' {
+                        break;
+                    }
+                    token.push(*c);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+        }
+    }
+    tokens
+}
+
+fn parse_object<I: Iterator<Item = String>>(tokens: &mut I) -> KeyValue {
+    let mut children = Vec::new();
+    while let Some(key) = tokens.next() {
+        if key == "}" {
+            break;
+        }
+        match tokens.next() {
+            Some(value) if value == "{" => children.push((key, parse_object(tokens))),
+            Some(value) => children.push((key, KeyValue::String(value))),
+            None => break,
+        }
+    }
+    KeyValue::Object(children)
+}
+
+fn parse_key_values(input: &str) -> KeyValue {
+    parse_object(&mut tokenize(input).into_iter())
+}
+
+/// Flattened item definition with all prefabs applied
+#[derive(Default)]
+struct ItemDefinition {
+    values: BTreeMap<String, String>,
+    classes: Vec<String>,
+    /// Slots from `per_class_loadout_slots`, by class name
+    class_slots: Vec<(String, String)>,
+}
+
+impl ItemDefinition {
+    fn apply(&mut self, definition: &KeyValue, prefabs: &KeyValue, depth: usize) {
+        if depth > 16 {
+            return;
+        }
+        if let Some(prefab_names) = definition.get_str("prefab") {
+            for prefab_name in prefab_names.split_whitespace() {
+                if let Some(prefab) = prefabs.get(prefab_name) {
+                    self.apply(prefab, prefabs, depth + 1);
+                }
+            }
+        }
+        for (key, value) in definition.children() {
+            match value {
+                KeyValue::String(value) => {
+                    self.values.insert(key.to_ascii_lowercase(), value.clone());
+                }
+                KeyValue::Object(_) if key.eq_ignore_ascii_case("used_by_classes") => {
+                    self.classes = value
+                        .children()
+                        .iter()
+                        .filter(|(_, enabled)| !matches!(enabled, KeyValue::String(value) if value == "0"))
+                        .map(|(class, _)| class.to_ascii_lowercase())
+                        .collect();
+                }
+                KeyValue::Object(_) if key.eq_ignore_ascii_case("per_class_loadout_slots") => {
+                    self.class_slots = value
+                        .children()
+                        .iter()
+                        .filter_map(|(class, slot)| match slot {
+                            KeyValue::String(slot) => {
+                                Some((class.to_ascii_lowercase(), slot.to_ascii_lowercase()))
+                            }
+                            KeyValue::Object(_) => None,
+                        })
+                        .collect();
+                }
+                KeyValue::Object(_) => {}
+            }
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    fn log_name(&self) -> Option<String> {
+        self.get("item_logname").map(String::from).or_else(|| {
+            self.get("item_class")
+                .and_then(|class| class.strip_prefix("tf_weapon_"))
+                .map(String::from)
+        })
+    }
+}
+
+struct ItemInfo {
+    definition_index: u16,
+    name: String,
+    log_name: Option<String>,
+    slot: ItemSlot,
+    /// Only the classes that use a different slot than `slot`
+    class_slots: Vec<(Class, ItemSlot)>,
+    classes: Vec<Class>,
+    quality: ItemQuality,
+}
+
+fn parse_items(input: &str) -> Vec<ItemInfo> {
+    let root = parse_key_values(input);
+    let Some(items_game) = root.get("items_game") else {
+        return Vec::new();
+    };
+    let empty = KeyValue::Object(Vec::new());
+    let prefabs = items_game.get("prefabs").unwrap_or(&empty);
+    let Some(items) = items_game.get("items") else {
+        return Vec::new();
+    };
+
+    let mut result: Vec<ItemInfo> = items
+        .children()
+        .iter()
+        .filter_map(|(index, item)| {
+            let definition_index: u16 = index.parse().ok()?;
+            let mut definition = ItemDefinition::default();
+            definition.apply(item, prefabs, 0);
+
+            let mut classes: Vec<Class> = definition
+                .classes
+                .iter()
+                .filter_map(|class| class.parse().ok())
+                .collect();
+            classes.sort_by_key(|class| *class as u8);
+
+            let slot = definition
+                .get("item_slot")
+                .and_then(|slot| slot.parse().ok())
+                .unwrap_or_default();
+            let mut class_slots: Vec<(Class, ItemSlot)> = definition
+                .class_slots
+                .iter()
+                .filter_map(|(class, slot)| Some((class.parse().ok()?, slot.parse().ok()?)))
+                .filter(|(_, class_slot)| *class_slot != slot)
+                .collect();
+            class_slots.sort_by_key(|(class, _)| *class as u8);
+
+            Some(ItemInfo {
+                definition_index,
+                name: definition.get("name").unwrap_or_default().to_string(),
+                log_name: definition.log_name(),
+                slot,
+                class_slots,
+                classes,
+                quality: definition
+                    .get("item_quality")
+                    .and_then(ItemQuality::from_schema_name)
+                    .unwrap_or_default(),
+            })
+        })
+        .collect();
+    result.sort_by_key(|item| item.definition_index);
+    result
+}
+
+pub fn generate_item_schema(items_game: &str) -> TokenStream {
+    let items = parse_items(items_game);
+
+    let imports = quote!(
+        use super::items::{ItemQuality, ItemSchema, ItemSlot};
+        use crate::demo::parser::analyser::Class;
+    );
+
+    // the first (lowest index) item for every log name
+    let mut log_names: BTreeMap<&str, u16> = BTreeMap::new();
+    for item in &items {
+        if let Some(log_name) = &item.log_name {
+            log_names
+                .entry(log_name.as_str())
+                .or_insert(item.definition_index);
+        }
+    }
+
+    let item_definitions = items.iter().map(|item| {
+        let definition_index = item.definition_index;
+        let name = &item.name;
+        let log_name = match &item.log_name {
+            Some(log_name) => quote!(Some(#log_name)),
+            None => quote!(None),
+        };
+        let slot = Ident::new(&format!("{:?}", item.slot), Span::call_site());
+        let class_slots = item.class_slots.iter().map(|(class, slot)| {
+            let class = Ident::new(&format!("{class:?}"), Span::call_site());
+            let slot = Ident::new(&format!("{slot:?}"), Span::call_site());
+            quote!((Class::#class, ItemSlot::#slot))
+        });
+        let classes = item
+            .classes
+            .iter()
+            .map(|class| Ident::new(&format!("{class:?}"), Span::call_site()));
+        let quality = Ident::new(&format!("{:?}", item.quality), Span::call_site());
+        quote!(
+            ItemSchema {
+                definition_index: #definition_index,
+                name: #name,
+                log_name: #log_name,
+                slot: ItemSlot::#slot,
+                class_slots: &[#(#class_slots),*],
+                classes: &[#(Class::#classes),*],
+                quality: ItemQuality::#quality,
+            }
+        )
+    });
+
+    let log_name_entries = log_names
+        .iter()
+        .map(|(name, definition_index)| quote!((#name, #definition_index)));
+
+    quote!(
+        #imports
+
+        /// All known items, sorted by definition index
+        pub static ITEMS: &[ItemSchema] = &[
+            #(#item_definitions,)*
+        ];
+
+        /// Definition index for every weapon log name, sorted by name
+        pub static LOG_NAMES: &[(&str, u16)] = &[
+            #(#log_name_entries,)*
+        ];
+    )
+}
+
+#[test]
+fn test_parse_items() {
+    let input = r#"
+"items_game"
+{
+    "prefabs"
+    {
+        "weapon_melee"
+        {
+            "item_slot"     "melee"
+            "item_quality"  "normal"
+        }
+        "weapon_bat"
+        {
+            "prefab"        "weapon_melee"
+            "item_class"    "tf_weapon_bat"
+            "used_by_classes"
+            {
+                "scout"     "1"
+            }
+        }
+    }
+    "items"
+    {
+        "default"
+        {
+            "name"  "default"
+        }
+        // cosmetics don't have a log name
+        "30394"
+        {
+            "name"          "The Frickin' Sweet Ninja Hood"
+            "item_slot"     "misc"
+            "item_quality"  "unique"
+            "used_by_classes"
+            {
+                "scout"     "1"
+                "spy"       "1"
+                "sniper"    "0"
+            }
+        }
+        "355"
+        {
+            "name"          "The Fan O'War"
+            "prefab"        "weapon_bat"
+            "item_logname"  "warfan"
+            "item_quality"  "unique" [$WIN32]
+        }
+        "0"
+        {
+            "name"      "TF_WEAPON_BAT"
+            "prefab"    "weapon_bat"
+        }
+        "199"
+        {
+            "name"          "Upgradeable TF_WEAPON_SHOTGUN_PRIMARY"
+            "item_class"    "tf_weapon_shotgun"
+            "item_slot"     "secondary"
+            "used_by_classes"
+            {
+                "soldier"   "1"
+                "engineer"  "1"
+            }
+            "per_class_loadout_slots"
+            {
+                "soldier"   "secondary"
+                "engineer"  "primary"
+            }
+        }
+    }
+}
+"#;
+    let items = parse_items(input);
+    assert_eq!(
+        vec![0, 199, 355, 30394],
+        items
+            .iter()
+            .map(|item| item.definition_index)
+            .collect::<Vec<_>>()
+    );
+
+    let [bat, shotgun, warfan, hood] = items.as_slice() else {
+        panic!("unexpected items");
+    };
+    assert_eq!("TF_WEAPON_BAT", bat.name);
+    assert_eq!(Some("bat"), bat.log_name.as_deref());
+    assert_eq!(ItemSlot::Melee, bat.slot);
+    assert_eq!(vec![Class::Scout], bat.classes);
+    assert_eq!(ItemQuality::Normal, bat.quality);
+
+    assert!(bat.class_slots.is_empty());
+
+    assert_eq!(ItemSlot::Secondary, shotgun.slot);
+    assert_eq!(
+        vec![(Class::Engineer, ItemSlot::Primary)],
+        shotgun.class_slots
+    );
+
+    assert_eq!(Some("warfan"), warfan.log_name.as_deref());
+    assert_eq!(ItemSlot::Melee, warfan.slot);
+    assert_eq!(ItemQuality::Unique, warfan.quality);
+
+    assert_eq!(None, hood.log_name);
+    assert_eq!(ItemSlot::Misc, hood.slot);
+    assert_eq!(vec![Class::Scout, Class::Spy], hood.classes);
+
+    let code = generate_item_schema(input).to_string();
+    assert!(code.contains("30394u16"));
+    assert!(code.contains("\"warfan\""));
+}
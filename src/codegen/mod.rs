@@ -1,2 +1,4 @@
+pub mod entityviews;
 pub mod gameevent;
+pub mod itemschema;
 pub mod propnames;
@@ -1,6 +1,8 @@
 use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use num_enum::TryFromPrimitive;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, TryFromPrimitive)]
 #[repr(i64)]
 pub enum Attribute {
     DamagePenalty = 1,
@@ -902,3 +904,56 @@ pub fn get_attribute_value<'a, T: AttributeValueType, I: IntoIterator<Item = &'a
 
     None
 }
+
+/// A single entry from the attribute list of an item
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AttributeValue {
+    pub definition_index: u16,
+    pub raw_value: u32,
+}
+
+impl AttributeValue {
+    /// Get the attribute for the definition index, if it's a known attribute
+    pub fn attribute(&self) -> Option<Attribute> {
+        Attribute::try_from(self.definition_index as i64).ok()
+    }
+
+    pub fn value<T: AttributeValueType>(&self) -> T {
+        T::from_prop_value(&SendPropValue::Integer(self.raw_value as i64))
+    }
+}
+
+/// Get all attributes set in an attribute list
+///
+/// The props are expected to be in the order of the flattened send table
+pub fn get_attributes<'a, I: IntoIterator<Item = &'a SendProp>>(props: I) -> Vec<AttributeValue> {
+    const ATTRIBUTE_INDEX: SendPropIdentifier =
+        SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+    const ATTRIBUTE_VALUE: SendPropIdentifier =
+        SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+
+    let mut attributes = Vec::new();
+    let mut definition_index = None;
+
+    for prop in props {
+        match prop.identifier {
+            ATTRIBUTE_INDEX => {
+                definition_index = i64::try_from(&prop.value)
+                    .ok()
+                    .map(|index| index as u16)
+                    .filter(|index| *index > 0);
+            }
+            ATTRIBUTE_VALUE => {
+                if let Some(definition_index) = definition_index.take() {
+                    attributes.push(AttributeValue {
+                        definition_index,
+                        raw_value: i64::try_from(&prop.value).unwrap_or_default() as u32,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    attributes
+}
@@ -0,0 +1,64 @@
+//! Typed read-only views of entity props
+//!
+//! The views are generated from the send tables using the `codegen` binary, every accessor
+//! returns the prop in the type matching its send prop definition.
+
+use crate::demo::message::packetentities::{PacketEntity, PacketEntityView};
+use crate::demo::parser::entitytracker::TrackedEntity;
+use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
+
+pub use super::entityviews_gen::*;
+
+/// Anything props can be read from
+pub trait EntityProps {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue>;
+
+    /// The server class name of the entity, if known
+    fn class_name(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Only contains the props included in the packet, for entities entering the PVS,
+/// props that are taken from the baseline are not included.
+impl EntityProps for PacketEntity {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue> {
+        self.props
+            .iter()
+            .find(|prop| prop.identifier == identifier)
+            .map(|prop| &prop.value)
+    }
+}
+
+/// Only contains the props included in the packet, like [`PacketEntity`]
+impl EntityProps for PacketEntityView<'_> {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue> {
+        self.props
+            .iter()
+            .find(|prop| prop.identifier == identifier)
+            .map(|prop| &prop.value)
+    }
+}
+
+impl EntityProps for TrackedEntity {
+    fn prop_value(&self, identifier: SendPropIdentifier) -> Option<&SendPropValue> {
+        self.get_prop(identifier).map(|prop| &prop.value)
+    }
+
+    fn class_name(&self) -> Option<&str> {
+        Some(self.class_name.as_str())
+    }
+}
+
+pub(super) fn get_prop<'a, E: EntityProps + ?Sized, T: TryFrom<&'a SendPropValue>>(
+    entity: &'a E,
+    identifier: SendPropIdentifier,
+) -> Option<T> {
+    entity
+        .prop_value(identifier)
+        .and_then(|value| T::try_from(value).ok())
+}
+
+pub(super) fn matches_class<E: EntityProps + ?Sized>(entity: &E, class_name: &str) -> bool {
+    entity.class_name().map_or(true, |name| name == class_name)
+}
@@ -0,0 +1,16576 @@
+use super::entityviews::{get_prop, matches_class, EntityProps};
+use crate::demo::data::game_state::Handle;
+use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
+use crate::demo::vector::{Vector, VectorXY};
+/// Typed view of the props of a `CTFPlayer` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CTFPlayerView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CTFPlayerView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CTFPlayer";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CTFPlayerView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CTFPlayerView { entity })
+    }
+    /// `DT_Local.m_flDucktime`
+    pub fn ducktime(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_flDucktime"),
+        )
+    }
+    /// `DT_Local.m_flFallVelocity`
+    pub fn fall_velocity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_flFallVelocity"),
+        )
+    }
+    /// `DT_Local.m_vecPunchAngle`
+    pub fn punch_angle(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_vecPunchAngle"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_vecViewOffset[2]`
+    pub fn view_offset_2(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[2]"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_nTickBase`
+    pub fn tick_base(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nTickBase"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_hGroundEntity`
+    pub fn ground_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hGroundEntity"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_vecVelocity[0]`
+    pub fn velocity_0(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[0]"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_vecVelocity[1]`
+    pub fn velocity_1(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[1]"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_vecVelocity[2]`
+    pub fn velocity_2(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[2]"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_vecOrigin`
+    pub fn origin(&self) -> Option<VectorXY> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_vecOrigin[2]`
+    pub fn origin_2(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin[2]"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_angEyeAngles[0]`
+    pub fn eye_angles_0(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[0]"),
+        )
+    }
+    /// `DT_TFNonLocalPlayerExclusive.m_vecOrigin`
+    pub fn tf_non_local_player_exclusive_origin(&self) -> Option<VectorXY> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin"),
+        )
+    }
+    /// `DT_TFNonLocalPlayerExclusive.m_vecOrigin[2]`
+    pub fn tf_non_local_player_exclusive_origin_2(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin[2]"),
+        )
+    }
+    /// `DT_TFNonLocalPlayerExclusive.m_angEyeAngles[0]`
+    pub fn tf_non_local_player_exclusive_eye_angles_0(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[0]"),
+        )
+    }
+    /// `DT_TFNonLocalPlayerExclusive.m_angEyeAngles[1]`
+    pub fn eye_angles_1(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[1]"),
+        )
+    }
+    /// `DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime"),
+        )
+    }
+    /// `DT_BasePlayer.m_iHealth`
+    pub fn health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_iHealth"),
+        )
+    }
+    /// `DT_BasePlayer.m_fFlags`
+    pub fn flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_fFlags"),
+        )
+    }
+    /// `m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "001"),
+        )
+    }
+    /// `m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "002"),
+        )
+    }
+    /// `m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "003"),
+        )
+    }
+    /// `DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub fn next_attack(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack"),
+        )
+    }
+    /// `m_hMyWeapons.000`
+    pub fn my_weapons_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "000"))
+    }
+    /// `m_hMyWeapons.001`
+    pub fn my_weapons_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "001"))
+    }
+    /// `m_hMyWeapons.002`
+    pub fn my_weapons_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "002"))
+    }
+    /// `m_hMyWeapons.003`
+    pub fn my_weapons_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "003"))
+    }
+    /// `m_hMyWeapons.004`
+    pub fn my_weapons_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "004"))
+    }
+    /// `m_hMyWeapons.005`
+    pub fn my_weapons_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "005"))
+    }
+    /// `m_hMyWeapons.006`
+    pub fn my_weapons_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "006"))
+    }
+    /// `m_hMyWeapons.007`
+    pub fn my_weapons_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "007"))
+    }
+    /// `m_hMyWeapons.008`
+    pub fn my_weapons_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "008"))
+    }
+    /// `m_hMyWeapons.009`
+    pub fn my_weapons_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "009"))
+    }
+    /// `m_hMyWeapons.010`
+    pub fn my_weapons_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "010"))
+    }
+    /// `m_hMyWeapons.011`
+    pub fn my_weapons_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "011"))
+    }
+    /// `m_hMyWeapons.012`
+    pub fn my_weapons_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "012"))
+    }
+    /// `m_hMyWeapons.013`
+    pub fn my_weapons_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "013"))
+    }
+    /// `m_hMyWeapons.014`
+    pub fn my_weapons_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "014"))
+    }
+    /// `m_hMyWeapons.015`
+    pub fn my_weapons_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "015"))
+    }
+    /// `m_hMyWeapons.016`
+    pub fn my_weapons_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "016"))
+    }
+    /// `m_hMyWeapons.017`
+    pub fn my_weapons_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "017"))
+    }
+    /// `m_hMyWeapons.018`
+    pub fn my_weapons_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "018"))
+    }
+    /// `m_hMyWeapons.019`
+    pub fn my_weapons_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "019"))
+    }
+    /// `m_hMyWeapons.020`
+    pub fn my_weapons_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "020"))
+    }
+    /// `m_hMyWeapons.021`
+    pub fn my_weapons_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "021"))
+    }
+    /// `m_hMyWeapons.022`
+    pub fn my_weapons_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "022"))
+    }
+    /// `m_hMyWeapons.023`
+    pub fn my_weapons_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "023"))
+    }
+    /// `m_hMyWeapons.024`
+    pub fn my_weapons_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "024"))
+    }
+    /// `m_hMyWeapons.025`
+    pub fn my_weapons_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "025"))
+    }
+    /// `m_hMyWeapons.026`
+    pub fn my_weapons_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "026"))
+    }
+    /// `m_hMyWeapons.027`
+    pub fn my_weapons_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "027"))
+    }
+    /// `m_hMyWeapons.028`
+    pub fn my_weapons_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "028"))
+    }
+    /// `m_hMyWeapons.029`
+    pub fn my_weapons_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "029"))
+    }
+    /// `m_hMyWeapons.030`
+    pub fn my_weapons_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "030"))
+    }
+    /// `m_hMyWeapons.031`
+    pub fn my_weapons_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "031"))
+    }
+    /// `m_hMyWeapons.032`
+    pub fn my_weapons_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "032"))
+    }
+    /// `m_hMyWeapons.033`
+    pub fn my_weapons_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "033"))
+    }
+    /// `m_hMyWeapons.034`
+    pub fn my_weapons_034(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "034"))
+    }
+    /// `m_hMyWeapons.035`
+    pub fn my_weapons_035(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "035"))
+    }
+    /// `m_hMyWeapons.036`
+    pub fn my_weapons_036(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "036"))
+    }
+    /// `m_hMyWeapons.037`
+    pub fn my_weapons_037(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "037"))
+    }
+    /// `m_hMyWeapons.038`
+    pub fn my_weapons_038(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "038"))
+    }
+    /// `m_hMyWeapons.039`
+    pub fn my_weapons_039(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "039"))
+    }
+    /// `m_hMyWeapons.040`
+    pub fn my_weapons_040(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "040"))
+    }
+    /// `m_hMyWeapons.041`
+    pub fn my_weapons_041(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "041"))
+    }
+    /// `m_hMyWeapons.042`
+    pub fn my_weapons_042(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "042"))
+    }
+    /// `m_hMyWeapons.043`
+    pub fn my_weapons_043(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "043"))
+    }
+    /// `m_hMyWeapons.044`
+    pub fn my_weapons_044(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "044"))
+    }
+    /// `m_hMyWeapons.045`
+    pub fn my_weapons_045(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "045"))
+    }
+    /// `m_hMyWeapons.046`
+    pub fn my_weapons_046(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "046"))
+    }
+    /// `m_hMyWeapons.047`
+    pub fn my_weapons_047(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "047"))
+    }
+    /// `DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn attribute_definition_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex"),
+        )
+    }
+    /// `DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn raw_value_32(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32"),
+        )
+    }
+    /// `DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn refundable_currency(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency"),
+        )
+    }
+    /// `DT_PlayerState.deadflag`
+    pub fn deadflag(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PlayerState", "deadflag"),
+        )
+    }
+    /// `m_chAreaBits.000`
+    pub fn area_bits_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "000"))
+    }
+    /// `m_chAreaBits.001`
+    pub fn area_bits_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "001"))
+    }
+    /// `m_chAreaBits.002`
+    pub fn area_bits_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "002"))
+    }
+    /// `m_chAreaBits.003`
+    pub fn area_bits_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "003"))
+    }
+    /// `m_chAreaBits.004`
+    pub fn area_bits_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "004"))
+    }
+    /// `m_chAreaBits.005`
+    pub fn area_bits_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "005"))
+    }
+    /// `m_chAreaBits.006`
+    pub fn area_bits_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "006"))
+    }
+    /// `m_chAreaBits.007`
+    pub fn area_bits_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "007"))
+    }
+    /// `m_chAreaBits.008`
+    pub fn area_bits_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "008"))
+    }
+    /// `m_chAreaBits.009`
+    pub fn area_bits_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "009"))
+    }
+    /// `m_chAreaBits.010`
+    pub fn area_bits_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "010"))
+    }
+    /// `m_chAreaBits.011`
+    pub fn area_bits_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "011"))
+    }
+    /// `m_chAreaBits.012`
+    pub fn area_bits_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "012"))
+    }
+    /// `m_chAreaBits.013`
+    pub fn area_bits_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "013"))
+    }
+    /// `m_chAreaBits.014`
+    pub fn area_bits_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "014"))
+    }
+    /// `m_chAreaBits.015`
+    pub fn area_bits_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "015"))
+    }
+    /// `m_chAreaBits.016`
+    pub fn area_bits_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "016"))
+    }
+    /// `m_chAreaBits.017`
+    pub fn area_bits_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "017"))
+    }
+    /// `m_chAreaBits.018`
+    pub fn area_bits_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "018"))
+    }
+    /// `m_chAreaBits.019`
+    pub fn area_bits_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "019"))
+    }
+    /// `m_chAreaBits.020`
+    pub fn area_bits_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "020"))
+    }
+    /// `m_chAreaBits.021`
+    pub fn area_bits_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "021"))
+    }
+    /// `m_chAreaBits.022`
+    pub fn area_bits_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "022"))
+    }
+    /// `m_chAreaBits.023`
+    pub fn area_bits_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "023"))
+    }
+    /// `m_chAreaBits.024`
+    pub fn area_bits_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "024"))
+    }
+    /// `m_chAreaBits.025`
+    pub fn area_bits_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "025"))
+    }
+    /// `m_chAreaBits.026`
+    pub fn area_bits_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "026"))
+    }
+    /// `m_chAreaBits.027`
+    pub fn area_bits_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "027"))
+    }
+    /// `m_chAreaBits.028`
+    pub fn area_bits_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "028"))
+    }
+    /// `m_chAreaBits.029`
+    pub fn area_bits_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "029"))
+    }
+    /// `m_chAreaBits.030`
+    pub fn area_bits_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "030"))
+    }
+    /// `m_chAreaBits.031`
+    pub fn area_bits_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_chAreaBits", "031"))
+    }
+    /// `m_chAreaPortalBits.000`
+    pub fn area_portal_bits_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "000"),
+        )
+    }
+    /// `m_chAreaPortalBits.001`
+    pub fn area_portal_bits_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "001"),
+        )
+    }
+    /// `m_chAreaPortalBits.002`
+    pub fn area_portal_bits_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "002"),
+        )
+    }
+    /// `m_chAreaPortalBits.003`
+    pub fn area_portal_bits_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "003"),
+        )
+    }
+    /// `m_chAreaPortalBits.004`
+    pub fn area_portal_bits_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "004"),
+        )
+    }
+    /// `m_chAreaPortalBits.005`
+    pub fn area_portal_bits_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "005"),
+        )
+    }
+    /// `m_chAreaPortalBits.006`
+    pub fn area_portal_bits_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "006"),
+        )
+    }
+    /// `m_chAreaPortalBits.007`
+    pub fn area_portal_bits_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "007"),
+        )
+    }
+    /// `m_chAreaPortalBits.008`
+    pub fn area_portal_bits_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "008"),
+        )
+    }
+    /// `m_chAreaPortalBits.009`
+    pub fn area_portal_bits_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "009"),
+        )
+    }
+    /// `m_chAreaPortalBits.010`
+    pub fn area_portal_bits_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "010"),
+        )
+    }
+    /// `m_chAreaPortalBits.011`
+    pub fn area_portal_bits_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "011"),
+        )
+    }
+    /// `m_chAreaPortalBits.012`
+    pub fn area_portal_bits_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "012"),
+        )
+    }
+    /// `m_chAreaPortalBits.013`
+    pub fn area_portal_bits_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "013"),
+        )
+    }
+    /// `m_chAreaPortalBits.014`
+    pub fn area_portal_bits_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "014"),
+        )
+    }
+    /// `m_chAreaPortalBits.015`
+    pub fn area_portal_bits_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "015"),
+        )
+    }
+    /// `m_chAreaPortalBits.016`
+    pub fn area_portal_bits_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "016"),
+        )
+    }
+    /// `m_chAreaPortalBits.017`
+    pub fn area_portal_bits_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "017"),
+        )
+    }
+    /// `m_chAreaPortalBits.018`
+    pub fn area_portal_bits_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "018"),
+        )
+    }
+    /// `m_chAreaPortalBits.019`
+    pub fn area_portal_bits_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "019"),
+        )
+    }
+    /// `m_chAreaPortalBits.020`
+    pub fn area_portal_bits_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "020"),
+        )
+    }
+    /// `m_chAreaPortalBits.021`
+    pub fn area_portal_bits_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "021"),
+        )
+    }
+    /// `m_chAreaPortalBits.022`
+    pub fn area_portal_bits_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "022"),
+        )
+    }
+    /// `m_chAreaPortalBits.023`
+    pub fn area_portal_bits_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_chAreaPortalBits", "023"),
+        )
+    }
+    /// `DT_Local.m_iHideHUD`
+    pub fn hide_hud(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_iHideHUD"),
+        )
+    }
+    /// `DT_Local.m_flFOVRate`
+    pub fn fov_rate(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_flFOVRate"),
+        )
+    }
+    /// `DT_Local.m_bDucked`
+    pub fn ducked(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_bDucked"),
+        )
+    }
+    /// `DT_Local.m_bDucking`
+    pub fn ducking(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_bDucking"),
+        )
+    }
+    /// `DT_Local.m_bInDuckJump`
+    pub fn in_duck_jump(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_bInDuckJump"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled"),
+        )
+    }
+    /// `DT_Local.m_flDuckJumpTime`
+    pub fn duck_jump_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_flDuckJumpTime"),
+        )
+    }
+    /// `DT_Local.m_flJumpTime`
+    pub fn jump_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_flJumpTime"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins"),
+        )
+    }
+    /// `DT_Local.m_vecPunchAngleVel`
+    pub fn punch_angle_vel(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_vecPunchAngleVel"),
+        )
+    }
+    /// `DT_Local.m_bDrawViewmodel`
+    pub fn draw_viewmodel(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_bDrawViewmodel"),
+        )
+    }
+    /// `DT_Local.m_bWearingSuit`
+    pub fn wearing_suit(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_bWearingSuit"),
+        )
+    }
+    /// `DT_Local.m_bPoisoned`
+    pub fn poisoned(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_bPoisoned"),
+        )
+    }
+    /// `DT_Local.m_flStepSize`
+    pub fn step_size(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_flStepSize"),
+        )
+    }
+    /// `DT_Local.m_bAllowAutoMovement`
+    pub fn allow_auto_movement(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_bAllowAutoMovement"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.scale`
+    pub fn skybox_3d_scale(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.scale"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.origin`
+    pub fn skybox_3d_origin(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.origin"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.area`
+    pub fn skybox_3d_area(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.area"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.fog.enable`
+    pub fn skybox_3d_fog_enable(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.enable"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.fog.blend`
+    pub fn skybox_3d_fog_blend(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.blend"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.fog.dirPrimary`
+    pub fn skybox_3d_fog_dir_primary(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.dirPrimary"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.fog.colorPrimary`
+    pub fn skybox_3d_fog_color_primary(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.colorPrimary"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.fog.colorSecondary`
+    pub fn skybox_3d_fog_color_secondary(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.colorSecondary"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.fog.start`
+    pub fn skybox_3d_fog_start(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.start"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.fog.end`
+    pub fn skybox_3d_fog_end(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.end"),
+        )
+    }
+    /// `DT_Local.m_skybox3d.fog.maxdensity`
+    pub fn skybox_3d_fog_maxdensity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.maxdensity"),
+        )
+    }
+    /// `DT_Local.m_PlayerFog.m_hCtrl`
+    pub fn player_fog_m_h_ctrl(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_PlayerFog.m_hCtrl"),
+        )
+    }
+    /// `DT_Local.m_audio.localSound[0]`
+    pub fn audio_local_sound_0(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[0]"),
+        )
+    }
+    /// `DT_Local.m_audio.localSound[1]`
+    pub fn audio_local_sound_1(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[1]"),
+        )
+    }
+    /// `DT_Local.m_audio.localSound[2]`
+    pub fn audio_local_sound_2(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[2]"),
+        )
+    }
+    /// `DT_Local.m_audio.localSound[3]`
+    pub fn audio_local_sound_3(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[3]"),
+        )
+    }
+    /// `DT_Local.m_audio.localSound[4]`
+    pub fn audio_local_sound_4(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[4]"),
+        )
+    }
+    /// `DT_Local.m_audio.localSound[5]`
+    pub fn audio_local_sound_5(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[5]"),
+        )
+    }
+    /// `DT_Local.m_audio.localSound[6]`
+    pub fn audio_local_sound_6(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[6]"),
+        )
+    }
+    /// `DT_Local.m_audio.localSound[7]`
+    pub fn audio_local_sound_7(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[7]"),
+        )
+    }
+    /// `DT_Local.m_audio.soundscapeIndex`
+    pub fn audio_soundscape_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.soundscapeIndex"),
+        )
+    }
+    /// `DT_Local.m_audio.localBits`
+    pub fn audio_local_bits(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Local", "m_audio.localBits"),
+        )
+    }
+    /// `m_iAmmo.000`
+    pub fn ammo_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "000"))
+    }
+    /// `m_iAmmo.001`
+    pub fn ammo_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "001"))
+    }
+    /// `m_iAmmo.002`
+    pub fn ammo_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "002"))
+    }
+    /// `m_iAmmo.003`
+    pub fn ammo_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "003"))
+    }
+    /// `m_iAmmo.004`
+    pub fn ammo_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "004"))
+    }
+    /// `m_iAmmo.005`
+    pub fn ammo_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "005"))
+    }
+    /// `m_iAmmo.006`
+    pub fn ammo_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "006"))
+    }
+    /// `m_iAmmo.007`
+    pub fn ammo_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "007"))
+    }
+    /// `m_iAmmo.008`
+    pub fn ammo_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "008"))
+    }
+    /// `m_iAmmo.009`
+    pub fn ammo_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "009"))
+    }
+    /// `m_iAmmo.010`
+    pub fn ammo_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "010"))
+    }
+    /// `m_iAmmo.011`
+    pub fn ammo_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "011"))
+    }
+    /// `m_iAmmo.012`
+    pub fn ammo_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "012"))
+    }
+    /// `m_iAmmo.013`
+    pub fn ammo_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "013"))
+    }
+    /// `m_iAmmo.014`
+    pub fn ammo_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "014"))
+    }
+    /// `m_iAmmo.015`
+    pub fn ammo_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "015"))
+    }
+    /// `m_iAmmo.016`
+    pub fn ammo_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "016"))
+    }
+    /// `m_iAmmo.017`
+    pub fn ammo_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "017"))
+    }
+    /// `m_iAmmo.018`
+    pub fn ammo_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "018"))
+    }
+    /// `m_iAmmo.019`
+    pub fn ammo_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "019"))
+    }
+    /// `m_iAmmo.020`
+    pub fn ammo_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "020"))
+    }
+    /// `m_iAmmo.021`
+    pub fn ammo_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "021"))
+    }
+    /// `m_iAmmo.022`
+    pub fn ammo_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "022"))
+    }
+    /// `m_iAmmo.023`
+    pub fn ammo_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "023"))
+    }
+    /// `m_iAmmo.024`
+    pub fn ammo_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "024"))
+    }
+    /// `m_iAmmo.025`
+    pub fn ammo_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "025"))
+    }
+    /// `m_iAmmo.026`
+    pub fn ammo_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "026"))
+    }
+    /// `m_iAmmo.027`
+    pub fn ammo_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "027"))
+    }
+    /// `m_iAmmo.028`
+    pub fn ammo_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "028"))
+    }
+    /// `m_iAmmo.029`
+    pub fn ammo_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "029"))
+    }
+    /// `m_iAmmo.030`
+    pub fn ammo_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "030"))
+    }
+    /// `m_iAmmo.031`
+    pub fn ammo_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iAmmo", "031"))
+    }
+    /// `DT_LocalPlayerExclusive.m_vecViewOffset[0]`
+    pub fn view_offset_0(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[0]"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_vecViewOffset[1]`
+    pub fn view_offset_1(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[1]"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_flFriction`
+    pub fn friction(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flFriction"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_fOnTarget`
+    pub fn on_target(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_fOnTarget"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_nNextThinkTick`
+    pub fn next_think_tick(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nNextThinkTick"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_hLastWeapon`
+    pub fn last_weapon(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hLastWeapon"),
+        )
+    }
+    /// `DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMinsPreScaled",
+            ),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_vecBaseVelocity`
+    pub fn base_velocity(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecBaseVelocity"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_hConstraintEntity`
+    pub fn constraint_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hConstraintEntity"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_vecConstraintCenter`
+    pub fn constraint_center(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecConstraintCenter"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_flConstraintRadius`
+    pub fn constraint_radius(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintRadius"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_flConstraintWidth`
+    pub fn constraint_width(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintWidth"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_flConstraintSpeedFactor`
+    pub fn constraint_speed_factor(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintSpeedFactor"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_flDeathTime`
+    pub fn death_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flDeathTime"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_nWaterLevel`
+    pub fn water_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nWaterLevel"),
+        )
+    }
+    /// `DT_LocalPlayerExclusive.m_flLaggedMovementValue`
+    pub fn lagged_movement_value(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flLaggedMovementValue"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_iClass`
+    pub fn class(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iClass"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_iszClassIcon`
+    pub fn class_icon(&self) -> Option<&'a str> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iszClassIcon"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_iszCustomModel`
+    pub fn custom_model(&self) -> Option<&'a str> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iszCustomModel"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_vecCustomModelOffset`
+    pub fn custom_model_offset(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_vecCustomModelOffset"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_angCustomModelRotation`
+    pub fn custom_model_rotation(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_angCustomModelRotation"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_bCustomModelRotates`
+    pub fn custom_model_rotates(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelRotates"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_bCustomModelRotationSet`
+    pub fn custom_model_rotation_set(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelRotationSet"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_bCustomModelVisibleToSelf`
+    pub fn custom_model_visible_to_self(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelVisibleToSelf"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_bUseClassAnimations`
+    pub fn use_class_animations(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bUseClassAnimations"),
+        )
+    }
+    /// `DT_TFPlayerClassShared.m_iClassModelParity`
+    pub fn class_model_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iClassModelParity"),
+        )
+    }
+    /// `m_bPlayerDominated.000`
+    pub fn player_dominated_000(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "000"),
+        )
+    }
+    /// `m_bPlayerDominated.001`
+    pub fn player_dominated_001(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "001"),
+        )
+    }
+    /// `m_bPlayerDominated.002`
+    pub fn player_dominated_002(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "002"),
+        )
+    }
+    /// `m_bPlayerDominated.003`
+    pub fn player_dominated_003(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "003"),
+        )
+    }
+    /// `m_bPlayerDominated.004`
+    pub fn player_dominated_004(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "004"),
+        )
+    }
+    /// `m_bPlayerDominated.005`
+    pub fn player_dominated_005(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "005"),
+        )
+    }
+    /// `m_bPlayerDominated.006`
+    pub fn player_dominated_006(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "006"),
+        )
+    }
+    /// `m_bPlayerDominated.007`
+    pub fn player_dominated_007(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "007"),
+        )
+    }
+    /// `m_bPlayerDominated.008`
+    pub fn player_dominated_008(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "008"),
+        )
+    }
+    /// `m_bPlayerDominated.009`
+    pub fn player_dominated_009(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "009"),
+        )
+    }
+    /// `m_bPlayerDominated.010`
+    pub fn player_dominated_010(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "010"),
+        )
+    }
+    /// `m_bPlayerDominated.011`
+    pub fn player_dominated_011(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "011"),
+        )
+    }
+    /// `m_bPlayerDominated.012`
+    pub fn player_dominated_012(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "012"),
+        )
+    }
+    /// `m_bPlayerDominated.013`
+    pub fn player_dominated_013(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "013"),
+        )
+    }
+    /// `m_bPlayerDominated.014`
+    pub fn player_dominated_014(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "014"),
+        )
+    }
+    /// `m_bPlayerDominated.015`
+    pub fn player_dominated_015(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "015"),
+        )
+    }
+    /// `m_bPlayerDominated.016`
+    pub fn player_dominated_016(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "016"),
+        )
+    }
+    /// `m_bPlayerDominated.017`
+    pub fn player_dominated_017(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "017"),
+        )
+    }
+    /// `m_bPlayerDominated.018`
+    pub fn player_dominated_018(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "018"),
+        )
+    }
+    /// `m_bPlayerDominated.019`
+    pub fn player_dominated_019(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "019"),
+        )
+    }
+    /// `m_bPlayerDominated.020`
+    pub fn player_dominated_020(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "020"),
+        )
+    }
+    /// `m_bPlayerDominated.021`
+    pub fn player_dominated_021(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "021"),
+        )
+    }
+    /// `m_bPlayerDominated.022`
+    pub fn player_dominated_022(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "022"),
+        )
+    }
+    /// `m_bPlayerDominated.023`
+    pub fn player_dominated_023(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "023"),
+        )
+    }
+    /// `m_bPlayerDominated.024`
+    pub fn player_dominated_024(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "024"),
+        )
+    }
+    /// `m_bPlayerDominated.025`
+    pub fn player_dominated_025(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "025"),
+        )
+    }
+    /// `m_bPlayerDominated.026`
+    pub fn player_dominated_026(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "026"),
+        )
+    }
+    /// `m_bPlayerDominated.027`
+    pub fn player_dominated_027(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "027"),
+        )
+    }
+    /// `m_bPlayerDominated.028`
+    pub fn player_dominated_028(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "028"),
+        )
+    }
+    /// `m_bPlayerDominated.029`
+    pub fn player_dominated_029(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "029"),
+        )
+    }
+    /// `m_bPlayerDominated.030`
+    pub fn player_dominated_030(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "030"),
+        )
+    }
+    /// `m_bPlayerDominated.031`
+    pub fn player_dominated_031(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "031"),
+        )
+    }
+    /// `m_bPlayerDominated.032`
+    pub fn player_dominated_032(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "032"),
+        )
+    }
+    /// `m_bPlayerDominated.033`
+    pub fn player_dominated_033(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominated", "033"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.000`
+    pub fn player_dominating_me_000(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "000"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.001`
+    pub fn player_dominating_me_001(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "001"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.002`
+    pub fn player_dominating_me_002(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "002"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.003`
+    pub fn player_dominating_me_003(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "003"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.004`
+    pub fn player_dominating_me_004(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "004"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.005`
+    pub fn player_dominating_me_005(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "005"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.006`
+    pub fn player_dominating_me_006(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "006"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.007`
+    pub fn player_dominating_me_007(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "007"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.008`
+    pub fn player_dominating_me_008(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "008"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.009`
+    pub fn player_dominating_me_009(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "009"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.010`
+    pub fn player_dominating_me_010(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "010"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.011`
+    pub fn player_dominating_me_011(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "011"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.012`
+    pub fn player_dominating_me_012(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "012"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.013`
+    pub fn player_dominating_me_013(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "013"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.014`
+    pub fn player_dominating_me_014(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "014"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.015`
+    pub fn player_dominating_me_015(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "015"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.016`
+    pub fn player_dominating_me_016(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "016"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.017`
+    pub fn player_dominating_me_017(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "017"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.018`
+    pub fn player_dominating_me_018(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "018"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.019`
+    pub fn player_dominating_me_019(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "019"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.020`
+    pub fn player_dominating_me_020(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "020"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.021`
+    pub fn player_dominating_me_021(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "021"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.022`
+    pub fn player_dominating_me_022(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "022"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.023`
+    pub fn player_dominating_me_023(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "023"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.024`
+    pub fn player_dominating_me_024(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "024"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.025`
+    pub fn player_dominating_me_025(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "025"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.026`
+    pub fn player_dominating_me_026(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "026"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.027`
+    pub fn player_dominating_me_027(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "027"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.028`
+    pub fn player_dominating_me_028(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "028"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.029`
+    pub fn player_dominating_me_029(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "029"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.030`
+    pub fn player_dominating_me_030(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "030"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.031`
+    pub fn player_dominating_me_031(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "031"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.032`
+    pub fn player_dominating_me_032(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "032"),
+        )
+    }
+    /// `m_bPlayerDominatingMe.033`
+    pub fn player_dominating_me_033(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "033"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iCaptures`
+    pub fn captures(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCaptures"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iDefenses`
+    pub fn defenses(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDefenses"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iKills`
+    pub fn kills(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKills"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iDeaths`
+    pub fn deaths(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDeaths"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iSuicides`
+    pub fn suicides(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iSuicides"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iDominations`
+    pub fn dominations(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDominations"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iRevenge`
+    pub fn revenge(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iRevenge"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iBuildingsBuilt`
+    pub fn buildings_built(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBuildingsBuilt"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iBuildingsDestroyed`
+    pub fn buildings_destroyed(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBuildingsDestroyed"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iHeadshots`
+    pub fn headshots(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iHeadshots"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iBackstabs`
+    pub fn backstabs(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBackstabs"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iHealPoints`
+    pub fn heal_points(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iHealPoints"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iInvulns`
+    pub fn invulns(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iInvulns"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iTeleports`
+    pub fn teleports(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iTeleports"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iDamageDone`
+    pub fn damage_done(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDamageDone"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iCrits`
+    pub fn crits(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCrits"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iResupplyPoints`
+    pub fn resupply_points(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iResupplyPoints"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iKillAssists`
+    pub fn kill_assists(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKillAssists"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iBonusPoints`
+    pub fn bonus_points(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBonusPoints"),
+        )
+    }
+    /// `DT_TFPlayerScoringDataExclusive.m_iPoints`
+    pub fn points(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iPoints"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_nDesiredDisguiseTeam`
+    pub fn desired_disguise_team(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_nDesiredDisguiseTeam"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_nDesiredDisguiseClass`
+    pub fn desired_disguise_class(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_nDesiredDisguiseClass"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_bLastDisguisedAsOwnTeam`
+    pub fn last_disguised_as_own_team(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bLastDisguisedAsOwnTeam"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_flStealthNoAttackExpire`
+    pub fn stealth_no_attack_expire(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flStealthNoAttackExpire"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_flStealthNextChangeTime`
+    pub fn stealth_next_change_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flStealthNextChangeTime"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_flRageMeter`
+    pub fn rage_meter(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flRageMeter"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_bRageDraining`
+    pub fn rage_draining(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bRageDraining"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_flNextRageEarnTime`
+    pub fn next_rage_earn_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flNextRageEarnTime"),
+        )
+    }
+    /// `DT_TFPlayerSharedLocal.m_bInUpgradeZone`
+    pub fn in_upgrade_zone(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bInUpgradeZone"),
+        )
+    }
+    /// `DT_TFPlayerConditionListExclusive._condition_bits`
+    pub fn condition_bits(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerConditionListExclusive", "_condition_bits"),
+        )
+    }
+    /// `m_nStreaks.000`
+    pub fn streaks_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_nStreaks", "000"))
+    }
+    /// `m_nStreaks.001`
+    pub fn streaks_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_nStreaks", "001"))
+    }
+    /// `m_nStreaks.002`
+    pub fn streaks_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_nStreaks", "002"))
+    }
+    /// `m_nStreaks.003`
+    pub fn streaks_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_nStreaks", "003"))
+    }
+    /// `DT_TFPlayerShared.m_nPlayerCond`
+    pub fn player_cond(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCond"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_bJumping`
+    pub fn jumping(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bJumping"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nNumHealers`
+    pub fn num_healers(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nNumHealers"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iCritMult`
+    pub fn crit_mult(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iCritMult"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iAirDash`
+    pub fn air_dash(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iAirDash"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nAirDucked`
+    pub fn air_ducked(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nAirDucked"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flDuckTimer`
+    pub fn duck_timer(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flDuckTimer"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nPlayerState`
+    pub fn player_state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerState"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iDesiredPlayerClass`
+    pub fn desired_player_class(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDesiredPlayerClass"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flMovementStunTime`
+    pub fn movement_stun_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flMovementStunTime"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iMovementStunAmount`
+    pub fn movement_stun_amount(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iMovementStunAmount"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iMovementStunParity`
+    pub fn movement_stun_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iMovementStunParity"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_hStunner`
+    pub fn stunner(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hStunner"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iStunFlags`
+    pub fn stun_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iStunFlags"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nArenaNumChanges`
+    pub fn arena_num_changes(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nArenaNumChanges"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_bArenaFirstBloodBoost`
+    pub fn arena_first_blood_boost(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bArenaFirstBloodBoost"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iWeaponKnockbackID`
+    pub fn weapon_knockback_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iWeaponKnockbackID"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_bLoadoutUnavailable`
+    pub fn loadout_unavailable(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bLoadoutUnavailable"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iItemFindBonus`
+    pub fn item_find_bonus(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iItemFindBonus"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_bShieldEquipped`
+    pub fn shield_equipped(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bShieldEquipped"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iNextMeleeCrit`
+    pub fn next_melee_crit(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iNextMeleeCrit"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iDecapitations`
+    pub fn decapitations(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDecapitations"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iRevengeCrits`
+    pub fn revenge_crits(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iRevengeCrits"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iDisguiseBody`
+    pub fn disguise_body(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDisguiseBody"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_hCarriedObject`
+    pub fn carried_object(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hCarriedObject"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_bCarryingObject`
+    pub fn carrying_object(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bCarryingObject"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flNextNoiseMakerTime`
+    pub fn next_noise_maker_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flNextNoiseMakerTime"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iKillCountSinceLastDeploy`
+    pub fn kill_count_since_last_deploy(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iKillCountSinceLastDeploy"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flFirstPrimaryAttack`
+    pub fn first_primary_attack(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flFirstPrimaryAttack"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flEnergyDrinkMeter`
+    pub fn energy_drink_meter(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flEnergyDrinkMeter"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flHypeMeter`
+    pub fn hype_meter(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flHypeMeter"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flChargeMeter`
+    pub fn charge_meter(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flChargeMeter"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flInvisChangeCompleteTime`
+    pub fn invis_change_complete_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flInvisChangeCompleteTime"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nDisguiseTeam`
+    pub fn disguise_team(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseTeam"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nDisguiseClass`
+    pub fn disguise_class(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseClass"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nMaskClass`
+    pub fn mask_class(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nMaskClass"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iDisguiseHealth`
+    pub fn disguise_health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDisguiseHealth"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_bFeignDeathReady`
+    pub fn feign_death_ready(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bFeignDeathReady"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_hDisguiseWeapon`
+    pub fn disguise_weapon(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hDisguiseWeapon"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nTeamTeleporterUsed`
+    pub fn team_teleporter_used(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nTeamTeleporterUsed"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flCloakMeter`
+    pub fn cloak_meter(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flCloakMeter"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_flSpyTranqBuffDuration`
+    pub fn spy_tranq_buff_duration(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flSpyTranqBuffDuration"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iTauntIndex`
+    pub fn taunt_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iTauntIndex"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iTauntConcept`
+    pub fn taunt_concept(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iTauntConcept"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nPlayerCondEx`
+    pub fn player_cond_ex(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_iStunIndex`
+    pub fn stun_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iStunIndex"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nHalloweenBombHeadStage`
+    pub fn halloween_bomb_head_stage(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nHalloweenBombHeadStage"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nPlayerCondEx2`
+    pub fn player_cond_ex_2(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx2"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_nPlayerCondEx3`
+    pub fn player_cond_ex_3(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx3"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_unTauntSourceItemID_Low`
+    pub fn taunt_source_item_id_low(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_unTauntSourceItemID_Low"),
+        )
+    }
+    /// `DT_TFPlayerShared.m_unTauntSourceItemID_High`
+    pub fn taunt_source_item_id_high(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_unTauntSourceItemID_High"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMaxsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive."player_object_array"`
+    pub fn player_object_array(&self) -> Option<&'a [SendPropValue]> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "\"player_object_array\""),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_bIsCoaching`
+    pub fn is_coaching(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_bIsCoaching"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_hCoach`
+    pub fn coach(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_hCoach"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_hStudent`
+    pub fn student(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_hStudent"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_nCurrency`
+    pub fn currency(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nCurrency"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_nExperienceLevel`
+    pub fn experience_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nExperienceLevel"),
+        )
+    }
+    /// `DT_TFLocalPlayerExclusive.m_nExperienceLevelProgress`
+    pub fn experience_level_progress(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nExperienceLevelProgress"),
+        )
+    }
+    /// `DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID"),
+        )
+    }
+    /// `DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated"),
+        )
+    }
+    /// `m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000"),
+        )
+    }
+    /// `m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001"),
+        )
+    }
+    /// `DT_AttributeManager.m_hOuter`
+    pub fn outer(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AttributeManager", "m_hOuter"),
+        )
+    }
+    /// `DT_AttributeManager.m_ProviderType`
+    pub fn provider_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AttributeManager", "m_ProviderType"),
+        )
+    }
+    /// `DT_AttributeManager.m_iReapplyProvisionParity`
+    pub fn reapply_provision_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AttributeManager", "m_iReapplyProvisionParity"),
+        )
+    }
+    /// `DT_TFSendHealersDataTable.m_nActiveWpnClip`
+    pub fn active_wpn_clip(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFSendHealersDataTable", "m_nActiveWpnClip"),
+        )
+    }
+    /// `m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002"),
+        )
+    }
+    /// `DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode"),
+        )
+    }
+    /// `DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects"),
+        )
+    }
+    /// `DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+        )
+    }
+    /// `DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup"),
+        )
+    }
+    /// `DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity"),
+        )
+    }
+    /// `DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance"),
+        )
+    }
+    /// `DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity"),
+        )
+    }
+    /// `DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity"),
+        )
+    }
+    /// `DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent"),
+        )
+    }
+    /// `DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment"),
+        )
+    }
+    /// `DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movetype"),
+        )
+    }
+    /// `DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone"),
+        )
+    }
+    /// `DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale"),
+        )
+    }
+    /// `DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub fn glow_enabled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled"),
+        )
+    }
+    /// `DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub fn active_weapon(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon"),
+        )
+    }
+    /// `DT_BasePlayer.m_hVehicle`
+    pub fn vehicle(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_hVehicle"),
+        )
+    }
+    /// `DT_BasePlayer.m_hUseEntity`
+    pub fn use_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_hUseEntity"),
+        )
+    }
+    /// `m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003"),
+        )
+    }
+    /// `DT_BasePlayer.m_lifeState`
+    pub fn life_state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_lifeState"),
+        )
+    }
+    /// `DT_BasePlayer.m_iBonusProgress`
+    pub fn bonus_progress(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_iBonusProgress"),
+        )
+    }
+    /// `DT_BasePlayer.m_iBonusChallenge`
+    pub fn bonus_challenge(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_iBonusChallenge"),
+        )
+    }
+    /// `DT_BasePlayer.m_flMaxspeed`
+    pub fn maxspeed(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_flMaxspeed"),
+        )
+    }
+    /// `m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "000"),
+        )
+    }
+    /// `DT_BasePlayer.m_iObserverMode`
+    pub fn observer_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_iObserverMode"),
+        )
+    }
+    /// `DT_BasePlayer.m_hObserverTarget`
+    pub fn observer_target(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_hObserverTarget"),
+        )
+    }
+    /// `DT_BasePlayer.m_iFOV`
+    pub fn fov(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_iFOV"),
+        )
+    }
+    /// `DT_BasePlayer.m_iFOVStart`
+    pub fn fov_start(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_iFOVStart"),
+        )
+    }
+    /// `DT_BasePlayer.m_flFOVTime`
+    pub fn fov_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_flFOVTime"),
+        )
+    }
+    /// `DT_BasePlayer.m_iDefaultFOV`
+    pub fn default_fov(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_iDefaultFOV"),
+        )
+    }
+    /// `DT_BasePlayer.m_hZoomOwner`
+    pub fn zoom_owner(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_hZoomOwner"),
+        )
+    }
+    /// `DT_BasePlayer.m_hViewModel`
+    pub fn view_model(&self) -> Option<&'a [SendPropValue]> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_hViewModel"),
+        )
+    }
+    /// `DT_BasePlayer.m_szLastPlaceName`
+    pub fn last_place_name(&self) -> Option<&'a str> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BasePlayer", "m_szLastPlaceName"),
+        )
+    }
+    /// `DT_TFPlayer.m_bSaveMeParity`
+    pub fn save_me_parity(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bSaveMeParity"),
+        )
+    }
+    /// `DT_TFPlayer.m_bIsMiniBoss`
+    pub fn is_mini_boss(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bIsMiniBoss"),
+        )
+    }
+    /// `DT_TFPlayer.m_bIsABot`
+    pub fn is_a_bot(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bIsABot"),
+        )
+    }
+    /// `DT_TFPlayer.m_nBotSkill`
+    pub fn bot_skill(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_nBotSkill"),
+        )
+    }
+    /// `DT_TFPlayer.m_nWaterLevel`
+    pub fn tf_player_water_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_nWaterLevel"),
+        )
+    }
+    /// `DT_TFPlayer.m_hRagdoll`
+    pub fn ragdoll(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_hRagdoll"),
+        )
+    }
+    /// `DT_TFPlayer.m_hItem`
+    pub fn item(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_hItem"),
+        )
+    }
+    /// `DT_TFPlayer.m_bAllowMoveDuringTaunt`
+    pub fn allow_move_during_taunt(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bAllowMoveDuringTaunt"),
+        )
+    }
+    /// `DT_TFPlayer.m_bIsReadyToHighFive`
+    pub fn is_ready_to_high_five(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bIsReadyToHighFive"),
+        )
+    }
+    /// `DT_TFPlayer.m_hHighFivePartner`
+    pub fn high_five_partner(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_hHighFivePartner"),
+        )
+    }
+    /// `DT_TFPlayer.m_nForceTauntCam`
+    pub fn force_taunt_cam(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_nForceTauntCam"),
+        )
+    }
+    /// `DT_TFPlayer.m_flTauntYaw`
+    pub fn taunt_yaw(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_flTauntYaw"),
+        )
+    }
+    /// `DT_TFPlayer.m_nActiveTauntSlot`
+    pub fn active_taunt_slot(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_nActiveTauntSlot"),
+        )
+    }
+    /// `DT_TFPlayer.m_iTauntItemDefIndex`
+    pub fn taunt_item_def_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_iTauntItemDefIndex"),
+        )
+    }
+    /// `DT_TFPlayer.m_flCurrentTauntMoveSpeed`
+    pub fn current_taunt_move_speed(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_flCurrentTauntMoveSpeed"),
+        )
+    }
+    /// `DT_TFPlayer.m_flVehicleReverseTime`
+    pub fn vehicle_reverse_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_flVehicleReverseTime"),
+        )
+    }
+    /// `DT_TFPlayer.m_bInPowerPlay`
+    pub fn in_power_play(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bInPowerPlay"),
+        )
+    }
+    /// `DT_TFPlayer.m_iSpawnCounter`
+    pub fn spawn_counter(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_iSpawnCounter"),
+        )
+    }
+    /// `DT_TFPlayer.m_bArenaSpectator`
+    pub fn arena_spectator(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bArenaSpectator"),
+        )
+    }
+    /// `DT_TFPlayer.m_flHeadScale`
+    pub fn head_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_flHeadScale"),
+        )
+    }
+    /// `DT_TFPlayer.m_flTorsoScale`
+    pub fn torso_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_flTorsoScale"),
+        )
+    }
+    /// `DT_TFPlayer.m_flHandScale`
+    pub fn hand_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_flHandScale"),
+        )
+    }
+    /// `DT_TFPlayer.m_bUseBossHealthBar`
+    pub fn use_boss_health_bar(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUseBossHealthBar"),
+        )
+    }
+    /// `DT_TFPlayer.m_bUsingVRHeadset`
+    pub fn using_vr_headset(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUsingVRHeadset"),
+        )
+    }
+    /// `DT_TFPlayer.m_bForcedSkin`
+    pub fn forced_skin(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bForcedSkin"),
+        )
+    }
+    /// `DT_TFPlayer.m_nForcedSkin`
+    pub fn tf_player_forced_skin(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_nForcedSkin"),
+        )
+    }
+    /// `DT_TFPlayer.m_flKartNextAvailableBoost`
+    pub fn kart_next_available_boost(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_flKartNextAvailableBoost"),
+        )
+    }
+    /// `DT_TFPlayer.m_iKartHealth`
+    pub fn kart_health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_iKartHealth"),
+        )
+    }
+    /// `DT_TFPlayer.m_iKartState`
+    pub fn kart_state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_iKartState"),
+        )
+    }
+    /// `DT_TFPlayer.m_hGrapplingHookTarget`
+    pub fn grappling_hook_target(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_hGrapplingHookTarget"),
+        )
+    }
+    /// `DT_TFPlayer.m_hSecondaryLastWeapon`
+    pub fn secondary_last_weapon(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_hSecondaryLastWeapon"),
+        )
+    }
+    /// `DT_TFPlayer.m_bUsingActionSlot`
+    pub fn using_action_slot(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUsingActionSlot"),
+        )
+    }
+}
+/// Typed view of the props of a `CTFPlayerResource` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CTFPlayerResourceView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CTFPlayerResourceView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CTFPlayerResource";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CTFPlayerResourceView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CTFPlayerResourceView { entity })
+    }
+    /// `m_iPing.000`
+    pub fn ping_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "000"))
+    }
+    /// `m_iPing.001`
+    pub fn ping_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "001"))
+    }
+    /// `m_iPing.002`
+    pub fn ping_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "002"))
+    }
+    /// `m_iPing.003`
+    pub fn ping_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "003"))
+    }
+    /// `m_iPing.004`
+    pub fn ping_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "004"))
+    }
+    /// `m_iPing.005`
+    pub fn ping_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "005"))
+    }
+    /// `m_iPing.006`
+    pub fn ping_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "006"))
+    }
+    /// `m_iPing.007`
+    pub fn ping_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "007"))
+    }
+    /// `m_iPing.008`
+    pub fn ping_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "008"))
+    }
+    /// `m_iPing.009`
+    pub fn ping_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "009"))
+    }
+    /// `m_iPing.010`
+    pub fn ping_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "010"))
+    }
+    /// `m_iPing.011`
+    pub fn ping_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "011"))
+    }
+    /// `m_iPing.012`
+    pub fn ping_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "012"))
+    }
+    /// `m_iPing.013`
+    pub fn ping_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "013"))
+    }
+    /// `m_iPing.014`
+    pub fn ping_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "014"))
+    }
+    /// `m_iPing.015`
+    pub fn ping_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "015"))
+    }
+    /// `m_iPing.016`
+    pub fn ping_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "016"))
+    }
+    /// `m_iPing.017`
+    pub fn ping_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "017"))
+    }
+    /// `m_iPing.018`
+    pub fn ping_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "018"))
+    }
+    /// `m_iPing.019`
+    pub fn ping_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "019"))
+    }
+    /// `m_iPing.020`
+    pub fn ping_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "020"))
+    }
+    /// `m_iPing.021`
+    pub fn ping_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "021"))
+    }
+    /// `m_iPing.022`
+    pub fn ping_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "022"))
+    }
+    /// `m_iPing.023`
+    pub fn ping_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "023"))
+    }
+    /// `m_iPing.024`
+    pub fn ping_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "024"))
+    }
+    /// `m_iPing.025`
+    pub fn ping_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "025"))
+    }
+    /// `m_iPing.026`
+    pub fn ping_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "026"))
+    }
+    /// `m_iPing.027`
+    pub fn ping_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "027"))
+    }
+    /// `m_iPing.028`
+    pub fn ping_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "028"))
+    }
+    /// `m_iPing.029`
+    pub fn ping_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "029"))
+    }
+    /// `m_iPing.030`
+    pub fn ping_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "030"))
+    }
+    /// `m_iPing.031`
+    pub fn ping_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "031"))
+    }
+    /// `m_iPing.032`
+    pub fn ping_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "032"))
+    }
+    /// `m_iPing.033`
+    pub fn ping_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iPing", "033"))
+    }
+    /// `m_iScore.000`
+    pub fn score_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "000"))
+    }
+    /// `m_iScore.001`
+    pub fn score_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "001"))
+    }
+    /// `m_iScore.002`
+    pub fn score_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "002"))
+    }
+    /// `m_iScore.003`
+    pub fn score_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "003"))
+    }
+    /// `m_iScore.004`
+    pub fn score_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "004"))
+    }
+    /// `m_iScore.005`
+    pub fn score_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "005"))
+    }
+    /// `m_iScore.006`
+    pub fn score_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "006"))
+    }
+    /// `m_iScore.007`
+    pub fn score_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "007"))
+    }
+    /// `m_iScore.008`
+    pub fn score_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "008"))
+    }
+    /// `m_iScore.009`
+    pub fn score_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "009"))
+    }
+    /// `m_iScore.010`
+    pub fn score_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "010"))
+    }
+    /// `m_iScore.011`
+    pub fn score_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "011"))
+    }
+    /// `m_iScore.012`
+    pub fn score_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "012"))
+    }
+    /// `m_iScore.013`
+    pub fn score_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "013"))
+    }
+    /// `m_iScore.014`
+    pub fn score_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "014"))
+    }
+    /// `m_iScore.015`
+    pub fn score_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "015"))
+    }
+    /// `m_iScore.016`
+    pub fn score_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "016"))
+    }
+    /// `m_iScore.017`
+    pub fn score_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "017"))
+    }
+    /// `m_iScore.018`
+    pub fn score_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "018"))
+    }
+    /// `m_iScore.019`
+    pub fn score_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "019"))
+    }
+    /// `m_iScore.020`
+    pub fn score_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "020"))
+    }
+    /// `m_iScore.021`
+    pub fn score_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "021"))
+    }
+    /// `m_iScore.022`
+    pub fn score_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "022"))
+    }
+    /// `m_iScore.023`
+    pub fn score_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "023"))
+    }
+    /// `m_iScore.024`
+    pub fn score_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "024"))
+    }
+    /// `m_iScore.025`
+    pub fn score_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "025"))
+    }
+    /// `m_iScore.026`
+    pub fn score_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "026"))
+    }
+    /// `m_iScore.027`
+    pub fn score_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "027"))
+    }
+    /// `m_iScore.028`
+    pub fn score_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "028"))
+    }
+    /// `m_iScore.029`
+    pub fn score_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "029"))
+    }
+    /// `m_iScore.030`
+    pub fn score_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "030"))
+    }
+    /// `m_iScore.031`
+    pub fn score_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "031"))
+    }
+    /// `m_iScore.032`
+    pub fn score_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "032"))
+    }
+    /// `m_iScore.033`
+    pub fn score_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iScore", "033"))
+    }
+    /// `m_iDeaths.000`
+    pub fn deaths_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "000"))
+    }
+    /// `m_iDeaths.001`
+    pub fn deaths_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "001"))
+    }
+    /// `m_iDeaths.002`
+    pub fn deaths_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "002"))
+    }
+    /// `m_iDeaths.003`
+    pub fn deaths_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "003"))
+    }
+    /// `m_iDeaths.004`
+    pub fn deaths_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "004"))
+    }
+    /// `m_iDeaths.005`
+    pub fn deaths_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "005"))
+    }
+    /// `m_iDeaths.006`
+    pub fn deaths_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "006"))
+    }
+    /// `m_iDeaths.007`
+    pub fn deaths_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "007"))
+    }
+    /// `m_iDeaths.008`
+    pub fn deaths_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "008"))
+    }
+    /// `m_iDeaths.009`
+    pub fn deaths_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "009"))
+    }
+    /// `m_iDeaths.010`
+    pub fn deaths_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "010"))
+    }
+    /// `m_iDeaths.011`
+    pub fn deaths_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "011"))
+    }
+    /// `m_iDeaths.012`
+    pub fn deaths_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "012"))
+    }
+    /// `m_iDeaths.013`
+    pub fn deaths_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "013"))
+    }
+    /// `m_iDeaths.014`
+    pub fn deaths_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "014"))
+    }
+    /// `m_iDeaths.015`
+    pub fn deaths_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "015"))
+    }
+    /// `m_iDeaths.016`
+    pub fn deaths_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "016"))
+    }
+    /// `m_iDeaths.017`
+    pub fn deaths_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "017"))
+    }
+    /// `m_iDeaths.018`
+    pub fn deaths_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "018"))
+    }
+    /// `m_iDeaths.019`
+    pub fn deaths_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "019"))
+    }
+    /// `m_iDeaths.020`
+    pub fn deaths_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "020"))
+    }
+    /// `m_iDeaths.021`
+    pub fn deaths_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "021"))
+    }
+    /// `m_iDeaths.022`
+    pub fn deaths_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "022"))
+    }
+    /// `m_iDeaths.023`
+    pub fn deaths_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "023"))
+    }
+    /// `m_iDeaths.024`
+    pub fn deaths_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "024"))
+    }
+    /// `m_iDeaths.025`
+    pub fn deaths_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "025"))
+    }
+    /// `m_iDeaths.026`
+    pub fn deaths_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "026"))
+    }
+    /// `m_iDeaths.027`
+    pub fn deaths_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "027"))
+    }
+    /// `m_iDeaths.028`
+    pub fn deaths_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "028"))
+    }
+    /// `m_iDeaths.029`
+    pub fn deaths_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "029"))
+    }
+    /// `m_iDeaths.030`
+    pub fn deaths_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "030"))
+    }
+    /// `m_iDeaths.031`
+    pub fn deaths_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "031"))
+    }
+    /// `m_iDeaths.032`
+    pub fn deaths_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "032"))
+    }
+    /// `m_iDeaths.033`
+    pub fn deaths_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDeaths", "033"))
+    }
+    /// `m_bConnected.000`
+    pub fn connected_000(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "000"))
+    }
+    /// `m_bConnected.001`
+    pub fn connected_001(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "001"))
+    }
+    /// `m_bConnected.002`
+    pub fn connected_002(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "002"))
+    }
+    /// `m_bConnected.003`
+    pub fn connected_003(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "003"))
+    }
+    /// `m_bConnected.004`
+    pub fn connected_004(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "004"))
+    }
+    /// `m_bConnected.005`
+    pub fn connected_005(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "005"))
+    }
+    /// `m_bConnected.006`
+    pub fn connected_006(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "006"))
+    }
+    /// `m_bConnected.007`
+    pub fn connected_007(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "007"))
+    }
+    /// `m_bConnected.008`
+    pub fn connected_008(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "008"))
+    }
+    /// `m_bConnected.009`
+    pub fn connected_009(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "009"))
+    }
+    /// `m_bConnected.010`
+    pub fn connected_010(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "010"))
+    }
+    /// `m_bConnected.011`
+    pub fn connected_011(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "011"))
+    }
+    /// `m_bConnected.012`
+    pub fn connected_012(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "012"))
+    }
+    /// `m_bConnected.013`
+    pub fn connected_013(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "013"))
+    }
+    /// `m_bConnected.014`
+    pub fn connected_014(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "014"))
+    }
+    /// `m_bConnected.015`
+    pub fn connected_015(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "015"))
+    }
+    /// `m_bConnected.016`
+    pub fn connected_016(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "016"))
+    }
+    /// `m_bConnected.017`
+    pub fn connected_017(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "017"))
+    }
+    /// `m_bConnected.018`
+    pub fn connected_018(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "018"))
+    }
+    /// `m_bConnected.019`
+    pub fn connected_019(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "019"))
+    }
+    /// `m_bConnected.020`
+    pub fn connected_020(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "020"))
+    }
+    /// `m_bConnected.021`
+    pub fn connected_021(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "021"))
+    }
+    /// `m_bConnected.022`
+    pub fn connected_022(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "022"))
+    }
+    /// `m_bConnected.023`
+    pub fn connected_023(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "023"))
+    }
+    /// `m_bConnected.024`
+    pub fn connected_024(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "024"))
+    }
+    /// `m_bConnected.025`
+    pub fn connected_025(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "025"))
+    }
+    /// `m_bConnected.026`
+    pub fn connected_026(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "026"))
+    }
+    /// `m_bConnected.027`
+    pub fn connected_027(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "027"))
+    }
+    /// `m_bConnected.028`
+    pub fn connected_028(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "028"))
+    }
+    /// `m_bConnected.029`
+    pub fn connected_029(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "029"))
+    }
+    /// `m_bConnected.030`
+    pub fn connected_030(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "030"))
+    }
+    /// `m_bConnected.031`
+    pub fn connected_031(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "031"))
+    }
+    /// `m_bConnected.032`
+    pub fn connected_032(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "032"))
+    }
+    /// `m_bConnected.033`
+    pub fn connected_033(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bConnected", "033"))
+    }
+    /// `m_iTeam.000`
+    pub fn team_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "000"))
+    }
+    /// `m_iTeam.001`
+    pub fn team_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "001"))
+    }
+    /// `m_iTeam.002`
+    pub fn team_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "002"))
+    }
+    /// `m_iTeam.003`
+    pub fn team_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "003"))
+    }
+    /// `m_iTeam.004`
+    pub fn team_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "004"))
+    }
+    /// `m_iTeam.005`
+    pub fn team_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "005"))
+    }
+    /// `m_iTeam.006`
+    pub fn team_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "006"))
+    }
+    /// `m_iTeam.007`
+    pub fn team_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "007"))
+    }
+    /// `m_iTeam.008`
+    pub fn team_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "008"))
+    }
+    /// `m_iTeam.009`
+    pub fn team_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "009"))
+    }
+    /// `m_iTeam.010`
+    pub fn team_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "010"))
+    }
+    /// `m_iTeam.011`
+    pub fn team_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "011"))
+    }
+    /// `m_iTeam.012`
+    pub fn team_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "012"))
+    }
+    /// `m_iTeam.013`
+    pub fn team_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "013"))
+    }
+    /// `m_iTeam.014`
+    pub fn team_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "014"))
+    }
+    /// `m_iTeam.015`
+    pub fn team_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "015"))
+    }
+    /// `m_iTeam.016`
+    pub fn team_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "016"))
+    }
+    /// `m_iTeam.017`
+    pub fn team_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "017"))
+    }
+    /// `m_iTeam.018`
+    pub fn team_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "018"))
+    }
+    /// `m_iTeam.019`
+    pub fn team_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "019"))
+    }
+    /// `m_iTeam.020`
+    pub fn team_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "020"))
+    }
+    /// `m_iTeam.021`
+    pub fn team_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "021"))
+    }
+    /// `m_iTeam.022`
+    pub fn team_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "022"))
+    }
+    /// `m_iTeam.023`
+    pub fn team_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "023"))
+    }
+    /// `m_iTeam.024`
+    pub fn team_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "024"))
+    }
+    /// `m_iTeam.025`
+    pub fn team_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "025"))
+    }
+    /// `m_iTeam.026`
+    pub fn team_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "026"))
+    }
+    /// `m_iTeam.027`
+    pub fn team_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "027"))
+    }
+    /// `m_iTeam.028`
+    pub fn team_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "028"))
+    }
+    /// `m_iTeam.029`
+    pub fn team_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "029"))
+    }
+    /// `m_iTeam.030`
+    pub fn team_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "030"))
+    }
+    /// `m_iTeam.031`
+    pub fn team_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "031"))
+    }
+    /// `m_iTeam.032`
+    pub fn team_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "032"))
+    }
+    /// `m_iTeam.033`
+    pub fn team_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTeam", "033"))
+    }
+    /// `m_bAlive.000`
+    pub fn alive_000(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "000"))
+    }
+    /// `m_bAlive.001`
+    pub fn alive_001(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "001"))
+    }
+    /// `m_bAlive.002`
+    pub fn alive_002(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "002"))
+    }
+    /// `m_bAlive.003`
+    pub fn alive_003(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "003"))
+    }
+    /// `m_bAlive.004`
+    pub fn alive_004(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "004"))
+    }
+    /// `m_bAlive.005`
+    pub fn alive_005(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "005"))
+    }
+    /// `m_bAlive.006`
+    pub fn alive_006(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "006"))
+    }
+    /// `m_bAlive.007`
+    pub fn alive_007(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "007"))
+    }
+    /// `m_bAlive.008`
+    pub fn alive_008(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "008"))
+    }
+    /// `m_bAlive.009`
+    pub fn alive_009(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "009"))
+    }
+    /// `m_bAlive.010`
+    pub fn alive_010(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "010"))
+    }
+    /// `m_bAlive.011`
+    pub fn alive_011(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "011"))
+    }
+    /// `m_bAlive.012`
+    pub fn alive_012(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "012"))
+    }
+    /// `m_bAlive.013`
+    pub fn alive_013(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "013"))
+    }
+    /// `m_bAlive.014`
+    pub fn alive_014(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "014"))
+    }
+    /// `m_bAlive.015`
+    pub fn alive_015(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "015"))
+    }
+    /// `m_bAlive.016`
+    pub fn alive_016(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "016"))
+    }
+    /// `m_bAlive.017`
+    pub fn alive_017(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "017"))
+    }
+    /// `m_bAlive.018`
+    pub fn alive_018(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "018"))
+    }
+    /// `m_bAlive.019`
+    pub fn alive_019(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "019"))
+    }
+    /// `m_bAlive.020`
+    pub fn alive_020(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "020"))
+    }
+    /// `m_bAlive.021`
+    pub fn alive_021(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "021"))
+    }
+    /// `m_bAlive.022`
+    pub fn alive_022(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "022"))
+    }
+    /// `m_bAlive.023`
+    pub fn alive_023(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "023"))
+    }
+    /// `m_bAlive.024`
+    pub fn alive_024(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "024"))
+    }
+    /// `m_bAlive.025`
+    pub fn alive_025(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "025"))
+    }
+    /// `m_bAlive.026`
+    pub fn alive_026(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "026"))
+    }
+    /// `m_bAlive.027`
+    pub fn alive_027(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "027"))
+    }
+    /// `m_bAlive.028`
+    pub fn alive_028(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "028"))
+    }
+    /// `m_bAlive.029`
+    pub fn alive_029(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "029"))
+    }
+    /// `m_bAlive.030`
+    pub fn alive_030(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "030"))
+    }
+    /// `m_bAlive.031`
+    pub fn alive_031(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "031"))
+    }
+    /// `m_bAlive.032`
+    pub fn alive_032(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "032"))
+    }
+    /// `m_bAlive.033`
+    pub fn alive_033(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bAlive", "033"))
+    }
+    /// `m_iHealth.000`
+    pub fn health_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "000"))
+    }
+    /// `m_iHealth.001`
+    pub fn health_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "001"))
+    }
+    /// `m_iHealth.002`
+    pub fn health_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "002"))
+    }
+    /// `m_iHealth.003`
+    pub fn health_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "003"))
+    }
+    /// `m_iHealth.004`
+    pub fn health_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "004"))
+    }
+    /// `m_iHealth.005`
+    pub fn health_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "005"))
+    }
+    /// `m_iHealth.006`
+    pub fn health_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "006"))
+    }
+    /// `m_iHealth.007`
+    pub fn health_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "007"))
+    }
+    /// `m_iHealth.008`
+    pub fn health_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "008"))
+    }
+    /// `m_iHealth.009`
+    pub fn health_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "009"))
+    }
+    /// `m_iHealth.010`
+    pub fn health_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "010"))
+    }
+    /// `m_iHealth.011`
+    pub fn health_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "011"))
+    }
+    /// `m_iHealth.012`
+    pub fn health_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "012"))
+    }
+    /// `m_iHealth.013`
+    pub fn health_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "013"))
+    }
+    /// `m_iHealth.014`
+    pub fn health_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "014"))
+    }
+    /// `m_iHealth.015`
+    pub fn health_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "015"))
+    }
+    /// `m_iHealth.016`
+    pub fn health_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "016"))
+    }
+    /// `m_iHealth.017`
+    pub fn health_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "017"))
+    }
+    /// `m_iHealth.018`
+    pub fn health_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "018"))
+    }
+    /// `m_iHealth.019`
+    pub fn health_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "019"))
+    }
+    /// `m_iHealth.020`
+    pub fn health_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "020"))
+    }
+    /// `m_iHealth.021`
+    pub fn health_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "021"))
+    }
+    /// `m_iHealth.022`
+    pub fn health_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "022"))
+    }
+    /// `m_iHealth.023`
+    pub fn health_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "023"))
+    }
+    /// `m_iHealth.024`
+    pub fn health_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "024"))
+    }
+    /// `m_iHealth.025`
+    pub fn health_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "025"))
+    }
+    /// `m_iHealth.026`
+    pub fn health_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "026"))
+    }
+    /// `m_iHealth.027`
+    pub fn health_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "027"))
+    }
+    /// `m_iHealth.028`
+    pub fn health_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "028"))
+    }
+    /// `m_iHealth.029`
+    pub fn health_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "029"))
+    }
+    /// `m_iHealth.030`
+    pub fn health_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "030"))
+    }
+    /// `m_iHealth.031`
+    pub fn health_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "031"))
+    }
+    /// `m_iHealth.032`
+    pub fn health_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "032"))
+    }
+    /// `m_iHealth.033`
+    pub fn health_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealth", "033"))
+    }
+    /// `m_iTotalScore.000`
+    pub fn total_score_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "000"))
+    }
+    /// `m_iTotalScore.001`
+    pub fn total_score_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "001"))
+    }
+    /// `m_iTotalScore.002`
+    pub fn total_score_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "002"))
+    }
+    /// `m_iTotalScore.003`
+    pub fn total_score_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "003"))
+    }
+    /// `m_iTotalScore.004`
+    pub fn total_score_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "004"))
+    }
+    /// `m_iTotalScore.005`
+    pub fn total_score_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "005"))
+    }
+    /// `m_iTotalScore.006`
+    pub fn total_score_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "006"))
+    }
+    /// `m_iTotalScore.007`
+    pub fn total_score_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "007"))
+    }
+    /// `m_iTotalScore.008`
+    pub fn total_score_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "008"))
+    }
+    /// `m_iTotalScore.009`
+    pub fn total_score_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "009"))
+    }
+    /// `m_iTotalScore.010`
+    pub fn total_score_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "010"))
+    }
+    /// `m_iTotalScore.011`
+    pub fn total_score_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "011"))
+    }
+    /// `m_iTotalScore.012`
+    pub fn total_score_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "012"))
+    }
+    /// `m_iTotalScore.013`
+    pub fn total_score_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "013"))
+    }
+    /// `m_iTotalScore.014`
+    pub fn total_score_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "014"))
+    }
+    /// `m_iTotalScore.015`
+    pub fn total_score_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "015"))
+    }
+    /// `m_iTotalScore.016`
+    pub fn total_score_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "016"))
+    }
+    /// `m_iTotalScore.017`
+    pub fn total_score_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "017"))
+    }
+    /// `m_iTotalScore.018`
+    pub fn total_score_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "018"))
+    }
+    /// `m_iTotalScore.019`
+    pub fn total_score_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "019"))
+    }
+    /// `m_iTotalScore.020`
+    pub fn total_score_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "020"))
+    }
+    /// `m_iTotalScore.021`
+    pub fn total_score_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "021"))
+    }
+    /// `m_iTotalScore.022`
+    pub fn total_score_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "022"))
+    }
+    /// `m_iTotalScore.023`
+    pub fn total_score_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "023"))
+    }
+    /// `m_iTotalScore.024`
+    pub fn total_score_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "024"))
+    }
+    /// `m_iTotalScore.025`
+    pub fn total_score_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "025"))
+    }
+    /// `m_iTotalScore.026`
+    pub fn total_score_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "026"))
+    }
+    /// `m_iTotalScore.027`
+    pub fn total_score_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "027"))
+    }
+    /// `m_iTotalScore.028`
+    pub fn total_score_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "028"))
+    }
+    /// `m_iTotalScore.029`
+    pub fn total_score_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "029"))
+    }
+    /// `m_iTotalScore.030`
+    pub fn total_score_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "030"))
+    }
+    /// `m_iTotalScore.031`
+    pub fn total_score_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "031"))
+    }
+    /// `m_iTotalScore.032`
+    pub fn total_score_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "032"))
+    }
+    /// `m_iTotalScore.033`
+    pub fn total_score_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iTotalScore", "033"))
+    }
+    /// `m_iMaxHealth.000`
+    pub fn max_health_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "000"))
+    }
+    /// `m_iMaxHealth.001`
+    pub fn max_health_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "001"))
+    }
+    /// `m_iMaxHealth.002`
+    pub fn max_health_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "002"))
+    }
+    /// `m_iMaxHealth.003`
+    pub fn max_health_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "003"))
+    }
+    /// `m_iMaxHealth.004`
+    pub fn max_health_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "004"))
+    }
+    /// `m_iMaxHealth.005`
+    pub fn max_health_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "005"))
+    }
+    /// `m_iMaxHealth.006`
+    pub fn max_health_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "006"))
+    }
+    /// `m_iMaxHealth.007`
+    pub fn max_health_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "007"))
+    }
+    /// `m_iMaxHealth.008`
+    pub fn max_health_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "008"))
+    }
+    /// `m_iMaxHealth.009`
+    pub fn max_health_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "009"))
+    }
+    /// `m_iMaxHealth.010`
+    pub fn max_health_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "010"))
+    }
+    /// `m_iMaxHealth.011`
+    pub fn max_health_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "011"))
+    }
+    /// `m_iMaxHealth.012`
+    pub fn max_health_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "012"))
+    }
+    /// `m_iMaxHealth.013`
+    pub fn max_health_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "013"))
+    }
+    /// `m_iMaxHealth.014`
+    pub fn max_health_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "014"))
+    }
+    /// `m_iMaxHealth.015`
+    pub fn max_health_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "015"))
+    }
+    /// `m_iMaxHealth.016`
+    pub fn max_health_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "016"))
+    }
+    /// `m_iMaxHealth.017`
+    pub fn max_health_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "017"))
+    }
+    /// `m_iMaxHealth.018`
+    pub fn max_health_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "018"))
+    }
+    /// `m_iMaxHealth.019`
+    pub fn max_health_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "019"))
+    }
+    /// `m_iMaxHealth.020`
+    pub fn max_health_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "020"))
+    }
+    /// `m_iMaxHealth.021`
+    pub fn max_health_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "021"))
+    }
+    /// `m_iMaxHealth.022`
+    pub fn max_health_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "022"))
+    }
+    /// `m_iMaxHealth.023`
+    pub fn max_health_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "023"))
+    }
+    /// `m_iMaxHealth.024`
+    pub fn max_health_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "024"))
+    }
+    /// `m_iMaxHealth.025`
+    pub fn max_health_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "025"))
+    }
+    /// `m_iMaxHealth.026`
+    pub fn max_health_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "026"))
+    }
+    /// `m_iMaxHealth.027`
+    pub fn max_health_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "027"))
+    }
+    /// `m_iMaxHealth.028`
+    pub fn max_health_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "028"))
+    }
+    /// `m_iMaxHealth.029`
+    pub fn max_health_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "029"))
+    }
+    /// `m_iMaxHealth.030`
+    pub fn max_health_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "030"))
+    }
+    /// `m_iMaxHealth.031`
+    pub fn max_health_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "031"))
+    }
+    /// `m_iMaxHealth.032`
+    pub fn max_health_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "032"))
+    }
+    /// `m_iMaxHealth.033`
+    pub fn max_health_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iMaxHealth", "033"))
+    }
+    /// `m_iMaxBuffedHealth.000`
+    pub fn max_buffed_health_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "000"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.001`
+    pub fn max_buffed_health_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "001"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.002`
+    pub fn max_buffed_health_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "002"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.003`
+    pub fn max_buffed_health_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "003"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.004`
+    pub fn max_buffed_health_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "004"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.005`
+    pub fn max_buffed_health_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "005"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.006`
+    pub fn max_buffed_health_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "006"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.007`
+    pub fn max_buffed_health_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "007"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.008`
+    pub fn max_buffed_health_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "008"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.009`
+    pub fn max_buffed_health_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "009"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.010`
+    pub fn max_buffed_health_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "010"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.011`
+    pub fn max_buffed_health_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "011"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.012`
+    pub fn max_buffed_health_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "012"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.013`
+    pub fn max_buffed_health_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "013"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.014`
+    pub fn max_buffed_health_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "014"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.015`
+    pub fn max_buffed_health_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "015"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.016`
+    pub fn max_buffed_health_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "016"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.017`
+    pub fn max_buffed_health_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "017"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.018`
+    pub fn max_buffed_health_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "018"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.019`
+    pub fn max_buffed_health_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "019"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.020`
+    pub fn max_buffed_health_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "020"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.021`
+    pub fn max_buffed_health_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "021"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.022`
+    pub fn max_buffed_health_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "022"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.023`
+    pub fn max_buffed_health_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "023"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.024`
+    pub fn max_buffed_health_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "024"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.025`
+    pub fn max_buffed_health_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "025"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.026`
+    pub fn max_buffed_health_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "026"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.027`
+    pub fn max_buffed_health_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "027"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.028`
+    pub fn max_buffed_health_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "028"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.029`
+    pub fn max_buffed_health_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "029"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.030`
+    pub fn max_buffed_health_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "030"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.031`
+    pub fn max_buffed_health_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "031"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.032`
+    pub fn max_buffed_health_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "032"),
+        )
+    }
+    /// `m_iMaxBuffedHealth.033`
+    pub fn max_buffed_health_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iMaxBuffedHealth", "033"),
+        )
+    }
+    /// `m_iPlayerClass.000`
+    pub fn player_class_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "000"),
+        )
+    }
+    /// `m_iPlayerClass.001`
+    pub fn player_class_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "001"),
+        )
+    }
+    /// `m_iPlayerClass.002`
+    pub fn player_class_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "002"),
+        )
+    }
+    /// `m_iPlayerClass.003`
+    pub fn player_class_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "003"),
+        )
+    }
+    /// `m_iPlayerClass.004`
+    pub fn player_class_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "004"),
+        )
+    }
+    /// `m_iPlayerClass.005`
+    pub fn player_class_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "005"),
+        )
+    }
+    /// `m_iPlayerClass.006`
+    pub fn player_class_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "006"),
+        )
+    }
+    /// `m_iPlayerClass.007`
+    pub fn player_class_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "007"),
+        )
+    }
+    /// `m_iPlayerClass.008`
+    pub fn player_class_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "008"),
+        )
+    }
+    /// `m_iPlayerClass.009`
+    pub fn player_class_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "009"),
+        )
+    }
+    /// `m_iPlayerClass.010`
+    pub fn player_class_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "010"),
+        )
+    }
+    /// `m_iPlayerClass.011`
+    pub fn player_class_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "011"),
+        )
+    }
+    /// `m_iPlayerClass.012`
+    pub fn player_class_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "012"),
+        )
+    }
+    /// `m_iPlayerClass.013`
+    pub fn player_class_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "013"),
+        )
+    }
+    /// `m_iPlayerClass.014`
+    pub fn player_class_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "014"),
+        )
+    }
+    /// `m_iPlayerClass.015`
+    pub fn player_class_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "015"),
+        )
+    }
+    /// `m_iPlayerClass.016`
+    pub fn player_class_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "016"),
+        )
+    }
+    /// `m_iPlayerClass.017`
+    pub fn player_class_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "017"),
+        )
+    }
+    /// `m_iPlayerClass.018`
+    pub fn player_class_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "018"),
+        )
+    }
+    /// `m_iPlayerClass.019`
+    pub fn player_class_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "019"),
+        )
+    }
+    /// `m_iPlayerClass.020`
+    pub fn player_class_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "020"),
+        )
+    }
+    /// `m_iPlayerClass.021`
+    pub fn player_class_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "021"),
+        )
+    }
+    /// `m_iPlayerClass.022`
+    pub fn player_class_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "022"),
+        )
+    }
+    /// `m_iPlayerClass.023`
+    pub fn player_class_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "023"),
+        )
+    }
+    /// `m_iPlayerClass.024`
+    pub fn player_class_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "024"),
+        )
+    }
+    /// `m_iPlayerClass.025`
+    pub fn player_class_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "025"),
+        )
+    }
+    /// `m_iPlayerClass.026`
+    pub fn player_class_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "026"),
+        )
+    }
+    /// `m_iPlayerClass.027`
+    pub fn player_class_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "027"),
+        )
+    }
+    /// `m_iPlayerClass.028`
+    pub fn player_class_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "028"),
+        )
+    }
+    /// `m_iPlayerClass.029`
+    pub fn player_class_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "029"),
+        )
+    }
+    /// `m_iPlayerClass.030`
+    pub fn player_class_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "030"),
+        )
+    }
+    /// `m_iPlayerClass.031`
+    pub fn player_class_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "031"),
+        )
+    }
+    /// `m_iPlayerClass.032`
+    pub fn player_class_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "032"),
+        )
+    }
+    /// `m_iPlayerClass.033`
+    pub fn player_class_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerClass", "033"),
+        )
+    }
+    /// `m_bArenaSpectator.000`
+    pub fn arena_spectator_000(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "000"),
+        )
+    }
+    /// `m_bArenaSpectator.001`
+    pub fn arena_spectator_001(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "001"),
+        )
+    }
+    /// `m_bArenaSpectator.002`
+    pub fn arena_spectator_002(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "002"),
+        )
+    }
+    /// `m_bArenaSpectator.003`
+    pub fn arena_spectator_003(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "003"),
+        )
+    }
+    /// `m_bArenaSpectator.004`
+    pub fn arena_spectator_004(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "004"),
+        )
+    }
+    /// `m_bArenaSpectator.005`
+    pub fn arena_spectator_005(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "005"),
+        )
+    }
+    /// `m_bArenaSpectator.006`
+    pub fn arena_spectator_006(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "006"),
+        )
+    }
+    /// `m_bArenaSpectator.007`
+    pub fn arena_spectator_007(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "007"),
+        )
+    }
+    /// `m_bArenaSpectator.008`
+    pub fn arena_spectator_008(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "008"),
+        )
+    }
+    /// `m_bArenaSpectator.009`
+    pub fn arena_spectator_009(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "009"),
+        )
+    }
+    /// `m_bArenaSpectator.010`
+    pub fn arena_spectator_010(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "010"),
+        )
+    }
+    /// `m_bArenaSpectator.011`
+    pub fn arena_spectator_011(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "011"),
+        )
+    }
+    /// `m_bArenaSpectator.012`
+    pub fn arena_spectator_012(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "012"),
+        )
+    }
+    /// `m_bArenaSpectator.013`
+    pub fn arena_spectator_013(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "013"),
+        )
+    }
+    /// `m_bArenaSpectator.014`
+    pub fn arena_spectator_014(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "014"),
+        )
+    }
+    /// `m_bArenaSpectator.015`
+    pub fn arena_spectator_015(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "015"),
+        )
+    }
+    /// `m_bArenaSpectator.016`
+    pub fn arena_spectator_016(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "016"),
+        )
+    }
+    /// `m_bArenaSpectator.017`
+    pub fn arena_spectator_017(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "017"),
+        )
+    }
+    /// `m_bArenaSpectator.018`
+    pub fn arena_spectator_018(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "018"),
+        )
+    }
+    /// `m_bArenaSpectator.019`
+    pub fn arena_spectator_019(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "019"),
+        )
+    }
+    /// `m_bArenaSpectator.020`
+    pub fn arena_spectator_020(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "020"),
+        )
+    }
+    /// `m_bArenaSpectator.021`
+    pub fn arena_spectator_021(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "021"),
+        )
+    }
+    /// `m_bArenaSpectator.022`
+    pub fn arena_spectator_022(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "022"),
+        )
+    }
+    /// `m_bArenaSpectator.023`
+    pub fn arena_spectator_023(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "023"),
+        )
+    }
+    /// `m_bArenaSpectator.024`
+    pub fn arena_spectator_024(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "024"),
+        )
+    }
+    /// `m_bArenaSpectator.025`
+    pub fn arena_spectator_025(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "025"),
+        )
+    }
+    /// `m_bArenaSpectator.026`
+    pub fn arena_spectator_026(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "026"),
+        )
+    }
+    /// `m_bArenaSpectator.027`
+    pub fn arena_spectator_027(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "027"),
+        )
+    }
+    /// `m_bArenaSpectator.028`
+    pub fn arena_spectator_028(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "028"),
+        )
+    }
+    /// `m_bArenaSpectator.029`
+    pub fn arena_spectator_029(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "029"),
+        )
+    }
+    /// `m_bArenaSpectator.030`
+    pub fn arena_spectator_030(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "030"),
+        )
+    }
+    /// `m_bArenaSpectator.031`
+    pub fn arena_spectator_031(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "031"),
+        )
+    }
+    /// `m_bArenaSpectator.032`
+    pub fn arena_spectator_032(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "032"),
+        )
+    }
+    /// `m_bArenaSpectator.033`
+    pub fn arena_spectator_033(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bArenaSpectator", "033"),
+        )
+    }
+    /// `m_iActiveDominations.000`
+    pub fn active_dominations_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "000"),
+        )
+    }
+    /// `m_iActiveDominations.001`
+    pub fn active_dominations_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "001"),
+        )
+    }
+    /// `m_iActiveDominations.002`
+    pub fn active_dominations_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "002"),
+        )
+    }
+    /// `m_iActiveDominations.003`
+    pub fn active_dominations_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "003"),
+        )
+    }
+    /// `m_iActiveDominations.004`
+    pub fn active_dominations_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "004"),
+        )
+    }
+    /// `m_iActiveDominations.005`
+    pub fn active_dominations_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "005"),
+        )
+    }
+    /// `m_iActiveDominations.006`
+    pub fn active_dominations_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "006"),
+        )
+    }
+    /// `m_iActiveDominations.007`
+    pub fn active_dominations_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "007"),
+        )
+    }
+    /// `m_iActiveDominations.008`
+    pub fn active_dominations_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "008"),
+        )
+    }
+    /// `m_iActiveDominations.009`
+    pub fn active_dominations_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "009"),
+        )
+    }
+    /// `m_iActiveDominations.010`
+    pub fn active_dominations_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "010"),
+        )
+    }
+    /// `m_iActiveDominations.011`
+    pub fn active_dominations_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "011"),
+        )
+    }
+    /// `m_iActiveDominations.012`
+    pub fn active_dominations_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "012"),
+        )
+    }
+    /// `m_iActiveDominations.013`
+    pub fn active_dominations_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "013"),
+        )
+    }
+    /// `m_iActiveDominations.014`
+    pub fn active_dominations_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "014"),
+        )
+    }
+    /// `m_iActiveDominations.015`
+    pub fn active_dominations_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "015"),
+        )
+    }
+    /// `m_iActiveDominations.016`
+    pub fn active_dominations_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "016"),
+        )
+    }
+    /// `m_iActiveDominations.017`
+    pub fn active_dominations_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "017"),
+        )
+    }
+    /// `m_iActiveDominations.018`
+    pub fn active_dominations_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "018"),
+        )
+    }
+    /// `m_iActiveDominations.019`
+    pub fn active_dominations_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "019"),
+        )
+    }
+    /// `m_iActiveDominations.020`
+    pub fn active_dominations_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "020"),
+        )
+    }
+    /// `m_iActiveDominations.021`
+    pub fn active_dominations_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "021"),
+        )
+    }
+    /// `m_iActiveDominations.022`
+    pub fn active_dominations_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "022"),
+        )
+    }
+    /// `m_iActiveDominations.023`
+    pub fn active_dominations_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "023"),
+        )
+    }
+    /// `m_iActiveDominations.024`
+    pub fn active_dominations_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "024"),
+        )
+    }
+    /// `m_iActiveDominations.025`
+    pub fn active_dominations_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "025"),
+        )
+    }
+    /// `m_iActiveDominations.026`
+    pub fn active_dominations_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "026"),
+        )
+    }
+    /// `m_iActiveDominations.027`
+    pub fn active_dominations_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "027"),
+        )
+    }
+    /// `m_iActiveDominations.028`
+    pub fn active_dominations_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "028"),
+        )
+    }
+    /// `m_iActiveDominations.029`
+    pub fn active_dominations_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "029"),
+        )
+    }
+    /// `m_iActiveDominations.030`
+    pub fn active_dominations_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "030"),
+        )
+    }
+    /// `m_iActiveDominations.031`
+    pub fn active_dominations_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "031"),
+        )
+    }
+    /// `m_iActiveDominations.032`
+    pub fn active_dominations_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "032"),
+        )
+    }
+    /// `m_iActiveDominations.033`
+    pub fn active_dominations_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iActiveDominations", "033"),
+        )
+    }
+    /// `m_flNextRespawnTime.000`
+    pub fn next_respawn_time_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "000"),
+        )
+    }
+    /// `m_flNextRespawnTime.001`
+    pub fn next_respawn_time_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "001"),
+        )
+    }
+    /// `m_flNextRespawnTime.002`
+    pub fn next_respawn_time_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "002"),
+        )
+    }
+    /// `m_flNextRespawnTime.003`
+    pub fn next_respawn_time_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "003"),
+        )
+    }
+    /// `m_flNextRespawnTime.004`
+    pub fn next_respawn_time_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "004"),
+        )
+    }
+    /// `m_flNextRespawnTime.005`
+    pub fn next_respawn_time_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "005"),
+        )
+    }
+    /// `m_flNextRespawnTime.006`
+    pub fn next_respawn_time_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "006"),
+        )
+    }
+    /// `m_flNextRespawnTime.007`
+    pub fn next_respawn_time_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "007"),
+        )
+    }
+    /// `m_flNextRespawnTime.008`
+    pub fn next_respawn_time_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "008"),
+        )
+    }
+    /// `m_flNextRespawnTime.009`
+    pub fn next_respawn_time_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "009"),
+        )
+    }
+    /// `m_flNextRespawnTime.010`
+    pub fn next_respawn_time_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "010"),
+        )
+    }
+    /// `m_flNextRespawnTime.011`
+    pub fn next_respawn_time_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "011"),
+        )
+    }
+    /// `m_flNextRespawnTime.012`
+    pub fn next_respawn_time_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "012"),
+        )
+    }
+    /// `m_flNextRespawnTime.013`
+    pub fn next_respawn_time_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "013"),
+        )
+    }
+    /// `m_flNextRespawnTime.014`
+    pub fn next_respawn_time_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "014"),
+        )
+    }
+    /// `m_flNextRespawnTime.015`
+    pub fn next_respawn_time_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "015"),
+        )
+    }
+    /// `m_flNextRespawnTime.016`
+    pub fn next_respawn_time_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "016"),
+        )
+    }
+    /// `m_flNextRespawnTime.017`
+    pub fn next_respawn_time_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "017"),
+        )
+    }
+    /// `m_flNextRespawnTime.018`
+    pub fn next_respawn_time_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "018"),
+        )
+    }
+    /// `m_flNextRespawnTime.019`
+    pub fn next_respawn_time_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "019"),
+        )
+    }
+    /// `m_flNextRespawnTime.020`
+    pub fn next_respawn_time_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "020"),
+        )
+    }
+    /// `m_flNextRespawnTime.021`
+    pub fn next_respawn_time_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "021"),
+        )
+    }
+    /// `m_flNextRespawnTime.022`
+    pub fn next_respawn_time_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "022"),
+        )
+    }
+    /// `m_flNextRespawnTime.023`
+    pub fn next_respawn_time_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "023"),
+        )
+    }
+    /// `m_flNextRespawnTime.024`
+    pub fn next_respawn_time_024(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "024"),
+        )
+    }
+    /// `m_flNextRespawnTime.025`
+    pub fn next_respawn_time_025(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "025"),
+        )
+    }
+    /// `m_flNextRespawnTime.026`
+    pub fn next_respawn_time_026(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "026"),
+        )
+    }
+    /// `m_flNextRespawnTime.027`
+    pub fn next_respawn_time_027(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "027"),
+        )
+    }
+    /// `m_flNextRespawnTime.028`
+    pub fn next_respawn_time_028(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "028"),
+        )
+    }
+    /// `m_flNextRespawnTime.029`
+    pub fn next_respawn_time_029(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "029"),
+        )
+    }
+    /// `m_flNextRespawnTime.030`
+    pub fn next_respawn_time_030(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "030"),
+        )
+    }
+    /// `m_flNextRespawnTime.031`
+    pub fn next_respawn_time_031(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "031"),
+        )
+    }
+    /// `m_flNextRespawnTime.032`
+    pub fn next_respawn_time_032(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "032"),
+        )
+    }
+    /// `m_flNextRespawnTime.033`
+    pub fn next_respawn_time_033(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnTime", "033"),
+        )
+    }
+    /// `m_iChargeLevel.000`
+    pub fn charge_level_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "000"),
+        )
+    }
+    /// `m_iChargeLevel.001`
+    pub fn charge_level_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "001"),
+        )
+    }
+    /// `m_iChargeLevel.002`
+    pub fn charge_level_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "002"),
+        )
+    }
+    /// `m_iChargeLevel.003`
+    pub fn charge_level_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "003"),
+        )
+    }
+    /// `m_iChargeLevel.004`
+    pub fn charge_level_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "004"),
+        )
+    }
+    /// `m_iChargeLevel.005`
+    pub fn charge_level_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "005"),
+        )
+    }
+    /// `m_iChargeLevel.006`
+    pub fn charge_level_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "006"),
+        )
+    }
+    /// `m_iChargeLevel.007`
+    pub fn charge_level_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "007"),
+        )
+    }
+    /// `m_iChargeLevel.008`
+    pub fn charge_level_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "008"),
+        )
+    }
+    /// `m_iChargeLevel.009`
+    pub fn charge_level_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "009"),
+        )
+    }
+    /// `m_iChargeLevel.010`
+    pub fn charge_level_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "010"),
+        )
+    }
+    /// `m_iChargeLevel.011`
+    pub fn charge_level_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "011"),
+        )
+    }
+    /// `m_iChargeLevel.012`
+    pub fn charge_level_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "012"),
+        )
+    }
+    /// `m_iChargeLevel.013`
+    pub fn charge_level_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "013"),
+        )
+    }
+    /// `m_iChargeLevel.014`
+    pub fn charge_level_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "014"),
+        )
+    }
+    /// `m_iChargeLevel.015`
+    pub fn charge_level_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "015"),
+        )
+    }
+    /// `m_iChargeLevel.016`
+    pub fn charge_level_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "016"),
+        )
+    }
+    /// `m_iChargeLevel.017`
+    pub fn charge_level_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "017"),
+        )
+    }
+    /// `m_iChargeLevel.018`
+    pub fn charge_level_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "018"),
+        )
+    }
+    /// `m_iChargeLevel.019`
+    pub fn charge_level_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "019"),
+        )
+    }
+    /// `m_iChargeLevel.020`
+    pub fn charge_level_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "020"),
+        )
+    }
+    /// `m_iChargeLevel.021`
+    pub fn charge_level_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "021"),
+        )
+    }
+    /// `m_iChargeLevel.022`
+    pub fn charge_level_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "022"),
+        )
+    }
+    /// `m_iChargeLevel.023`
+    pub fn charge_level_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "023"),
+        )
+    }
+    /// `m_iChargeLevel.024`
+    pub fn charge_level_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "024"),
+        )
+    }
+    /// `m_iChargeLevel.025`
+    pub fn charge_level_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "025"),
+        )
+    }
+    /// `m_iChargeLevel.026`
+    pub fn charge_level_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "026"),
+        )
+    }
+    /// `m_iChargeLevel.027`
+    pub fn charge_level_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "027"),
+        )
+    }
+    /// `m_iChargeLevel.028`
+    pub fn charge_level_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "028"),
+        )
+    }
+    /// `m_iChargeLevel.029`
+    pub fn charge_level_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "029"),
+        )
+    }
+    /// `m_iChargeLevel.030`
+    pub fn charge_level_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "030"),
+        )
+    }
+    /// `m_iChargeLevel.031`
+    pub fn charge_level_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "031"),
+        )
+    }
+    /// `m_iChargeLevel.032`
+    pub fn charge_level_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "032"),
+        )
+    }
+    /// `m_iChargeLevel.033`
+    pub fn charge_level_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iChargeLevel", "033"),
+        )
+    }
+    /// `m_iDamage.000`
+    pub fn damage_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "000"))
+    }
+    /// `m_iDamage.001`
+    pub fn damage_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "001"))
+    }
+    /// `m_iDamage.002`
+    pub fn damage_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "002"))
+    }
+    /// `m_iDamage.003`
+    pub fn damage_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "003"))
+    }
+    /// `m_iDamage.004`
+    pub fn damage_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "004"))
+    }
+    /// `m_iDamage.005`
+    pub fn damage_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "005"))
+    }
+    /// `m_iDamage.006`
+    pub fn damage_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "006"))
+    }
+    /// `m_iDamage.007`
+    pub fn damage_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "007"))
+    }
+    /// `m_iDamage.008`
+    pub fn damage_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "008"))
+    }
+    /// `m_iDamage.009`
+    pub fn damage_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "009"))
+    }
+    /// `m_iDamage.010`
+    pub fn damage_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "010"))
+    }
+    /// `m_iDamage.011`
+    pub fn damage_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "011"))
+    }
+    /// `m_iDamage.012`
+    pub fn damage_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "012"))
+    }
+    /// `m_iDamage.013`
+    pub fn damage_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "013"))
+    }
+    /// `m_iDamage.014`
+    pub fn damage_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "014"))
+    }
+    /// `m_iDamage.015`
+    pub fn damage_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "015"))
+    }
+    /// `m_iDamage.016`
+    pub fn damage_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "016"))
+    }
+    /// `m_iDamage.017`
+    pub fn damage_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "017"))
+    }
+    /// `m_iDamage.018`
+    pub fn damage_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "018"))
+    }
+    /// `m_iDamage.019`
+    pub fn damage_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "019"))
+    }
+    /// `m_iDamage.020`
+    pub fn damage_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "020"))
+    }
+    /// `m_iDamage.021`
+    pub fn damage_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "021"))
+    }
+    /// `m_iDamage.022`
+    pub fn damage_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "022"))
+    }
+    /// `m_iDamage.023`
+    pub fn damage_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "023"))
+    }
+    /// `m_iDamage.024`
+    pub fn damage_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "024"))
+    }
+    /// `m_iDamage.025`
+    pub fn damage_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "025"))
+    }
+    /// `m_iDamage.026`
+    pub fn damage_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "026"))
+    }
+    /// `m_iDamage.027`
+    pub fn damage_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "027"))
+    }
+    /// `m_iDamage.028`
+    pub fn damage_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "028"))
+    }
+    /// `m_iDamage.029`
+    pub fn damage_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "029"))
+    }
+    /// `m_iDamage.030`
+    pub fn damage_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "030"))
+    }
+    /// `m_iDamage.031`
+    pub fn damage_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "031"))
+    }
+    /// `m_iDamage.032`
+    pub fn damage_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "032"))
+    }
+    /// `m_iDamage.033`
+    pub fn damage_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamage", "033"))
+    }
+    /// `m_iDamageAssist.000`
+    pub fn damage_assist_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "000"),
+        )
+    }
+    /// `m_iDamageAssist.001`
+    pub fn damage_assist_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "001"),
+        )
+    }
+    /// `m_iDamageAssist.002`
+    pub fn damage_assist_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "002"),
+        )
+    }
+    /// `m_iDamageAssist.003`
+    pub fn damage_assist_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "003"),
+        )
+    }
+    /// `m_iDamageAssist.004`
+    pub fn damage_assist_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "004"),
+        )
+    }
+    /// `m_iDamageAssist.005`
+    pub fn damage_assist_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "005"),
+        )
+    }
+    /// `m_iDamageAssist.006`
+    pub fn damage_assist_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "006"),
+        )
+    }
+    /// `m_iDamageAssist.007`
+    pub fn damage_assist_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "007"),
+        )
+    }
+    /// `m_iDamageAssist.008`
+    pub fn damage_assist_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "008"),
+        )
+    }
+    /// `m_iDamageAssist.009`
+    pub fn damage_assist_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "009"),
+        )
+    }
+    /// `m_iDamageAssist.010`
+    pub fn damage_assist_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "010"),
+        )
+    }
+    /// `m_iDamageAssist.011`
+    pub fn damage_assist_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "011"),
+        )
+    }
+    /// `m_iDamageAssist.012`
+    pub fn damage_assist_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "012"),
+        )
+    }
+    /// `m_iDamageAssist.013`
+    pub fn damage_assist_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "013"),
+        )
+    }
+    /// `m_iDamageAssist.014`
+    pub fn damage_assist_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "014"),
+        )
+    }
+    /// `m_iDamageAssist.015`
+    pub fn damage_assist_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "015"),
+        )
+    }
+    /// `m_iDamageAssist.016`
+    pub fn damage_assist_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "016"),
+        )
+    }
+    /// `m_iDamageAssist.017`
+    pub fn damage_assist_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "017"),
+        )
+    }
+    /// `m_iDamageAssist.018`
+    pub fn damage_assist_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "018"),
+        )
+    }
+    /// `m_iDamageAssist.019`
+    pub fn damage_assist_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "019"),
+        )
+    }
+    /// `m_iDamageAssist.020`
+    pub fn damage_assist_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "020"),
+        )
+    }
+    /// `m_iDamageAssist.021`
+    pub fn damage_assist_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "021"),
+        )
+    }
+    /// `m_iDamageAssist.022`
+    pub fn damage_assist_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "022"),
+        )
+    }
+    /// `m_iDamageAssist.023`
+    pub fn damage_assist_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "023"),
+        )
+    }
+    /// `m_iDamageAssist.024`
+    pub fn damage_assist_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "024"),
+        )
+    }
+    /// `m_iDamageAssist.025`
+    pub fn damage_assist_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "025"),
+        )
+    }
+    /// `m_iDamageAssist.026`
+    pub fn damage_assist_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "026"),
+        )
+    }
+    /// `m_iDamageAssist.027`
+    pub fn damage_assist_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "027"),
+        )
+    }
+    /// `m_iDamageAssist.028`
+    pub fn damage_assist_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "028"),
+        )
+    }
+    /// `m_iDamageAssist.029`
+    pub fn damage_assist_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "029"),
+        )
+    }
+    /// `m_iDamageAssist.030`
+    pub fn damage_assist_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "030"),
+        )
+    }
+    /// `m_iDamageAssist.031`
+    pub fn damage_assist_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "031"),
+        )
+    }
+    /// `m_iDamageAssist.032`
+    pub fn damage_assist_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "032"),
+        )
+    }
+    /// `m_iDamageAssist.033`
+    pub fn damage_assist_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageAssist", "033"),
+        )
+    }
+    /// `m_iDamageBoss.000`
+    pub fn damage_boss_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "000"))
+    }
+    /// `m_iDamageBoss.001`
+    pub fn damage_boss_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "001"))
+    }
+    /// `m_iDamageBoss.002`
+    pub fn damage_boss_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "002"))
+    }
+    /// `m_iDamageBoss.003`
+    pub fn damage_boss_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "003"))
+    }
+    /// `m_iDamageBoss.004`
+    pub fn damage_boss_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "004"))
+    }
+    /// `m_iDamageBoss.005`
+    pub fn damage_boss_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "005"))
+    }
+    /// `m_iDamageBoss.006`
+    pub fn damage_boss_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "006"))
+    }
+    /// `m_iDamageBoss.007`
+    pub fn damage_boss_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "007"))
+    }
+    /// `m_iDamageBoss.008`
+    pub fn damage_boss_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "008"))
+    }
+    /// `m_iDamageBoss.009`
+    pub fn damage_boss_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "009"))
+    }
+    /// `m_iDamageBoss.010`
+    pub fn damage_boss_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "010"))
+    }
+    /// `m_iDamageBoss.011`
+    pub fn damage_boss_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "011"))
+    }
+    /// `m_iDamageBoss.012`
+    pub fn damage_boss_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "012"))
+    }
+    /// `m_iDamageBoss.013`
+    pub fn damage_boss_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "013"))
+    }
+    /// `m_iDamageBoss.014`
+    pub fn damage_boss_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "014"))
+    }
+    /// `m_iDamageBoss.015`
+    pub fn damage_boss_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "015"))
+    }
+    /// `m_iDamageBoss.016`
+    pub fn damage_boss_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "016"))
+    }
+    /// `m_iDamageBoss.017`
+    pub fn damage_boss_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "017"))
+    }
+    /// `m_iDamageBoss.018`
+    pub fn damage_boss_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "018"))
+    }
+    /// `m_iDamageBoss.019`
+    pub fn damage_boss_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "019"))
+    }
+    /// `m_iDamageBoss.020`
+    pub fn damage_boss_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "020"))
+    }
+    /// `m_iDamageBoss.021`
+    pub fn damage_boss_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "021"))
+    }
+    /// `m_iDamageBoss.022`
+    pub fn damage_boss_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "022"))
+    }
+    /// `m_iDamageBoss.023`
+    pub fn damage_boss_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "023"))
+    }
+    /// `m_iDamageBoss.024`
+    pub fn damage_boss_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "024"))
+    }
+    /// `m_iDamageBoss.025`
+    pub fn damage_boss_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "025"))
+    }
+    /// `m_iDamageBoss.026`
+    pub fn damage_boss_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "026"))
+    }
+    /// `m_iDamageBoss.027`
+    pub fn damage_boss_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "027"))
+    }
+    /// `m_iDamageBoss.028`
+    pub fn damage_boss_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "028"))
+    }
+    /// `m_iDamageBoss.029`
+    pub fn damage_boss_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "029"))
+    }
+    /// `m_iDamageBoss.030`
+    pub fn damage_boss_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "030"))
+    }
+    /// `m_iDamageBoss.031`
+    pub fn damage_boss_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "031"))
+    }
+    /// `m_iDamageBoss.032`
+    pub fn damage_boss_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "032"))
+    }
+    /// `m_iDamageBoss.033`
+    pub fn damage_boss_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iDamageBoss", "033"))
+    }
+    /// `m_iHealing.000`
+    pub fn healing_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "000"))
+    }
+    /// `m_iHealing.001`
+    pub fn healing_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "001"))
+    }
+    /// `m_iHealing.002`
+    pub fn healing_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "002"))
+    }
+    /// `m_iHealing.003`
+    pub fn healing_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "003"))
+    }
+    /// `m_iHealing.004`
+    pub fn healing_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "004"))
+    }
+    /// `m_iHealing.005`
+    pub fn healing_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "005"))
+    }
+    /// `m_iHealing.006`
+    pub fn healing_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "006"))
+    }
+    /// `m_iHealing.007`
+    pub fn healing_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "007"))
+    }
+    /// `m_iHealing.008`
+    pub fn healing_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "008"))
+    }
+    /// `m_iHealing.009`
+    pub fn healing_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "009"))
+    }
+    /// `m_iHealing.010`
+    pub fn healing_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "010"))
+    }
+    /// `m_iHealing.011`
+    pub fn healing_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "011"))
+    }
+    /// `m_iHealing.012`
+    pub fn healing_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "012"))
+    }
+    /// `m_iHealing.013`
+    pub fn healing_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "013"))
+    }
+    /// `m_iHealing.014`
+    pub fn healing_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "014"))
+    }
+    /// `m_iHealing.015`
+    pub fn healing_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "015"))
+    }
+    /// `m_iHealing.016`
+    pub fn healing_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "016"))
+    }
+    /// `m_iHealing.017`
+    pub fn healing_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "017"))
+    }
+    /// `m_iHealing.018`
+    pub fn healing_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "018"))
+    }
+    /// `m_iHealing.019`
+    pub fn healing_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "019"))
+    }
+    /// `m_iHealing.020`
+    pub fn healing_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "020"))
+    }
+    /// `m_iHealing.021`
+    pub fn healing_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "021"))
+    }
+    /// `m_iHealing.022`
+    pub fn healing_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "022"))
+    }
+    /// `m_iHealing.023`
+    pub fn healing_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "023"))
+    }
+    /// `m_iHealing.024`
+    pub fn healing_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "024"))
+    }
+    /// `m_iHealing.025`
+    pub fn healing_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "025"))
+    }
+    /// `m_iHealing.026`
+    pub fn healing_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "026"))
+    }
+    /// `m_iHealing.027`
+    pub fn healing_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "027"))
+    }
+    /// `m_iHealing.028`
+    pub fn healing_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "028"))
+    }
+    /// `m_iHealing.029`
+    pub fn healing_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "029"))
+    }
+    /// `m_iHealing.030`
+    pub fn healing_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "030"))
+    }
+    /// `m_iHealing.031`
+    pub fn healing_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "031"))
+    }
+    /// `m_iHealing.032`
+    pub fn healing_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "032"))
+    }
+    /// `m_iHealing.033`
+    pub fn healing_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iHealing", "033"))
+    }
+    /// `m_iHealingAssist.000`
+    pub fn healing_assist_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "000"),
+        )
+    }
+    /// `m_iHealingAssist.001`
+    pub fn healing_assist_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "001"),
+        )
+    }
+    /// `m_iHealingAssist.002`
+    pub fn healing_assist_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "002"),
+        )
+    }
+    /// `m_iHealingAssist.003`
+    pub fn healing_assist_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "003"),
+        )
+    }
+    /// `m_iHealingAssist.004`
+    pub fn healing_assist_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "004"),
+        )
+    }
+    /// `m_iHealingAssist.005`
+    pub fn healing_assist_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "005"),
+        )
+    }
+    /// `m_iHealingAssist.006`
+    pub fn healing_assist_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "006"),
+        )
+    }
+    /// `m_iHealingAssist.007`
+    pub fn healing_assist_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "007"),
+        )
+    }
+    /// `m_iHealingAssist.008`
+    pub fn healing_assist_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "008"),
+        )
+    }
+    /// `m_iHealingAssist.009`
+    pub fn healing_assist_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "009"),
+        )
+    }
+    /// `m_iHealingAssist.010`
+    pub fn healing_assist_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "010"),
+        )
+    }
+    /// `m_iHealingAssist.011`
+    pub fn healing_assist_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "011"),
+        )
+    }
+    /// `m_iHealingAssist.012`
+    pub fn healing_assist_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "012"),
+        )
+    }
+    /// `m_iHealingAssist.013`
+    pub fn healing_assist_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "013"),
+        )
+    }
+    /// `m_iHealingAssist.014`
+    pub fn healing_assist_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "014"),
+        )
+    }
+    /// `m_iHealingAssist.015`
+    pub fn healing_assist_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "015"),
+        )
+    }
+    /// `m_iHealingAssist.016`
+    pub fn healing_assist_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "016"),
+        )
+    }
+    /// `m_iHealingAssist.017`
+    pub fn healing_assist_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "017"),
+        )
+    }
+    /// `m_iHealingAssist.018`
+    pub fn healing_assist_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "018"),
+        )
+    }
+    /// `m_iHealingAssist.019`
+    pub fn healing_assist_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "019"),
+        )
+    }
+    /// `m_iHealingAssist.020`
+    pub fn healing_assist_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "020"),
+        )
+    }
+    /// `m_iHealingAssist.021`
+    pub fn healing_assist_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "021"),
+        )
+    }
+    /// `m_iHealingAssist.022`
+    pub fn healing_assist_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "022"),
+        )
+    }
+    /// `m_iHealingAssist.023`
+    pub fn healing_assist_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "023"),
+        )
+    }
+    /// `m_iHealingAssist.024`
+    pub fn healing_assist_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "024"),
+        )
+    }
+    /// `m_iHealingAssist.025`
+    pub fn healing_assist_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "025"),
+        )
+    }
+    /// `m_iHealingAssist.026`
+    pub fn healing_assist_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "026"),
+        )
+    }
+    /// `m_iHealingAssist.027`
+    pub fn healing_assist_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "027"),
+        )
+    }
+    /// `m_iHealingAssist.028`
+    pub fn healing_assist_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "028"),
+        )
+    }
+    /// `m_iHealingAssist.029`
+    pub fn healing_assist_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "029"),
+        )
+    }
+    /// `m_iHealingAssist.030`
+    pub fn healing_assist_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "030"),
+        )
+    }
+    /// `m_iHealingAssist.031`
+    pub fn healing_assist_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "031"),
+        )
+    }
+    /// `m_iHealingAssist.032`
+    pub fn healing_assist_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "032"),
+        )
+    }
+    /// `m_iHealingAssist.033`
+    pub fn healing_assist_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iHealingAssist", "033"),
+        )
+    }
+    /// `m_iDamageBlocked.000`
+    pub fn damage_blocked_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "000"),
+        )
+    }
+    /// `m_iDamageBlocked.001`
+    pub fn damage_blocked_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "001"),
+        )
+    }
+    /// `m_iDamageBlocked.002`
+    pub fn damage_blocked_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "002"),
+        )
+    }
+    /// `m_iDamageBlocked.003`
+    pub fn damage_blocked_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "003"),
+        )
+    }
+    /// `m_iDamageBlocked.004`
+    pub fn damage_blocked_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "004"),
+        )
+    }
+    /// `m_iDamageBlocked.005`
+    pub fn damage_blocked_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "005"),
+        )
+    }
+    /// `m_iDamageBlocked.006`
+    pub fn damage_blocked_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "006"),
+        )
+    }
+    /// `m_iDamageBlocked.007`
+    pub fn damage_blocked_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "007"),
+        )
+    }
+    /// `m_iDamageBlocked.008`
+    pub fn damage_blocked_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "008"),
+        )
+    }
+    /// `m_iDamageBlocked.009`
+    pub fn damage_blocked_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "009"),
+        )
+    }
+    /// `m_iDamageBlocked.010`
+    pub fn damage_blocked_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "010"),
+        )
+    }
+    /// `m_iDamageBlocked.011`
+    pub fn damage_blocked_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "011"),
+        )
+    }
+    /// `m_iDamageBlocked.012`
+    pub fn damage_blocked_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "012"),
+        )
+    }
+    /// `m_iDamageBlocked.013`
+    pub fn damage_blocked_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "013"),
+        )
+    }
+    /// `m_iDamageBlocked.014`
+    pub fn damage_blocked_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "014"),
+        )
+    }
+    /// `m_iDamageBlocked.015`
+    pub fn damage_blocked_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "015"),
+        )
+    }
+    /// `m_iDamageBlocked.016`
+    pub fn damage_blocked_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "016"),
+        )
+    }
+    /// `m_iDamageBlocked.017`
+    pub fn damage_blocked_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "017"),
+        )
+    }
+    /// `m_iDamageBlocked.018`
+    pub fn damage_blocked_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "018"),
+        )
+    }
+    /// `m_iDamageBlocked.019`
+    pub fn damage_blocked_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "019"),
+        )
+    }
+    /// `m_iDamageBlocked.020`
+    pub fn damage_blocked_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "020"),
+        )
+    }
+    /// `m_iDamageBlocked.021`
+    pub fn damage_blocked_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "021"),
+        )
+    }
+    /// `m_iDamageBlocked.022`
+    pub fn damage_blocked_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "022"),
+        )
+    }
+    /// `m_iDamageBlocked.023`
+    pub fn damage_blocked_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "023"),
+        )
+    }
+    /// `m_iDamageBlocked.024`
+    pub fn damage_blocked_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "024"),
+        )
+    }
+    /// `m_iDamageBlocked.025`
+    pub fn damage_blocked_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "025"),
+        )
+    }
+    /// `m_iDamageBlocked.026`
+    pub fn damage_blocked_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "026"),
+        )
+    }
+    /// `m_iDamageBlocked.027`
+    pub fn damage_blocked_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "027"),
+        )
+    }
+    /// `m_iDamageBlocked.028`
+    pub fn damage_blocked_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "028"),
+        )
+    }
+    /// `m_iDamageBlocked.029`
+    pub fn damage_blocked_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "029"),
+        )
+    }
+    /// `m_iDamageBlocked.030`
+    pub fn damage_blocked_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "030"),
+        )
+    }
+    /// `m_iDamageBlocked.031`
+    pub fn damage_blocked_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "031"),
+        )
+    }
+    /// `m_iDamageBlocked.032`
+    pub fn damage_blocked_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "032"),
+        )
+    }
+    /// `m_iDamageBlocked.033`
+    pub fn damage_blocked_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iDamageBlocked", "033"),
+        )
+    }
+    /// `m_iCurrencyCollected.000`
+    pub fn currency_collected_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "000"),
+        )
+    }
+    /// `m_iCurrencyCollected.001`
+    pub fn currency_collected_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "001"),
+        )
+    }
+    /// `m_iCurrencyCollected.002`
+    pub fn currency_collected_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "002"),
+        )
+    }
+    /// `m_iCurrencyCollected.003`
+    pub fn currency_collected_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "003"),
+        )
+    }
+    /// `m_iCurrencyCollected.004`
+    pub fn currency_collected_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "004"),
+        )
+    }
+    /// `m_iCurrencyCollected.005`
+    pub fn currency_collected_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "005"),
+        )
+    }
+    /// `m_iCurrencyCollected.006`
+    pub fn currency_collected_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "006"),
+        )
+    }
+    /// `m_iCurrencyCollected.007`
+    pub fn currency_collected_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "007"),
+        )
+    }
+    /// `m_iCurrencyCollected.008`
+    pub fn currency_collected_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "008"),
+        )
+    }
+    /// `m_iCurrencyCollected.009`
+    pub fn currency_collected_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "009"),
+        )
+    }
+    /// `m_iCurrencyCollected.010`
+    pub fn currency_collected_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "010"),
+        )
+    }
+    /// `m_iCurrencyCollected.011`
+    pub fn currency_collected_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "011"),
+        )
+    }
+    /// `m_iCurrencyCollected.012`
+    pub fn currency_collected_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "012"),
+        )
+    }
+    /// `m_iCurrencyCollected.013`
+    pub fn currency_collected_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "013"),
+        )
+    }
+    /// `m_iCurrencyCollected.014`
+    pub fn currency_collected_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "014"),
+        )
+    }
+    /// `m_iCurrencyCollected.015`
+    pub fn currency_collected_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "015"),
+        )
+    }
+    /// `m_iCurrencyCollected.016`
+    pub fn currency_collected_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "016"),
+        )
+    }
+    /// `m_iCurrencyCollected.017`
+    pub fn currency_collected_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "017"),
+        )
+    }
+    /// `m_iCurrencyCollected.018`
+    pub fn currency_collected_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "018"),
+        )
+    }
+    /// `m_iCurrencyCollected.019`
+    pub fn currency_collected_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "019"),
+        )
+    }
+    /// `m_iCurrencyCollected.020`
+    pub fn currency_collected_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "020"),
+        )
+    }
+    /// `m_iCurrencyCollected.021`
+    pub fn currency_collected_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "021"),
+        )
+    }
+    /// `m_iCurrencyCollected.022`
+    pub fn currency_collected_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "022"),
+        )
+    }
+    /// `m_iCurrencyCollected.023`
+    pub fn currency_collected_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "023"),
+        )
+    }
+    /// `m_iCurrencyCollected.024`
+    pub fn currency_collected_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "024"),
+        )
+    }
+    /// `m_iCurrencyCollected.025`
+    pub fn currency_collected_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "025"),
+        )
+    }
+    /// `m_iCurrencyCollected.026`
+    pub fn currency_collected_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "026"),
+        )
+    }
+    /// `m_iCurrencyCollected.027`
+    pub fn currency_collected_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "027"),
+        )
+    }
+    /// `m_iCurrencyCollected.028`
+    pub fn currency_collected_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "028"),
+        )
+    }
+    /// `m_iCurrencyCollected.029`
+    pub fn currency_collected_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "029"),
+        )
+    }
+    /// `m_iCurrencyCollected.030`
+    pub fn currency_collected_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "030"),
+        )
+    }
+    /// `m_iCurrencyCollected.031`
+    pub fn currency_collected_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "031"),
+        )
+    }
+    /// `m_iCurrencyCollected.032`
+    pub fn currency_collected_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "032"),
+        )
+    }
+    /// `m_iCurrencyCollected.033`
+    pub fn currency_collected_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iCurrencyCollected", "033"),
+        )
+    }
+    /// `m_iBonusPoints.000`
+    pub fn bonus_points_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "000"),
+        )
+    }
+    /// `m_iBonusPoints.001`
+    pub fn bonus_points_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "001"),
+        )
+    }
+    /// `m_iBonusPoints.002`
+    pub fn bonus_points_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "002"),
+        )
+    }
+    /// `m_iBonusPoints.003`
+    pub fn bonus_points_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "003"),
+        )
+    }
+    /// `m_iBonusPoints.004`
+    pub fn bonus_points_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "004"),
+        )
+    }
+    /// `m_iBonusPoints.005`
+    pub fn bonus_points_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "005"),
+        )
+    }
+    /// `m_iBonusPoints.006`
+    pub fn bonus_points_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "006"),
+        )
+    }
+    /// `m_iBonusPoints.007`
+    pub fn bonus_points_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "007"),
+        )
+    }
+    /// `m_iBonusPoints.008`
+    pub fn bonus_points_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "008"),
+        )
+    }
+    /// `m_iBonusPoints.009`
+    pub fn bonus_points_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "009"),
+        )
+    }
+    /// `m_iBonusPoints.010`
+    pub fn bonus_points_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "010"),
+        )
+    }
+    /// `m_iBonusPoints.011`
+    pub fn bonus_points_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "011"),
+        )
+    }
+    /// `m_iBonusPoints.012`
+    pub fn bonus_points_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "012"),
+        )
+    }
+    /// `m_iBonusPoints.013`
+    pub fn bonus_points_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "013"),
+        )
+    }
+    /// `m_iBonusPoints.014`
+    pub fn bonus_points_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "014"),
+        )
+    }
+    /// `m_iBonusPoints.015`
+    pub fn bonus_points_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "015"),
+        )
+    }
+    /// `m_iBonusPoints.016`
+    pub fn bonus_points_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "016"),
+        )
+    }
+    /// `m_iBonusPoints.017`
+    pub fn bonus_points_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "017"),
+        )
+    }
+    /// `m_iBonusPoints.018`
+    pub fn bonus_points_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "018"),
+        )
+    }
+    /// `m_iBonusPoints.019`
+    pub fn bonus_points_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "019"),
+        )
+    }
+    /// `m_iBonusPoints.020`
+    pub fn bonus_points_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "020"),
+        )
+    }
+    /// `m_iBonusPoints.021`
+    pub fn bonus_points_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "021"),
+        )
+    }
+    /// `m_iBonusPoints.022`
+    pub fn bonus_points_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "022"),
+        )
+    }
+    /// `m_iBonusPoints.023`
+    pub fn bonus_points_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "023"),
+        )
+    }
+    /// `m_iBonusPoints.024`
+    pub fn bonus_points_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "024"),
+        )
+    }
+    /// `m_iBonusPoints.025`
+    pub fn bonus_points_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "025"),
+        )
+    }
+    /// `m_iBonusPoints.026`
+    pub fn bonus_points_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "026"),
+        )
+    }
+    /// `m_iBonusPoints.027`
+    pub fn bonus_points_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "027"),
+        )
+    }
+    /// `m_iBonusPoints.028`
+    pub fn bonus_points_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "028"),
+        )
+    }
+    /// `m_iBonusPoints.029`
+    pub fn bonus_points_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "029"),
+        )
+    }
+    /// `m_iBonusPoints.030`
+    pub fn bonus_points_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "030"),
+        )
+    }
+    /// `m_iBonusPoints.031`
+    pub fn bonus_points_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "031"),
+        )
+    }
+    /// `m_iBonusPoints.032`
+    pub fn bonus_points_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "032"),
+        )
+    }
+    /// `m_iBonusPoints.033`
+    pub fn bonus_points_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iBonusPoints", "033"),
+        )
+    }
+    /// `m_iPlayerLevel.000`
+    pub fn player_level_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "000"),
+        )
+    }
+    /// `m_iPlayerLevel.001`
+    pub fn player_level_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "001"),
+        )
+    }
+    /// `m_iPlayerLevel.002`
+    pub fn player_level_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "002"),
+        )
+    }
+    /// `m_iPlayerLevel.003`
+    pub fn player_level_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "003"),
+        )
+    }
+    /// `m_iPlayerLevel.004`
+    pub fn player_level_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "004"),
+        )
+    }
+    /// `m_iPlayerLevel.005`
+    pub fn player_level_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "005"),
+        )
+    }
+    /// `m_iPlayerLevel.006`
+    pub fn player_level_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "006"),
+        )
+    }
+    /// `m_iPlayerLevel.007`
+    pub fn player_level_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "007"),
+        )
+    }
+    /// `m_iPlayerLevel.008`
+    pub fn player_level_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "008"),
+        )
+    }
+    /// `m_iPlayerLevel.009`
+    pub fn player_level_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "009"),
+        )
+    }
+    /// `m_iPlayerLevel.010`
+    pub fn player_level_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "010"),
+        )
+    }
+    /// `m_iPlayerLevel.011`
+    pub fn player_level_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "011"),
+        )
+    }
+    /// `m_iPlayerLevel.012`
+    pub fn player_level_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "012"),
+        )
+    }
+    /// `m_iPlayerLevel.013`
+    pub fn player_level_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "013"),
+        )
+    }
+    /// `m_iPlayerLevel.014`
+    pub fn player_level_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "014"),
+        )
+    }
+    /// `m_iPlayerLevel.015`
+    pub fn player_level_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "015"),
+        )
+    }
+    /// `m_iPlayerLevel.016`
+    pub fn player_level_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "016"),
+        )
+    }
+    /// `m_iPlayerLevel.017`
+    pub fn player_level_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "017"),
+        )
+    }
+    /// `m_iPlayerLevel.018`
+    pub fn player_level_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "018"),
+        )
+    }
+    /// `m_iPlayerLevel.019`
+    pub fn player_level_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "019"),
+        )
+    }
+    /// `m_iPlayerLevel.020`
+    pub fn player_level_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "020"),
+        )
+    }
+    /// `m_iPlayerLevel.021`
+    pub fn player_level_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "021"),
+        )
+    }
+    /// `m_iPlayerLevel.022`
+    pub fn player_level_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "022"),
+        )
+    }
+    /// `m_iPlayerLevel.023`
+    pub fn player_level_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "023"),
+        )
+    }
+    /// `m_iPlayerLevel.024`
+    pub fn player_level_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "024"),
+        )
+    }
+    /// `m_iPlayerLevel.025`
+    pub fn player_level_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "025"),
+        )
+    }
+    /// `m_iPlayerLevel.026`
+    pub fn player_level_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "026"),
+        )
+    }
+    /// `m_iPlayerLevel.027`
+    pub fn player_level_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "027"),
+        )
+    }
+    /// `m_iPlayerLevel.028`
+    pub fn player_level_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "028"),
+        )
+    }
+    /// `m_iPlayerLevel.029`
+    pub fn player_level_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "029"),
+        )
+    }
+    /// `m_iPlayerLevel.030`
+    pub fn player_level_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "030"),
+        )
+    }
+    /// `m_iPlayerLevel.031`
+    pub fn player_level_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "031"),
+        )
+    }
+    /// `m_iPlayerLevel.032`
+    pub fn player_level_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "032"),
+        )
+    }
+    /// `m_iPlayerLevel.033`
+    pub fn player_level_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iPlayerLevel", "033"),
+        )
+    }
+    /// `m_iStreaks.000`
+    pub fn streaks_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "000"))
+    }
+    /// `m_iStreaks.001`
+    pub fn streaks_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "001"))
+    }
+    /// `m_iStreaks.002`
+    pub fn streaks_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "002"))
+    }
+    /// `m_iStreaks.003`
+    pub fn streaks_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "003"))
+    }
+    /// `m_iStreaks.004`
+    pub fn streaks_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "004"))
+    }
+    /// `m_iStreaks.005`
+    pub fn streaks_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "005"))
+    }
+    /// `m_iStreaks.006`
+    pub fn streaks_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "006"))
+    }
+    /// `m_iStreaks.007`
+    pub fn streaks_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "007"))
+    }
+    /// `m_iStreaks.008`
+    pub fn streaks_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "008"))
+    }
+    /// `m_iStreaks.009`
+    pub fn streaks_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "009"))
+    }
+    /// `m_iStreaks.010`
+    pub fn streaks_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "010"))
+    }
+    /// `m_iStreaks.011`
+    pub fn streaks_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "011"))
+    }
+    /// `m_iStreaks.012`
+    pub fn streaks_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "012"))
+    }
+    /// `m_iStreaks.013`
+    pub fn streaks_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "013"))
+    }
+    /// `m_iStreaks.014`
+    pub fn streaks_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "014"))
+    }
+    /// `m_iStreaks.015`
+    pub fn streaks_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "015"))
+    }
+    /// `m_iStreaks.016`
+    pub fn streaks_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "016"))
+    }
+    /// `m_iStreaks.017`
+    pub fn streaks_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "017"))
+    }
+    /// `m_iStreaks.018`
+    pub fn streaks_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "018"))
+    }
+    /// `m_iStreaks.019`
+    pub fn streaks_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "019"))
+    }
+    /// `m_iStreaks.020`
+    pub fn streaks_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "020"))
+    }
+    /// `m_iStreaks.021`
+    pub fn streaks_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "021"))
+    }
+    /// `m_iStreaks.022`
+    pub fn streaks_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "022"))
+    }
+    /// `m_iStreaks.023`
+    pub fn streaks_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "023"))
+    }
+    /// `m_iStreaks.024`
+    pub fn streaks_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "024"))
+    }
+    /// `m_iStreaks.025`
+    pub fn streaks_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "025"))
+    }
+    /// `m_iStreaks.026`
+    pub fn streaks_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "026"))
+    }
+    /// `m_iStreaks.027`
+    pub fn streaks_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "027"))
+    }
+    /// `m_iStreaks.028`
+    pub fn streaks_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "028"))
+    }
+    /// `m_iStreaks.029`
+    pub fn streaks_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "029"))
+    }
+    /// `m_iStreaks.030`
+    pub fn streaks_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "030"))
+    }
+    /// `m_iStreaks.031`
+    pub fn streaks_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "031"))
+    }
+    /// `m_iStreaks.032`
+    pub fn streaks_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "032"))
+    }
+    /// `m_iStreaks.033`
+    pub fn streaks_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "033"))
+    }
+    /// `m_iStreaks.034`
+    pub fn streaks_034(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "034"))
+    }
+    /// `m_iStreaks.035`
+    pub fn streaks_035(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "035"))
+    }
+    /// `m_iStreaks.036`
+    pub fn streaks_036(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "036"))
+    }
+    /// `m_iStreaks.037`
+    pub fn streaks_037(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "037"))
+    }
+    /// `m_iStreaks.038`
+    pub fn streaks_038(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "038"))
+    }
+    /// `m_iStreaks.039`
+    pub fn streaks_039(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "039"))
+    }
+    /// `m_iStreaks.040`
+    pub fn streaks_040(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "040"))
+    }
+    /// `m_iStreaks.041`
+    pub fn streaks_041(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "041"))
+    }
+    /// `m_iStreaks.042`
+    pub fn streaks_042(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "042"))
+    }
+    /// `m_iStreaks.043`
+    pub fn streaks_043(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "043"))
+    }
+    /// `m_iStreaks.044`
+    pub fn streaks_044(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "044"))
+    }
+    /// `m_iStreaks.045`
+    pub fn streaks_045(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "045"))
+    }
+    /// `m_iStreaks.046`
+    pub fn streaks_046(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "046"))
+    }
+    /// `m_iStreaks.047`
+    pub fn streaks_047(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "047"))
+    }
+    /// `m_iStreaks.048`
+    pub fn streaks_048(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "048"))
+    }
+    /// `m_iStreaks.049`
+    pub fn streaks_049(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "049"))
+    }
+    /// `m_iStreaks.050`
+    pub fn streaks_050(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "050"))
+    }
+    /// `m_iStreaks.051`
+    pub fn streaks_051(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "051"))
+    }
+    /// `m_iStreaks.052`
+    pub fn streaks_052(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "052"))
+    }
+    /// `m_iStreaks.053`
+    pub fn streaks_053(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "053"))
+    }
+    /// `m_iStreaks.054`
+    pub fn streaks_054(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "054"))
+    }
+    /// `m_iStreaks.055`
+    pub fn streaks_055(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "055"))
+    }
+    /// `m_iStreaks.056`
+    pub fn streaks_056(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "056"))
+    }
+    /// `m_iStreaks.057`
+    pub fn streaks_057(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "057"))
+    }
+    /// `m_iStreaks.058`
+    pub fn streaks_058(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "058"))
+    }
+    /// `m_iStreaks.059`
+    pub fn streaks_059(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "059"))
+    }
+    /// `m_iStreaks.060`
+    pub fn streaks_060(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "060"))
+    }
+    /// `m_iStreaks.061`
+    pub fn streaks_061(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "061"))
+    }
+    /// `m_iStreaks.062`
+    pub fn streaks_062(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "062"))
+    }
+    /// `m_iStreaks.063`
+    pub fn streaks_063(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "063"))
+    }
+    /// `m_iStreaks.064`
+    pub fn streaks_064(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "064"))
+    }
+    /// `m_iStreaks.065`
+    pub fn streaks_065(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "065"))
+    }
+    /// `m_iStreaks.066`
+    pub fn streaks_066(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "066"))
+    }
+    /// `m_iStreaks.067`
+    pub fn streaks_067(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "067"))
+    }
+    /// `m_iStreaks.068`
+    pub fn streaks_068(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "068"))
+    }
+    /// `m_iStreaks.069`
+    pub fn streaks_069(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "069"))
+    }
+    /// `m_iStreaks.070`
+    pub fn streaks_070(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "070"))
+    }
+    /// `m_iStreaks.071`
+    pub fn streaks_071(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "071"))
+    }
+    /// `m_iStreaks.072`
+    pub fn streaks_072(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "072"))
+    }
+    /// `m_iStreaks.073`
+    pub fn streaks_073(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "073"))
+    }
+    /// `m_iStreaks.074`
+    pub fn streaks_074(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "074"))
+    }
+    /// `m_iStreaks.075`
+    pub fn streaks_075(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "075"))
+    }
+    /// `m_iStreaks.076`
+    pub fn streaks_076(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "076"))
+    }
+    /// `m_iStreaks.077`
+    pub fn streaks_077(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "077"))
+    }
+    /// `m_iStreaks.078`
+    pub fn streaks_078(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "078"))
+    }
+    /// `m_iStreaks.079`
+    pub fn streaks_079(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "079"))
+    }
+    /// `m_iStreaks.080`
+    pub fn streaks_080(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "080"))
+    }
+    /// `m_iStreaks.081`
+    pub fn streaks_081(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "081"))
+    }
+    /// `m_iStreaks.082`
+    pub fn streaks_082(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "082"))
+    }
+    /// `m_iStreaks.083`
+    pub fn streaks_083(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "083"))
+    }
+    /// `m_iStreaks.084`
+    pub fn streaks_084(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "084"))
+    }
+    /// `m_iStreaks.085`
+    pub fn streaks_085(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "085"))
+    }
+    /// `m_iStreaks.086`
+    pub fn streaks_086(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "086"))
+    }
+    /// `m_iStreaks.087`
+    pub fn streaks_087(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "087"))
+    }
+    /// `m_iStreaks.088`
+    pub fn streaks_088(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "088"))
+    }
+    /// `m_iStreaks.089`
+    pub fn streaks_089(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "089"))
+    }
+    /// `m_iStreaks.090`
+    pub fn streaks_090(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "090"))
+    }
+    /// `m_iStreaks.091`
+    pub fn streaks_091(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "091"))
+    }
+    /// `m_iStreaks.092`
+    pub fn streaks_092(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "092"))
+    }
+    /// `m_iStreaks.093`
+    pub fn streaks_093(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "093"))
+    }
+    /// `m_iStreaks.094`
+    pub fn streaks_094(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "094"))
+    }
+    /// `m_iStreaks.095`
+    pub fn streaks_095(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "095"))
+    }
+    /// `m_iStreaks.096`
+    pub fn streaks_096(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "096"))
+    }
+    /// `m_iStreaks.097`
+    pub fn streaks_097(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "097"))
+    }
+    /// `m_iStreaks.098`
+    pub fn streaks_098(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "098"))
+    }
+    /// `m_iStreaks.099`
+    pub fn streaks_099(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "099"))
+    }
+    /// `m_iStreaks.100`
+    pub fn streaks_100(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "100"))
+    }
+    /// `m_iStreaks.101`
+    pub fn streaks_101(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "101"))
+    }
+    /// `m_iStreaks.102`
+    pub fn streaks_102(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "102"))
+    }
+    /// `m_iStreaks.103`
+    pub fn streaks_103(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "103"))
+    }
+    /// `m_iStreaks.104`
+    pub fn streaks_104(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "104"))
+    }
+    /// `m_iStreaks.105`
+    pub fn streaks_105(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "105"))
+    }
+    /// `m_iStreaks.106`
+    pub fn streaks_106(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "106"))
+    }
+    /// `m_iStreaks.107`
+    pub fn streaks_107(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "107"))
+    }
+    /// `m_iStreaks.108`
+    pub fn streaks_108(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "108"))
+    }
+    /// `m_iStreaks.109`
+    pub fn streaks_109(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "109"))
+    }
+    /// `m_iStreaks.110`
+    pub fn streaks_110(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "110"))
+    }
+    /// `m_iStreaks.111`
+    pub fn streaks_111(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "111"))
+    }
+    /// `m_iStreaks.112`
+    pub fn streaks_112(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "112"))
+    }
+    /// `m_iStreaks.113`
+    pub fn streaks_113(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "113"))
+    }
+    /// `m_iStreaks.114`
+    pub fn streaks_114(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "114"))
+    }
+    /// `m_iStreaks.115`
+    pub fn streaks_115(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "115"))
+    }
+    /// `m_iStreaks.116`
+    pub fn streaks_116(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "116"))
+    }
+    /// `m_iStreaks.117`
+    pub fn streaks_117(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "117"))
+    }
+    /// `m_iStreaks.118`
+    pub fn streaks_118(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "118"))
+    }
+    /// `m_iStreaks.119`
+    pub fn streaks_119(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "119"))
+    }
+    /// `m_iStreaks.120`
+    pub fn streaks_120(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "120"))
+    }
+    /// `m_iStreaks.121`
+    pub fn streaks_121(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "121"))
+    }
+    /// `m_iStreaks.122`
+    pub fn streaks_122(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "122"))
+    }
+    /// `m_iStreaks.123`
+    pub fn streaks_123(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "123"))
+    }
+    /// `m_iStreaks.124`
+    pub fn streaks_124(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "124"))
+    }
+    /// `m_iStreaks.125`
+    pub fn streaks_125(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "125"))
+    }
+    /// `m_iStreaks.126`
+    pub fn streaks_126(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "126"))
+    }
+    /// `m_iStreaks.127`
+    pub fn streaks_127(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "127"))
+    }
+    /// `m_iStreaks.128`
+    pub fn streaks_128(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "128"))
+    }
+    /// `m_iStreaks.129`
+    pub fn streaks_129(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "129"))
+    }
+    /// `m_iStreaks.130`
+    pub fn streaks_130(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "130"))
+    }
+    /// `m_iStreaks.131`
+    pub fn streaks_131(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "131"))
+    }
+    /// `m_iStreaks.132`
+    pub fn streaks_132(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "132"))
+    }
+    /// `m_iStreaks.133`
+    pub fn streaks_133(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "133"))
+    }
+    /// `m_iStreaks.134`
+    pub fn streaks_134(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "134"))
+    }
+    /// `m_iStreaks.135`
+    pub fn streaks_135(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_iStreaks", "135"))
+    }
+    /// `m_iUpgradeRefundCredits.000`
+    pub fn upgrade_refund_credits_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "000"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.001`
+    pub fn upgrade_refund_credits_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "001"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.002`
+    pub fn upgrade_refund_credits_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "002"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.003`
+    pub fn upgrade_refund_credits_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "003"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.004`
+    pub fn upgrade_refund_credits_004(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "004"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.005`
+    pub fn upgrade_refund_credits_005(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "005"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.006`
+    pub fn upgrade_refund_credits_006(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "006"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.007`
+    pub fn upgrade_refund_credits_007(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "007"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.008`
+    pub fn upgrade_refund_credits_008(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "008"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.009`
+    pub fn upgrade_refund_credits_009(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "009"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.010`
+    pub fn upgrade_refund_credits_010(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "010"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.011`
+    pub fn upgrade_refund_credits_011(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "011"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.012`
+    pub fn upgrade_refund_credits_012(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "012"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.013`
+    pub fn upgrade_refund_credits_013(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "013"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.014`
+    pub fn upgrade_refund_credits_014(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "014"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.015`
+    pub fn upgrade_refund_credits_015(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "015"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.016`
+    pub fn upgrade_refund_credits_016(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "016"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.017`
+    pub fn upgrade_refund_credits_017(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "017"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.018`
+    pub fn upgrade_refund_credits_018(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "018"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.019`
+    pub fn upgrade_refund_credits_019(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "019"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.020`
+    pub fn upgrade_refund_credits_020(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "020"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.021`
+    pub fn upgrade_refund_credits_021(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "021"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.022`
+    pub fn upgrade_refund_credits_022(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "022"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.023`
+    pub fn upgrade_refund_credits_023(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "023"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.024`
+    pub fn upgrade_refund_credits_024(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "024"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.025`
+    pub fn upgrade_refund_credits_025(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "025"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.026`
+    pub fn upgrade_refund_credits_026(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "026"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.027`
+    pub fn upgrade_refund_credits_027(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "027"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.028`
+    pub fn upgrade_refund_credits_028(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "028"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.029`
+    pub fn upgrade_refund_credits_029(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "029"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.030`
+    pub fn upgrade_refund_credits_030(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "030"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.031`
+    pub fn upgrade_refund_credits_031(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "031"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.032`
+    pub fn upgrade_refund_credits_032(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "032"),
+        )
+    }
+    /// `m_iUpgradeRefundCredits.033`
+    pub fn upgrade_refund_credits_033(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_iUpgradeRefundCredits", "033"),
+        )
+    }
+}
+/// Typed view of the props of a `CTFTeam` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CTFTeamView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CTFTeamView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CTFTeam";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CTFTeamView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CTFTeamView { entity })
+    }
+    /// `DT_Team.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Team", "m_iTeamNum"),
+        )
+    }
+    /// `DT_Team.m_iScore`
+    pub fn score(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("DT_Team", "m_iScore"))
+    }
+    /// `DT_Team.m_iRoundsWon`
+    pub fn rounds_won(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Team", "m_iRoundsWon"),
+        )
+    }
+    /// `DT_Team.m_szTeamname`
+    pub fn teamname(&self) -> Option<&'a str> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Team", "m_szTeamname"),
+        )
+    }
+    /// `DT_Team."player_array"`
+    pub fn player_array(&self) -> Option<&'a [SendPropValue]> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Team", "\"player_array\""),
+        )
+    }
+    /// `DT_TFTeam.m_nFlagCaptures`
+    pub fn flag_captures(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFTeam", "m_nFlagCaptures"),
+        )
+    }
+    /// `DT_TFTeam.m_iRole`
+    pub fn role(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("DT_TFTeam", "m_iRole"))
+    }
+    /// `DT_TFTeam."team_object_array"`
+    pub fn team_object_array(&self) -> Option<&'a [SendPropValue]> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFTeam", "\"team_object_array\""),
+        )
+    }
+    /// `DT_TFTeam.m_hLeader`
+    pub fn leader(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFTeam", "m_hLeader"),
+        )
+    }
+}
+/// Typed view of the props of a `CTFGameRulesProxy` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CTFGameRulesProxyView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CTFGameRulesProxyView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CTFGameRulesProxy";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CTFGameRulesProxyView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CTFGameRulesProxyView { entity })
+    }
+    /// `m_flNextRespawnWave.000`
+    pub fn next_respawn_wave_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "000"),
+        )
+    }
+    /// `m_flNextRespawnWave.001`
+    pub fn next_respawn_wave_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "001"),
+        )
+    }
+    /// `m_flNextRespawnWave.002`
+    pub fn next_respawn_wave_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "002"),
+        )
+    }
+    /// `m_flNextRespawnWave.003`
+    pub fn next_respawn_wave_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "003"),
+        )
+    }
+    /// `m_flNextRespawnWave.004`
+    pub fn next_respawn_wave_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "004"),
+        )
+    }
+    /// `m_flNextRespawnWave.005`
+    pub fn next_respawn_wave_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "005"),
+        )
+    }
+    /// `m_flNextRespawnWave.006`
+    pub fn next_respawn_wave_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "006"),
+        )
+    }
+    /// `m_flNextRespawnWave.007`
+    pub fn next_respawn_wave_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "007"),
+        )
+    }
+    /// `m_flNextRespawnWave.008`
+    pub fn next_respawn_wave_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "008"),
+        )
+    }
+    /// `m_flNextRespawnWave.009`
+    pub fn next_respawn_wave_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "009"),
+        )
+    }
+    /// `m_flNextRespawnWave.010`
+    pub fn next_respawn_wave_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "010"),
+        )
+    }
+    /// `m_flNextRespawnWave.011`
+    pub fn next_respawn_wave_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "011"),
+        )
+    }
+    /// `m_flNextRespawnWave.012`
+    pub fn next_respawn_wave_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "012"),
+        )
+    }
+    /// `m_flNextRespawnWave.013`
+    pub fn next_respawn_wave_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "013"),
+        )
+    }
+    /// `m_flNextRespawnWave.014`
+    pub fn next_respawn_wave_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "014"),
+        )
+    }
+    /// `m_flNextRespawnWave.015`
+    pub fn next_respawn_wave_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "015"),
+        )
+    }
+    /// `m_flNextRespawnWave.016`
+    pub fn next_respawn_wave_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "016"),
+        )
+    }
+    /// `m_flNextRespawnWave.017`
+    pub fn next_respawn_wave_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "017"),
+        )
+    }
+    /// `m_flNextRespawnWave.018`
+    pub fn next_respawn_wave_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "018"),
+        )
+    }
+    /// `m_flNextRespawnWave.019`
+    pub fn next_respawn_wave_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "019"),
+        )
+    }
+    /// `m_flNextRespawnWave.020`
+    pub fn next_respawn_wave_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "020"),
+        )
+    }
+    /// `m_flNextRespawnWave.021`
+    pub fn next_respawn_wave_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "021"),
+        )
+    }
+    /// `m_flNextRespawnWave.022`
+    pub fn next_respawn_wave_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "022"),
+        )
+    }
+    /// `m_flNextRespawnWave.023`
+    pub fn next_respawn_wave_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "023"),
+        )
+    }
+    /// `m_flNextRespawnWave.024`
+    pub fn next_respawn_wave_024(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "024"),
+        )
+    }
+    /// `m_flNextRespawnWave.025`
+    pub fn next_respawn_wave_025(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "025"),
+        )
+    }
+    /// `m_flNextRespawnWave.026`
+    pub fn next_respawn_wave_026(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "026"),
+        )
+    }
+    /// `m_flNextRespawnWave.027`
+    pub fn next_respawn_wave_027(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "027"),
+        )
+    }
+    /// `m_flNextRespawnWave.028`
+    pub fn next_respawn_wave_028(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "028"),
+        )
+    }
+    /// `m_flNextRespawnWave.029`
+    pub fn next_respawn_wave_029(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "029"),
+        )
+    }
+    /// `m_flNextRespawnWave.030`
+    pub fn next_respawn_wave_030(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "030"),
+        )
+    }
+    /// `m_flNextRespawnWave.031`
+    pub fn next_respawn_wave_031(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flNextRespawnWave", "031"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.000`
+    pub fn team_respawn_wave_times_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "000"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.001`
+    pub fn team_respawn_wave_times_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "001"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.002`
+    pub fn team_respawn_wave_times_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "002"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.003`
+    pub fn team_respawn_wave_times_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "003"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.004`
+    pub fn team_respawn_wave_times_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "004"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.005`
+    pub fn team_respawn_wave_times_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "005"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.006`
+    pub fn team_respawn_wave_times_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "006"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.007`
+    pub fn team_respawn_wave_times_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "007"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.008`
+    pub fn team_respawn_wave_times_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "008"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.009`
+    pub fn team_respawn_wave_times_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "009"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.010`
+    pub fn team_respawn_wave_times_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "010"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.011`
+    pub fn team_respawn_wave_times_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "011"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.012`
+    pub fn team_respawn_wave_times_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "012"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.013`
+    pub fn team_respawn_wave_times_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "013"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.014`
+    pub fn team_respawn_wave_times_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "014"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.015`
+    pub fn team_respawn_wave_times_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "015"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.016`
+    pub fn team_respawn_wave_times_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "016"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.017`
+    pub fn team_respawn_wave_times_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "017"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.018`
+    pub fn team_respawn_wave_times_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "018"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.019`
+    pub fn team_respawn_wave_times_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "019"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.020`
+    pub fn team_respawn_wave_times_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "020"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.021`
+    pub fn team_respawn_wave_times_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "021"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.022`
+    pub fn team_respawn_wave_times_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "022"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.023`
+    pub fn team_respawn_wave_times_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "023"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.024`
+    pub fn team_respawn_wave_times_024(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "024"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.025`
+    pub fn team_respawn_wave_times_025(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "025"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.026`
+    pub fn team_respawn_wave_times_026(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "026"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.027`
+    pub fn team_respawn_wave_times_027(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "027"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.028`
+    pub fn team_respawn_wave_times_028(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "028"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.029`
+    pub fn team_respawn_wave_times_029(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "029"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.030`
+    pub fn team_respawn_wave_times_030(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "030"),
+        )
+    }
+    /// `m_TeamRespawnWaveTimes.031`
+    pub fn team_respawn_wave_times_031(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_TeamRespawnWaveTimes", "031"),
+        )
+    }
+    /// `m_bTeamReady.000`
+    pub fn team_ready_000(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "000"))
+    }
+    /// `m_bTeamReady.001`
+    pub fn team_ready_001(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "001"))
+    }
+    /// `m_bTeamReady.002`
+    pub fn team_ready_002(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "002"))
+    }
+    /// `m_bTeamReady.003`
+    pub fn team_ready_003(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "003"))
+    }
+    /// `m_bTeamReady.004`
+    pub fn team_ready_004(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "004"))
+    }
+    /// `m_bTeamReady.005`
+    pub fn team_ready_005(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "005"))
+    }
+    /// `m_bTeamReady.006`
+    pub fn team_ready_006(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "006"))
+    }
+    /// `m_bTeamReady.007`
+    pub fn team_ready_007(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "007"))
+    }
+    /// `m_bTeamReady.008`
+    pub fn team_ready_008(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "008"))
+    }
+    /// `m_bTeamReady.009`
+    pub fn team_ready_009(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "009"))
+    }
+    /// `m_bTeamReady.010`
+    pub fn team_ready_010(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "010"))
+    }
+    /// `m_bTeamReady.011`
+    pub fn team_ready_011(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "011"))
+    }
+    /// `m_bTeamReady.012`
+    pub fn team_ready_012(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "012"))
+    }
+    /// `m_bTeamReady.013`
+    pub fn team_ready_013(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "013"))
+    }
+    /// `m_bTeamReady.014`
+    pub fn team_ready_014(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "014"))
+    }
+    /// `m_bTeamReady.015`
+    pub fn team_ready_015(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "015"))
+    }
+    /// `m_bTeamReady.016`
+    pub fn team_ready_016(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "016"))
+    }
+    /// `m_bTeamReady.017`
+    pub fn team_ready_017(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "017"))
+    }
+    /// `m_bTeamReady.018`
+    pub fn team_ready_018(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "018"))
+    }
+    /// `m_bTeamReady.019`
+    pub fn team_ready_019(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "019"))
+    }
+    /// `m_bTeamReady.020`
+    pub fn team_ready_020(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "020"))
+    }
+    /// `m_bTeamReady.021`
+    pub fn team_ready_021(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "021"))
+    }
+    /// `m_bTeamReady.022`
+    pub fn team_ready_022(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "022"))
+    }
+    /// `m_bTeamReady.023`
+    pub fn team_ready_023(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "023"))
+    }
+    /// `m_bTeamReady.024`
+    pub fn team_ready_024(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "024"))
+    }
+    /// `m_bTeamReady.025`
+    pub fn team_ready_025(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "025"))
+    }
+    /// `m_bTeamReady.026`
+    pub fn team_ready_026(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "026"))
+    }
+    /// `m_bTeamReady.027`
+    pub fn team_ready_027(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "027"))
+    }
+    /// `m_bTeamReady.028`
+    pub fn team_ready_028(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "028"))
+    }
+    /// `m_bTeamReady.029`
+    pub fn team_ready_029(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "029"))
+    }
+    /// `m_bTeamReady.030`
+    pub fn team_ready_030(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "030"))
+    }
+    /// `m_bTeamReady.031`
+    pub fn team_ready_031(&self) -> Option<bool> {
+        get_prop(self.entity, SendPropIdentifier::new("m_bTeamReady", "031"))
+    }
+    /// `m_bPlayerReady.000`
+    pub fn player_ready_000(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "000"),
+        )
+    }
+    /// `m_bPlayerReady.001`
+    pub fn player_ready_001(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "001"),
+        )
+    }
+    /// `m_bPlayerReady.002`
+    pub fn player_ready_002(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "002"),
+        )
+    }
+    /// `m_bPlayerReady.003`
+    pub fn player_ready_003(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "003"),
+        )
+    }
+    /// `m_bPlayerReady.004`
+    pub fn player_ready_004(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "004"),
+        )
+    }
+    /// `m_bPlayerReady.005`
+    pub fn player_ready_005(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "005"),
+        )
+    }
+    /// `m_bPlayerReady.006`
+    pub fn player_ready_006(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "006"),
+        )
+    }
+    /// `m_bPlayerReady.007`
+    pub fn player_ready_007(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "007"),
+        )
+    }
+    /// `m_bPlayerReady.008`
+    pub fn player_ready_008(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "008"),
+        )
+    }
+    /// `m_bPlayerReady.009`
+    pub fn player_ready_009(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "009"),
+        )
+    }
+    /// `m_bPlayerReady.010`
+    pub fn player_ready_010(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "010"),
+        )
+    }
+    /// `m_bPlayerReady.011`
+    pub fn player_ready_011(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "011"),
+        )
+    }
+    /// `m_bPlayerReady.012`
+    pub fn player_ready_012(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "012"),
+        )
+    }
+    /// `m_bPlayerReady.013`
+    pub fn player_ready_013(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "013"),
+        )
+    }
+    /// `m_bPlayerReady.014`
+    pub fn player_ready_014(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "014"),
+        )
+    }
+    /// `m_bPlayerReady.015`
+    pub fn player_ready_015(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "015"),
+        )
+    }
+    /// `m_bPlayerReady.016`
+    pub fn player_ready_016(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "016"),
+        )
+    }
+    /// `m_bPlayerReady.017`
+    pub fn player_ready_017(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "017"),
+        )
+    }
+    /// `m_bPlayerReady.018`
+    pub fn player_ready_018(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "018"),
+        )
+    }
+    /// `m_bPlayerReady.019`
+    pub fn player_ready_019(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "019"),
+        )
+    }
+    /// `m_bPlayerReady.020`
+    pub fn player_ready_020(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "020"),
+        )
+    }
+    /// `m_bPlayerReady.021`
+    pub fn player_ready_021(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "021"),
+        )
+    }
+    /// `m_bPlayerReady.022`
+    pub fn player_ready_022(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "022"),
+        )
+    }
+    /// `m_bPlayerReady.023`
+    pub fn player_ready_023(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "023"),
+        )
+    }
+    /// `m_bPlayerReady.024`
+    pub fn player_ready_024(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "024"),
+        )
+    }
+    /// `m_bPlayerReady.025`
+    pub fn player_ready_025(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "025"),
+        )
+    }
+    /// `m_bPlayerReady.026`
+    pub fn player_ready_026(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "026"),
+        )
+    }
+    /// `m_bPlayerReady.027`
+    pub fn player_ready_027(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "027"),
+        )
+    }
+    /// `m_bPlayerReady.028`
+    pub fn player_ready_028(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "028"),
+        )
+    }
+    /// `m_bPlayerReady.029`
+    pub fn player_ready_029(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "029"),
+        )
+    }
+    /// `m_bPlayerReady.030`
+    pub fn player_ready_030(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "030"),
+        )
+    }
+    /// `m_bPlayerReady.031`
+    pub fn player_ready_031(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "031"),
+        )
+    }
+    /// `m_bPlayerReady.032`
+    pub fn player_ready_032(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_bPlayerReady", "032"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_iRoundState`
+    pub fn round_state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_iRoundState"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_bInWaitingForPlayers`
+    pub fn in_waiting_for_players(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_bInWaitingForPlayers"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_iWinningTeam`
+    pub fn winning_team(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_iWinningTeam"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_bInOvertime`
+    pub fn in_overtime(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_bInOvertime"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_bInSetup`
+    pub fn in_setup(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_bInSetup"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_bSwitchedTeamsThisRound`
+    pub fn switched_teams_this_round(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_bSwitchedTeamsThisRound"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_bAwaitingReadyRestart`
+    pub fn awaiting_ready_restart(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_bAwaitingReadyRestart"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_flRestartRoundTime`
+    pub fn restart_round_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_flRestartRoundTime"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_flMapResetTime`
+    pub fn map_reset_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_flMapResetTime"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_bStopWatch`
+    pub fn stop_watch(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_bStopWatch"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_bMultipleTrains`
+    pub fn multiple_trains(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_bMultipleTrains"),
+        )
+    }
+    /// `DT_TeamplayRoundBasedRules.m_bCheatsEnabledDuringLevel`
+    pub fn cheats_enabled_during_level(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TeamplayRoundBasedRules", "m_bCheatsEnabledDuringLevel"),
+        )
+    }
+    /// `DT_TFGameRules.m_nGameType`
+    pub fn game_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_nGameType"),
+        )
+    }
+    /// `DT_TFGameRules.m_nStopWatchState`
+    pub fn stop_watch_state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_nStopWatchState"),
+        )
+    }
+    /// `DT_TFGameRules.m_pszTeamGoalStringRed`
+    pub fn team_goal_string_red(&self) -> Option<&'a str> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_pszTeamGoalStringRed"),
+        )
+    }
+    /// `DT_TFGameRules.m_pszTeamGoalStringBlue`
+    pub fn team_goal_string_blue(&self) -> Option<&'a str> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_pszTeamGoalStringBlue"),
+        )
+    }
+    /// `DT_TFGameRules.m_flCapturePointEnableTime`
+    pub fn capture_point_enable_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_flCapturePointEnableTime"),
+        )
+    }
+    /// `DT_TFGameRules.m_nHudType`
+    pub fn hud_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_nHudType"),
+        )
+    }
+    /// `DT_TFGameRules.m_bIsInTraining`
+    pub fn is_in_training(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bIsInTraining"),
+        )
+    }
+    /// `DT_TFGameRules.m_bAllowTrainingAchievements`
+    pub fn allow_training_achievements(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bAllowTrainingAchievements"),
+        )
+    }
+    /// `DT_TFGameRules.m_bIsWaitingForTrainingContinue`
+    pub fn is_waiting_for_training_continue(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bIsWaitingForTrainingContinue"),
+        )
+    }
+    /// `DT_TFGameRules.m_bIsTrainingHUDVisible`
+    pub fn is_training_hud_visible(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bIsTrainingHUDVisible"),
+        )
+    }
+    /// `DT_TFGameRules.m_bIsInItemTestingMode`
+    pub fn is_in_item_testing_mode(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bIsInItemTestingMode"),
+        )
+    }
+    /// `DT_TFGameRules.m_hBonusLogic`
+    pub fn bonus_logic(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_hBonusLogic"),
+        )
+    }
+    /// `DT_TFGameRules.m_bPlayingKoth`
+    pub fn playing_koth(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bPlayingKoth"),
+        )
+    }
+    /// `DT_TFGameRules.m_bPlayingMedieval`
+    pub fn playing_medieval(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bPlayingMedieval"),
+        )
+    }
+    /// `DT_TFGameRules.m_bPlayingHybrid_CTF_CP`
+    pub fn playing_hybrid_ctf_cp(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bPlayingHybrid_CTF_CP"),
+        )
+    }
+    /// `DT_TFGameRules.m_bPlayingSpecialDeliveryMode`
+    pub fn playing_special_delivery_mode(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bPlayingSpecialDeliveryMode"),
+        )
+    }
+    /// `DT_TFGameRules.m_bPlayingRobotDestructionMode`
+    pub fn playing_robot_destruction_mode(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bPlayingRobotDestructionMode"),
+        )
+    }
+    /// `DT_TFGameRules.m_hRedKothTimer`
+    pub fn red_koth_timer(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_hRedKothTimer"),
+        )
+    }
+    /// `DT_TFGameRules.m_hBlueKothTimer`
+    pub fn blue_koth_timer(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_hBlueKothTimer"),
+        )
+    }
+    /// `DT_TFGameRules.m_nMapHolidayType`
+    pub fn map_holiday_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_nMapHolidayType"),
+        )
+    }
+    /// `DT_TFGameRules.m_itHandle`
+    pub fn handle(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_itHandle"),
+        )
+    }
+    /// `DT_TFGameRules.m_bPlayingMannVsMachine`
+    pub fn playing_mann_vs_machine(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bPlayingMannVsMachine"),
+        )
+    }
+    /// `DT_TFGameRules.m_hBirthdayPlayer`
+    pub fn birthday_player(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_hBirthdayPlayer"),
+        )
+    }
+    /// `DT_TFGameRules.m_nBossHealth`
+    pub fn boss_health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_nBossHealth"),
+        )
+    }
+    /// `DT_TFGameRules.m_nMaxBossHealth`
+    pub fn max_boss_health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_nMaxBossHealth"),
+        )
+    }
+    /// `DT_TFGameRules.m_fBossNormalizedTravelDistance`
+    pub fn boss_normalized_travel_distance(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_fBossNormalizedTravelDistance"),
+        )
+    }
+    /// `DT_TFGameRules.m_bMannVsMachineAlarmStatus`
+    pub fn mann_vs_machine_alarm_status(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bMannVsMachineAlarmStatus"),
+        )
+    }
+    /// `DT_TFGameRules.m_bHaveMinPlayersToEnableReady`
+    pub fn have_min_players_to_enable_ready(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bHaveMinPlayersToEnableReady"),
+        )
+    }
+    /// `DT_TFGameRules.m_bBountyModeEnabled`
+    pub fn bounty_mode_enabled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bBountyModeEnabled"),
+        )
+    }
+    /// `DT_TFGameRules.m_nHalloweenEffect`
+    pub fn halloween_effect(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_nHalloweenEffect"),
+        )
+    }
+    /// `DT_TFGameRules.m_fHalloweenEffectStartTime`
+    pub fn halloween_effect_start_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_fHalloweenEffectStartTime"),
+        )
+    }
+    /// `DT_TFGameRules.m_fHalloweenEffectDuration`
+    pub fn halloween_effect_duration(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_fHalloweenEffectDuration"),
+        )
+    }
+    /// `DT_TFGameRules.m_halloweenScenario`
+    pub fn halloween_scenario(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_halloweenScenario"),
+        )
+    }
+    /// `DT_TFGameRules.m_bHelltowerPlayersInHell`
+    pub fn helltower_players_in_hell(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bHelltowerPlayersInHell"),
+        )
+    }
+    /// `DT_TFGameRules.m_bIsUsingSpells`
+    pub fn is_using_spells(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bIsUsingSpells"),
+        )
+    }
+    /// `DT_TFGameRules.m_bCompetitiveMode`
+    pub fn competitive_mode(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bCompetitiveMode"),
+        )
+    }
+    /// `DT_TFGameRules.m_bPowerupMode`
+    pub fn powerup_mode(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_bPowerupMode"),
+        )
+    }
+    /// `DT_TFGameRules.m_pszCustomUpgradesFile`
+    pub fn custom_upgrades_file(&self) -> Option<&'a str> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFGameRules", "m_pszCustomUpgradesFile"),
+        )
+    }
+}
+/// Typed view of the props of a `CObjectSentrygun` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CObjectSentrygunView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CObjectSentrygunView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CObjectSentrygun";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CObjectSentrygunView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CObjectSentrygunView { entity })
+    }
+    /// `DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub fn anim_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime"),
+        )
+    }
+    /// `DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle"),
+        )
+    }
+    /// `DT_SentrygunLocalData.m_iKills`
+    pub fn kills(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_SentrygunLocalData", "m_iKills"),
+        )
+    }
+    /// `DT_SentrygunLocalData.m_iAssists`
+    pub fn assists(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_SentrygunLocalData", "m_iAssists"),
+        )
+    }
+    /// `DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime"),
+        )
+    }
+    /// `DT_BaseEntity.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin"),
+        )
+    }
+    /// `DT_BaseEntity.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation"),
+        )
+    }
+    /// `DT_ObjectSentrygun.m_iAmmoShells`
+    pub fn ammo_shells(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_iAmmoShells"),
+        )
+    }
+    /// `DT_ObjectSentrygun.m_iAmmoRockets`
+    pub fn ammo_rockets(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_iAmmoRockets"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMinsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMaxsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs"),
+        )
+    }
+    /// `DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID"),
+        )
+    }
+    /// `DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated"),
+        )
+    }
+    /// `m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000"),
+        )
+    }
+    /// `m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001"),
+        )
+    }
+    /// `m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002"),
+        )
+    }
+    /// `m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003"),
+        )
+    }
+    /// `m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "000"),
+        )
+    }
+    /// `m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "001"),
+        )
+    }
+    /// `m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "002"),
+        )
+    }
+    /// `m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "003"),
+        )
+    }
+    /// `m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "004"),
+        )
+    }
+    /// `m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "005"),
+        )
+    }
+    /// `m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "006"),
+        )
+    }
+    /// `m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "007"),
+        )
+    }
+    /// `m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "008"),
+        )
+    }
+    /// `m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "009"),
+        )
+    }
+    /// `m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "010"),
+        )
+    }
+    /// `m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "011"),
+        )
+    }
+    /// `m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "012"),
+        )
+    }
+    /// `m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "013"),
+        )
+    }
+    /// `m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "014"),
+        )
+    }
+    /// `m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "015"),
+        )
+    }
+    /// `m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "016"),
+        )
+    }
+    /// `m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "017"),
+        )
+    }
+    /// `m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "018"),
+        )
+    }
+    /// `m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "019"),
+        )
+    }
+    /// `m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "020"),
+        )
+    }
+    /// `m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "021"),
+        )
+    }
+    /// `m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "022"),
+        )
+    }
+    /// `m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "023"),
+        )
+    }
+    /// `m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "000"),
+        )
+    }
+    /// `m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "001"),
+        )
+    }
+    /// `m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "002"),
+        )
+    }
+    /// `m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "003"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled"),
+        )
+    }
+    /// `DT_Animationlayer.m_nSequence`
+    pub fn sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence"),
+        )
+    }
+    /// `DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle"),
+        )
+    }
+    /// `DT_Animationlayer.m_flPrevCycle`
+    pub fn prev_cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle"),
+        )
+    }
+    /// `DT_Animationlayer.m_flWeight`
+    pub fn weight(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight"),
+        )
+    }
+    /// `DT_Animationlayer.m_nOrder`
+    pub fn order(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder"),
+        )
+    }
+    /// `m_flexWeight.000`
+    pub fn flex_weight_000(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "000"))
+    }
+    /// `m_flexWeight.001`
+    pub fn flex_weight_001(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "001"))
+    }
+    /// `m_flexWeight.002`
+    pub fn flex_weight_002(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "002"))
+    }
+    /// `m_flexWeight.003`
+    pub fn flex_weight_003(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "003"))
+    }
+    /// `m_flexWeight.004`
+    pub fn flex_weight_004(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "004"))
+    }
+    /// `m_flexWeight.005`
+    pub fn flex_weight_005(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "005"))
+    }
+    /// `m_flexWeight.006`
+    pub fn flex_weight_006(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "006"))
+    }
+    /// `m_flexWeight.007`
+    pub fn flex_weight_007(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "007"))
+    }
+    /// `m_flexWeight.008`
+    pub fn flex_weight_008(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "008"))
+    }
+    /// `m_flexWeight.009`
+    pub fn flex_weight_009(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "009"))
+    }
+    /// `m_flexWeight.010`
+    pub fn flex_weight_010(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "010"))
+    }
+    /// `m_flexWeight.011`
+    pub fn flex_weight_011(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "011"))
+    }
+    /// `m_flexWeight.012`
+    pub fn flex_weight_012(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "012"))
+    }
+    /// `m_flexWeight.013`
+    pub fn flex_weight_013(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "013"))
+    }
+    /// `m_flexWeight.014`
+    pub fn flex_weight_014(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "014"))
+    }
+    /// `m_flexWeight.015`
+    pub fn flex_weight_015(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "015"))
+    }
+    /// `m_flexWeight.016`
+    pub fn flex_weight_016(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "016"))
+    }
+    /// `m_flexWeight.017`
+    pub fn flex_weight_017(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "017"))
+    }
+    /// `m_flexWeight.018`
+    pub fn flex_weight_018(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "018"))
+    }
+    /// `m_flexWeight.019`
+    pub fn flex_weight_019(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "019"))
+    }
+    /// `m_flexWeight.020`
+    pub fn flex_weight_020(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "020"))
+    }
+    /// `m_flexWeight.021`
+    pub fn flex_weight_021(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "021"))
+    }
+    /// `m_flexWeight.022`
+    pub fn flex_weight_022(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "022"))
+    }
+    /// `m_flexWeight.023`
+    pub fn flex_weight_023(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "023"))
+    }
+    /// `m_flexWeight.024`
+    pub fn flex_weight_024(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "024"))
+    }
+    /// `m_flexWeight.025`
+    pub fn flex_weight_025(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "025"))
+    }
+    /// `m_flexWeight.026`
+    pub fn flex_weight_026(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "026"))
+    }
+    /// `m_flexWeight.027`
+    pub fn flex_weight_027(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "027"))
+    }
+    /// `m_flexWeight.028`
+    pub fn flex_weight_028(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "028"))
+    }
+    /// `m_flexWeight.029`
+    pub fn flex_weight_029(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "029"))
+    }
+    /// `m_flexWeight.030`
+    pub fn flex_weight_030(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "030"))
+    }
+    /// `m_flexWeight.031`
+    pub fn flex_weight_031(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "031"))
+    }
+    /// `m_flexWeight.032`
+    pub fn flex_weight_032(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "032"))
+    }
+    /// `m_flexWeight.033`
+    pub fn flex_weight_033(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "033"))
+    }
+    /// `m_flexWeight.034`
+    pub fn flex_weight_034(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "034"))
+    }
+    /// `m_flexWeight.035`
+    pub fn flex_weight_035(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "035"))
+    }
+    /// `m_flexWeight.036`
+    pub fn flex_weight_036(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "036"))
+    }
+    /// `m_flexWeight.037`
+    pub fn flex_weight_037(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "037"))
+    }
+    /// `m_flexWeight.038`
+    pub fn flex_weight_038(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "038"))
+    }
+    /// `m_flexWeight.039`
+    pub fn flex_weight_039(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "039"))
+    }
+    /// `m_flexWeight.040`
+    pub fn flex_weight_040(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "040"))
+    }
+    /// `m_flexWeight.041`
+    pub fn flex_weight_041(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "041"))
+    }
+    /// `m_flexWeight.042`
+    pub fn flex_weight_042(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "042"))
+    }
+    /// `m_flexWeight.043`
+    pub fn flex_weight_043(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "043"))
+    }
+    /// `m_flexWeight.044`
+    pub fn flex_weight_044(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "044"))
+    }
+    /// `m_flexWeight.045`
+    pub fn flex_weight_045(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "045"))
+    }
+    /// `m_flexWeight.046`
+    pub fn flex_weight_046(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "046"))
+    }
+    /// `m_flexWeight.047`
+    pub fn flex_weight_047(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "047"))
+    }
+    /// `m_flexWeight.048`
+    pub fn flex_weight_048(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "048"))
+    }
+    /// `m_flexWeight.049`
+    pub fn flex_weight_049(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "049"))
+    }
+    /// `m_flexWeight.050`
+    pub fn flex_weight_050(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "050"))
+    }
+    /// `m_flexWeight.051`
+    pub fn flex_weight_051(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "051"))
+    }
+    /// `m_flexWeight.052`
+    pub fn flex_weight_052(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "052"))
+    }
+    /// `m_flexWeight.053`
+    pub fn flex_weight_053(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "053"))
+    }
+    /// `m_flexWeight.054`
+    pub fn flex_weight_054(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "054"))
+    }
+    /// `m_flexWeight.055`
+    pub fn flex_weight_055(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "055"))
+    }
+    /// `m_flexWeight.056`
+    pub fn flex_weight_056(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "056"))
+    }
+    /// `m_flexWeight.057`
+    pub fn flex_weight_057(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "057"))
+    }
+    /// `m_flexWeight.058`
+    pub fn flex_weight_058(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "058"))
+    }
+    /// `m_flexWeight.059`
+    pub fn flex_weight_059(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "059"))
+    }
+    /// `m_flexWeight.060`
+    pub fn flex_weight_060(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "060"))
+    }
+    /// `m_flexWeight.061`
+    pub fn flex_weight_061(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "061"))
+    }
+    /// `m_flexWeight.062`
+    pub fn flex_weight_062(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "062"))
+    }
+    /// `m_flexWeight.063`
+    pub fn flex_weight_063(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "063"))
+    }
+    /// `m_flexWeight.064`
+    pub fn flex_weight_064(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "064"))
+    }
+    /// `m_flexWeight.065`
+    pub fn flex_weight_065(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "065"))
+    }
+    /// `m_flexWeight.066`
+    pub fn flex_weight_066(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "066"))
+    }
+    /// `m_flexWeight.067`
+    pub fn flex_weight_067(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "067"))
+    }
+    /// `m_flexWeight.068`
+    pub fn flex_weight_068(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "068"))
+    }
+    /// `m_flexWeight.069`
+    pub fn flex_weight_069(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "069"))
+    }
+    /// `m_flexWeight.070`
+    pub fn flex_weight_070(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "070"))
+    }
+    /// `m_flexWeight.071`
+    pub fn flex_weight_071(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "071"))
+    }
+    /// `m_flexWeight.072`
+    pub fn flex_weight_072(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "072"))
+    }
+    /// `m_flexWeight.073`
+    pub fn flex_weight_073(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "073"))
+    }
+    /// `m_flexWeight.074`
+    pub fn flex_weight_074(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "074"))
+    }
+    /// `m_flexWeight.075`
+    pub fn flex_weight_075(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "075"))
+    }
+    /// `m_flexWeight.076`
+    pub fn flex_weight_076(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "076"))
+    }
+    /// `m_flexWeight.077`
+    pub fn flex_weight_077(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "077"))
+    }
+    /// `m_flexWeight.078`
+    pub fn flex_weight_078(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "078"))
+    }
+    /// `m_flexWeight.079`
+    pub fn flex_weight_079(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "079"))
+    }
+    /// `m_flexWeight.080`
+    pub fn flex_weight_080(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "080"))
+    }
+    /// `m_flexWeight.081`
+    pub fn flex_weight_081(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "081"))
+    }
+    /// `m_flexWeight.082`
+    pub fn flex_weight_082(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "082"))
+    }
+    /// `m_flexWeight.083`
+    pub fn flex_weight_083(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "083"))
+    }
+    /// `m_flexWeight.084`
+    pub fn flex_weight_084(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "084"))
+    }
+    /// `m_flexWeight.085`
+    pub fn flex_weight_085(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "085"))
+    }
+    /// `m_flexWeight.086`
+    pub fn flex_weight_086(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "086"))
+    }
+    /// `m_flexWeight.087`
+    pub fn flex_weight_087(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "087"))
+    }
+    /// `m_flexWeight.088`
+    pub fn flex_weight_088(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "088"))
+    }
+    /// `m_flexWeight.089`
+    pub fn flex_weight_089(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "089"))
+    }
+    /// `m_flexWeight.090`
+    pub fn flex_weight_090(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "090"))
+    }
+    /// `m_flexWeight.091`
+    pub fn flex_weight_091(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "091"))
+    }
+    /// `m_flexWeight.092`
+    pub fn flex_weight_092(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "092"))
+    }
+    /// `m_flexWeight.093`
+    pub fn flex_weight_093(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "093"))
+    }
+    /// `m_flexWeight.094`
+    pub fn flex_weight_094(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "094"))
+    }
+    /// `m_flexWeight.095`
+    pub fn flex_weight_095(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "095"))
+    }
+    /// `DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub fn next_attack(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack"),
+        )
+    }
+    /// `m_hMyWeapons.000`
+    pub fn my_weapons_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "000"))
+    }
+    /// `m_hMyWeapons.001`
+    pub fn my_weapons_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "001"))
+    }
+    /// `m_hMyWeapons.002`
+    pub fn my_weapons_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "002"))
+    }
+    /// `m_hMyWeapons.003`
+    pub fn my_weapons_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "003"))
+    }
+    /// `m_hMyWeapons.004`
+    pub fn my_weapons_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "004"))
+    }
+    /// `m_hMyWeapons.005`
+    pub fn my_weapons_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "005"))
+    }
+    /// `m_hMyWeapons.006`
+    pub fn my_weapons_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "006"))
+    }
+    /// `m_hMyWeapons.007`
+    pub fn my_weapons_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "007"))
+    }
+    /// `m_hMyWeapons.008`
+    pub fn my_weapons_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "008"))
+    }
+    /// `m_hMyWeapons.009`
+    pub fn my_weapons_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "009"))
+    }
+    /// `m_hMyWeapons.010`
+    pub fn my_weapons_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "010"))
+    }
+    /// `m_hMyWeapons.011`
+    pub fn my_weapons_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "011"))
+    }
+    /// `m_hMyWeapons.012`
+    pub fn my_weapons_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "012"))
+    }
+    /// `m_hMyWeapons.013`
+    pub fn my_weapons_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "013"))
+    }
+    /// `m_hMyWeapons.014`
+    pub fn my_weapons_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "014"))
+    }
+    /// `m_hMyWeapons.015`
+    pub fn my_weapons_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "015"))
+    }
+    /// `m_hMyWeapons.016`
+    pub fn my_weapons_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "016"))
+    }
+    /// `m_hMyWeapons.017`
+    pub fn my_weapons_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "017"))
+    }
+    /// `m_hMyWeapons.018`
+    pub fn my_weapons_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "018"))
+    }
+    /// `m_hMyWeapons.019`
+    pub fn my_weapons_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "019"))
+    }
+    /// `m_hMyWeapons.020`
+    pub fn my_weapons_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "020"))
+    }
+    /// `m_hMyWeapons.021`
+    pub fn my_weapons_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "021"))
+    }
+    /// `m_hMyWeapons.022`
+    pub fn my_weapons_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "022"))
+    }
+    /// `m_hMyWeapons.023`
+    pub fn my_weapons_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "023"))
+    }
+    /// `m_hMyWeapons.024`
+    pub fn my_weapons_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "024"))
+    }
+    /// `m_hMyWeapons.025`
+    pub fn my_weapons_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "025"))
+    }
+    /// `m_hMyWeapons.026`
+    pub fn my_weapons_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "026"))
+    }
+    /// `m_hMyWeapons.027`
+    pub fn my_weapons_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "027"))
+    }
+    /// `m_hMyWeapons.028`
+    pub fn my_weapons_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "028"))
+    }
+    /// `m_hMyWeapons.029`
+    pub fn my_weapons_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "029"))
+    }
+    /// `m_hMyWeapons.030`
+    pub fn my_weapons_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "030"))
+    }
+    /// `m_hMyWeapons.031`
+    pub fn my_weapons_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "031"))
+    }
+    /// `m_hMyWeapons.032`
+    pub fn my_weapons_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "032"))
+    }
+    /// `m_hMyWeapons.033`
+    pub fn my_weapons_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "033"))
+    }
+    /// `m_hMyWeapons.034`
+    pub fn my_weapons_034(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "034"))
+    }
+    /// `m_hMyWeapons.035`
+    pub fn my_weapons_035(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "035"))
+    }
+    /// `m_hMyWeapons.036`
+    pub fn my_weapons_036(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "036"))
+    }
+    /// `m_hMyWeapons.037`
+    pub fn my_weapons_037(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "037"))
+    }
+    /// `m_hMyWeapons.038`
+    pub fn my_weapons_038(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "038"))
+    }
+    /// `m_hMyWeapons.039`
+    pub fn my_weapons_039(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "039"))
+    }
+    /// `m_hMyWeapons.040`
+    pub fn my_weapons_040(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "040"))
+    }
+    /// `m_hMyWeapons.041`
+    pub fn my_weapons_041(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "041"))
+    }
+    /// `m_hMyWeapons.042`
+    pub fn my_weapons_042(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "042"))
+    }
+    /// `m_hMyWeapons.043`
+    pub fn my_weapons_043(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "043"))
+    }
+    /// `m_hMyWeapons.044`
+    pub fn my_weapons_044(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "044"))
+    }
+    /// `m_hMyWeapons.045`
+    pub fn my_weapons_045(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "045"))
+    }
+    /// `m_hMyWeapons.046`
+    pub fn my_weapons_046(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "046"))
+    }
+    /// `m_hMyWeapons.047`
+    pub fn my_weapons_047(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "047"))
+    }
+    /// `DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType"),
+        )
+    }
+    /// `DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame"),
+        )
+    }
+    /// `DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode"),
+        )
+    }
+    /// `DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects"),
+        )
+    }
+    /// `DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+        )
+    }
+    /// `DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup"),
+        )
+    }
+    /// `DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity"),
+        )
+    }
+    /// `DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance"),
+        )
+    }
+    /// `DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity"),
+        )
+    }
+    /// `DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity"),
+        )
+    }
+    /// `DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent"),
+        )
+    }
+    /// `DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment"),
+        )
+    }
+    /// `DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movetype"),
+        )
+    }
+    /// `DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide"),
+        )
+    }
+    /// `DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone"),
+        )
+    }
+    /// `DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSequence`
+    pub fn base_animating_sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale"),
+        )
+    }
+    /// `DT_BaseFlex.m_blinktoggle`
+    pub fn blinktoggle(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle"),
+        )
+    }
+    /// `DT_BaseFlex.m_viewtarget`
+    pub fn viewtarget(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget"),
+        )
+    }
+    /// `DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub fn glow_enabled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled"),
+        )
+    }
+    /// `DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub fn active_weapon(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon"),
+        )
+    }
+    /// `DT_BaseObject.m_iHealth`
+    pub fn health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iHealth"),
+        )
+    }
+    /// `DT_BaseObject.m_iMaxHealth`
+    pub fn max_health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth"),
+        )
+    }
+    /// `DT_BaseObject.m_bHasSapper`
+    pub fn has_sapper(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper"),
+        )
+    }
+    /// `DT_BaseObject.m_iObjectType`
+    pub fn object_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType"),
+        )
+    }
+    /// `DT_BaseObject.m_bBuilding`
+    pub fn building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding"),
+        )
+    }
+    /// `DT_BaseObject.m_bPlacing`
+    pub fn placing(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlacing"),
+        )
+    }
+    /// `DT_BaseObject.m_bCarried`
+    pub fn carried(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarried"),
+        )
+    }
+    /// `DT_BaseObject.m_bCarryDeploy`
+    pub fn carry_deploy(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy"),
+        )
+    }
+    /// `DT_BaseObject.m_bMiniBuilding`
+    pub fn mini_building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding"),
+        )
+    }
+    /// `DT_BaseObject.m_flPercentageConstructed`
+    pub fn percentage_constructed(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed"),
+        )
+    }
+    /// `DT_BaseObject.m_fObjectFlags`
+    pub fn object_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags"),
+        )
+    }
+    /// `DT_BaseObject.m_hBuiltOnEntity`
+    pub fn built_on_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity"),
+        )
+    }
+    /// `DT_BaseObject.m_bDisabled`
+    pub fn disabled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled"),
+        )
+    }
+    /// `DT_BaseObject.m_hBuilder`
+    pub fn builder(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuilder"),
+        )
+    }
+    /// `DT_BaseObject.m_vecBuildMaxs`
+    pub fn build_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs"),
+        )
+    }
+    /// `DT_BaseObject.m_vecBuildMins`
+    pub fn build_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins"),
+        )
+    }
+    /// `DT_BaseObject.m_iDesiredBuildRotations`
+    pub fn desired_build_rotations(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations"),
+        )
+    }
+    /// `DT_BaseObject.m_bServerOverridePlacement`
+    pub fn server_override_placement(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeLevel`
+    pub fn upgrade_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeMetal`
+    pub fn upgrade_metal(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeMetalRequired`
+    pub fn upgrade_metal_required(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired"),
+        )
+    }
+    /// `DT_BaseObject.m_iHighestUpgradeLevel`
+    pub fn highest_upgrade_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel"),
+        )
+    }
+    /// `DT_BaseObject.m_iObjectMode`
+    pub fn object_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode"),
+        )
+    }
+    /// `DT_BaseObject.m_bDisposableBuilding`
+    pub fn disposable_building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat"),
+        )
+    }
+    /// `DT_ObjectSentrygun.m_iState`
+    pub fn state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_iState"),
+        )
+    }
+    /// `DT_ObjectSentrygun.m_bPlayerControlled`
+    pub fn player_controlled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_bPlayerControlled"),
+        )
+    }
+    /// `DT_ObjectSentrygun.m_nShieldLevel`
+    pub fn shield_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_nShieldLevel"),
+        )
+    }
+    /// `DT_ObjectSentrygun.m_hEnemy`
+    pub fn enemy(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_hEnemy"),
+        )
+    }
+    /// `DT_ObjectSentrygun.m_hAutoAimTarget`
+    pub fn auto_aim_target(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_hAutoAimTarget"),
+        )
+    }
+}
+/// Typed view of the props of a `CObjectDispenser` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CObjectDispenserView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CObjectDispenserView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CObjectDispenser";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CObjectDispenserView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CObjectDispenserView { entity })
+    }
+    /// `DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub fn anim_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime"),
+        )
+    }
+    /// `DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle"),
+        )
+    }
+    /// `DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime"),
+        )
+    }
+    /// `DT_BaseEntity.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin"),
+        )
+    }
+    /// `DT_BaseEntity.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMinsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMaxsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs"),
+        )
+    }
+    /// `DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID"),
+        )
+    }
+    /// `DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated"),
+        )
+    }
+    /// `m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000"),
+        )
+    }
+    /// `m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001"),
+        )
+    }
+    /// `m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002"),
+        )
+    }
+    /// `m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003"),
+        )
+    }
+    /// `m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "000"),
+        )
+    }
+    /// `m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "001"),
+        )
+    }
+    /// `m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "002"),
+        )
+    }
+    /// `m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "003"),
+        )
+    }
+    /// `m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "004"),
+        )
+    }
+    /// `m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "005"),
+        )
+    }
+    /// `m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "006"),
+        )
+    }
+    /// `m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "007"),
+        )
+    }
+    /// `m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "008"),
+        )
+    }
+    /// `m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "009"),
+        )
+    }
+    /// `m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "010"),
+        )
+    }
+    /// `m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "011"),
+        )
+    }
+    /// `m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "012"),
+        )
+    }
+    /// `m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "013"),
+        )
+    }
+    /// `m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "014"),
+        )
+    }
+    /// `m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "015"),
+        )
+    }
+    /// `m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "016"),
+        )
+    }
+    /// `m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "017"),
+        )
+    }
+    /// `m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "018"),
+        )
+    }
+    /// `m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "019"),
+        )
+    }
+    /// `m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "020"),
+        )
+    }
+    /// `m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "021"),
+        )
+    }
+    /// `m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "022"),
+        )
+    }
+    /// `m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "023"),
+        )
+    }
+    /// `m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "000"),
+        )
+    }
+    /// `m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "001"),
+        )
+    }
+    /// `m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "002"),
+        )
+    }
+    /// `m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "003"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled"),
+        )
+    }
+    /// `DT_Animationlayer.m_nSequence`
+    pub fn sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence"),
+        )
+    }
+    /// `DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle"),
+        )
+    }
+    /// `DT_Animationlayer.m_flPrevCycle`
+    pub fn prev_cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle"),
+        )
+    }
+    /// `DT_Animationlayer.m_flWeight`
+    pub fn weight(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight"),
+        )
+    }
+    /// `DT_Animationlayer.m_nOrder`
+    pub fn order(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder"),
+        )
+    }
+    /// `m_flexWeight.000`
+    pub fn flex_weight_000(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "000"))
+    }
+    /// `m_flexWeight.001`
+    pub fn flex_weight_001(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "001"))
+    }
+    /// `m_flexWeight.002`
+    pub fn flex_weight_002(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "002"))
+    }
+    /// `m_flexWeight.003`
+    pub fn flex_weight_003(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "003"))
+    }
+    /// `m_flexWeight.004`
+    pub fn flex_weight_004(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "004"))
+    }
+    /// `m_flexWeight.005`
+    pub fn flex_weight_005(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "005"))
+    }
+    /// `m_flexWeight.006`
+    pub fn flex_weight_006(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "006"))
+    }
+    /// `m_flexWeight.007`
+    pub fn flex_weight_007(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "007"))
+    }
+    /// `m_flexWeight.008`
+    pub fn flex_weight_008(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "008"))
+    }
+    /// `m_flexWeight.009`
+    pub fn flex_weight_009(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "009"))
+    }
+    /// `m_flexWeight.010`
+    pub fn flex_weight_010(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "010"))
+    }
+    /// `m_flexWeight.011`
+    pub fn flex_weight_011(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "011"))
+    }
+    /// `m_flexWeight.012`
+    pub fn flex_weight_012(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "012"))
+    }
+    /// `m_flexWeight.013`
+    pub fn flex_weight_013(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "013"))
+    }
+    /// `m_flexWeight.014`
+    pub fn flex_weight_014(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "014"))
+    }
+    /// `m_flexWeight.015`
+    pub fn flex_weight_015(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "015"))
+    }
+    /// `m_flexWeight.016`
+    pub fn flex_weight_016(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "016"))
+    }
+    /// `m_flexWeight.017`
+    pub fn flex_weight_017(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "017"))
+    }
+    /// `m_flexWeight.018`
+    pub fn flex_weight_018(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "018"))
+    }
+    /// `m_flexWeight.019`
+    pub fn flex_weight_019(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "019"))
+    }
+    /// `m_flexWeight.020`
+    pub fn flex_weight_020(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "020"))
+    }
+    /// `m_flexWeight.021`
+    pub fn flex_weight_021(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "021"))
+    }
+    /// `m_flexWeight.022`
+    pub fn flex_weight_022(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "022"))
+    }
+    /// `m_flexWeight.023`
+    pub fn flex_weight_023(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "023"))
+    }
+    /// `m_flexWeight.024`
+    pub fn flex_weight_024(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "024"))
+    }
+    /// `m_flexWeight.025`
+    pub fn flex_weight_025(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "025"))
+    }
+    /// `m_flexWeight.026`
+    pub fn flex_weight_026(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "026"))
+    }
+    /// `m_flexWeight.027`
+    pub fn flex_weight_027(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "027"))
+    }
+    /// `m_flexWeight.028`
+    pub fn flex_weight_028(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "028"))
+    }
+    /// `m_flexWeight.029`
+    pub fn flex_weight_029(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "029"))
+    }
+    /// `m_flexWeight.030`
+    pub fn flex_weight_030(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "030"))
+    }
+    /// `m_flexWeight.031`
+    pub fn flex_weight_031(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "031"))
+    }
+    /// `m_flexWeight.032`
+    pub fn flex_weight_032(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "032"))
+    }
+    /// `m_flexWeight.033`
+    pub fn flex_weight_033(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "033"))
+    }
+    /// `m_flexWeight.034`
+    pub fn flex_weight_034(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "034"))
+    }
+    /// `m_flexWeight.035`
+    pub fn flex_weight_035(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "035"))
+    }
+    /// `m_flexWeight.036`
+    pub fn flex_weight_036(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "036"))
+    }
+    /// `m_flexWeight.037`
+    pub fn flex_weight_037(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "037"))
+    }
+    /// `m_flexWeight.038`
+    pub fn flex_weight_038(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "038"))
+    }
+    /// `m_flexWeight.039`
+    pub fn flex_weight_039(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "039"))
+    }
+    /// `m_flexWeight.040`
+    pub fn flex_weight_040(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "040"))
+    }
+    /// `m_flexWeight.041`
+    pub fn flex_weight_041(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "041"))
+    }
+    /// `m_flexWeight.042`
+    pub fn flex_weight_042(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "042"))
+    }
+    /// `m_flexWeight.043`
+    pub fn flex_weight_043(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "043"))
+    }
+    /// `m_flexWeight.044`
+    pub fn flex_weight_044(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "044"))
+    }
+    /// `m_flexWeight.045`
+    pub fn flex_weight_045(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "045"))
+    }
+    /// `m_flexWeight.046`
+    pub fn flex_weight_046(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "046"))
+    }
+    /// `m_flexWeight.047`
+    pub fn flex_weight_047(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "047"))
+    }
+    /// `m_flexWeight.048`
+    pub fn flex_weight_048(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "048"))
+    }
+    /// `m_flexWeight.049`
+    pub fn flex_weight_049(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "049"))
+    }
+    /// `m_flexWeight.050`
+    pub fn flex_weight_050(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "050"))
+    }
+    /// `m_flexWeight.051`
+    pub fn flex_weight_051(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "051"))
+    }
+    /// `m_flexWeight.052`
+    pub fn flex_weight_052(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "052"))
+    }
+    /// `m_flexWeight.053`
+    pub fn flex_weight_053(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "053"))
+    }
+    /// `m_flexWeight.054`
+    pub fn flex_weight_054(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "054"))
+    }
+    /// `m_flexWeight.055`
+    pub fn flex_weight_055(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "055"))
+    }
+    /// `m_flexWeight.056`
+    pub fn flex_weight_056(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "056"))
+    }
+    /// `m_flexWeight.057`
+    pub fn flex_weight_057(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "057"))
+    }
+    /// `m_flexWeight.058`
+    pub fn flex_weight_058(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "058"))
+    }
+    /// `m_flexWeight.059`
+    pub fn flex_weight_059(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "059"))
+    }
+    /// `m_flexWeight.060`
+    pub fn flex_weight_060(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "060"))
+    }
+    /// `m_flexWeight.061`
+    pub fn flex_weight_061(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "061"))
+    }
+    /// `m_flexWeight.062`
+    pub fn flex_weight_062(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "062"))
+    }
+    /// `m_flexWeight.063`
+    pub fn flex_weight_063(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "063"))
+    }
+    /// `m_flexWeight.064`
+    pub fn flex_weight_064(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "064"))
+    }
+    /// `m_flexWeight.065`
+    pub fn flex_weight_065(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "065"))
+    }
+    /// `m_flexWeight.066`
+    pub fn flex_weight_066(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "066"))
+    }
+    /// `m_flexWeight.067`
+    pub fn flex_weight_067(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "067"))
+    }
+    /// `m_flexWeight.068`
+    pub fn flex_weight_068(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "068"))
+    }
+    /// `m_flexWeight.069`
+    pub fn flex_weight_069(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "069"))
+    }
+    /// `m_flexWeight.070`
+    pub fn flex_weight_070(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "070"))
+    }
+    /// `m_flexWeight.071`
+    pub fn flex_weight_071(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "071"))
+    }
+    /// `m_flexWeight.072`
+    pub fn flex_weight_072(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "072"))
+    }
+    /// `m_flexWeight.073`
+    pub fn flex_weight_073(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "073"))
+    }
+    /// `m_flexWeight.074`
+    pub fn flex_weight_074(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "074"))
+    }
+    /// `m_flexWeight.075`
+    pub fn flex_weight_075(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "075"))
+    }
+    /// `m_flexWeight.076`
+    pub fn flex_weight_076(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "076"))
+    }
+    /// `m_flexWeight.077`
+    pub fn flex_weight_077(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "077"))
+    }
+    /// `m_flexWeight.078`
+    pub fn flex_weight_078(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "078"))
+    }
+    /// `m_flexWeight.079`
+    pub fn flex_weight_079(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "079"))
+    }
+    /// `m_flexWeight.080`
+    pub fn flex_weight_080(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "080"))
+    }
+    /// `m_flexWeight.081`
+    pub fn flex_weight_081(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "081"))
+    }
+    /// `m_flexWeight.082`
+    pub fn flex_weight_082(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "082"))
+    }
+    /// `m_flexWeight.083`
+    pub fn flex_weight_083(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "083"))
+    }
+    /// `m_flexWeight.084`
+    pub fn flex_weight_084(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "084"))
+    }
+    /// `m_flexWeight.085`
+    pub fn flex_weight_085(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "085"))
+    }
+    /// `m_flexWeight.086`
+    pub fn flex_weight_086(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "086"))
+    }
+    /// `m_flexWeight.087`
+    pub fn flex_weight_087(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "087"))
+    }
+    /// `m_flexWeight.088`
+    pub fn flex_weight_088(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "088"))
+    }
+    /// `m_flexWeight.089`
+    pub fn flex_weight_089(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "089"))
+    }
+    /// `m_flexWeight.090`
+    pub fn flex_weight_090(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "090"))
+    }
+    /// `m_flexWeight.091`
+    pub fn flex_weight_091(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "091"))
+    }
+    /// `m_flexWeight.092`
+    pub fn flex_weight_092(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "092"))
+    }
+    /// `m_flexWeight.093`
+    pub fn flex_weight_093(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "093"))
+    }
+    /// `m_flexWeight.094`
+    pub fn flex_weight_094(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "094"))
+    }
+    /// `m_flexWeight.095`
+    pub fn flex_weight_095(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "095"))
+    }
+    /// `DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub fn next_attack(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack"),
+        )
+    }
+    /// `m_hMyWeapons.000`
+    pub fn my_weapons_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "000"))
+    }
+    /// `m_hMyWeapons.001`
+    pub fn my_weapons_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "001"))
+    }
+    /// `m_hMyWeapons.002`
+    pub fn my_weapons_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "002"))
+    }
+    /// `m_hMyWeapons.003`
+    pub fn my_weapons_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "003"))
+    }
+    /// `m_hMyWeapons.004`
+    pub fn my_weapons_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "004"))
+    }
+    /// `m_hMyWeapons.005`
+    pub fn my_weapons_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "005"))
+    }
+    /// `m_hMyWeapons.006`
+    pub fn my_weapons_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "006"))
+    }
+    /// `m_hMyWeapons.007`
+    pub fn my_weapons_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "007"))
+    }
+    /// `m_hMyWeapons.008`
+    pub fn my_weapons_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "008"))
+    }
+    /// `m_hMyWeapons.009`
+    pub fn my_weapons_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "009"))
+    }
+    /// `m_hMyWeapons.010`
+    pub fn my_weapons_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "010"))
+    }
+    /// `m_hMyWeapons.011`
+    pub fn my_weapons_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "011"))
+    }
+    /// `m_hMyWeapons.012`
+    pub fn my_weapons_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "012"))
+    }
+    /// `m_hMyWeapons.013`
+    pub fn my_weapons_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "013"))
+    }
+    /// `m_hMyWeapons.014`
+    pub fn my_weapons_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "014"))
+    }
+    /// `m_hMyWeapons.015`
+    pub fn my_weapons_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "015"))
+    }
+    /// `m_hMyWeapons.016`
+    pub fn my_weapons_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "016"))
+    }
+    /// `m_hMyWeapons.017`
+    pub fn my_weapons_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "017"))
+    }
+    /// `m_hMyWeapons.018`
+    pub fn my_weapons_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "018"))
+    }
+    /// `m_hMyWeapons.019`
+    pub fn my_weapons_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "019"))
+    }
+    /// `m_hMyWeapons.020`
+    pub fn my_weapons_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "020"))
+    }
+    /// `m_hMyWeapons.021`
+    pub fn my_weapons_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "021"))
+    }
+    /// `m_hMyWeapons.022`
+    pub fn my_weapons_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "022"))
+    }
+    /// `m_hMyWeapons.023`
+    pub fn my_weapons_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "023"))
+    }
+    /// `m_hMyWeapons.024`
+    pub fn my_weapons_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "024"))
+    }
+    /// `m_hMyWeapons.025`
+    pub fn my_weapons_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "025"))
+    }
+    /// `m_hMyWeapons.026`
+    pub fn my_weapons_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "026"))
+    }
+    /// `m_hMyWeapons.027`
+    pub fn my_weapons_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "027"))
+    }
+    /// `m_hMyWeapons.028`
+    pub fn my_weapons_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "028"))
+    }
+    /// `m_hMyWeapons.029`
+    pub fn my_weapons_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "029"))
+    }
+    /// `m_hMyWeapons.030`
+    pub fn my_weapons_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "030"))
+    }
+    /// `m_hMyWeapons.031`
+    pub fn my_weapons_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "031"))
+    }
+    /// `m_hMyWeapons.032`
+    pub fn my_weapons_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "032"))
+    }
+    /// `m_hMyWeapons.033`
+    pub fn my_weapons_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "033"))
+    }
+    /// `m_hMyWeapons.034`
+    pub fn my_weapons_034(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "034"))
+    }
+    /// `m_hMyWeapons.035`
+    pub fn my_weapons_035(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "035"))
+    }
+    /// `m_hMyWeapons.036`
+    pub fn my_weapons_036(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "036"))
+    }
+    /// `m_hMyWeapons.037`
+    pub fn my_weapons_037(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "037"))
+    }
+    /// `m_hMyWeapons.038`
+    pub fn my_weapons_038(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "038"))
+    }
+    /// `m_hMyWeapons.039`
+    pub fn my_weapons_039(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "039"))
+    }
+    /// `m_hMyWeapons.040`
+    pub fn my_weapons_040(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "040"))
+    }
+    /// `m_hMyWeapons.041`
+    pub fn my_weapons_041(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "041"))
+    }
+    /// `m_hMyWeapons.042`
+    pub fn my_weapons_042(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "042"))
+    }
+    /// `m_hMyWeapons.043`
+    pub fn my_weapons_043(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "043"))
+    }
+    /// `m_hMyWeapons.044`
+    pub fn my_weapons_044(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "044"))
+    }
+    /// `m_hMyWeapons.045`
+    pub fn my_weapons_045(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "045"))
+    }
+    /// `m_hMyWeapons.046`
+    pub fn my_weapons_046(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "046"))
+    }
+    /// `m_hMyWeapons.047`
+    pub fn my_weapons_047(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "047"))
+    }
+    /// `DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins"),
+        )
+    }
+    /// `DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame"),
+        )
+    }
+    /// `DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode"),
+        )
+    }
+    /// `DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects"),
+        )
+    }
+    /// `DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+        )
+    }
+    /// `DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup"),
+        )
+    }
+    /// `DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity"),
+        )
+    }
+    /// `DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance"),
+        )
+    }
+    /// `DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity"),
+        )
+    }
+    /// `DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity"),
+        )
+    }
+    /// `DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent"),
+        )
+    }
+    /// `DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment"),
+        )
+    }
+    /// `DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movetype"),
+        )
+    }
+    /// `DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone"),
+        )
+    }
+    /// `DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSequence`
+    pub fn base_animating_sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale"),
+        )
+    }
+    /// `DT_BaseFlex.m_blinktoggle`
+    pub fn blinktoggle(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle"),
+        )
+    }
+    /// `DT_BaseFlex.m_viewtarget`
+    pub fn viewtarget(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget"),
+        )
+    }
+    /// `DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub fn glow_enabled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled"),
+        )
+    }
+    /// `DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub fn active_weapon(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon"),
+        )
+    }
+    /// `DT_BaseObject.m_iHealth`
+    pub fn health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iHealth"),
+        )
+    }
+    /// `DT_BaseObject.m_iMaxHealth`
+    pub fn max_health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth"),
+        )
+    }
+    /// `DT_BaseObject.m_bHasSapper`
+    pub fn has_sapper(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper"),
+        )
+    }
+    /// `DT_BaseObject.m_iObjectType`
+    pub fn object_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType"),
+        )
+    }
+    /// `DT_BaseObject.m_bBuilding`
+    pub fn building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding"),
+        )
+    }
+    /// `DT_BaseObject.m_bPlacing`
+    pub fn placing(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlacing"),
+        )
+    }
+    /// `DT_BaseObject.m_bCarried`
+    pub fn carried(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarried"),
+        )
+    }
+    /// `DT_BaseObject.m_bCarryDeploy`
+    pub fn carry_deploy(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy"),
+        )
+    }
+    /// `DT_BaseObject.m_bMiniBuilding`
+    pub fn mini_building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding"),
+        )
+    }
+    /// `DT_BaseObject.m_flPercentageConstructed`
+    pub fn percentage_constructed(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed"),
+        )
+    }
+    /// `DT_BaseObject.m_fObjectFlags`
+    pub fn object_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags"),
+        )
+    }
+    /// `DT_BaseObject.m_hBuiltOnEntity`
+    pub fn built_on_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity"),
+        )
+    }
+    /// `DT_BaseObject.m_bDisabled`
+    pub fn disabled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled"),
+        )
+    }
+    /// `DT_BaseObject.m_hBuilder`
+    pub fn builder(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuilder"),
+        )
+    }
+    /// `DT_BaseObject.m_vecBuildMaxs`
+    pub fn build_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs"),
+        )
+    }
+    /// `DT_BaseObject.m_vecBuildMins`
+    pub fn build_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins"),
+        )
+    }
+    /// `DT_BaseObject.m_iDesiredBuildRotations`
+    pub fn desired_build_rotations(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations"),
+        )
+    }
+    /// `DT_BaseObject.m_bServerOverridePlacement`
+    pub fn server_override_placement(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeLevel`
+    pub fn upgrade_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeMetal`
+    pub fn upgrade_metal(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeMetalRequired`
+    pub fn upgrade_metal_required(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired"),
+        )
+    }
+    /// `DT_BaseObject.m_iHighestUpgradeLevel`
+    pub fn highest_upgrade_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel"),
+        )
+    }
+    /// `DT_BaseObject.m_iObjectMode`
+    pub fn object_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode"),
+        )
+    }
+    /// `DT_BaseObject.m_bDisposableBuilding`
+    pub fn disposable_building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding"),
+        )
+    }
+    /// `DT_ObjectDispenser.m_iState`
+    pub fn state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectDispenser", "m_iState"),
+        )
+    }
+    /// `DT_ObjectDispenser.m_iAmmoMetal`
+    pub fn ammo_metal(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectDispenser", "m_iAmmoMetal"),
+        )
+    }
+    /// `DT_ObjectDispenser.m_iMiniBombCounter`
+    pub fn mini_bomb_counter(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectDispenser", "m_iMiniBombCounter"),
+        )
+    }
+    /// `DT_ObjectDispenser."healing_array"`
+    pub fn healing_array(&self) -> Option<&'a [SendPropValue]> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectDispenser", "\"healing_array\""),
+        )
+    }
+}
+/// Typed view of the props of a `CObjectTeleporter` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CObjectTeleporterView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CObjectTeleporterView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CObjectTeleporter";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CObjectTeleporterView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CObjectTeleporterView { entity })
+    }
+    /// `DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub fn anim_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime"),
+        )
+    }
+    /// `DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle"),
+        )
+    }
+    /// `DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime"),
+        )
+    }
+    /// `DT_BaseEntity.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin"),
+        )
+    }
+    /// `DT_BaseEntity.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMinsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMaxsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs"),
+        )
+    }
+    /// `DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID"),
+        )
+    }
+    /// `DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated"),
+        )
+    }
+    /// `m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000"),
+        )
+    }
+    /// `m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001"),
+        )
+    }
+    /// `m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002"),
+        )
+    }
+    /// `m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003"),
+        )
+    }
+    /// `m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "000"),
+        )
+    }
+    /// `m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "001"),
+        )
+    }
+    /// `m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "002"),
+        )
+    }
+    /// `m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "003"),
+        )
+    }
+    /// `m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "004"),
+        )
+    }
+    /// `m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "005"),
+        )
+    }
+    /// `m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "006"),
+        )
+    }
+    /// `m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "007"),
+        )
+    }
+    /// `m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "008"),
+        )
+    }
+    /// `m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "009"),
+        )
+    }
+    /// `m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "010"),
+        )
+    }
+    /// `m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "011"),
+        )
+    }
+    /// `m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "012"),
+        )
+    }
+    /// `m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "013"),
+        )
+    }
+    /// `m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "014"),
+        )
+    }
+    /// `m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "015"),
+        )
+    }
+    /// `m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "016"),
+        )
+    }
+    /// `m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "017"),
+        )
+    }
+    /// `m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "018"),
+        )
+    }
+    /// `m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "019"),
+        )
+    }
+    /// `m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "020"),
+        )
+    }
+    /// `m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "021"),
+        )
+    }
+    /// `m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "022"),
+        )
+    }
+    /// `m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "023"),
+        )
+    }
+    /// `m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "000"),
+        )
+    }
+    /// `m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "001"),
+        )
+    }
+    /// `m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "002"),
+        )
+    }
+    /// `m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "003"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled"),
+        )
+    }
+    /// `DT_Animationlayer.m_nSequence`
+    pub fn sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence"),
+        )
+    }
+    /// `DT_Animationlayer.m_flCycle`
+    pub fn animationlayer_cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle"),
+        )
+    }
+    /// `DT_Animationlayer.m_flPrevCycle`
+    pub fn prev_cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle"),
+        )
+    }
+    /// `DT_Animationlayer.m_flWeight`
+    pub fn weight(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight"),
+        )
+    }
+    /// `DT_Animationlayer.m_nOrder`
+    pub fn order(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_Animationlayer", "m_nOrder"),
+        )
+    }
+    /// `m_flexWeight.000`
+    pub fn flex_weight_000(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "000"))
+    }
+    /// `m_flexWeight.001`
+    pub fn flex_weight_001(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "001"))
+    }
+    /// `m_flexWeight.002`
+    pub fn flex_weight_002(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "002"))
+    }
+    /// `m_flexWeight.003`
+    pub fn flex_weight_003(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "003"))
+    }
+    /// `m_flexWeight.004`
+    pub fn flex_weight_004(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "004"))
+    }
+    /// `m_flexWeight.005`
+    pub fn flex_weight_005(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "005"))
+    }
+    /// `m_flexWeight.006`
+    pub fn flex_weight_006(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "006"))
+    }
+    /// `m_flexWeight.007`
+    pub fn flex_weight_007(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "007"))
+    }
+    /// `m_flexWeight.008`
+    pub fn flex_weight_008(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "008"))
+    }
+    /// `m_flexWeight.009`
+    pub fn flex_weight_009(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "009"))
+    }
+    /// `m_flexWeight.010`
+    pub fn flex_weight_010(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "010"))
+    }
+    /// `m_flexWeight.011`
+    pub fn flex_weight_011(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "011"))
+    }
+    /// `m_flexWeight.012`
+    pub fn flex_weight_012(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "012"))
+    }
+    /// `m_flexWeight.013`
+    pub fn flex_weight_013(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "013"))
+    }
+    /// `m_flexWeight.014`
+    pub fn flex_weight_014(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "014"))
+    }
+    /// `m_flexWeight.015`
+    pub fn flex_weight_015(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "015"))
+    }
+    /// `m_flexWeight.016`
+    pub fn flex_weight_016(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "016"))
+    }
+    /// `m_flexWeight.017`
+    pub fn flex_weight_017(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "017"))
+    }
+    /// `m_flexWeight.018`
+    pub fn flex_weight_018(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "018"))
+    }
+    /// `m_flexWeight.019`
+    pub fn flex_weight_019(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "019"))
+    }
+    /// `m_flexWeight.020`
+    pub fn flex_weight_020(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "020"))
+    }
+    /// `m_flexWeight.021`
+    pub fn flex_weight_021(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "021"))
+    }
+    /// `m_flexWeight.022`
+    pub fn flex_weight_022(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "022"))
+    }
+    /// `m_flexWeight.023`
+    pub fn flex_weight_023(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "023"))
+    }
+    /// `m_flexWeight.024`
+    pub fn flex_weight_024(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "024"))
+    }
+    /// `m_flexWeight.025`
+    pub fn flex_weight_025(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "025"))
+    }
+    /// `m_flexWeight.026`
+    pub fn flex_weight_026(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "026"))
+    }
+    /// `m_flexWeight.027`
+    pub fn flex_weight_027(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "027"))
+    }
+    /// `m_flexWeight.028`
+    pub fn flex_weight_028(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "028"))
+    }
+    /// `m_flexWeight.029`
+    pub fn flex_weight_029(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "029"))
+    }
+    /// `m_flexWeight.030`
+    pub fn flex_weight_030(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "030"))
+    }
+    /// `m_flexWeight.031`
+    pub fn flex_weight_031(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "031"))
+    }
+    /// `m_flexWeight.032`
+    pub fn flex_weight_032(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "032"))
+    }
+    /// `m_flexWeight.033`
+    pub fn flex_weight_033(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "033"))
+    }
+    /// `m_flexWeight.034`
+    pub fn flex_weight_034(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "034"))
+    }
+    /// `m_flexWeight.035`
+    pub fn flex_weight_035(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "035"))
+    }
+    /// `m_flexWeight.036`
+    pub fn flex_weight_036(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "036"))
+    }
+    /// `m_flexWeight.037`
+    pub fn flex_weight_037(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "037"))
+    }
+    /// `m_flexWeight.038`
+    pub fn flex_weight_038(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "038"))
+    }
+    /// `m_flexWeight.039`
+    pub fn flex_weight_039(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "039"))
+    }
+    /// `m_flexWeight.040`
+    pub fn flex_weight_040(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "040"))
+    }
+    /// `m_flexWeight.041`
+    pub fn flex_weight_041(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "041"))
+    }
+    /// `m_flexWeight.042`
+    pub fn flex_weight_042(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "042"))
+    }
+    /// `m_flexWeight.043`
+    pub fn flex_weight_043(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "043"))
+    }
+    /// `m_flexWeight.044`
+    pub fn flex_weight_044(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "044"))
+    }
+    /// `m_flexWeight.045`
+    pub fn flex_weight_045(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "045"))
+    }
+    /// `m_flexWeight.046`
+    pub fn flex_weight_046(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "046"))
+    }
+    /// `m_flexWeight.047`
+    pub fn flex_weight_047(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "047"))
+    }
+    /// `m_flexWeight.048`
+    pub fn flex_weight_048(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "048"))
+    }
+    /// `m_flexWeight.049`
+    pub fn flex_weight_049(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "049"))
+    }
+    /// `m_flexWeight.050`
+    pub fn flex_weight_050(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "050"))
+    }
+    /// `m_flexWeight.051`
+    pub fn flex_weight_051(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "051"))
+    }
+    /// `m_flexWeight.052`
+    pub fn flex_weight_052(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "052"))
+    }
+    /// `m_flexWeight.053`
+    pub fn flex_weight_053(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "053"))
+    }
+    /// `m_flexWeight.054`
+    pub fn flex_weight_054(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "054"))
+    }
+    /// `m_flexWeight.055`
+    pub fn flex_weight_055(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "055"))
+    }
+    /// `m_flexWeight.056`
+    pub fn flex_weight_056(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "056"))
+    }
+    /// `m_flexWeight.057`
+    pub fn flex_weight_057(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "057"))
+    }
+    /// `m_flexWeight.058`
+    pub fn flex_weight_058(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "058"))
+    }
+    /// `m_flexWeight.059`
+    pub fn flex_weight_059(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "059"))
+    }
+    /// `m_flexWeight.060`
+    pub fn flex_weight_060(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "060"))
+    }
+    /// `m_flexWeight.061`
+    pub fn flex_weight_061(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "061"))
+    }
+    /// `m_flexWeight.062`
+    pub fn flex_weight_062(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "062"))
+    }
+    /// `m_flexWeight.063`
+    pub fn flex_weight_063(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "063"))
+    }
+    /// `m_flexWeight.064`
+    pub fn flex_weight_064(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "064"))
+    }
+    /// `m_flexWeight.065`
+    pub fn flex_weight_065(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "065"))
+    }
+    /// `m_flexWeight.066`
+    pub fn flex_weight_066(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "066"))
+    }
+    /// `m_flexWeight.067`
+    pub fn flex_weight_067(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "067"))
+    }
+    /// `m_flexWeight.068`
+    pub fn flex_weight_068(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "068"))
+    }
+    /// `m_flexWeight.069`
+    pub fn flex_weight_069(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "069"))
+    }
+    /// `m_flexWeight.070`
+    pub fn flex_weight_070(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "070"))
+    }
+    /// `m_flexWeight.071`
+    pub fn flex_weight_071(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "071"))
+    }
+    /// `m_flexWeight.072`
+    pub fn flex_weight_072(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "072"))
+    }
+    /// `m_flexWeight.073`
+    pub fn flex_weight_073(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "073"))
+    }
+    /// `m_flexWeight.074`
+    pub fn flex_weight_074(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "074"))
+    }
+    /// `m_flexWeight.075`
+    pub fn flex_weight_075(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "075"))
+    }
+    /// `m_flexWeight.076`
+    pub fn flex_weight_076(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "076"))
+    }
+    /// `m_flexWeight.077`
+    pub fn flex_weight_077(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "077"))
+    }
+    /// `m_flexWeight.078`
+    pub fn flex_weight_078(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "078"))
+    }
+    /// `m_flexWeight.079`
+    pub fn flex_weight_079(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "079"))
+    }
+    /// `m_flexWeight.080`
+    pub fn flex_weight_080(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "080"))
+    }
+    /// `m_flexWeight.081`
+    pub fn flex_weight_081(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "081"))
+    }
+    /// `m_flexWeight.082`
+    pub fn flex_weight_082(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "082"))
+    }
+    /// `m_flexWeight.083`
+    pub fn flex_weight_083(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "083"))
+    }
+    /// `m_flexWeight.084`
+    pub fn flex_weight_084(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "084"))
+    }
+    /// `m_flexWeight.085`
+    pub fn flex_weight_085(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "085"))
+    }
+    /// `m_flexWeight.086`
+    pub fn flex_weight_086(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "086"))
+    }
+    /// `m_flexWeight.087`
+    pub fn flex_weight_087(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "087"))
+    }
+    /// `m_flexWeight.088`
+    pub fn flex_weight_088(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "088"))
+    }
+    /// `m_flexWeight.089`
+    pub fn flex_weight_089(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "089"))
+    }
+    /// `m_flexWeight.090`
+    pub fn flex_weight_090(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "090"))
+    }
+    /// `m_flexWeight.091`
+    pub fn flex_weight_091(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "091"))
+    }
+    /// `m_flexWeight.092`
+    pub fn flex_weight_092(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "092"))
+    }
+    /// `m_flexWeight.093`
+    pub fn flex_weight_093(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "093"))
+    }
+    /// `m_flexWeight.094`
+    pub fn flex_weight_094(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "094"))
+    }
+    /// `m_flexWeight.095`
+    pub fn flex_weight_095(&self) -> Option<f32> {
+        get_prop(self.entity, SendPropIdentifier::new("m_flexWeight", "095"))
+    }
+    /// `DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub fn next_attack(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack"),
+        )
+    }
+    /// `m_hMyWeapons.000`
+    pub fn my_weapons_000(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "000"))
+    }
+    /// `m_hMyWeapons.001`
+    pub fn my_weapons_001(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "001"))
+    }
+    /// `m_hMyWeapons.002`
+    pub fn my_weapons_002(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "002"))
+    }
+    /// `m_hMyWeapons.003`
+    pub fn my_weapons_003(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "003"))
+    }
+    /// `m_hMyWeapons.004`
+    pub fn my_weapons_004(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "004"))
+    }
+    /// `m_hMyWeapons.005`
+    pub fn my_weapons_005(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "005"))
+    }
+    /// `m_hMyWeapons.006`
+    pub fn my_weapons_006(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "006"))
+    }
+    /// `m_hMyWeapons.007`
+    pub fn my_weapons_007(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "007"))
+    }
+    /// `m_hMyWeapons.008`
+    pub fn my_weapons_008(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "008"))
+    }
+    /// `m_hMyWeapons.009`
+    pub fn my_weapons_009(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "009"))
+    }
+    /// `m_hMyWeapons.010`
+    pub fn my_weapons_010(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "010"))
+    }
+    /// `m_hMyWeapons.011`
+    pub fn my_weapons_011(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "011"))
+    }
+    /// `m_hMyWeapons.012`
+    pub fn my_weapons_012(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "012"))
+    }
+    /// `m_hMyWeapons.013`
+    pub fn my_weapons_013(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "013"))
+    }
+    /// `m_hMyWeapons.014`
+    pub fn my_weapons_014(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "014"))
+    }
+    /// `m_hMyWeapons.015`
+    pub fn my_weapons_015(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "015"))
+    }
+    /// `m_hMyWeapons.016`
+    pub fn my_weapons_016(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "016"))
+    }
+    /// `m_hMyWeapons.017`
+    pub fn my_weapons_017(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "017"))
+    }
+    /// `m_hMyWeapons.018`
+    pub fn my_weapons_018(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "018"))
+    }
+    /// `m_hMyWeapons.019`
+    pub fn my_weapons_019(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "019"))
+    }
+    /// `m_hMyWeapons.020`
+    pub fn my_weapons_020(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "020"))
+    }
+    /// `m_hMyWeapons.021`
+    pub fn my_weapons_021(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "021"))
+    }
+    /// `m_hMyWeapons.022`
+    pub fn my_weapons_022(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "022"))
+    }
+    /// `m_hMyWeapons.023`
+    pub fn my_weapons_023(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "023"))
+    }
+    /// `m_hMyWeapons.024`
+    pub fn my_weapons_024(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "024"))
+    }
+    /// `m_hMyWeapons.025`
+    pub fn my_weapons_025(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "025"))
+    }
+    /// `m_hMyWeapons.026`
+    pub fn my_weapons_026(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "026"))
+    }
+    /// `m_hMyWeapons.027`
+    pub fn my_weapons_027(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "027"))
+    }
+    /// `m_hMyWeapons.028`
+    pub fn my_weapons_028(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "028"))
+    }
+    /// `m_hMyWeapons.029`
+    pub fn my_weapons_029(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "029"))
+    }
+    /// `m_hMyWeapons.030`
+    pub fn my_weapons_030(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "030"))
+    }
+    /// `m_hMyWeapons.031`
+    pub fn my_weapons_031(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "031"))
+    }
+    /// `m_hMyWeapons.032`
+    pub fn my_weapons_032(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "032"))
+    }
+    /// `m_hMyWeapons.033`
+    pub fn my_weapons_033(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "033"))
+    }
+    /// `m_hMyWeapons.034`
+    pub fn my_weapons_034(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "034"))
+    }
+    /// `m_hMyWeapons.035`
+    pub fn my_weapons_035(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "035"))
+    }
+    /// `m_hMyWeapons.036`
+    pub fn my_weapons_036(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "036"))
+    }
+    /// `m_hMyWeapons.037`
+    pub fn my_weapons_037(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "037"))
+    }
+    /// `m_hMyWeapons.038`
+    pub fn my_weapons_038(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "038"))
+    }
+    /// `m_hMyWeapons.039`
+    pub fn my_weapons_039(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "039"))
+    }
+    /// `m_hMyWeapons.040`
+    pub fn my_weapons_040(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "040"))
+    }
+    /// `m_hMyWeapons.041`
+    pub fn my_weapons_041(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "041"))
+    }
+    /// `m_hMyWeapons.042`
+    pub fn my_weapons_042(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "042"))
+    }
+    /// `m_hMyWeapons.043`
+    pub fn my_weapons_043(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "043"))
+    }
+    /// `m_hMyWeapons.044`
+    pub fn my_weapons_044(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "044"))
+    }
+    /// `m_hMyWeapons.045`
+    pub fn my_weapons_045(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "045"))
+    }
+    /// `m_hMyWeapons.046`
+    pub fn my_weapons_046(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "046"))
+    }
+    /// `m_hMyWeapons.047`
+    pub fn my_weapons_047(&self) -> Option<i64> {
+        get_prop(self.entity, SendPropIdentifier::new("m_hMyWeapons", "047"))
+    }
+    /// `DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins"),
+        )
+    }
+    /// `DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame"),
+        )
+    }
+    /// `DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode"),
+        )
+    }
+    /// `DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects"),
+        )
+    }
+    /// `DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+        )
+    }
+    /// `DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup"),
+        )
+    }
+    /// `DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity"),
+        )
+    }
+    /// `DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance"),
+        )
+    }
+    /// `DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity"),
+        )
+    }
+    /// `DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity"),
+        )
+    }
+    /// `DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent"),
+        )
+    }
+    /// `DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment"),
+        )
+    }
+    /// `DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movetype"),
+        )
+    }
+    /// `DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone"),
+        )
+    }
+    /// `DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSequence`
+    pub fn base_animating_sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale"),
+        )
+    }
+    /// `DT_BaseFlex.m_blinktoggle`
+    pub fn blinktoggle(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle"),
+        )
+    }
+    /// `DT_BaseFlex.m_viewtarget`
+    pub fn viewtarget(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget"),
+        )
+    }
+    /// `DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub fn glow_enabled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled"),
+        )
+    }
+    /// `DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub fn active_weapon(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon"),
+        )
+    }
+    /// `DT_BaseObject.m_iHealth`
+    pub fn health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iHealth"),
+        )
+    }
+    /// `DT_BaseObject.m_iMaxHealth`
+    pub fn max_health(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth"),
+        )
+    }
+    /// `DT_BaseObject.m_bHasSapper`
+    pub fn has_sapper(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper"),
+        )
+    }
+    /// `DT_BaseObject.m_iObjectType`
+    pub fn object_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType"),
+        )
+    }
+    /// `DT_BaseObject.m_bBuilding`
+    pub fn building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding"),
+        )
+    }
+    /// `DT_BaseObject.m_bPlacing`
+    pub fn placing(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlacing"),
+        )
+    }
+    /// `DT_BaseObject.m_bCarried`
+    pub fn carried(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarried"),
+        )
+    }
+    /// `DT_BaseObject.m_bCarryDeploy`
+    pub fn carry_deploy(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy"),
+        )
+    }
+    /// `DT_BaseObject.m_bMiniBuilding`
+    pub fn mini_building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding"),
+        )
+    }
+    /// `DT_BaseObject.m_flPercentageConstructed`
+    pub fn percentage_constructed(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed"),
+        )
+    }
+    /// `DT_BaseObject.m_fObjectFlags`
+    pub fn object_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags"),
+        )
+    }
+    /// `DT_BaseObject.m_hBuiltOnEntity`
+    pub fn built_on_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity"),
+        )
+    }
+    /// `DT_BaseObject.m_bDisabled`
+    pub fn disabled(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled"),
+        )
+    }
+    /// `DT_BaseObject.m_hBuilder`
+    pub fn builder(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuilder"),
+        )
+    }
+    /// `DT_BaseObject.m_vecBuildMaxs`
+    pub fn build_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs"),
+        )
+    }
+    /// `DT_BaseObject.m_vecBuildMins`
+    pub fn build_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins"),
+        )
+    }
+    /// `DT_BaseObject.m_iDesiredBuildRotations`
+    pub fn desired_build_rotations(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations"),
+        )
+    }
+    /// `DT_BaseObject.m_bServerOverridePlacement`
+    pub fn server_override_placement(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeLevel`
+    pub fn upgrade_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeMetal`
+    pub fn upgrade_metal(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal"),
+        )
+    }
+    /// `DT_BaseObject.m_iUpgradeMetalRequired`
+    pub fn upgrade_metal_required(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired"),
+        )
+    }
+    /// `DT_BaseObject.m_iHighestUpgradeLevel`
+    pub fn highest_upgrade_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel"),
+        )
+    }
+    /// `DT_BaseObject.m_iObjectMode`
+    pub fn object_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode"),
+        )
+    }
+    /// `DT_BaseObject.m_bDisposableBuilding`
+    pub fn disposable_building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding"),
+        )
+    }
+    /// `DT_ObjectTeleporter.m_iState`
+    pub fn state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_iState"),
+        )
+    }
+    /// `DT_ObjectTeleporter.m_flRechargeTime`
+    pub fn recharge_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flRechargeTime"),
+        )
+    }
+    /// `DT_ObjectTeleporter.m_flCurrentRechargeDuration`
+    pub fn current_recharge_duration(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flCurrentRechargeDuration"),
+        )
+    }
+    /// `DT_ObjectTeleporter.m_iTimesUsed`
+    pub fn times_used(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_iTimesUsed"),
+        )
+    }
+    /// `DT_ObjectTeleporter.m_flYawToExit`
+    pub fn yaw_to_exit(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flYawToExit"),
+        )
+    }
+    /// `DT_ObjectTeleporter.m_bMatchBuilding`
+    pub fn match_building(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_bMatchBuilding"),
+        )
+    }
+}
+/// Typed view of the props of a `CWeaponMedigun` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CWeaponMedigunView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CWeaponMedigunView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CWeaponMedigun";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CWeaponMedigunView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CWeaponMedigunView { entity })
+    }
+    /// `DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle"),
+        )
+    }
+    /// `DT_LocalTFWeaponMedigunData.m_flChargeLevel`
+    pub fn charge_level(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalTFWeaponMedigunData", "m_flChargeLevel"),
+        )
+    }
+    /// `DT_TFWeaponMedigunDataNonLocal.m_flChargeLevel`
+    pub fn tf_weapon_medigun_data_non_local_charge_level(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponMedigunDataNonLocal", "m_flChargeLevel"),
+        )
+    }
+    /// `DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime"),
+        )
+    }
+    /// `DT_BaseEntity.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin"),
+        )
+    }
+    /// `DT_BaseEntity.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMinsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMaxsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs"),
+        )
+    }
+    /// `DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID"),
+        )
+    }
+    /// `DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated"),
+        )
+    }
+    /// `m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000"),
+        )
+    }
+    /// `m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001"),
+        )
+    }
+    /// `m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002"),
+        )
+    }
+    /// `m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003"),
+        )
+    }
+    /// `m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "000"),
+        )
+    }
+    /// `m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "001"),
+        )
+    }
+    /// `m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "002"),
+        )
+    }
+    /// `m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "003"),
+        )
+    }
+    /// `m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "004"),
+        )
+    }
+    /// `m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "005"),
+        )
+    }
+    /// `m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "006"),
+        )
+    }
+    /// `m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "007"),
+        )
+    }
+    /// `m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "008"),
+        )
+    }
+    /// `m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "009"),
+        )
+    }
+    /// `m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "010"),
+        )
+    }
+    /// `m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "011"),
+        )
+    }
+    /// `m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "012"),
+        )
+    }
+    /// `m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "013"),
+        )
+    }
+    /// `m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "014"),
+        )
+    }
+    /// `m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "015"),
+        )
+    }
+    /// `m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "016"),
+        )
+    }
+    /// `m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "017"),
+        )
+    }
+    /// `m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "018"),
+        )
+    }
+    /// `m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "019"),
+        )
+    }
+    /// `m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "020"),
+        )
+    }
+    /// `m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "021"),
+        )
+    }
+    /// `m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "022"),
+        )
+    }
+    /// `m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "023"),
+        )
+    }
+    /// `m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "000"),
+        )
+    }
+    /// `m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "001"),
+        )
+    }
+    /// `m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "002"),
+        )
+    }
+    /// `m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "003"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled"),
+        )
+    }
+    /// `DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub fn attribute_definition_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex"),
+        )
+    }
+    /// `DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub fn raw_value_32(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32"),
+        )
+    }
+    /// `DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub fn refundable_currency(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency"),
+        )
+    }
+    /// `DT_ScriptCreatedItem.m_iItemDefinitionIndex`
+    pub fn item_definition_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iItemDefinitionIndex"),
+        )
+    }
+    /// `DT_ScriptCreatedItem.m_iEntityLevel`
+    pub fn entity_level(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iEntityLevel"),
+        )
+    }
+    /// `DT_ScriptCreatedItem.m_iItemIDHigh`
+    pub fn item_id_high(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iItemIDHigh"),
+        )
+    }
+    /// `DT_ScriptCreatedItem.m_iItemIDLow`
+    pub fn item_id_low(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iItemIDLow"),
+        )
+    }
+    /// `DT_ScriptCreatedItem.m_iAccountID`
+    pub fn account_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iAccountID"),
+        )
+    }
+    /// `DT_ScriptCreatedItem.m_iEntityQuality`
+    pub fn entity_quality(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iEntityQuality"),
+        )
+    }
+    /// `DT_ScriptCreatedItem.m_bInitialized`
+    pub fn initialized(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_bInitialized"),
+        )
+    }
+    /// `DT_ScriptCreatedItem.m_bOnlyIterateItemViewAttributes`
+    pub fn only_iterate_item_view_attributes(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_bOnlyIterateItemViewAttributes"),
+        )
+    }
+    /// `DT_AttributeContainer.m_hOuter`
+    pub fn outer(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AttributeContainer", "m_hOuter"),
+        )
+    }
+    /// `DT_AttributeContainer.m_ProviderType`
+    pub fn provider_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AttributeContainer", "m_ProviderType"),
+        )
+    }
+    /// `DT_AttributeContainer.m_iReapplyProvisionParity`
+    pub fn reapply_provision_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AttributeContainer", "m_iReapplyProvisionParity"),
+        )
+    }
+    /// `DT_LocalWeaponData.m_iClip1`
+    pub fn clip_1(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_iClip1"),
+        )
+    }
+    /// `DT_LocalWeaponData.m_iClip2`
+    pub fn clip_2(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_iClip2"),
+        )
+    }
+    /// `DT_LocalWeaponData.m_iPrimaryAmmoType`
+    pub fn primary_ammo_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_iPrimaryAmmoType"),
+        )
+    }
+    /// `DT_LocalWeaponData.m_iSecondaryAmmoType`
+    pub fn secondary_ammo_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_iSecondaryAmmoType"),
+        )
+    }
+    /// `DT_LocalWeaponData.m_nViewModelIndex`
+    pub fn view_model_index(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_nViewModelIndex"),
+        )
+    }
+    /// `DT_LocalWeaponData.m_bFlipViewModel`
+    pub fn flip_view_model(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_bFlipViewModel"),
+        )
+    }
+    /// `DT_LocalActiveWeaponData.m_flNextPrimaryAttack`
+    pub fn next_primary_attack(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalActiveWeaponData", "m_flNextPrimaryAttack"),
+        )
+    }
+    /// `DT_LocalActiveWeaponData.m_flNextSecondaryAttack`
+    pub fn next_secondary_attack(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalActiveWeaponData", "m_flNextSecondaryAttack"),
+        )
+    }
+    /// `DT_LocalActiveWeaponData.m_nNextThinkTick`
+    pub fn next_think_tick(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalActiveWeaponData", "m_nNextThinkTick"),
+        )
+    }
+    /// `DT_LocalActiveWeaponData.m_flTimeWeaponIdle`
+    pub fn time_weapon_idle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalActiveWeaponData", "m_flTimeWeaponIdle"),
+        )
+    }
+    /// `DT_LocalTFWeaponData.m_flLastCritCheckTime`
+    pub fn last_crit_check_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flLastCritCheckTime"),
+        )
+    }
+    /// `DT_LocalTFWeaponData.m_flReloadPriorNextFire`
+    pub fn reload_prior_next_fire(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flReloadPriorNextFire"),
+        )
+    }
+    /// `DT_LocalTFWeaponData.m_flLastFireTime`
+    pub fn last_fire_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flLastFireTime"),
+        )
+    }
+    /// `DT_LocalTFWeaponData.m_flEffectBarRegenTime`
+    pub fn effect_bar_regen_time(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flEffectBarRegenTime"),
+        )
+    }
+    /// `DT_LocalTFWeaponData.m_flObservedCritChance`
+    pub fn observed_crit_chance(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flObservedCritChance"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType"),
+        )
+    }
+    /// `DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame"),
+        )
+    }
+    /// `DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode"),
+        )
+    }
+    /// `DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects"),
+        )
+    }
+    /// `DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+        )
+    }
+    /// `DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup"),
+        )
+    }
+    /// `DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity"),
+        )
+    }
+    /// `DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance"),
+        )
+    }
+    /// `DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity"),
+        )
+    }
+    /// `DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity"),
+        )
+    }
+    /// `DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent"),
+        )
+    }
+    /// `DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment"),
+        )
+    }
+    /// `DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movetype"),
+        )
+    }
+    /// `DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide"),
+        )
+    }
+    /// `DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone"),
+        )
+    }
+    /// `DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSequence`
+    pub fn sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale"),
+        )
+    }
+    /// `DT_BaseCombatWeapon.m_iViewModelIndex`
+    pub fn base_combat_weapon_view_model_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatWeapon", "m_iViewModelIndex"),
+        )
+    }
+    /// `DT_BaseCombatWeapon.m_iWorldModelIndex`
+    pub fn world_model_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatWeapon", "m_iWorldModelIndex"),
+        )
+    }
+    /// `DT_BaseCombatWeapon.m_iState`
+    pub fn state(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatWeapon", "m_iState"),
+        )
+    }
+    /// `DT_BaseCombatWeapon.m_hOwner`
+    pub fn owner(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseCombatWeapon", "m_hOwner"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_bLowered`
+    pub fn lowered(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bLowered"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_bResetParity`
+    pub fn reset_parity(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bResetParity"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_iReloadMode`
+    pub fn reload_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_iReloadMode"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_bReloadedThroughAnimEvent`
+    pub fn reloaded_through_anim_event(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bReloadedThroughAnimEvent"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_bDisguiseWeapon`
+    pub fn disguise_weapon(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bDisguiseWeapon"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_flEnergy`
+    pub fn energy(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_flEnergy"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_hExtraWearable`
+    pub fn extra_wearable(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_hExtraWearable"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_hExtraWearableViewModel`
+    pub fn extra_wearable_view_model(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_hExtraWearableViewModel"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_bBeingRepurposedForTaunt`
+    pub fn being_repurposed_for_taunt(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bBeingRepurposedForTaunt"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_nKillComboClass`
+    pub fn kill_combo_class(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_nKillComboClass"),
+        )
+    }
+    /// `DT_TFWeaponBase.m_nKillComboCount`
+    pub fn kill_combo_count(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_nKillComboCount"),
+        )
+    }
+    /// `DT_WeaponMedigun.m_hHealingTarget`
+    pub fn healing_target(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_hHealingTarget"),
+        )
+    }
+    /// `DT_WeaponMedigun.m_bHealing`
+    pub fn healing(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_bHealing"),
+        )
+    }
+    /// `DT_WeaponMedigun.m_bAttacking`
+    pub fn attacking(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_bAttacking"),
+        )
+    }
+    /// `DT_WeaponMedigun.m_bChargeRelease`
+    pub fn charge_release(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_bChargeRelease"),
+        )
+    }
+    /// `DT_WeaponMedigun.m_bHolstered`
+    pub fn holstered(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_bHolstered"),
+        )
+    }
+    /// `DT_WeaponMedigun.m_nChargeResistType`
+    pub fn charge_resist_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_nChargeResistType"),
+        )
+    }
+}
+/// Typed view of the props of a `CTFProjectile_Rocket` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CTFProjectileRocketView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CTFProjectileRocketView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CTFProjectile_Rocket";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CTFProjectileRocketView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME).then_some(CTFProjectileRocketView { entity })
+    }
+    /// `DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub fn anim_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime"),
+        )
+    }
+    /// `DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle"),
+        )
+    }
+    /// `DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime"),
+        )
+    }
+    /// `DT_TFBaseRocket.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_vecOrigin"),
+        )
+    }
+    /// `DT_TFBaseRocket.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_angRotation"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMinsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMaxsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs"),
+        )
+    }
+    /// `DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID"),
+        )
+    }
+    /// `DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated"),
+        )
+    }
+    /// `m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000"),
+        )
+    }
+    /// `m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001"),
+        )
+    }
+    /// `m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002"),
+        )
+    }
+    /// `m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003"),
+        )
+    }
+    /// `m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "000"),
+        )
+    }
+    /// `m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "001"),
+        )
+    }
+    /// `m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "002"),
+        )
+    }
+    /// `m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "003"),
+        )
+    }
+    /// `m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "004"),
+        )
+    }
+    /// `m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "005"),
+        )
+    }
+    /// `m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "006"),
+        )
+    }
+    /// `m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "007"),
+        )
+    }
+    /// `m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "008"),
+        )
+    }
+    /// `m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "009"),
+        )
+    }
+    /// `m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "010"),
+        )
+    }
+    /// `m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "011"),
+        )
+    }
+    /// `m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "012"),
+        )
+    }
+    /// `m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "013"),
+        )
+    }
+    /// `m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "014"),
+        )
+    }
+    /// `m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "015"),
+        )
+    }
+    /// `m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "016"),
+        )
+    }
+    /// `m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "017"),
+        )
+    }
+    /// `m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "018"),
+        )
+    }
+    /// `m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "019"),
+        )
+    }
+    /// `m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "020"),
+        )
+    }
+    /// `m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "021"),
+        )
+    }
+    /// `m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "022"),
+        )
+    }
+    /// `m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "023"),
+        )
+    }
+    /// `m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "000"),
+        )
+    }
+    /// `m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "001"),
+        )
+    }
+    /// `m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "002"),
+        )
+    }
+    /// `m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "003"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled"),
+        )
+    }
+    /// `DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame"),
+        )
+    }
+    /// `DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode"),
+        )
+    }
+    /// `DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects"),
+        )
+    }
+    /// `DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+        )
+    }
+    /// `DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup"),
+        )
+    }
+    /// `DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity"),
+        )
+    }
+    /// `DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance"),
+        )
+    }
+    /// `DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity"),
+        )
+    }
+    /// `DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity"),
+        )
+    }
+    /// `DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent"),
+        )
+    }
+    /// `DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment"),
+        )
+    }
+    /// `DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movetype"),
+        )
+    }
+    /// `DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone"),
+        )
+    }
+    /// `DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSequence`
+    pub fn sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale"),
+        )
+    }
+    /// `DT_BaseProjectile.m_hOriginalLauncher`
+    pub fn original_launcher(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseProjectile", "m_hOriginalLauncher"),
+        )
+    }
+    /// `DT_TFBaseRocket.m_vInitialVelocity`
+    pub fn initial_velocity(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_vInitialVelocity"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs"),
+        )
+    }
+    /// `DT_TFBaseRocket.m_iDeflected`
+    pub fn deflected(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_iDeflected"),
+        )
+    }
+    /// `DT_TFBaseRocket.m_hLauncher`
+    pub fn launcher(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_hLauncher"),
+        )
+    }
+    /// `DT_TFProjectile_Rocket.m_bCritical`
+    pub fn critical(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFProjectile_Rocket", "m_bCritical"),
+        )
+    }
+}
+/// Typed view of the props of a `CTFGrenadePipebombProjectile` entity
+#[derive(Debug, Clone, Copy)]
+pub struct CTFGrenadePipebombProjectileView<'a, E: EntityProps + ?Sized> {
+    entity: &'a E,
+}
+impl<'a, E: EntityProps + ?Sized> CTFGrenadePipebombProjectileView<'a, E> {
+    pub const CLASS_NAME: &'static str = "CTFGrenadePipebombProjectile";
+    /// Create a view without checking the class of the entity
+    pub fn new(entity: &'a E) -> Self {
+        CTFGrenadePipebombProjectileView { entity }
+    }
+    /// Create a view if the entity is of the right class, or if the class of the entity isn't known
+    pub fn try_new(entity: &'a E) -> Option<Self> {
+        matches_class(entity, Self::CLASS_NAME)
+            .then_some(CTFGrenadePipebombProjectileView { entity })
+    }
+    /// `DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub fn anim_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime"),
+        )
+    }
+    /// `DT_ServerAnimationData.m_flCycle`
+    pub fn cycle(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle"),
+        )
+    }
+    /// `DT_BaseEntity.m_flSimulationTime`
+    pub fn simulation_time(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime"),
+        )
+    }
+    /// `DT_TFWeaponBaseGrenadeProj.m_vecOrigin`
+    pub fn origin(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_vecOrigin"),
+        )
+    }
+    /// `DT_TFWeaponBaseGrenadeProj.m_angRotation`
+    pub fn rotation(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_angRotation"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSolidType`
+    pub fn solid_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_usSolidFlags`
+    pub fn solid_flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags"),
+        )
+    }
+    /// `DT_CollisionProperty.m_nSurroundType`
+    pub fn surround_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType"),
+        )
+    }
+    /// `DT_CollisionProperty.m_triggerBloat`
+    pub fn trigger_bloat(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub fn specified_surrounding_mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMinsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub fn specified_surrounding_maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new(
+                "DT_CollisionProperty",
+                "m_vecSpecifiedSurroundingMaxsPreScaled",
+            ),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub fn specified_surrounding_mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub fn specified_surrounding_maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs"),
+        )
+    }
+    /// `DT_PredictableId.m_PredictableID`
+    pub fn predictable_id(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID"),
+        )
+    }
+    /// `DT_PredictableId.m_bIsPlayerSimulated`
+    pub fn is_player_simulated(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated"),
+        )
+    }
+    /// `m_nModelIndexOverrides.000`
+    pub fn model_index_overrides_000(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000"),
+        )
+    }
+    /// `m_nModelIndexOverrides.001`
+    pub fn model_index_overrides_001(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001"),
+        )
+    }
+    /// `m_nModelIndexOverrides.002`
+    pub fn model_index_overrides_002(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002"),
+        )
+    }
+    /// `m_nModelIndexOverrides.003`
+    pub fn model_index_overrides_003(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003"),
+        )
+    }
+    /// `m_flPoseParameter.000`
+    pub fn pose_parameter_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "000"),
+        )
+    }
+    /// `m_flPoseParameter.001`
+    pub fn pose_parameter_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "001"),
+        )
+    }
+    /// `m_flPoseParameter.002`
+    pub fn pose_parameter_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "002"),
+        )
+    }
+    /// `m_flPoseParameter.003`
+    pub fn pose_parameter_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "003"),
+        )
+    }
+    /// `m_flPoseParameter.004`
+    pub fn pose_parameter_004(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "004"),
+        )
+    }
+    /// `m_flPoseParameter.005`
+    pub fn pose_parameter_005(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "005"),
+        )
+    }
+    /// `m_flPoseParameter.006`
+    pub fn pose_parameter_006(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "006"),
+        )
+    }
+    /// `m_flPoseParameter.007`
+    pub fn pose_parameter_007(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "007"),
+        )
+    }
+    /// `m_flPoseParameter.008`
+    pub fn pose_parameter_008(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "008"),
+        )
+    }
+    /// `m_flPoseParameter.009`
+    pub fn pose_parameter_009(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "009"),
+        )
+    }
+    /// `m_flPoseParameter.010`
+    pub fn pose_parameter_010(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "010"),
+        )
+    }
+    /// `m_flPoseParameter.011`
+    pub fn pose_parameter_011(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "011"),
+        )
+    }
+    /// `m_flPoseParameter.012`
+    pub fn pose_parameter_012(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "012"),
+        )
+    }
+    /// `m_flPoseParameter.013`
+    pub fn pose_parameter_013(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "013"),
+        )
+    }
+    /// `m_flPoseParameter.014`
+    pub fn pose_parameter_014(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "014"),
+        )
+    }
+    /// `m_flPoseParameter.015`
+    pub fn pose_parameter_015(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "015"),
+        )
+    }
+    /// `m_flPoseParameter.016`
+    pub fn pose_parameter_016(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "016"),
+        )
+    }
+    /// `m_flPoseParameter.017`
+    pub fn pose_parameter_017(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "017"),
+        )
+    }
+    /// `m_flPoseParameter.018`
+    pub fn pose_parameter_018(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "018"),
+        )
+    }
+    /// `m_flPoseParameter.019`
+    pub fn pose_parameter_019(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "019"),
+        )
+    }
+    /// `m_flPoseParameter.020`
+    pub fn pose_parameter_020(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "020"),
+        )
+    }
+    /// `m_flPoseParameter.021`
+    pub fn pose_parameter_021(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "021"),
+        )
+    }
+    /// `m_flPoseParameter.022`
+    pub fn pose_parameter_022(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "022"),
+        )
+    }
+    /// `m_flPoseParameter.023`
+    pub fn pose_parameter_023(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flPoseParameter", "023"),
+        )
+    }
+    /// `m_flEncodedController.000`
+    pub fn encoded_controller_000(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "000"),
+        )
+    }
+    /// `m_flEncodedController.001`
+    pub fn encoded_controller_001(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "001"),
+        )
+    }
+    /// `m_flEncodedController.002`
+    pub fn encoded_controller_002(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "002"),
+        )
+    }
+    /// `m_flEncodedController.003`
+    pub fn encoded_controller_003(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("m_flEncodedController", "003"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMinsPreScaled`
+    pub fn mins_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub fn maxs_pre_scaled(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled"),
+        )
+    }
+    /// `DT_BaseEntity.m_ubInterpolationFrame`
+    pub fn interpolation_frame(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame"),
+        )
+    }
+    /// `DT_BaseEntity.m_nModelIndex`
+    pub fn model_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderFX`
+    pub fn render_fx(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX"),
+        )
+    }
+    /// `DT_BaseEntity.m_nRenderMode`
+    pub fn render_mode(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode"),
+        )
+    }
+    /// `DT_BaseEntity.m_fEffects`
+    pub fn effects(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_fEffects"),
+        )
+    }
+    /// `DT_BaseEntity.m_clrRender`
+    pub fn render(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_clrRender"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTeamNum`
+    pub fn team_num(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+        )
+    }
+    /// `DT_BaseEntity.m_CollisionGroup`
+    pub fn collision_group(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup"),
+        )
+    }
+    /// `DT_BaseEntity.m_flElasticity`
+    pub fn elasticity(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity"),
+        )
+    }
+    /// `DT_BaseEntity.m_flShadowCastDistance`
+    pub fn shadow_cast_distance(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance"),
+        )
+    }
+    /// `DT_BaseEntity.m_hOwnerEntity`
+    pub fn owner_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity"),
+        )
+    }
+    /// `DT_BaseEntity.m_hEffectEntity`
+    pub fn effect_entity(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity"),
+        )
+    }
+    /// `DT_BaseEntity.moveparent`
+    pub fn moveparent(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent"),
+        )
+    }
+    /// `DT_BaseEntity.m_iParentAttachment`
+    pub fn parent_attachment(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment"),
+        )
+    }
+    /// `DT_BaseEntity.movetype`
+    pub fn movetype(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movetype"),
+        )
+    }
+    /// `DT_BaseEntity.movecollide`
+    pub fn movecollide(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide"),
+        )
+    }
+    /// `DT_BaseEntity.m_iTextureFrameIndex`
+    pub fn texture_frame_index(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex"),
+        )
+    }
+    /// `DT_BaseEntity.m_bSimulatedEveryTick`
+    pub fn simulated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAnimatedEveryTick`
+    pub fn animated_every_tick(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick"),
+        )
+    }
+    /// `DT_BaseEntity.m_bAlternateSorting`
+    pub fn alternate_sorting(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nForceBone`
+    pub fn force_bone(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone"),
+        )
+    }
+    /// `DT_BaseAnimating.m_vecForce`
+    pub fn force(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSkin`
+    pub fn skin(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nBody`
+    pub fn body(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nBody"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nHitboxSet`
+    pub fn hitbox_set(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flModelScale`
+    pub fn model_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nSequence`
+    pub fn sequence(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flPlaybackRate`
+    pub fn playback_rate(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideAnimation`
+    pub fn client_side_animation(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation"),
+        )
+    }
+    /// `DT_BaseAnimating.m_bClientSideFrameReset`
+    pub fn client_side_frame_reset(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nNewSequenceParity`
+    pub fn new_sequence_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nResetEventsParity`
+    pub fn reset_events_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub fn muzzle_flash_parity(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOrigin`
+    pub fn lighting_origin(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin"),
+        )
+    }
+    /// `DT_BaseAnimating.m_hLightingOriginRelative`
+    pub fn lighting_origin_relative(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMinDist`
+    pub fn fade_min_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_fadeMaxDist`
+    pub fn fade_max_dist(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist"),
+        )
+    }
+    /// `DT_BaseAnimating.m_flFadeScale`
+    pub fn fade_scale(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale"),
+        )
+    }
+    /// `DT_BaseProjectile.m_hOriginalLauncher`
+    pub fn original_launcher(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseProjectile", "m_hOriginalLauncher"),
+        )
+    }
+    /// `DT_BaseGrenade.m_flDamage`
+    pub fn damage(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseGrenade", "m_flDamage"),
+        )
+    }
+    /// `DT_BaseGrenade.m_DmgRadius`
+    pub fn dmg_radius(&self) -> Option<f32> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseGrenade", "m_DmgRadius"),
+        )
+    }
+    /// `DT_BaseGrenade.m_bIsLive`
+    pub fn is_live(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseGrenade", "m_bIsLive"),
+        )
+    }
+    /// `DT_BaseGrenade.m_hThrower`
+    pub fn thrower(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseGrenade", "m_hThrower"),
+        )
+    }
+    /// `DT_BaseGrenade.m_vecVelocity`
+    pub fn velocity(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseGrenade", "m_vecVelocity"),
+        )
+    }
+    /// `DT_BaseGrenade.m_fFlags`
+    pub fn flags(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_BaseGrenade", "m_fFlags"),
+        )
+    }
+    /// `DT_TFWeaponBaseGrenadeProj.m_vInitialVelocity`
+    pub fn initial_velocity(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_vInitialVelocity"),
+        )
+    }
+    /// `DT_TFWeaponBaseGrenadeProj.m_bCritical`
+    pub fn critical(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_bCritical"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMins`
+    pub fn mins(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins"),
+        )
+    }
+    /// `DT_CollisionProperty.m_vecMaxs`
+    pub fn maxs(&self) -> Option<Vector> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs"),
+        )
+    }
+    /// `DT_TFWeaponBaseGrenadeProj.m_iDeflected`
+    pub fn deflected(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_iDeflected"),
+        )
+    }
+    /// `DT_TFWeaponBaseGrenadeProj.m_hDeflectOwner`
+    pub fn deflect_owner(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_hDeflectOwner"),
+        )
+    }
+    /// `DT_TFProjectile_Pipebomb.m_bTouched`
+    pub fn touched(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFProjectile_Pipebomb", "m_bTouched"),
+        )
+    }
+    /// `DT_TFProjectile_Pipebomb.m_iType`
+    pub fn i_type(&self) -> Option<i64> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFProjectile_Pipebomb", "m_iType"),
+        )
+    }
+    /// `DT_TFProjectile_Pipebomb.m_hLauncher`
+    pub fn launcher(&self) -> Option<Handle> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFProjectile_Pipebomb", "m_hLauncher"),
+        )
+    }
+    /// `DT_TFProjectile_Pipebomb.m_bDefensiveBomb`
+    pub fn defensive_bomb(&self) -> Option<bool> {
+        get_prop(
+            self.entity,
+            SendPropIdentifier::new("DT_TFProjectile_Pipebomb", "m_bDefensiveBomb"),
+        )
+    }
+}
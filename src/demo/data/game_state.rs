@@ -1,4 +1,5 @@
 pub use super::cond::PlayerCondition;
+use super::items::{get_item_schema_by_log_name, ItemSchema};
 use crate::demo::data::DemoTick;
 use crate::demo::gameevent_gen::PlayerDeathEvent;
 use crate::demo::gamevent::GameEvent;
@@ -17,6 +18,30 @@ use std::ops::Rem;
 #[derive(Default, Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash, Display)]
 pub struct Handle(pub i64);
 
+impl Handle {
+    const ENTITY_BITS: i64 = 11;
+    const SERIAL_BITS: i64 = 10;
+    const INVALID: i64 = (1 << (Self::ENTITY_BITS + Self::SERIAL_BITS)) - 1;
+
+    /// Get the entity the handle refers to
+    ///
+    /// Returns `None` for the "invalid" handle used for empty slots
+    pub fn entity_id(&self) -> Option<EntityId> {
+        if self.0 & Self::INVALID == Self::INVALID {
+            None
+        } else {
+            Some(EntityId::from(
+                (self.0 & ((1 << Self::ENTITY_BITS) - 1)) as u32,
+            ))
+        }
+    }
+
+    /// Get the serial number of the entity the handle refers to
+    pub fn serial(&self) -> u32 {
+        ((self.0 >> Self::ENTITY_BITS) & ((1 << Self::SERIAL_BITS) - 1)) as u32
+    }
+}
+
 impl TryFrom<&SendPropValue> for Handle {
     type Error = MalformedSendPropDefinitionError;
     fn try_from(value: &SendPropValue) -> Result<Self, Self::Error> {
@@ -174,16 +199,9 @@ impl Player {
     }
 
     pub fn conditions(&self) -> impl Iterator<Item = PlayerCondition> + '_ {
-        (1..=(PlayerCondition::MAX as u8)).filter_map(|cond_int| {
-            let byte = cond_int / 8;
-            let bit = cond_int.rem(8);
-            let cond_byte = *self.conditions.get(byte as usize)?;
-            if (cond_byte >> bit as usize) == 1 {
-                PlayerCondition::try_from(cond_byte).ok()
-            } else {
-                None
-            }
-        })
+        (0..=(PlayerCondition::MAX as u8))
+            .filter_map(|cond_int| PlayerCondition::try_from(cond_int).ok())
+            .filter(|condition| self.has_condition(*condition))
     }
 
     pub fn has_condition(&self, condition: PlayerCondition) -> bool {
@@ -195,7 +213,7 @@ impl Player {
             .get(byte as usize)
             .copied()
             .unwrap_or_default();
-        cond_byte >> bit as usize == 1
+        (cond_byte >> bit as usize) & 1 == 1
     }
 }
 
@@ -204,6 +222,9 @@ impl Player {
 pub struct Sentry {
     pub entity: EntityId,
     pub builder: UserId,
+    /// The networked handle of the builder, used to find the builder once their user info is known
+    #[serde(skip)]
+    pub builder_handle: Handle,
     pub position: Vector,
     pub level: u8,
     pub max_health: u16,
@@ -227,6 +248,9 @@ pub struct Sentry {
 pub struct Dispenser {
     pub entity: EntityId,
     pub builder: UserId,
+    /// The networked handle of the builder, used to find the builder once their user info is known
+    #[serde(skip)]
+    pub builder_handle: Handle,
     pub position: Vector,
     pub level: u8,
     pub max_health: u16,
@@ -245,6 +269,9 @@ pub struct Dispenser {
 pub struct Teleporter {
     pub entity: EntityId,
     pub builder: UserId,
+    /// The networked handle of the builder, used to find the builder once their user info is known
+    #[serde(skip)]
+    pub builder_handle: Handle,
     pub position: Vector,
     pub level: u8,
     pub max_health: u16,
@@ -320,6 +347,22 @@ impl Building {
         }
     }
 
+    pub fn builder_handle(&self) -> Handle {
+        match self {
+            Building::Sentry(Sentry { builder_handle, .. })
+            | Building::Dispenser(Dispenser { builder_handle, .. })
+            | Building::Teleporter(Teleporter { builder_handle, .. }) => *builder_handle,
+        }
+    }
+
+    fn builder_mut(&mut self) -> &mut UserId {
+        match self {
+            Building::Sentry(Sentry { builder, .. })
+            | Building::Dispenser(Dispenser { builder, .. })
+            | Building::Teleporter(Teleporter { builder, .. }) => builder,
+        }
+    }
+
     pub fn angle(&self) -> f32 {
         match self {
             Building::Sentry(Sentry { angle, .. })
@@ -363,7 +406,7 @@ impl Building {
     pub fn class(&self) -> BuildingClass {
         match self {
             Building::Sentry(_) => BuildingClass::Sentry,
-            Building::Dispenser(_) => BuildingClass::Sentry,
+            Building::Dispenser(_) => BuildingClass::Dispenser,
             Building::Teleporter(_) => BuildingClass::Teleporter,
         }
     }
@@ -386,6 +429,7 @@ impl Building {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum BuildingClass {
     Sentry,
@@ -532,6 +576,11 @@ impl Kill {
             tick,
         }
     }
+
+    /// Look up the weapon used in the bundled item table
+    pub fn weapon_schema(&self) -> Option<&'static ItemSchema> {
+        get_item_schema_by_log_name(&self.weapon)
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
@@ -581,6 +630,27 @@ impl GameState {
         self.players.iter().find(|player| player.entity == id)
     }
 
+    /// Set the builder of buildings whose builder wasn't known yet when the builder handle was received
+    pub fn resolve_builders(&mut self) {
+        let players = &self.players;
+        for building in self.buildings.values_mut() {
+            if building.builder() != UserId::default() {
+                continue;
+            }
+            let user_id = building.builder_handle().entity_id().and_then(|entity| {
+                players
+                    .iter()
+                    .find(|player| player.entity == entity)?
+                    .info
+                    .as_ref()
+                    .map(|info| info.user_id)
+            });
+            if let Some(user_id) = user_id {
+                *building.builder_mut() = user_id;
+            }
+        }
+    }
+
     pub fn get_or_create_player(&mut self, entity_id: EntityId) -> &mut Player {
         let index = match self
             .players
@@ -640,9 +710,83 @@ impl GameState {
             .find(|player| player.weapons.contains(&handle))
     }
 
+    pub fn get_player_by_user_id(&self, user_id: UserId) -> Option<&Player> {
+        self.players.iter().find(|player| {
+            player
+                .info
+                .as_ref()
+                .map(|info| info.user_id == user_id)
+                .unwrap_or_default()
+        })
+    }
+
     pub fn get_player_by_handle(&mut self, handle: Handle) -> Option<&mut Player> {
         self.players
             .iter_mut()
             .find(|player| player.handle == handle)
     }
 }
+
+#[test]
+fn test_player_conditions() {
+    let mut player = Player::default();
+    // multiple conditions set in the same byte
+    player.conditions[0] = 0b0001_1000;
+    player.conditions[1] = 0b0010_0000;
+
+    assert!(player.has_condition(PlayerCondition::Disguised));
+    assert!(player.has_condition(PlayerCondition::Stealthed));
+    assert!(player.has_condition(PlayerCondition::FeignDeath));
+    assert!(!player.has_condition(PlayerCondition::Aiming));
+    assert!(!player.has_condition(PlayerCondition::Disguising));
+    assert_eq!(
+        vec![
+            PlayerCondition::Disguised,
+            PlayerCondition::Stealthed,
+            PlayerCondition::FeignDeath
+        ],
+        player.conditions().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_building_class() {
+    assert_eq!(
+        BuildingClass::Sentry,
+        Building::Sentry(Sentry::default()).class()
+    );
+    assert_eq!(
+        BuildingClass::Dispenser,
+        Building::Dispenser(Dispenser::default()).class()
+    );
+    assert_eq!(
+        BuildingClass::Teleporter,
+        Building::Teleporter(Teleporter::default()).class()
+    );
+}
+
+#[test]
+fn test_resolve_builders() {
+    use crate::demo::parser::analyser::ClassList;
+
+    let mut state = GameState::default();
+    let entity = EntityId::from(30u32);
+    if let Building::Sentry(sentry) = state.get_or_create_building(entity, BuildingClass::Sentry) {
+        sentry.builder_handle = Handle(2);
+    }
+
+    // the user info of the builder isn't known yet
+    state.resolve_builders();
+    assert_eq!(UserId::default(), state.buildings[&entity].builder());
+
+    state.get_or_create_player(EntityId::from(2u32)).info = Some(UserInfo {
+        classes: ClassList::default(),
+        name: "engineer".into(),
+        user_id: UserId::from(4u16),
+        steam_id: String::new(),
+        entity_id: EntityId::from(2u32),
+        team: Team::Blue,
+    });
+    state.resolve_builders();
+    assert_eq!(UserId::from(4u16), state.buildings[&entity].builder());
+}
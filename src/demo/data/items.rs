@@ -0,0 +1,162 @@
+use crate::demo::parser::analyser::Class;
+use num_enum::TryFromPrimitive;
+use parse_display::{Display, FromStr};
+use serde::{Deserialize, Serialize};
+
+pub use super::items_table::{ITEMS, LOG_NAMES};
+
+/// The loadout slot an item is equipped in
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display, FromStr, Default,
+)]
+#[display(style = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ItemSlot {
+    Primary,
+    Secondary,
+    Melee,
+    Pda,
+    Pda2,
+    Building,
+    Head,
+    Misc,
+    Action,
+    Taunt,
+    Utility,
+    #[default]
+    Other,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TryFromPrimitive, Default,
+)]
+#[serde(rename_all = "lowercase")]
+#[repr(u8)]
+pub enum ItemQuality {
+    #[default]
+    Normal = 0,
+    Genuine = 1,
+    Vintage = 3,
+    Unusual = 5,
+    Unique = 6,
+    Community = 7,
+    Developer = 8,
+    SelfMade = 9,
+    Customized = 10,
+    Strange = 11,
+    Completed = 12,
+    Haunted = 13,
+    Collectors = 14,
+    Decorated = 15,
+}
+
+impl ItemQuality {
+    /// Parse the quality names as used in `items_game.txt`
+    pub fn from_schema_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "normal" => ItemQuality::Normal,
+            "rarity1" | "genuine" => ItemQuality::Genuine,
+            "vintage" => ItemQuality::Vintage,
+            "rarity4" | "unusual" => ItemQuality::Unusual,
+            "unique" => ItemQuality::Unique,
+            "community" => ItemQuality::Community,
+            "developer" => ItemQuality::Developer,
+            "selfmade" => ItemQuality::SelfMade,
+            "customized" => ItemQuality::Customized,
+            "strange" => ItemQuality::Strange,
+            "completed" => ItemQuality::Completed,
+            "haunted" => ItemQuality::Haunted,
+            "collectors" => ItemQuality::Collectors,
+            "paintkitweapon" | "decorated" => ItemQuality::Decorated,
+            _ => return None,
+        })
+    }
+}
+
+/// Static information about an item definition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ItemSchema {
+    pub definition_index: u16,
+    pub name: &'static str,
+    /// The name used for the item in kill feed weapon strings
+    pub log_name: Option<&'static str>,
+    /// The slot the item is equipped in, unless overwritten for the class in `class_slots`
+    pub slot: ItemSlot,
+    /// Classes that equip the item in a different slot, such as the shotgun for engineers
+    pub class_slots: &'static [(Class, ItemSlot)],
+    /// Classes that can equip the item, empty if the item can't be equipped by players
+    pub classes: &'static [Class],
+    pub quality: ItemQuality,
+}
+
+impl ItemSchema {
+    pub fn usable_by(&self, class: Class) -> bool {
+        self.classes.contains(&class)
+    }
+
+    /// The slot the item is equipped in when used by the class
+    pub fn slot_for(&self, class: Class) -> ItemSlot {
+        self.class_slots
+            .iter()
+            .find(|(slot_class, _)| *slot_class == class)
+            .map_or(self.slot, |(_, slot)| *slot)
+    }
+}
+
+/// Get the schema information for an item definition index
+///
+/// The item table bundled with the crate only contains the stock and unlockable weapons, cosmetics
+/// and most reskinned weapons are not included. A full table can be generated from `items_game.txt`
+/// using `codegen <items_game.txt> items`.
+pub fn get_item_schema(definition_index: u16) -> Option<&'static ItemSchema> {
+    ITEMS
+        .binary_search_by_key(&definition_index, |item| item.definition_index)
+        .ok()
+        .and_then(|index| ITEMS.get(index))
+}
+
+/// Get the schema information for a weapon string as used in `Kill` and `Death`
+///
+/// Weapons that share a log name (such as strange and stock variants) resolve to the item with the lowest definition index.
+pub fn get_item_schema_by_log_name(log_name: &str) -> Option<&'static ItemSchema> {
+    LOG_NAMES
+        .binary_search_by_key(&log_name, |(name, _)| name)
+        .ok()
+        .and_then(|index| LOG_NAMES.get(index))
+        .and_then(|(_, definition_index)| get_item_schema(*definition_index))
+}
+
+#[test]
+fn test_item_lookup() {
+    let scattergun = get_item_schema(200).unwrap();
+    assert_eq!(ItemSlot::Primary, scattergun.slot);
+    assert!(scattergun.usable_by(Class::Scout));
+    assert!(!scattergun.usable_by(Class::Soldier));
+    assert_eq!(ItemSlot::Primary, scattergun.slot_for(Class::Scout));
+
+    let shotgun = get_item_schema(199).unwrap();
+    assert_eq!(ItemSlot::Secondary, shotgun.slot_for(Class::Soldier));
+    assert_eq!(ItemSlot::Primary, shotgun.slot_for(Class::Engineer));
+
+    assert_eq!(
+        Some(355),
+        get_item_schema_by_log_name("warfan").map(|item| item.definition_index)
+    );
+    assert_eq!(
+        Some(13),
+        get_item_schema_by_log_name("scattergun").map(|item| item.definition_index)
+    );
+    assert_eq!(
+        Some(215),
+        get_item_schema_by_log_name("degreaser").map(|item| item.definition_index)
+    );
+    assert_eq!(None, get_item_schema(u16::MAX));
+}
+
+#[test]
+fn test_items_sorted() {
+    assert!(ITEMS
+        .windows(2)
+        .all(|pair| pair[0].definition_index < pair[1].definition_index));
+    assert!(LOG_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
@@ -0,0 +1,1765 @@
+use super::items::{ItemQuality, ItemSchema, ItemSlot};
+use crate::demo::parser::analyser::Class;
+
+// Maintained by hand, this only covers the stock and unlockable weapons.
+// A complete table including cosmetics and reskins can be generated from `items_game.txt`
+// using `codegen <items_game.txt> items`.
+
+/// Stock and unlockable weapons, sorted by definition index
+pub static ITEMS: &[ItemSchema] = &[
+    ItemSchema {
+        definition_index: 0,
+        name: "TF_WEAPON_BAT",
+        log_name: Some("bat"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 1,
+        name: "TF_WEAPON_BOTTLE",
+        log_name: Some("bottle"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 2,
+        name: "TF_WEAPON_FIREAXE",
+        log_name: Some("fireaxe"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 3,
+        name: "TF_WEAPON_CLUB",
+        log_name: Some("club"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 4,
+        name: "TF_WEAPON_KNIFE",
+        log_name: Some("knife"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 5,
+        name: "TF_WEAPON_FISTS",
+        log_name: Some("fists"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 6,
+        name: "TF_WEAPON_SHOVEL",
+        log_name: Some("shovel"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 7,
+        name: "TF_WEAPON_WRENCH",
+        log_name: Some("wrench"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 8,
+        name: "TF_WEAPON_BONESAW",
+        log_name: Some("bonesaw"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 9,
+        name: "TF_WEAPON_SHOTGUN_PRIMARY",
+        log_name: Some("shotgun_primary"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 10,
+        name: "TF_WEAPON_SHOTGUN_SOLDIER",
+        log_name: Some("shotgun_soldier"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 11,
+        name: "TF_WEAPON_SHOTGUN_HWG",
+        log_name: Some("shotgun_hwg"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 12,
+        name: "TF_WEAPON_SHOTGUN_PYRO",
+        log_name: Some("shotgun_pyro"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 13,
+        name: "TF_WEAPON_SCATTERGUN",
+        log_name: Some("scattergun"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 14,
+        name: "TF_WEAPON_SNIPERRIFLE",
+        log_name: Some("sniperrifle"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 15,
+        name: "TF_WEAPON_MINIGUN",
+        log_name: Some("minigun"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 16,
+        name: "TF_WEAPON_SMG",
+        log_name: Some("smg"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 17,
+        name: "TF_WEAPON_SYRINGEGUN_MEDIC",
+        log_name: Some("syringegun_medic"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 18,
+        name: "TF_WEAPON_ROCKETLAUNCHER",
+        log_name: Some("rocketlauncher"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 19,
+        name: "TF_WEAPON_GRENADELAUNCHER",
+        log_name: Some("grenadelauncher"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 20,
+        name: "TF_WEAPON_PIPEBOMBLAUNCHER",
+        log_name: Some("pipebomblauncher"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 21,
+        name: "TF_WEAPON_FLAMETHROWER",
+        log_name: Some("flamethrower"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 22,
+        name: "TF_WEAPON_PISTOL",
+        log_name: Some("pistol"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 23,
+        name: "TF_WEAPON_PISTOL_SCOUT",
+        log_name: Some("pistol_scout"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 24,
+        name: "TF_WEAPON_REVOLVER",
+        log_name: Some("revolver"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 25,
+        name: "TF_WEAPON_PDA_ENGINEER_BUILD",
+        log_name: Some("pda_engineer_build"),
+        slot: ItemSlot::Pda,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 26,
+        name: "TF_WEAPON_PDA_ENGINEER_DESTROY",
+        log_name: Some("pda_engineer_destroy"),
+        slot: ItemSlot::Pda2,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 27,
+        name: "TF_WEAPON_PDA_SPY",
+        log_name: Some("pda_spy"),
+        slot: ItemSlot::Pda,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 28,
+        name: "TF_WEAPON_BUILDER",
+        log_name: Some("builder"),
+        slot: ItemSlot::Building,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 29,
+        name: "TF_WEAPON_MEDIGUN",
+        log_name: Some("medigun"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 30,
+        name: "TF_WEAPON_INVIS",
+        log_name: Some("invis"),
+        slot: ItemSlot::Pda2,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Normal,
+    },
+    ItemSchema {
+        definition_index: 35,
+        name: "The Kritzkrieg",
+        log_name: Some("medigun"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 36,
+        name: "The Blutsauger",
+        log_name: Some("blutsauger"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 37,
+        name: "The Ubersaw",
+        log_name: Some("ubersaw"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 38,
+        name: "The Axtinguisher",
+        log_name: Some("axtinguisher"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 39,
+        name: "The Flare Gun",
+        log_name: Some("flaregun"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 40,
+        name: "The Backburner",
+        log_name: Some("backburner"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 41,
+        name: "Natascha",
+        log_name: Some("natascha"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 42,
+        name: "The Sandvich",
+        log_name: Some("lunchbox"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 43,
+        name: "The Killing Gloves of Boxing",
+        log_name: Some("gloves"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 44,
+        name: "The Sandman",
+        log_name: Some("sandman"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 45,
+        name: "The Force-A-Nature",
+        log_name: Some("force_a_nature"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 46,
+        name: "Bonk! Atomic Punch",
+        log_name: Some("lunchbox_drink"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 56,
+        name: "The Huntsman",
+        log_name: Some("compound_bow"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 57,
+        name: "The Razorback",
+        log_name: None,
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 58,
+        name: "Jarate",
+        log_name: Some("jar"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 59,
+        name: "The Dead Ringer",
+        log_name: Some("invis"),
+        slot: ItemSlot::Pda2,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 60,
+        name: "The Cloak and Dagger",
+        log_name: Some("invis"),
+        slot: ItemSlot::Pda2,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 61,
+        name: "The Ambassador",
+        log_name: Some("ambassador"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 127,
+        name: "The Direct Hit",
+        log_name: Some("rocketlauncher_directhit"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 128,
+        name: "The Equalizer",
+        log_name: Some("unique_pickaxe"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 129,
+        name: "The Buff Banner",
+        log_name: Some("buff_item"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 130,
+        name: "The Scottish Resistance",
+        log_name: Some("pipebomblauncher"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 131,
+        name: "The Chargin' Targe",
+        log_name: None,
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 132,
+        name: "The Eyelander",
+        log_name: Some("sword"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 133,
+        name: "Gunboats",
+        log_name: None,
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 140,
+        name: "The Wrangler",
+        log_name: Some("laser_pointer"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 141,
+        name: "The Frontier Justice",
+        log_name: Some("frontier_justice"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 142,
+        name: "The Gunslinger",
+        log_name: Some("robot_arm"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 153,
+        name: "The Homewrecker",
+        log_name: Some("sledgehammer"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 154,
+        name: "The Pain Train",
+        log_name: Some("paintrain"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Soldier, Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 155,
+        name: "The Southern Hospitality",
+        log_name: Some("southern_hospitality"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 159,
+        name: "The Dalokohs Bar",
+        log_name: Some("lunchbox"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 163,
+        name: "Crit-a-Cola",
+        log_name: Some("lunchbox_drink"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 171,
+        name: "The Tribalman's Shiv",
+        log_name: Some("tribalkukri"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 172,
+        name: "The Scotsman's Skullcutter",
+        log_name: Some("battleaxe"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 173,
+        name: "The Vita-Saw",
+        log_name: Some("bonesaw"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 190,
+        name: "Upgradeable TF_WEAPON_BAT",
+        log_name: Some("bat"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 191,
+        name: "Upgradeable TF_WEAPON_BOTTLE",
+        log_name: Some("bottle"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 192,
+        name: "Upgradeable TF_WEAPON_FIREAXE",
+        log_name: Some("fireaxe"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 193,
+        name: "Upgradeable TF_WEAPON_CLUB",
+        log_name: Some("club"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 194,
+        name: "Upgradeable TF_WEAPON_KNIFE",
+        log_name: Some("knife"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 195,
+        name: "Upgradeable TF_WEAPON_FISTS",
+        log_name: Some("fists"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 196,
+        name: "Upgradeable TF_WEAPON_SHOVEL",
+        log_name: Some("shovel"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 197,
+        name: "Upgradeable TF_WEAPON_WRENCH",
+        log_name: Some("wrench"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 198,
+        name: "Upgradeable TF_WEAPON_BONESAW",
+        log_name: Some("bonesaw"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 199,
+        name: "Upgradeable TF_WEAPON_SHOTGUN_PRIMARY",
+        log_name: Some("shotgun"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[(Class::Engineer, ItemSlot::Primary)],
+        classes: &[Class::Soldier, Class::Heavy, Class::Pyro, Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 200,
+        name: "Upgradeable TF_WEAPON_SCATTERGUN",
+        log_name: Some("scattergun"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 201,
+        name: "Upgradeable TF_WEAPON_SNIPERRIFLE",
+        log_name: Some("sniperrifle"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 202,
+        name: "Upgradeable TF_WEAPON_MINIGUN",
+        log_name: Some("minigun"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 203,
+        name: "Upgradeable TF_WEAPON_SMG",
+        log_name: Some("smg"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 204,
+        name: "Upgradeable TF_WEAPON_SYRINGEGUN_MEDIC",
+        log_name: Some("syringegun_medic"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 205,
+        name: "Upgradeable TF_WEAPON_ROCKETLAUNCHER",
+        log_name: Some("rocketlauncher"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 206,
+        name: "Upgradeable TF_WEAPON_GRENADELAUNCHER",
+        log_name: Some("grenadelauncher"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 207,
+        name: "Upgradeable TF_WEAPON_PIPEBOMBLAUNCHER",
+        log_name: Some("pipebomblauncher"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 208,
+        name: "Upgradeable TF_WEAPON_FLAMETHROWER",
+        log_name: Some("flamethrower"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 209,
+        name: "Upgradeable TF_WEAPON_PISTOL",
+        log_name: Some("pistol"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Scout, Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 210,
+        name: "Upgradeable TF_WEAPON_REVOLVER",
+        log_name: Some("revolver"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 211,
+        name: "Upgradeable TF_WEAPON_MEDIGUN",
+        log_name: Some("medigun"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 212,
+        name: "Upgradeable TF_WEAPON_INVIS",
+        log_name: Some("invis"),
+        slot: ItemSlot::Pda2,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 214,
+        name: "The Powerjack",
+        log_name: Some("powerjack"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 215,
+        name: "The Degreaser",
+        log_name: Some("degreaser"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 220,
+        name: "The Shortstop",
+        log_name: Some("shortstop"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 221,
+        name: "The Holy Mackerel",
+        log_name: Some("holymackerel"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 222,
+        name: "Mad Milk",
+        log_name: Some("jar_milk"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 224,
+        name: "L'Etranger",
+        log_name: Some("letranger"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 225,
+        name: "Your Eternal Reward",
+        log_name: Some("eternal_reward"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 226,
+        name: "The Battalion's Backup",
+        log_name: Some("buff_item"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 228,
+        name: "The Black Box",
+        log_name: Some("blackbox"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 230,
+        name: "The Sydney Sleeper",
+        log_name: Some("sydney_sleeper"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 231,
+        name: "Darwin's Danger Shield",
+        log_name: None,
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 232,
+        name: "The Bushwacka",
+        log_name: Some("bushwacka"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 237,
+        name: "Rocket Jumper",
+        log_name: Some("rocketjumper"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 239,
+        name: "Gloves of Running Urgently",
+        log_name: Some("gloves_running_urgently"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 265,
+        name: "Sticky Jumper",
+        log_name: Some("sticky_jumper"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 304,
+        name: "Amputator",
+        log_name: Some("amputator"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 305,
+        name: "Crusader's Crossbow",
+        log_name: Some("crossbow"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 307,
+        name: "Ullapool Caber",
+        log_name: Some("stickbomb"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 308,
+        name: "The Loch-n-Load",
+        log_name: Some("loch_n_load"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 310,
+        name: "Warrior's Spirit",
+        log_name: Some("warrior_spirit"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 311,
+        name: "The Buffalo Steak Sandvich",
+        log_name: Some("lunchbox"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 312,
+        name: "The Brass Beast",
+        log_name: Some("brass_beast"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 317,
+        name: "The Candy Cane",
+        log_name: Some("candy_cane"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 325,
+        name: "The Boston Basher",
+        log_name: Some("boston_basher"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 326,
+        name: "The Back Scratcher",
+        log_name: Some("back_scratcher"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 327,
+        name: "The Claidheamh Mor",
+        log_name: Some("claidheamohmor"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 329,
+        name: "The Jag",
+        log_name: Some("wrench_jag"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 331,
+        name: "Fists of Steel",
+        log_name: Some("steel_fists"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 348,
+        name: "Sharpened Volcano Fragment",
+        log_name: Some("lava_axe"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 349,
+        name: "Sun-on-a-Stick",
+        log_name: Some("lava_bat"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 351,
+        name: "The Detonator",
+        log_name: Some("detonator"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 354,
+        name: "The Concheror",
+        log_name: Some("buff_item"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 355,
+        name: "The Fan O'War",
+        log_name: Some("warfan"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 356,
+        name: "Conniver's Kunai",
+        log_name: Some("kunai"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 357,
+        name: "The Half-Zatoichi",
+        log_name: Some("demokatana"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Soldier, Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 401,
+        name: "The Shahanshah",
+        log_name: Some("shahanshah"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 402,
+        name: "The Bazaar Bargain",
+        log_name: Some("bazaar_bargain"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 404,
+        name: "The Persian Persuader",
+        log_name: Some("persian_persuader"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 406,
+        name: "The Splendid Screen",
+        log_name: None,
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 411,
+        name: "The Quick-Fix",
+        log_name: Some("medigun"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 412,
+        name: "The Overdose",
+        log_name: Some("proto_syringe"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 413,
+        name: "The Solemn Vow",
+        log_name: Some("solemn_vow"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 414,
+        name: "The Liberty Launcher",
+        log_name: Some("liberty_launcher"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 415,
+        name: "The Reserve Shooter",
+        log_name: Some("reserve_shooter"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Soldier, Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 416,
+        name: "The Market Gardener",
+        log_name: Some("market_gardener"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 424,
+        name: "Tomislav",
+        log_name: Some("tomislav"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 425,
+        name: "The Family Business",
+        log_name: Some("family_business"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 426,
+        name: "The Eviction Notice",
+        log_name: Some("eviction_notice"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Heavy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 441,
+        name: "The Cow Mangler 5000",
+        log_name: Some("cow_mangler"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 442,
+        name: "The Righteous Bison",
+        log_name: Some("righteous_bison"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 444,
+        name: "The Mantreads",
+        log_name: Some("mantreads"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 447,
+        name: "The Disciplinary Action",
+        log_name: Some("disciplinary_action"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 448,
+        name: "The Soda Popper",
+        log_name: Some("soda_popper"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 449,
+        name: "The Winger",
+        log_name: Some("the_winger"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 450,
+        name: "The Atomizer",
+        log_name: Some("atomizer"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 460,
+        name: "The Enforcer",
+        log_name: Some("enforcer"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 461,
+        name: "The Big Earner",
+        log_name: Some("big_earner"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 513,
+        name: "The Original",
+        log_name: Some("quake_rl"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 525,
+        name: "The Diamondback",
+        log_name: Some("diamondback"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 526,
+        name: "The Machina",
+        log_name: Some("machina"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 527,
+        name: "The Widowmaker",
+        log_name: Some("widowmaker"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 528,
+        name: "The Short Circuit",
+        log_name: Some("short_circuit"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 588,
+        name: "The Pomson 6000",
+        log_name: Some("pomson"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 589,
+        name: "The Eureka Effect",
+        log_name: Some("eureka_effect"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 593,
+        name: "The Third Degree",
+        log_name: Some("thirddegree"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 594,
+        name: "The Phlogistinator",
+        log_name: Some("phlogistinator"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 595,
+        name: "The Manmelter",
+        log_name: Some("manmelter"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 648,
+        name: "The Wrap Assassin",
+        log_name: Some("wrap_assassin"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 649,
+        name: "The Spy-cicle",
+        log_name: Some("spy_cicle"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Spy],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 730,
+        name: "The Beggar's Bazooka",
+        log_name: Some("dumpster_device"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 751,
+        name: "The Cleaner's Carbine",
+        log_name: Some("pro_smg"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 752,
+        name: "The Hitman's Heatmaker",
+        log_name: Some("pro_rifle"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 772,
+        name: "Baby Face's Blaster",
+        log_name: Some("pep_brawlerblaster"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 773,
+        name: "Pretty Boy's Pocket Pistol",
+        log_name: Some("pep_pistol"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 775,
+        name: "The Escape Plan",
+        log_name: Some("unique_pickaxe_escape"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 812,
+        name: "The Flying Guillotine",
+        log_name: Some("guillotine"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Scout],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 996,
+        name: "The Loose Cannon",
+        log_name: Some("loose_cannon"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 997,
+        name: "The Rescue Ranger",
+        log_name: Some("rescue_ranger_bolt"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 998,
+        name: "The Vaccinator",
+        log_name: Some("medigun"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Medic],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1098,
+        name: "The Classic",
+        log_name: Some("the_classic"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Sniper],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1099,
+        name: "The Tide Turner",
+        log_name: Some("tide_turner"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1104,
+        name: "The Air Strike",
+        log_name: Some("airstrike"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Soldier],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1150,
+        name: "The Quickiebomb Launcher",
+        log_name: Some("quickiebomb_launcher"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1151,
+        name: "The Iron Bomber",
+        log_name: Some("iron_bomber"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Demoman],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1153,
+        name: "Panic Attack",
+        log_name: Some("panic_attack"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[(Class::Engineer, ItemSlot::Primary)],
+        classes: &[Class::Soldier, Class::Heavy, Class::Pyro, Class::Engineer],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1178,
+        name: "Dragon's Fury",
+        log_name: Some("dragons_fury"),
+        slot: ItemSlot::Primary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1179,
+        name: "Thermal Thruster",
+        log_name: Some("rocketpack"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1180,
+        name: "Gas Passer",
+        log_name: Some("jar_gas"),
+        slot: ItemSlot::Secondary,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+    ItemSchema {
+        definition_index: 1181,
+        name: "The Hot Hand",
+        log_name: Some("hot_hand"),
+        slot: ItemSlot::Melee,
+        class_slots: &[],
+        classes: &[Class::Pyro],
+        quality: ItemQuality::Unique,
+    },
+];
+
+/// Definition index for every weapon log name, sorted by name
+pub static LOG_NAMES: &[(&str, u16)] = &[
+    ("airstrike", 1104),
+    ("ambassador", 61),
+    ("amputator", 304),
+    ("atomizer", 450),
+    ("axtinguisher", 38),
+    ("back_scratcher", 326),
+    ("backburner", 40),
+    ("bat", 0),
+    ("battleaxe", 172),
+    ("bazaar_bargain", 402),
+    ("big_earner", 461),
+    ("blackbox", 228),
+    ("blutsauger", 36),
+    ("bonesaw", 8),
+    ("boston_basher", 325),
+    ("bottle", 1),
+    ("brass_beast", 312),
+    ("buff_item", 129),
+    ("builder", 28),
+    ("bushwacka", 232),
+    ("candy_cane", 317),
+    ("claidheamohmor", 327),
+    ("club", 3),
+    ("compound_bow", 56),
+    ("cow_mangler", 441),
+    ("crossbow", 305),
+    ("degreaser", 215),
+    ("demokatana", 357),
+    ("detonator", 351),
+    ("diamondback", 525),
+    ("disciplinary_action", 447),
+    ("dragons_fury", 1178),
+    ("dumpster_device", 730),
+    ("enforcer", 460),
+    ("eternal_reward", 225),
+    ("eureka_effect", 589),
+    ("eviction_notice", 426),
+    ("family_business", 425),
+    ("fireaxe", 2),
+    ("fists", 5),
+    ("flamethrower", 21),
+    ("flaregun", 39),
+    ("force_a_nature", 45),
+    ("frontier_justice", 141),
+    ("gloves", 43),
+    ("gloves_running_urgently", 239),
+    ("grenadelauncher", 19),
+    ("guillotine", 812),
+    ("holymackerel", 221),
+    ("hot_hand", 1181),
+    ("invis", 30),
+    ("iron_bomber", 1151),
+    ("jar", 58),
+    ("jar_gas", 1180),
+    ("jar_milk", 222),
+    ("knife", 4),
+    ("kunai", 356),
+    ("laser_pointer", 140),
+    ("lava_axe", 348),
+    ("lava_bat", 349),
+    ("letranger", 224),
+    ("liberty_launcher", 414),
+    ("loch_n_load", 308),
+    ("loose_cannon", 996),
+    ("lunchbox", 42),
+    ("lunchbox_drink", 46),
+    ("machina", 526),
+    ("manmelter", 595),
+    ("mantreads", 444),
+    ("market_gardener", 416),
+    ("medigun", 29),
+    ("minigun", 15),
+    ("natascha", 41),
+    ("paintrain", 154),
+    ("panic_attack", 1153),
+    ("pda_engineer_build", 25),
+    ("pda_engineer_destroy", 26),
+    ("pda_spy", 27),
+    ("pep_brawlerblaster", 772),
+    ("pep_pistol", 773),
+    ("persian_persuader", 404),
+    ("phlogistinator", 594),
+    ("pipebomblauncher", 20),
+    ("pistol", 22),
+    ("pistol_scout", 23),
+    ("pomson", 588),
+    ("powerjack", 214),
+    ("pro_rifle", 752),
+    ("pro_smg", 751),
+    ("proto_syringe", 412),
+    ("quake_rl", 513),
+    ("quickiebomb_launcher", 1150),
+    ("rescue_ranger_bolt", 997),
+    ("reserve_shooter", 415),
+    ("revolver", 24),
+    ("righteous_bison", 442),
+    ("robot_arm", 142),
+    ("rocketjumper", 237),
+    ("rocketlauncher", 18),
+    ("rocketlauncher_directhit", 127),
+    ("rocketpack", 1179),
+    ("sandman", 44),
+    ("scattergun", 13),
+    ("shahanshah", 401),
+    ("short_circuit", 528),
+    ("shortstop", 220),
+    ("shotgun", 199),
+    ("shotgun_hwg", 11),
+    ("shotgun_primary", 9),
+    ("shotgun_pyro", 12),
+    ("shotgun_soldier", 10),
+    ("shovel", 6),
+    ("sledgehammer", 153),
+    ("smg", 16),
+    ("sniperrifle", 14),
+    ("soda_popper", 448),
+    ("solemn_vow", 413),
+    ("southern_hospitality", 155),
+    ("spy_cicle", 649),
+    ("steel_fists", 331),
+    ("stickbomb", 307),
+    ("sticky_jumper", 265),
+    ("sword", 132),
+    ("sydney_sleeper", 230),
+    ("syringegun_medic", 17),
+    ("the_classic", 1098),
+    ("the_winger", 449),
+    ("thirddegree", 593),
+    ("tide_turner", 1099),
+    ("tomislav", 424),
+    ("tribalkukri", 171),
+    ("ubersaw", 37),
+    ("unique_pickaxe", 128),
+    ("unique_pickaxe_escape", 775),
+    ("warfan", 355),
+    ("warrior_spirit", 310),
+    ("widowmaker", 527),
+    ("wrap_assassin", 648),
+    ("wrench", 7),
+    ("wrench_jag", 329),
+];
@@ -1,6 +1,10 @@
 pub mod attributes;
 mod cond;
+pub mod entityviews;
+mod entityviews_gen;
 pub mod game_state;
+pub mod items;
+mod items_table;
 pub mod userinfo;
 
 use bitbuffer::{BitRead, BitReadStream, BitWrite, BitWriteStream, Endianness};
@@ -0,0 +1,503 @@
+//! Structural comparison between two demos
+//!
+//! Useful for finding where an edited or re-encoded demo diverges from the original.
+
+use crate::demo::data::DemoTick;
+use crate::demo::header::Header;
+use crate::demo::message::packetentities::PacketEntity;
+use crate::demo::message::Message;
+use crate::demo::packet::message::MessagePacket;
+use crate::demo::packet::stringtable::{StringTable, StringTableEntry};
+use crate::demo::packet::Packet;
+use crate::demo::parser::{DemoHandler, RawPacketStream};
+use crate::demo::sendprop::{SendProp, SendPropIdentifier};
+use crate::{Demo, Result};
+use bitbuffer::BitRead;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Options for [`diff_demos`]
+#[derive(Debug, Clone, Default)]
+pub struct DiffOptions {
+    /// Ignore differences in the header
+    pub ignore_header: bool,
+    /// Ignore differences in the view angles of message packets
+    pub ignore_view_angles: bool,
+    /// Ignore differences in the flags and sequence numbers of message packets
+    pub ignore_sequence: bool,
+    /// Stop comparing after this many differences have been found
+    pub max_differences: Option<usize>,
+}
+
+/// How a value differs between the two demos
+#[derive(Debug, Clone, PartialEq)]
+pub enum DifferenceKind {
+    /// The value is different in both demos
+    Changed { left: String, right: String },
+    /// The value is only present in the left demo
+    Removed(String),
+    /// The value is only present in the right demo
+    Added(String),
+}
+
+/// A single difference between two demos
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// The tick of the left packet, or the right packet if the left demo has ended
+    pub tick: DemoTick,
+    /// The location of the difference,
+    /// e.g. `packet[812](Message).messages[2](PacketEntities).entities[4].props[54](DT_BasePlayer.m_iHealth)`
+    pub path: String,
+    pub kind: DifferenceKind,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: ", self.tick, self.path)?;
+        match &self.kind {
+            DifferenceKind::Changed { left, right } => write!(f, "{left} -> {right}"),
+            DifferenceKind::Removed(left) => write!(f, "- {left}"),
+            DifferenceKind::Added(right) => write!(f, "+ {right}"),
+        }
+    }
+}
+
+/// Compare two demos packet by packet
+///
+/// Both demos are parsed in lockstep, and the differences are reported at the packet, message,
+/// string table entry, game event and entity prop level.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::diff::{diff_demos, DiffOptions};
+/// let original = std::fs::read("original.dem").unwrap();
+/// let edited = std::fs::read("edited.dem").unwrap();
+/// let options = DiffOptions {
+///     ignore_view_angles: true,
+///     ..DiffOptions::default()
+/// };
+/// let differences = diff_demos(&Demo::new(&original), &Demo::new(&edited), &options).unwrap();
+/// for difference in differences {
+///     println!("{}", difference);
+/// }
+/// ```
+pub fn diff_demos(left: &Demo, right: &Demo, options: &DiffOptions) -> Result<Vec<Difference>> {
+    let mut left_stream = left.get_stream();
+    let mut right_stream = right.get_stream();
+    let left_header = Header::read(&mut left_stream)?;
+    let right_header = Header::read(&mut right_stream)?;
+
+    let mut differ = Differ {
+        options,
+        tick: DemoTick::default(),
+        differences: Vec::new(),
+    };
+    if !options.ignore_header {
+        differ.value("header", &to_value(&left_header), &to_value(&right_header));
+    }
+
+    let mut left_handler = DemoHandler::default();
+    let mut right_handler = DemoHandler::default();
+    left_handler.handle_header(&left_header);
+    right_handler.handle_header(&right_header);
+    let mut left_packets = RawPacketStream::new(left_stream);
+    let mut right_packets = RawPacketStream::new(right_stream);
+
+    let mut index = 0;
+    while !differ.is_full() {
+        let left_packet = left_packets.next(&left_handler.state_handler)?;
+        let right_packet = right_packets.next(&right_handler.state_handler)?;
+        match (left_packet, right_packet) {
+            (None, None) => break,
+            (Some(left), Some(right)) => {
+                differ.tick = left.tick();
+                differ.packet(index, &left, &right);
+                left_handler.handle_packet(left)?;
+                right_handler.handle_packet(right)?;
+            }
+            (Some(left), None) => {
+                differ.tick = left.tick();
+                let path = packet_path(index, &left);
+                differ.push(path, DifferenceKind::Removed(to_value(&left).to_string()));
+                left_handler.handle_packet(left)?;
+            }
+            (None, Some(right)) => {
+                differ.tick = right.tick();
+                let path = packet_path(index, &right);
+                differ.push(path, DifferenceKind::Added(to_value(&right).to_string()));
+                right_handler.handle_packet(right)?;
+            }
+        }
+        index += 1;
+    }
+
+    Ok(differ.differences)
+}
+
+struct Differ<'o> {
+    options: &'o DiffOptions,
+    tick: DemoTick,
+    differences: Vec<Difference>,
+}
+
+impl Differ<'_> {
+    fn is_full(&self) -> bool {
+        matches!(self.options.max_differences, Some(max) if self.differences.len() >= max)
+    }
+
+    fn push(&mut self, path: String, kind: DifferenceKind) {
+        if !self.is_full() {
+            self.differences.push(Difference {
+                tick: self.tick,
+                path,
+                kind,
+            });
+        }
+    }
+
+    fn packet(&mut self, index: usize, left: &Packet, right: &Packet) {
+        let path = packet_path(index, left);
+        if left.packet_type() != right.packet_type() {
+            self.push(
+                format!("packet[{index}]"),
+                DifferenceKind::Changed {
+                    left: format!("{:?}", left.packet_type()),
+                    right: format!("{:?}", right.packet_type()),
+                },
+            );
+            return;
+        }
+        match (left, right) {
+            (Packet::Signon(left), Packet::Signon(right))
+            | (Packet::Message(left), Packet::Message(right)) => {
+                self.message_packet(&path, left, right)
+            }
+            (Packet::StringTables(left), Packet::StringTables(right)) => {
+                self.value(
+                    &format!("{path}.tick"),
+                    &to_value(&left.tick),
+                    &to_value(&right.tick),
+                );
+                self.list(
+                    &format!("{path}.tables"),
+                    &left.tables,
+                    &right.tables,
+                    |differ, path, left, right| differ.string_table(path, left, right),
+                );
+            }
+            _ => self.value(&path, &to_value(left), &to_value(right)),
+        }
+    }
+
+    fn message_packet(&mut self, path: &str, left: &MessagePacket, right: &MessagePacket) {
+        self.value(
+            &format!("{path}.tick"),
+            &to_value(&left.tick),
+            &to_value(&right.tick),
+        );
+
+        let mut left_meta = to_value(&left.meta);
+        let mut right_meta = to_value(&right.meta);
+        let mut ignored = Vec::new();
+        if self.options.ignore_view_angles {
+            ignored.push("view_angles");
+        }
+        if self.options.ignore_sequence {
+            ignored.extend(["flags", "sequence_in", "sequence_out"]);
+        }
+        for meta in [&mut left_meta, &mut right_meta] {
+            if let Value::Object(fields) = meta {
+                for key in &ignored {
+                    fields.remove(*key);
+                }
+            }
+        }
+        self.value(&format!("{path}.meta"), &left_meta, &right_meta);
+
+        for index in 0..left.messages.len().max(right.messages.len()) {
+            match (left.messages.get(index), right.messages.get(index)) {
+                (Some(left), Some(right)) => self.message(
+                    &format!("{path}.messages[{index}]({:?})", left.get_message_type()),
+                    left,
+                    right,
+                ),
+                (Some(left), None) => self.push(
+                    format!("{path}.messages[{index}]({:?})", left.get_message_type()),
+                    DifferenceKind::Removed(to_value(left).to_string()),
+                ),
+                (None, Some(right)) => self.push(
+                    format!("{path}.messages[{index}]({:?})", right.get_message_type()),
+                    DifferenceKind::Added(to_value(right).to_string()),
+                ),
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn message(&mut self, path: &str, left: &Message, right: &Message) {
+        if left.get_message_type() != right.get_message_type() {
+            self.push(
+                path.into(),
+                DifferenceKind::Changed {
+                    left: format!("{:?}", left.get_message_type()),
+                    right: format!("{:?}", right.get_message_type()),
+                },
+            );
+            return;
+        }
+        match (left, right) {
+            (Message::PacketEntities(left), Message::PacketEntities(right)) => {
+                self.value(
+                    path,
+                    &without(to_value(left), "entities"),
+                    &without(to_value(right), "entities"),
+                );
+                self.keyed(
+                    &format!("{path}.entities"),
+                    &left.entities,
+                    &right.entities,
+                    |entity| entity.entity_index,
+                    |entity| format!("[{}]", entity.entity_index),
+                    |differ, path, left, right| differ.entity(path, left, right),
+                );
+            }
+            (Message::CreateStringTable(left), Message::CreateStringTable(right)) => {
+                self.string_table(&format!("{path}.table"), &left.table, &right.table);
+            }
+            (Message::UpdateStringTable(left), Message::UpdateStringTable(right)) => {
+                self.value(
+                    &format!("{path}.table_id"),
+                    &to_value(&left.table_id),
+                    &to_value(&right.table_id),
+                );
+                self.entries(&format!("{path}.entries"), &left.entries, &right.entries);
+            }
+            _ => self.value(path, &to_value(left), &to_value(right)),
+        }
+    }
+
+    fn string_table(&mut self, path: &str, left: &StringTable, right: &StringTable) {
+        self.value(
+            path,
+            &without(to_value(left), "entries"),
+            &without(to_value(right), "entries"),
+        );
+        self.entries(&format!("{path}.entries"), &left.entries, &right.entries);
+    }
+
+    fn entries(
+        &mut self,
+        path: &str,
+        left: &[(u16, StringTableEntry)],
+        right: &[(u16, StringTableEntry)],
+    ) {
+        self.keyed(
+            path,
+            left,
+            right,
+            |(index, _)| *index,
+            |(index, _)| format!("[{index}]"),
+            |differ, path, (_, left), (_, right)| {
+                differ.value(path, &to_value(left), &to_value(right))
+            },
+        );
+    }
+
+    fn entity(&mut self, path: &str, left: &PacketEntity, right: &PacketEntity) {
+        self.value(
+            path,
+            &without(to_value(left), "props"),
+            &without(to_value(right), "props"),
+        );
+        self.keyed(
+            &format!("{path}.props"),
+            &left.props,
+            &right.props,
+            // props are keyed by index since a send table can contain the same prop multiple times
+            |prop| prop.index,
+            |prop| format!("[{}]({})", prop.index, prop_name(prop.identifier)),
+            |differ, path, left: &SendProp, right: &SendProp| {
+                if left.value != right.value {
+                    differ.push(
+                        path.into(),
+                        DifferenceKind::Changed {
+                            left: left.value.to_string(),
+                            right: right.value.to_string(),
+                        },
+                    );
+                }
+            },
+        );
+    }
+
+    /// Compare two lists by position
+    fn list<T: Serialize>(
+        &mut self,
+        path: &str,
+        left: &[T],
+        right: &[T],
+        compare: impl Fn(&mut Self, &str, &T, &T),
+    ) {
+        for index in 0..left.len().max(right.len()) {
+            let item_path = format!("{path}[{index}]");
+            match (left.get(index), right.get(index)) {
+                (Some(left), Some(right)) => compare(self, &item_path, left, right),
+                (Some(left), None) => self.push(
+                    item_path,
+                    DifferenceKind::Removed(to_value(left).to_string()),
+                ),
+                (None, Some(right)) => self.push(
+                    item_path,
+                    DifferenceKind::Added(to_value(right).to_string()),
+                ),
+                (None, None) => {}
+            }
+        }
+    }
+
+    /// Compare two lists by a key of the items, `name` formats the key of an item for its path
+    fn keyed<T: Serialize, K: Ord>(
+        &mut self,
+        path: &str,
+        left: &[T],
+        right: &[T],
+        key: impl Fn(&T) -> K,
+        name: impl Fn(&T) -> String,
+        compare: impl Fn(&mut Self, &str, &T, &T),
+    ) {
+        let right_items: BTreeMap<K, &T> = right.iter().map(|item| (key(item), item)).collect();
+        let left_items: BTreeMap<K, &T> = left.iter().map(|item| (key(item), item)).collect();
+        for (item_key, left) in &left_items {
+            let item_path = format!("{path}{}", name(left));
+            match right_items.get(item_key) {
+                Some(right) => compare(self, &item_path, left, right),
+                None => self.push(
+                    item_path,
+                    DifferenceKind::Removed(to_value(left).to_string()),
+                ),
+            }
+        }
+        for (item_key, right) in &right_items {
+            if !left_items.contains_key(item_key) {
+                let item_path = format!("{path}{}", name(right));
+                self.push(
+                    item_path,
+                    DifferenceKind::Added(to_value(right).to_string()),
+                );
+            }
+        }
+    }
+
+    /// Compare two serialized values field by field
+    fn value(&mut self, path: &str, left: &Value, right: &Value) {
+        if left == right {
+            return;
+        }
+        match (left, right) {
+            (Value::Object(left), Value::Object(right)) => {
+                for (key, left_value) in left {
+                    let field_path = format!("{path}.{key}");
+                    match right.get(key) {
+                        Some(right_value) => self.value(&field_path, left_value, right_value),
+                        None => {
+                            self.push(field_path, DifferenceKind::Removed(left_value.to_string()))
+                        }
+                    }
+                }
+                for (key, right_value) in right {
+                    if !left.contains_key(key) {
+                        let field_path = format!("{path}.{key}");
+                        self.push(field_path, DifferenceKind::Added(right_value.to_string()));
+                    }
+                }
+            }
+            // lists of plain values (like raw data) are reported as a whole
+            (Value::Array(left_items), Value::Array(right_items))
+                if !left_items.iter().chain(right_items).all(is_scalar) =>
+            {
+                self.list(
+                    path,
+                    left_items,
+                    right_items,
+                    |differ, path, left, right| differ.value(path, left, right),
+                );
+            }
+            _ => self.push(
+                path.into(),
+                DifferenceKind::Changed {
+                    left: left.to_string(),
+                    right: right.to_string(),
+                },
+            ),
+        }
+    }
+}
+
+fn packet_path(index: usize, packet: &Packet) -> String {
+    format!("packet[{index}]({:?})", packet.packet_type())
+}
+
+fn prop_name(identifier: SendPropIdentifier) -> String {
+    match identifier.names() {
+        Some((table, prop)) => format!("{table}.{prop}"),
+        None => u64::from(identifier).to_string(),
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or_else(|e| Value::String(e.to_string()))
+}
+
+fn without(mut value: Value, key: &str) -> Value {
+    if let Value::Object(fields) = &mut value {
+        fields.remove(key);
+    }
+    value
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Array(_) | Value::Object(_))
+}
+
+#[test]
+fn test_diff_duplicate_props() {
+    let prop = |index: u32, name: &str, value: i64| SendProp {
+        index,
+        identifier: SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", name),
+        value: crate::demo::sendprop::SendPropValue::Integer(value),
+    };
+    let entity = |props| PacketEntity {
+        server_class: 1u16.into(),
+        entity_index: 1u32.into(),
+        props,
+        in_pvs: true,
+        update_type: crate::demo::message::packetentities::UpdateType::Delta,
+        serial_number: 0,
+        delay: None,
+        delta: None,
+        baseline_index: Default::default(),
+    };
+    let left = entity(vec![prop(3, "m_iKills", 5), prop(23, "m_iKills", 1)]);
+    let right = entity(vec![prop(3, "m_iKills", 5), prop(23, "m_iKills", 2)]);
+
+    let options = DiffOptions::default();
+    let mut differ = Differ {
+        options: &options,
+        tick: DemoTick::default(),
+        differences: Vec::new(),
+    };
+    differ.entity("entity", &left, &right);
+    assert_eq!(
+        vec![Difference {
+            tick: DemoTick::default(),
+            path: "entity.props[23](DT_TFPlayerScoringDataExclusive.m_iKills)".into(),
+            kind: DifferenceKind::Changed {
+                left: "1".into(),
+                right: "2".into()
+            }
+        }],
+        differ.differences
+    );
+}
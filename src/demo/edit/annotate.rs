@@ -0,0 +1,122 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::message::usermessage::{ChatMessageKind, SayText2Message, UserMessage};
+use crate::demo::message::{Message, PrintMessage};
+use crate::demo::packet::consolecmd::ConsoleCmdPacket;
+use crate::demo::packet::Packet;
+
+/// Control character that starts the text of a chat message in the default color
+const CHAT_COLOR_NORMAL: char = '\x01';
+
+/// A marker or note to add to a demo
+#[derive(Debug, Clone, PartialEq)]
+pub enum Annotation {
+    /// A console command that is executed when the demo is played back
+    Command(String),
+    /// A message printed to the console
+    Print(String),
+    /// A message shown in the chat
+    Chat(String),
+}
+
+impl Annotation {
+    /// A command that prints a marker to the console when the demo is played back
+    pub fn echo(text: &str) -> Self {
+        Annotation::Command(format!("echo \"{}\"", text.replace('"', "'")))
+    }
+
+    fn into_message(self) -> Option<Message<'static>> {
+        match self {
+            Annotation::Command(_) => None,
+            Annotation::Print(text) => Some(Message::Print(PrintMessage {
+                value: format!("{text}\n").as_str().into(),
+            })),
+            Annotation::Chat(text) => Some(Message::UserMessage(UserMessage::SayText2(Box::new(
+                SayText2Message {
+                    client: EntityId::default(),
+                    raw: 1,
+                    kind: ChatMessageKind::ChatAll,
+                    from: None,
+                    text: format!("{CHAT_COLOR_NORMAL}{text}").as_str().into(),
+                },
+            )))),
+        }
+    }
+}
+
+/// Insert annotations into a demo at chosen ticks
+///
+/// Commands are added as console command packets before the first message packet at or after the
+/// tick of the annotation, prints and chat messages are added to that message packet.
+/// Annotations after the last message packet of the demo are not added.
+///
+/// The packets have to be passed to the annotator in order, before they are encoded.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::edit::{Annotation, Annotator, DemoRewriter};
+/// let file = std::fs::read("demo.dem").unwrap();
+/// let demo = Demo::new(&file);
+/// let mut annotator = Annotator::new();
+/// annotator.add(1500u32.into(), Annotation::echo("uber drop"));
+/// annotator.add(1500u32.into(), Annotation::Chat("uber drop".into()));
+/// let output = DemoRewriter::new()
+///     .with_annotator(annotator)
+///     .rewrite(&demo)
+///     .unwrap();
+/// std::fs::write("annotated.dem", output).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Annotator {
+    // sorted by tick, annotations for the same tick are kept in the order they were added
+    annotations: Vec<(DemoTick, Annotation)>,
+    next: usize,
+    last_tick: DemoTick,
+}
+
+impl Annotator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, tick: DemoTick, annotation: Annotation) {
+        let index = self
+            .annotations
+            .partition_point(|(existing, _)| *existing <= tick);
+        self.annotations.insert(index, (tick, annotation));
+    }
+
+    /// Add the annotations that are due to a packet
+    ///
+    /// Returns the packet together with any console command packets that have to be added before it
+    pub fn annotate_packet<'p>(&mut self, mut packet: Packet<'p>) -> Vec<Packet<'p>> {
+        let mut packets = Vec::new();
+        if let Packet::Message(message_packet) = &mut packet {
+            while let Some((tick, annotation)) = self.annotations.get(self.next) {
+                if *tick > message_packet.tick {
+                    break;
+                }
+                match annotation {
+                    Annotation::Command(command) => {
+                        packets.push(Packet::ConsoleCmd(ConsoleCmdPacket {
+                            // keep the ticks increasing when the annotation is between packets
+                            tick: (*tick).max(self.last_tick),
+                            command: command.clone(),
+                        }))
+                    }
+                    annotation => message_packet
+                        .messages
+                        .extend(annotation.clone().into_message()),
+                }
+                self.next += 1;
+            }
+        }
+        self.last_tick = match packet {
+            // signon packets can have a higher tick than the packets after the sync tick
+            Packet::SyncTick(_) => packet.tick(),
+            _ => self.last_tick.max(packet.tick()),
+        };
+        packets.push(packet);
+        packets
+    }
+}
@@ -0,0 +1,349 @@
+use crate::demo::data::{MaybeUtf8String, UserInfo};
+use crate::demo::gamevent::GameEvent;
+use crate::demo::header::Header;
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::message::usermessage::{ChatMessageKind, UserMessage};
+use crate::demo::message::Message;
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::packet::Packet;
+use crate::demo::parser::analyser::UserId;
+use crate::Result;
+use std::collections::HashMap;
+
+/// Options for [`Anonymizer`]
+#[derive(Debug, Clone, Default)]
+pub struct AnonymizeOptions {
+    /// Drop all chat messages
+    ///
+    /// Chat messages that are kept are not modified, so they can still contain player names
+    pub drop_chat: bool,
+    /// Drop all voice data
+    pub drop_voice: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Identity {
+    name: String,
+    steam_id: String,
+}
+
+/// Replace the names and steam ids of players with pseudonyms
+///
+/// Every player gets a pseudonym like `Player 3` when they are first seen, the same player keeps the
+/// same pseudonym for the entire demo. The steam ids of bots are kept.
+///
+/// The pseudonym steam ids, like `[I:0:3]`, use the invalid account type and universe so they can't
+/// belong to a real account.
+///
+/// The packets have to be passed to the anonymizer in order, before they are encoded.
+#[derive(Debug, Default, Clone)]
+pub struct Anonymizer {
+    options: AnonymizeOptions,
+    identities: Vec<Identity>,
+    by_steam_id: HashMap<String, usize>,
+    by_name: HashMap<String, usize>,
+    by_user_id: HashMap<UserId, usize>,
+    by_entity: HashMap<EntityId, usize>,
+    // indexed by table id
+    string_table_names: Vec<String>,
+}
+
+impl Anonymizer {
+    pub fn new(options: AnonymizeOptions) -> Self {
+        Anonymizer {
+            options,
+            ..Anonymizer::default()
+        }
+    }
+
+    /// Clear the name of the player that recorded the demo and the server address
+    pub fn anonymize_header(&self, header: &mut Header) {
+        header.nick.clear();
+        header.server.clear();
+    }
+
+    pub fn anonymize_packet(&mut self, packet: &mut Packet) -> Result<()> {
+        match packet {
+            Packet::StringTables(packet) => {
+                for table in packet.tables.iter_mut() {
+                    if table.name == "userinfo" {
+                        self.anonymize_user_info(&mut table.entries)?;
+                    }
+                }
+            }
+            Packet::Signon(packet) | Packet::Message(packet) => {
+                packet.messages.retain(|message| !self.drops(message));
+                for message in packet.messages.iter_mut() {
+                    self.anonymize_message(message)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn drops(&self, message: &Message) -> bool {
+        match message {
+            Message::VoiceData(_) => self.options.drop_voice,
+            Message::UserMessage(UserMessage::SayText2(message)) => {
+                self.options.drop_chat
+                    && !matches!(
+                        message.kind,
+                        ChatMessageKind::NameChange | ChatMessageKind::Empty
+                    )
+            }
+            Message::GameEvent(message) => {
+                self.options.drop_chat
+                    && matches!(
+                        message.event,
+                        GameEvent::PlayerSay(_) | GameEvent::PlayerChat(_)
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    fn anonymize_message(&mut self, message: &mut Message) -> Result<()> {
+        match message {
+            Message::CreateStringTable(message) => {
+                self.string_table_names.push(message.table.name.to_string());
+                if message.table.name == "userinfo" {
+                    self.anonymize_user_info(&mut message.table.entries)?;
+                }
+            }
+            Message::UpdateStringTable(message)
+                if self
+                    .string_table_names
+                    .get(message.table_id as usize)
+                    .is_some_and(|name| name == "userinfo") =>
+            {
+                self.anonymize_user_info(&mut message.entries)?;
+            }
+            Message::UserMessage(UserMessage::SayText2(message)) => {
+                let identity = match self.by_entity.get(&message.client) {
+                    Some(identity) => *identity,
+                    None => {
+                        self.identity("", message.from.as_ref().map_or("", |from| from.as_ref()))
+                    }
+                };
+                let name = self.name(identity);
+                if message.from.is_some() {
+                    message.from = Some(name.clone());
+                }
+                if message.kind == ChatMessageKind::NameChange {
+                    message.text = name;
+                }
+            }
+            Message::GameEvent(message) => self.anonymize_event(&mut message.event),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn anonymize_event(&mut self, event: &mut GameEvent) {
+        match event {
+            GameEvent::PlayerConnect(event) => {
+                let identity = self.player_identity(event.user_id, &event.network_id, &event.name);
+                (event.name, event.network_id) = self.name_and_steam_id(identity);
+                event.address = MaybeUtf8String::default();
+            }
+            GameEvent::PlayerConnectClient(event) => {
+                let identity = self.player_identity(event.user_id, &event.network_id, &event.name);
+                (event.name, event.network_id) = self.name_and_steam_id(identity);
+            }
+            GameEvent::PlayerInfo(event) => {
+                let identity = self.player_identity(event.user_id, &event.network_id, &event.name);
+                (event.name, event.network_id) = self.name_and_steam_id(identity);
+            }
+            GameEvent::PlayerDisconnect(event) => {
+                let identity = self.player_identity(event.user_id, &event.network_id, &event.name);
+                (event.name, event.network_id) = self.name_and_steam_id(identity);
+            }
+            GameEvent::PlayerTeam(event) => {
+                let identity = self.user_identity(event.user_id, &event.name);
+                event.name = self.name(identity);
+            }
+            GameEvent::PlayerChangeName(event) => {
+                let identity = self.user_identity(event.user_id, &event.old_name);
+                event.old_name = self.name(identity);
+                event.new_name = self.name(identity);
+            }
+            GameEvent::PlayerDeath(event) if !event.assister_fallback.as_ref().is_empty() => {
+                let identity = self.identity("", event.assister_fallback.as_ref());
+                event.assister_fallback = self.name(identity);
+            }
+            _ => {}
+        }
+    }
+
+    fn anonymize_user_info(&mut self, entries: &mut [(u16, StringTableEntry)]) -> Result<()> {
+        for (index, entry) in entries.iter_mut() {
+            let Some(mut user_info) = UserInfo::parse_from_string_table(
+                *index,
+                entry.text.as_deref(),
+                entry.extra_data.as_ref().map(|extra| extra.data.clone()),
+            )?
+            else {
+                continue;
+            };
+            let info = &mut user_info.player_info;
+            let identity = self.identity(&info.steam_id, &info.name);
+            self.by_user_id.insert(info.user_id, identity);
+            self.by_entity.insert(user_info.entity_id, identity);
+            if let Some(Identity { name, steam_id }) = self.identities.get(identity) {
+                info.name.clone_from(name);
+                info.steam_id.clone_from(steam_id);
+            }
+            // the friends id is the account id of the real steam id, pseudonyms don't have one
+            info.friends_id = 0;
+            info.friends_name_bytes = [0; 32];
+            info.custom_file = [0; 4];
+            entry.extra_data = user_info.encode_to_string_table()?.extra_data;
+        }
+        Ok(())
+    }
+
+    fn player_identity(
+        &mut self,
+        user_id: u16,
+        steam_id: &MaybeUtf8String,
+        name: &MaybeUtf8String,
+    ) -> usize {
+        let identity = self.identity(steam_id.as_ref(), name.as_ref());
+        self.by_user_id.insert(user_id.into(), identity);
+        identity
+    }
+
+    fn user_identity(&mut self, user_id: u16, name: &MaybeUtf8String) -> usize {
+        match self.by_user_id.get(&UserId::from(user_id)) {
+            Some(identity) => *identity,
+            None => self.identity("", name.as_ref()),
+        }
+    }
+
+    /// Find or create the pseudonym for a player
+    ///
+    /// Players are matched by steam id, the name is only used for players without a steam id
+    fn identity(&mut self, steam_id: &str, name: &str) -> usize {
+        let has_steam_id = !steam_id.is_empty() && steam_id != "BOT";
+        let existing = if has_steam_id {
+            self.by_steam_id.get(steam_id)
+        } else {
+            self.by_name.get(name)
+        }
+        .copied();
+        let identity = existing.unwrap_or_else(|| {
+            let number = self.identities.len() + 1;
+            self.identities.push(Identity {
+                name: format!("Player {number}"),
+                steam_id: if has_steam_id {
+                    format!("[I:0:{number}]")
+                } else {
+                    steam_id.into()
+                },
+            });
+            number - 1
+        });
+        if has_steam_id {
+            self.by_steam_id.insert(steam_id.into(), identity);
+        }
+        if !name.is_empty() {
+            self.by_name.insert(name.into(), identity);
+        }
+        identity
+    }
+
+    fn name(&self, identity: usize) -> MaybeUtf8String {
+        self.identities
+            .get(identity)
+            .map(|identity| identity.name.as_str().into())
+            .unwrap_or_default()
+    }
+
+    fn name_and_steam_id(&self, identity: usize) -> (MaybeUtf8String, MaybeUtf8String) {
+        self.identities
+            .get(identity)
+            .map(|identity| {
+                (
+                    identity.name.as_str().into(),
+                    identity.steam_id.as_str().into(),
+                )
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[test]
+fn test_anonymize_events() {
+    use crate::demo::gameevent_gen::{PlayerConnectClientEvent, PlayerTeamEvent};
+    use crate::demo::message::usermessage::SayText2Message;
+
+    let mut anonymizer = Anonymizer::new(AnonymizeOptions {
+        drop_chat: true,
+        drop_voice: false,
+    });
+
+    let mut connect = GameEvent::PlayerConnectClient(PlayerConnectClientEvent {
+        name: "player".into(),
+        index: 0,
+        user_id: 3,
+        network_id: "[U:1:1234]".into(),
+        bot: 0,
+    });
+    anonymizer.anonymize_event(&mut connect);
+    let GameEvent::PlayerConnectClient(connect) = connect else {
+        unreachable!()
+    };
+    assert_eq!("Player 1", connect.name.as_ref());
+    assert_eq!("[I:0:1]", connect.network_id.as_ref());
+
+    let mut team = GameEvent::PlayerTeam(PlayerTeamEvent {
+        user_id: 3,
+        team: 2,
+        old_team: 0,
+        disconnect: false,
+        auto_team: false,
+        silent: false,
+        name: "player".into(),
+    });
+    anonymizer.anonymize_event(&mut team);
+    let GameEvent::PlayerTeam(team) = team else {
+        unreachable!()
+    };
+    assert_eq!("Player 1", team.name.as_ref());
+
+    let mut name_change = Message::UserMessage(UserMessage::SayText2(Box::new(SayText2Message {
+        client: 2u32.into(),
+        raw: 1,
+        kind: ChatMessageKind::NameChange,
+        from: Some("player".into()),
+        text: "new name".into(),
+    })));
+    assert!(!anonymizer.drops(&name_change));
+    anonymizer.anonymize_message(&mut name_change).unwrap();
+    let Message::UserMessage(UserMessage::SayText2(name_change)) = &name_change else {
+        unreachable!()
+    };
+    assert_eq!(
+        Some("Player 1"),
+        name_change.from.as_ref().map(AsRef::as_ref)
+    );
+    assert_eq!("Player 1", name_change.text.as_ref());
+
+    let chat = Message::UserMessage(UserMessage::SayText2(Box::new(SayText2Message {
+        kind: ChatMessageKind::ChatAll,
+        ..(**name_change).clone()
+    })));
+    assert!(anonymizer.drops(&chat));
+}
+
+#[test]
+fn test_anonymize_same_name() {
+    let mut anonymizer = Anonymizer::default();
+    let first = anonymizer.identity("[U:1:1234]", "player");
+    let second = anonymizer.identity("[U:1:5678]", "player");
+    assert_ne!(first, second);
+    assert_eq!(first, anonymizer.identity("[U:1:1234]", "renamed"));
+    // without a steam id the player is matched by name
+    assert_eq!(second, anonymizer.identity("", "player"));
+}
@@ -0,0 +1,803 @@
+use crate::demo::data::userinfo::PlayerInfo;
+use crate::demo::data::{DemoTick, UserInfo};
+use crate::demo::gamevent::{GameEvent, GameEventDefinition, GameEventEntry, GameEventType};
+use crate::demo::header::Header;
+use crate::demo::message::gameevent::{GameEventListMessage, GameEventMessage};
+use crate::demo::message::packetentities::{
+    BaselineIndex, EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
+};
+use crate::demo::message::stringtable::CreateStringTableMessage;
+use crate::demo::message::{Message, NetTickMessage, ServerInfoMessage};
+use crate::demo::packet::datatable::{
+    flatten_send_tables, ClassId, DataTablePacket, ParseSendTable, SendTable, SendTableName,
+    ServerClass,
+};
+use crate::demo::packet::message::MessagePacket;
+use crate::demo::packet::stop::StopPacket;
+use crate::demo::packet::stringtable::{StringTable, StringTableCompression, StringTableEntry};
+use crate::demo::packet::synctick::SyncTickPacket;
+use crate::demo::packet::Packet;
+use crate::demo::parser::{DemoHandler, Encode, NullHandler};
+use crate::demo::sendprop::{
+    RawSendPropDefinition, SendProp, SendPropFlag, SendPropFlags, SendPropIdentifier, SendPropName,
+    SendPropType, SendPropValue,
+};
+use crate::{ParseError, Result};
+use bitbuffer::{BitWrite, BitWriteStream, LittleEndian};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+/// A prop of a server class added with [`DemoBuilder::add_server_class`]
+///
+/// The table is the send table that owns the prop, this is the table used in the
+/// [`SendPropIdentifier`] of the prop.
+#[derive(Debug, Clone)]
+pub struct PropDefinition {
+    table: SendTableName,
+    definition: RawSendPropDefinition,
+}
+
+impl PropDefinition {
+    pub fn new(table: impl Into<SendTableName>, definition: RawSendPropDefinition) -> Self {
+        PropDefinition {
+            table: table.into(),
+            definition,
+        }
+    }
+
+    fn simple(
+        table: &'static str,
+        name: &'static str,
+        prop_type: SendPropType,
+        flags: SendPropFlags,
+        bit_count: u32,
+    ) -> Self {
+        PropDefinition::new(
+            table,
+            RawSendPropDefinition {
+                prop_type,
+                name: name.into(),
+                identifier: SendPropIdentifier::new(table, name),
+                flags,
+                table_name: None,
+                low_value: Some(0.0),
+                high_value: Some(0.0),
+                bit_count: Some(bit_count),
+                element_count: None,
+                array_property: None,
+                original_bit_count: Some(bit_count),
+            },
+        )
+    }
+
+    /// A signed integer prop
+    pub fn int(table: &'static str, name: &'static str, bit_count: u32) -> Self {
+        Self::simple(
+            table,
+            name,
+            SendPropType::Int,
+            SendPropFlags::default(),
+            bit_count,
+        )
+    }
+
+    /// An unsigned integer prop
+    pub fn unsigned_int(table: &'static str, name: &'static str, bit_count: u32) -> Self {
+        Self::simple(
+            table,
+            name,
+            SendPropType::Int,
+            SendPropFlags::default() | SendPropFlag::Unsigned,
+            bit_count,
+        )
+    }
+
+    /// A float prop that is sent without loss of precision
+    pub fn float(table: &'static str, name: &'static str) -> Self {
+        Self::simple(
+            table,
+            name,
+            SendPropType::Float,
+            SendPropFlags::default() | SendPropFlag::NoScale,
+            32,
+        )
+    }
+
+    /// A vector prop that is sent without loss of precision
+    pub fn vector(table: &'static str, name: &'static str) -> Self {
+        Self::simple(
+            table,
+            name,
+            SendPropType::Vector,
+            SendPropFlags::default() | SendPropFlag::NoScale,
+            96,
+        )
+    }
+
+    /// A 2d vector prop that is sent without loss of precision
+    pub fn vector_xy(table: &'static str, name: &'static str) -> Self {
+        Self::simple(
+            table,
+            name,
+            SendPropType::VectorXY,
+            SendPropFlags::default() | SendPropFlag::NoScale,
+            64,
+        )
+    }
+
+    pub fn string(table: &'static str, name: &'static str) -> Self {
+        Self::simple(
+            table,
+            name,
+            SendPropType::String,
+            SendPropFlags::default(),
+            0,
+        )
+    }
+
+    /// A prop that includes all props owned by `sub_table`
+    ///
+    /// Sub tables are normally included once per class, referencing the same sub table from
+    /// multiple data table props includes its props multiple times.
+    pub fn data_table(table: &'static str, name: &'static str, sub_table: &'static str) -> Self {
+        PropDefinition::new(
+            table,
+            RawSendPropDefinition {
+                prop_type: SendPropType::DataTable,
+                name: name.into(),
+                identifier: SendPropIdentifier::new(table, name),
+                flags: SendPropFlags::default(),
+                table_name: Some(sub_table.into()),
+                low_value: None,
+                high_value: None,
+                bit_count: None,
+                element_count: None,
+                array_property: None,
+                original_bit_count: None,
+            },
+        )
+    }
+}
+
+struct ClassDefinition {
+    name: String,
+    props: Vec<PropDefinition>,
+}
+
+struct EntityUpdate {
+    entity: EntityId,
+    class: Option<ClassId>,
+    update_type: UpdateType,
+    props: Vec<(SendPropIdentifier, SendPropValue)>,
+}
+
+#[derive(Default)]
+struct TickData {
+    entities: Vec<EntityUpdate>,
+    events: Vec<GameEvent>,
+}
+
+const USER_INFO_TABLE: &str = "userinfo";
+
+/// Create a demo from a scripted set of server classes, players, entity updates and game events
+///
+/// This is intended for testing analysers against specific scenarios without needing a recorded demo.
+/// The send tables for every server class are generated from the props of the class, the prop
+/// values set for entities need to match the type of their definition.
+///
+/// The created demo is an stv demo with all packets at the ticks they were added at.
+///
+/// ```
+/// # use tf_demo_parser::demo::edit::builder::{DemoBuilder, PropDefinition};
+/// # use tf_demo_parser::demo::sendprop::{SendPropIdentifier, SendPropValue};
+/// # use tf_demo_parser::{Demo, DemoParser};
+/// const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+///
+/// let mut builder = DemoBuilder::new("cp_test");
+/// let player = builder.add_server_class(
+///     "CTFPlayer",
+///     [PropDefinition::int("DT_BasePlayer", "m_iHealth", 10)],
+/// );
+/// builder.enter_entity(1u32.into(), 1u32.into(), player, [(HEALTH, SendPropValue::Integer(125))]);
+/// builder.update_entity(2u32.into(), 1u32.into(), [(HEALTH, SendPropValue::Integer(80))]);
+///
+/// let data = builder.build().unwrap();
+/// let (header, _state) = DemoParser::new(Demo::new(&data).get_stream()).parse().unwrap();
+/// assert_eq!(2, header.ticks);
+/// ```
+pub struct DemoBuilder {
+    map: String,
+    interval_per_tick: f32,
+    classes: Vec<ClassDefinition>,
+    string_tables: Vec<StringTable<'static>>,
+    players: Vec<(EntityId, PlayerInfo)>,
+    event_definitions: Vec<GameEventDefinition>,
+    ticks: BTreeMap<u32, TickData>,
+}
+
+impl DemoBuilder {
+    pub fn new(map: &str) -> Self {
+        DemoBuilder {
+            map: map.into(),
+            interval_per_tick: 0.015,
+            classes: Vec::new(),
+            string_tables: Vec::new(),
+            players: Vec::new(),
+            event_definitions: Vec::new(),
+            ticks: BTreeMap::new(),
+        }
+    }
+
+    pub fn set_interval_per_tick(&mut self, interval_per_tick: f32) {
+        self.interval_per_tick = interval_per_tick;
+    }
+
+    /// Add a server class with the provided props
+    ///
+    /// The data table of the class is named `DT_` followed by the class name, props owned by
+    /// other tables are placed in sub tables that are shared between classes.
+    pub fn add_server_class(
+        &mut self,
+        name: &str,
+        props: impl IntoIterator<Item = PropDefinition>,
+    ) -> ClassId {
+        let id = ClassId::from(self.classes.len() as u16);
+        self.classes.push(ClassDefinition {
+            name: name.into(),
+            props: props.into_iter().collect(),
+        });
+        id
+    }
+
+    /// Add a string table that is created during the signon of the demo
+    pub fn add_string_table(&mut self, name: &str, max_entries: u16) {
+        if self.string_table(name).is_none() {
+            self.string_tables.push(StringTable {
+                name: Cow::Owned(name.into()),
+                entries: Vec::new(),
+                max_entries,
+                fixed_user_data_size: None,
+                client_entries: None,
+                compressed: false,
+                compression: StringTableCompression::default(),
+            });
+        }
+    }
+
+    /// Add an entry to a string table, the table is created if it hasn't been added yet
+    pub fn add_string_table_entry(&mut self, table: &str, entry: StringTableEntry<'static>) {
+        self.add_string_table(table, 1024);
+        if let Some(table) = self.string_table(table) {
+            let index = table.entries.len() as u16;
+            table.entries.push((index, entry));
+        }
+    }
+
+    fn string_table(&mut self, name: &str) -> Option<&mut StringTable<'static>> {
+        self.string_tables
+            .iter_mut()
+            .find(|table| table.name == name)
+    }
+
+    /// Add a player to the `userinfo` table, the entity id of the player is the player slot plus one
+    pub fn add_player(&mut self, entity: EntityId, info: PlayerInfo) {
+        self.add_string_table(USER_INFO_TABLE, 256);
+        self.players.push((entity, info));
+    }
+
+    /// Add the definition for a type of game event, events can only be added for types with a definition
+    pub fn add_event_definition(
+        &mut self,
+        event_type: GameEventType,
+        entries: Vec<GameEventEntry>,
+    ) {
+        self.event_definitions.push(GameEventDefinition {
+            id: (self.event_definitions.len() as u16).into(),
+            event_type,
+            entries,
+        });
+    }
+
+    pub fn add_game_event(&mut self, tick: DemoTick, event: GameEvent) {
+        self.tick(tick).events.push(event);
+    }
+
+    /// Create an entity, or re-send it when it enters the pvs again
+    ///
+    /// When a prop occurs multiple times in the class, repeating its identifier sets the
+    /// occurrences in the order they are flattened.
+    pub fn enter_entity(
+        &mut self,
+        tick: DemoTick,
+        entity: EntityId,
+        class: ClassId,
+        props: impl IntoIterator<Item = (SendPropIdentifier, SendPropValue)>,
+    ) {
+        self.add_entity_update(tick, entity, Some(class), UpdateType::Enter, props);
+    }
+
+    pub fn update_entity(
+        &mut self,
+        tick: DemoTick,
+        entity: EntityId,
+        props: impl IntoIterator<Item = (SendPropIdentifier, SendPropValue)>,
+    ) {
+        self.add_entity_update(tick, entity, None, UpdateType::Delta, props);
+    }
+
+    /// Mark an entity as having left the pvs
+    pub fn leave_entity(&mut self, tick: DemoTick, entity: EntityId) {
+        self.add_entity_update(tick, entity, None, UpdateType::Leave, []);
+    }
+
+    pub fn delete_entity(&mut self, tick: DemoTick, entity: EntityId) {
+        self.add_entity_update(tick, entity, None, UpdateType::Delete, []);
+    }
+
+    fn add_entity_update(
+        &mut self,
+        tick: DemoTick,
+        entity: EntityId,
+        class: Option<ClassId>,
+        update_type: UpdateType,
+        props: impl IntoIterator<Item = (SendPropIdentifier, SendPropValue)>,
+    ) {
+        self.tick(tick).entities.push(EntityUpdate {
+            entity,
+            class,
+            update_type,
+            props: props.into_iter().collect(),
+        });
+    }
+
+    fn tick(&mut self, tick: DemoTick) -> &mut TickData {
+        self.ticks.entry(tick.into()).or_default()
+    }
+
+    /// Encode the demo
+    pub fn build(&self) -> Result<Vec<u8>> {
+        let (tables, server_classes) = self.data_tables();
+        let send_tables = flatten_send_tables(&tables, &server_classes)?;
+        let mut packets = self.signon_packets(tables, server_classes)?;
+        let signon_count = packets.len();
+
+        let mut entity_classes = HashMap::new();
+        let mut last_tick = 0;
+        for (tick, data) in self.ticks.iter() {
+            let mut messages = vec![Message::NetTick(NetTickMessage {
+                tick: (*tick).into(),
+                frame_time: 0,
+                std_dev: 0,
+            })];
+            if !data.entities.is_empty() {
+                messages.push(Message::PacketEntities(entities_message(
+                    &data.entities,
+                    &send_tables,
+                    &mut entity_classes,
+                    (last_tick > 0).then_some(last_tick),
+                )?));
+            }
+            for event in data.events.iter() {
+                messages.push(Message::GameEvent(self.event_message(event)?));
+            }
+            packets.push(Packet::Message(MessagePacket {
+                tick: (*tick).into(),
+                messages,
+                meta: Default::default(),
+            }));
+            last_tick = *tick;
+        }
+        packets.push(Packet::Stop(StopPacket {
+            tick: last_tick.into(),
+        }));
+
+        let mut header = Header {
+            demo_type: "HL2DEMO".into(),
+            version: 3,
+            protocol: 24,
+            server: String::new(),
+            nick: String::new(),
+            map: self.map.clone(),
+            game: "tf".into(),
+            duration: last_tick as f32 * self.interval_per_tick,
+            ticks: last_tick,
+            frames: self.ticks.len() as u32,
+            signon: 0,
+        };
+
+        let mut body = Vec::new();
+        {
+            let mut out = BitWriteStream::new(&mut body, LittleEndian);
+            let mut handler: DemoHandler<NullHandler> = DemoHandler::new();
+            handler.handle_header(&header);
+            for (index, packet) in packets.into_iter().enumerate() {
+                if index == signon_count {
+                    header.signon = out.byte_len() as u32;
+                }
+                packet.encode(&mut out, &handler.state_handler)?;
+                handler.handle_packet(packet)?;
+            }
+        }
+
+        let mut out = Vec::with_capacity(body.len() + 1072);
+        {
+            let mut stream = BitWriteStream::new(&mut out, LittleEndian);
+            header.write(&mut stream)?;
+        }
+        out.extend_from_slice(&body);
+        Ok(out)
+    }
+
+    fn data_tables(&self) -> (Vec<ParseSendTable>, Vec<ServerClass>) {
+        let mut tables: Vec<ParseSendTable> = Vec::new();
+        let mut server_classes = Vec::with_capacity(self.classes.len());
+
+        for (id, class) in self.classes.iter().enumerate() {
+            let table_name = format!("DT_{}", class.name);
+            let mut class_table = ParseSendTable {
+                name: table_name.clone().into(),
+                props: Vec::new(),
+                needs_decoder: false,
+            };
+
+            for prop in class.props.iter() {
+                if prop.table == table_name.as_str() {
+                    class_table.props.push(prop.definition.clone());
+                    continue;
+                }
+
+                let has_sub_table = class_table
+                    .props
+                    .iter()
+                    .any(|sub_table| sub_table.table_name.as_ref() == Some(&prop.table));
+                if !has_sub_table {
+                    class_table.props.push(RawSendPropDefinition {
+                        prop_type: SendPropType::DataTable,
+                        name: SendPropName::from(prop.table.to_string()),
+                        identifier: SendPropIdentifier::new(&table_name, &prop.table),
+                        flags: SendPropFlags::default(),
+                        table_name: Some(prop.table.clone()),
+                        low_value: None,
+                        high_value: None,
+                        bit_count: None,
+                        element_count: None,
+                        array_property: None,
+                        original_bit_count: None,
+                    });
+                }
+
+                // sub tables are shared between classes
+                let index = match tables.iter().position(|table| table.name == prop.table) {
+                    Some(index) => index,
+                    None => {
+                        tables.push(ParseSendTable {
+                            name: prop.table.clone(),
+                            props: Vec::new(),
+                            needs_decoder: false,
+                        });
+                        tables.len() - 1
+                    }
+                };
+                if let Some(sub_table) = tables.get_mut(index) {
+                    if !sub_table.props.contains(&prop.definition) {
+                        sub_table.props.push(prop.definition.clone());
+                    }
+                }
+            }
+
+            tables.push(class_table);
+            server_classes.push(ServerClass {
+                id: ClassId::from(id as u16),
+                name: class.name.as_str().into(),
+                data_table: table_name.into(),
+            });
+        }
+
+        (tables, server_classes)
+    }
+
+    fn signon_packets(
+        &self,
+        tables: Vec<ParseSendTable>,
+        server_classes: Vec<ServerClass>,
+    ) -> Result<Vec<Packet<'static>>> {
+        let server_info = ServerInfoMessage {
+            version: 24,
+            server_count: 1,
+            stv: true,
+            dedicated: true,
+            max_crc: 0,
+            max_classes: server_classes.len() as u16,
+            map_hash: [0; 16],
+            player_slot: 0,
+            max_player_count: 24,
+            interval_per_tick: self.interval_per_tick,
+            platform: "l".into(),
+            game: "tf".into(),
+            map: self.map.clone(),
+            skybox: String::new(),
+            server_name: String::new(),
+            replay: false,
+        };
+
+        let mut string_tables = self.string_tables.clone();
+        for (entity, info) in self.players.iter() {
+            let user_info = UserInfo {
+                entity_id: *entity,
+                player_info: info.clone(),
+            };
+            let slot = u32::from(*entity).saturating_sub(1) as u16;
+            let entry = StringTableEntry {
+                text: Some(Cow::Owned(slot.to_string())),
+                extra_data: user_info.encode_to_string_table()?.extra_data,
+            };
+            if let Some(table) = string_tables
+                .iter_mut()
+                .find(|table| table.name == USER_INFO_TABLE)
+            {
+                table.entries.push((slot, entry));
+            }
+        }
+
+        let mut messages: Vec<Message> = string_tables
+            .into_iter()
+            .map(|mut table| {
+                table.entries.sort_by_key(|(index, _)| *index);
+                Message::CreateStringTable(CreateStringTableMessage { table })
+            })
+            .collect();
+        messages.push(Message::GameEventList(GameEventListMessage {
+            event_list: self.event_definitions.clone(),
+        }));
+
+        Ok(vec![
+            Packet::Signon(MessagePacket {
+                tick: DemoTick::default(),
+                messages: vec![Message::ServerInfo(Box::new(server_info))],
+                meta: Default::default(),
+            }),
+            Packet::DataTables(DataTablePacket {
+                tick: DemoTick::default(),
+                tables,
+                server_classes,
+            }),
+            Packet::Signon(MessagePacket {
+                tick: DemoTick::default(),
+                messages,
+                meta: Default::default(),
+            }),
+            Packet::SyncTick(SyncTickPacket {
+                tick: DemoTick::default(),
+            }),
+        ])
+    }
+
+    fn event_message(&self, event: &GameEvent) -> Result<GameEventMessage> {
+        let event_type = event.event_type();
+        let definition = self
+            .event_definitions
+            .iter()
+            .find(|definition| definition.event_type == event_type)
+            .ok_or(ParseError::InvalidDemo(
+                "No definition added for the type of game event",
+            ))?;
+        Ok(GameEventMessage {
+            event_type_id: definition.id,
+            event_type,
+            event: event.clone(),
+        })
+    }
+}
+
+fn entities_message(
+    updates: &[EntityUpdate],
+    send_tables: &[SendTable],
+    entity_classes: &mut HashMap<EntityId, ClassId>,
+    delta: Option<u32>,
+) -> Result<PacketEntitiesMessage> {
+    // entities have to be sent in order and only once per message
+    let mut entities: BTreeMap<EntityId, PacketEntity> = BTreeMap::new();
+    for update in updates {
+        let class = match update.class {
+            Some(class) => class,
+            None => *entity_classes
+                .get(&update.entity)
+                .ok_or(ParseError::UnknownEntity(update.entity))?,
+        };
+        entity_classes.insert(update.entity, class);
+
+        let send_table = send_tables
+            .get(usize::from(class))
+            .ok_or(ParseError::UnknownServerClass(class))?;
+        let mut props: Vec<SendProp> = Vec::with_capacity(update.props.len());
+        for (identifier, value) in update.props.iter() {
+            let occurrence = props
+                .iter()
+                .filter(|prop| prop.identifier == *identifier)
+                .count();
+            let index = send_table
+                .flattened_props()
+                .iter()
+                .enumerate()
+                .filter(|(_, prop)| prop.identifier == *identifier)
+                .nth(occurrence)
+                .map(|(index, _)| index as u32)
+                .ok_or(ParseError::UnknownDefinition(*identifier))?;
+            props.push(SendProp {
+                index,
+                identifier: *identifier,
+                value: value.clone(),
+            });
+        }
+
+        let entity = entities
+            .entry(update.entity)
+            .or_insert_with(|| PacketEntity {
+                server_class: class,
+                entity_index: update.entity,
+                props: Vec::new(),
+                in_pvs: true,
+                update_type: update.update_type,
+                serial_number: 0,
+                delay: None,
+                delta: None,
+                baseline_index: BaselineIndex::First,
+            });
+        if update.update_type != UpdateType::Delta {
+            entity.update_type = update.update_type;
+        }
+        entity.server_class = class;
+        entity.in_pvs = matches!(entity.update_type, UpdateType::Enter | UpdateType::Delta);
+        entity.apply_update(&props);
+
+        if update.update_type == UpdateType::Delete {
+            entity_classes.remove(&update.entity);
+        }
+    }
+
+    Ok(PacketEntitiesMessage {
+        entities: entities.into_values().collect(),
+        removed_entities: Vec::new(),
+        max_entries: 2047,
+        delta: delta.map(Into::into),
+        base_line: BaselineIndex::First,
+        updated_base_line: false,
+    })
+}
+
+#[test]
+fn test_build_game_state() {
+    use crate::demo::gameevent_gen::PlayerDeathEvent;
+    use crate::demo::gamevent::GameEventValueType;
+    use crate::demo::parser::gamestateanalyser::GameStateAnalyser;
+    use crate::demo::vector::{Vector, VectorXY};
+    use crate::{Demo, DemoParser};
+
+    const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+    const ORIGIN: SendPropIdentifier =
+        SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin");
+    const ORIGIN_Z: SendPropIdentifier =
+        SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin[2]");
+
+    let mut builder = DemoBuilder::new("cp_test");
+    let player_class = builder.add_server_class(
+        "CTFPlayer",
+        [
+            PropDefinition::int("DT_BasePlayer", "m_iHealth", 10),
+            PropDefinition::vector_xy("DT_TFNonLocalPlayerExclusive", "m_vecOrigin"),
+            PropDefinition::float("DT_TFNonLocalPlayerExclusive", "m_vecOrigin[2]"),
+        ],
+    );
+    for (entity, name, steam_id) in [(1u32, "first", "[U:1:1]"), (2, "second", "[U:1:2]")] {
+        builder.add_player(
+            EntityId::from(entity),
+            PlayerInfo {
+                name: name.into(),
+                user_id: (entity as u16 + 10).into(),
+                steam_id: steam_id.into(),
+                ..PlayerInfo::default()
+            },
+        );
+    }
+    builder.add_event_definition(
+        GameEventType::PlayerDeath,
+        vec![
+            GameEventEntry::new("userid", GameEventValueType::Short),
+            GameEventEntry::new("attacker", GameEventValueType::Short),
+            GameEventEntry::new("weapon", GameEventValueType::String),
+        ],
+    );
+
+    for entity in [1u32, 2] {
+        builder.enter_entity(
+            1u32.into(),
+            entity.into(),
+            player_class,
+            [
+                (HEALTH, SendPropValue::Integer(150)),
+                (
+                    ORIGIN,
+                    SendPropValue::VectorXY(VectorXY {
+                        x: entity as f32 * 100.0,
+                        y: 50.0,
+                    }),
+                ),
+                (ORIGIN_Z, SendPropValue::Float(10.0)),
+            ],
+        );
+    }
+    builder.update_entity(
+        5u32.into(),
+        2u32.into(),
+        [(HEALTH, SendPropValue::Integer(0))],
+    );
+    builder.add_game_event(
+        5u32.into(),
+        GameEvent::PlayerDeath(Box::new(PlayerDeathEvent {
+            user_id: 12,
+            victim_ent_index: 0,
+            inflictor_ent_index: 0,
+            attacker: 11,
+            weapon: "tf_projectile_rocket".into(),
+            weapon_id: 0,
+            damage_bits: 0,
+            custom_kill: 0,
+            assister: 0,
+            weapon_log_class_name: Default::default(),
+            stun_flags: 0,
+            death_flags: 0,
+            silent_kill: false,
+            player_penetrate_count: 0,
+            assister_fallback: Default::default(),
+            kill_streak_total: 0,
+            kill_streak_wep: 0,
+            kill_streak_assist: 0,
+            kill_streak_victim: 0,
+            ducks_streaked: 0,
+            duck_streak_total: 0,
+            duck_streak_assist: 0,
+            duck_streak_victim: 0,
+            rocket_jump: false,
+            weapon_def_index: 0,
+            crit_type: 0,
+        })),
+    );
+    builder.update_entity(
+        8u32.into(),
+        1u32.into(),
+        [(HEALTH, SendPropValue::Integer(120))],
+    );
+
+    let data = builder.build().unwrap();
+    let (header, state) =
+        DemoParser::new_with_analyser(Demo::new(&data).get_stream(), GameStateAnalyser::new())
+            .parse()
+            .unwrap();
+
+    assert_eq!(8, header.ticks);
+    assert_eq!(3, header.frames);
+    assert_eq!(0.015, state.interval_per_tick);
+    assert_eq!(2, state.players.len());
+
+    let first = &state.players[0];
+    assert_eq!(120, first.health);
+    assert_eq!(
+        Vector {
+            x: 100.0,
+            y: 50.0,
+            z: 10.0
+        },
+        first.position
+    );
+    assert_eq!("first", first.info.as_ref().unwrap().name);
+
+    let second = &state.players[1];
+    assert_eq!(0, second.health);
+    assert_eq!("[U:1:2]", second.info.as_ref().unwrap().steam_id);
+
+    assert_eq!(1, state.kills.len());
+    assert_eq!(11, state.kills[0].attacker_id);
+    assert_eq!(12, state.kills[0].victim_id);
+    assert_eq!("tf_projectile_rocket", state.kills[0].weapon);
+    assert_eq!(DemoTick::from(5u32), state.kills[0].tick);
+}
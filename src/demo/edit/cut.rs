@@ -0,0 +1,281 @@
+use crate::demo::data::DemoTick;
+use crate::demo::header::Header;
+use crate::demo::message::packetentities::{
+    BaselineIndex, PacketEntitiesMessage, PacketEntity, UpdateType,
+};
+use crate::demo::message::{Message, NetTickMessage};
+use crate::demo::packet::message::{MessagePacket, MessagePacketMeta};
+use crate::demo::packet::stop::StopPacket;
+use crate::demo::packet::Packet;
+use crate::demo::parser::entitytracker::EntityTracker;
+use crate::demo::parser::{DemoHandler, Encode, NullHandler, RawPacketStream};
+use crate::{Demo, ParseError, ParserState, Result};
+use bitbuffer::{BitRead, BitWrite, BitWriteStream, LittleEndian};
+
+/// Extract the ticks from `start` up to and including `end` into a standalone demo
+///
+/// The signon of the original demo is kept as is, the state changes from before `start` are replayed
+/// at the start of the new demo: string table updates and other messages that change the state of the
+/// client are kept, and all entities are sent as a single full update.
+///
+/// The ticks in the new demo are relative to `start`.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::edit::cut_demo;
+/// let file = std::fs::read("demo.dem").unwrap();
+/// let demo = Demo::new(&file);
+/// let clip = cut_demo(&demo, 1000u32.into(), 2000u32.into()).unwrap();
+/// std::fs::write("clip.dem", clip).unwrap();
+/// ```
+pub fn cut_demo(demo: &Demo, start: DemoTick, end: DemoTick) -> Result<Vec<u8>> {
+    let mut stream = demo.get_stream();
+    let mut header = Header::read(&mut stream)?;
+    let mut packets = RawPacketStream::new(stream);
+
+    let mut cutter = DemoCutter::new(u32::from(start), u32::from(end).max(u32::from(start)));
+    cutter.handler.handle_header(&header);
+    cutter.encode_handler.handle_header(&header);
+
+    let mut body = Vec::new();
+    {
+        let mut out = BitWriteStream::new(&mut body, LittleEndian);
+        while let Some(packet) = packets.next(&cutter.handler.state_handler)? {
+            if !cutter.handle_packet(packet, &mut out)? {
+                break;
+            }
+        }
+        cutter.finish(&mut out)?;
+    }
+
+    header.ticks = cutter.last_tick;
+    header.frames = cutter.frames;
+    header.signon = cutter.signon_length.unwrap_or_default();
+    header.duration =
+        cutter.last_tick as f32 * cutter.handler.state_handler.demo_meta.interval_per_tick;
+
+    let mut out = Vec::with_capacity(body.len() + 1072);
+    {
+        let mut stream = BitWriteStream::new(&mut out, LittleEndian);
+        header.write(&mut stream)?;
+    }
+    out.extend_from_slice(&body);
+    Ok(out)
+}
+
+/// The state of the last packet entities message before the start of the cut
+struct EntitiesMeta {
+    max_entries: u16,
+    base_line: BaselineIndex,
+}
+
+struct DemoCutter<'a> {
+    start: u32,
+    end: u32,
+    handler: DemoHandler<'a, EntityTracker>,
+    encode_handler: DemoHandler<'a, NullHandler>,
+    /// Byte length of the packets before the sync tick
+    signon_length: Option<u32>,
+    frames: u32,
+    last_tick: u32,
+    last_net_tick: Option<NetTickMessage>,
+    last_meta: MessagePacketMeta,
+    /// Set when entities have been skipped that still need to be written
+    skipped_entities: Option<EntitiesMeta>,
+    stopped: bool,
+}
+
+impl<'a> DemoCutter<'a> {
+    fn new(start: u32, end: u32) -> Self {
+        DemoCutter {
+            start,
+            end,
+            handler: DemoHandler::parse_all_with_analyser(EntityTracker::new()),
+            encode_handler: DemoHandler::new(),
+            signon_length: None,
+            frames: 0,
+            last_tick: 0,
+            last_net_tick: None,
+            last_meta: MessagePacketMeta::default(),
+            skipped_entities: None,
+            stopped: false,
+        }
+    }
+
+    /// Returns `false` once the end of the cut is reached
+    fn handle_packet(
+        &mut self,
+        packet: Packet<'a>,
+        out: &mut BitWriteStream<LittleEndian>,
+    ) -> Result<bool> {
+        let tick = u32::from(packet.tick());
+
+        if self.signon_length.is_none() {
+            if let Packet::SyncTick(_) = &packet {
+                self.signon_length = Some(out.byte_len() as u32);
+            } else {
+                self.write(packet.clone(), out)?;
+                self.handler.handle_packet(packet)?;
+                return Ok(true);
+            }
+        }
+
+        if tick < self.start {
+            if let Some(skipped) = self.skip_packet(&packet) {
+                self.write(skipped, out)?;
+            }
+            self.handler.handle_packet(packet)?;
+            return Ok(true);
+        }
+
+        if tick > self.end {
+            return Ok(false);
+        }
+
+        self.write_snapshot(out)?;
+
+        let mut encode_packet = packet.clone();
+        encode_packet.set_tick((tick - self.start).into());
+        if let Packet::Message(message_packet) = &mut encode_packet {
+            expand_entering_entities(message_packet, &self.handler.state_handler);
+        }
+        self.stopped = matches!(packet, Packet::Stop(_));
+        self.last_tick = tick - self.start;
+        self.write(encode_packet, out)?;
+        self.handler.handle_packet(packet)?;
+
+        Ok(!self.stopped)
+    }
+
+    /// Strip a packet from before the start of the cut down to the parts that change the client state
+    fn skip_packet(&mut self, packet: &Packet<'a>) -> Option<Packet<'a>> {
+        match packet {
+            Packet::Message(message_packet) => {
+                self.last_meta = message_packet.meta.clone();
+                let mut messages = Vec::new();
+                for message in &message_packet.messages {
+                    match message {
+                        Message::NetTick(net_tick) => self.last_net_tick = Some(net_tick.clone()),
+                        Message::PacketEntities(entities) => {
+                            self.skipped_entities = Some(EntitiesMeta {
+                                max_entries: entities.max_entries,
+                                base_line: entities.base_line,
+                            })
+                        }
+                        Message::SetConVar(_)
+                        | Message::ServerInfo(_)
+                        | Message::ClassInfo(_)
+                        | Message::CreateStringTable(_)
+                        | Message::UpdateStringTable(_)
+                        | Message::VoiceInit(_)
+                        | Message::SetView(_)
+                        | Message::GameEventList(_) => messages.push(message.clone()),
+                        _ => {}
+                    }
+                }
+                (!messages.is_empty()).then(|| {
+                    Packet::Message(MessagePacket {
+                        tick: DemoTick::default(),
+                        messages,
+                        meta: message_packet.meta.clone(),
+                    })
+                })
+            }
+            // commands from before the cut have no effect on the playback
+            Packet::ConsoleCmd(_) | Packet::UserCmd(_) | Packet::Stop(_) => None,
+            packet => {
+                let mut packet = packet.clone();
+                packet.set_tick(DemoTick::default());
+                Some(packet)
+            }
+        }
+    }
+
+    /// Send all tracked entities as a full update, if entities have been skipped
+    fn write_snapshot(&mut self, out: &mut BitWriteStream<LittleEndian>) -> Result<()> {
+        let Some(meta) = self.skipped_entities.take() else {
+            return Ok(());
+        };
+
+        let entities = self
+            .handler
+            .analyser()
+            .state
+            .iter()
+            .filter(|entity| entity.in_pvs)
+            .map(|entity| PacketEntity {
+                server_class: entity.class,
+                entity_index: entity.id,
+                props: entity.props().cloned().collect(),
+                in_pvs: true,
+                update_type: UpdateType::Enter,
+                serial_number: entity.serial,
+                delay: None,
+                delta: None,
+                baseline_index: meta.base_line,
+            })
+            .collect();
+
+        let mut messages = Vec::with_capacity(2);
+        if let Some(net_tick) = self.last_net_tick.clone() {
+            messages.push(Message::NetTick(net_tick));
+        }
+        messages.push(Message::PacketEntities(PacketEntitiesMessage {
+            entities,
+            removed_entities: Vec::new(),
+            max_entries: meta.max_entries,
+            delta: None,
+            base_line: meta.base_line,
+            updated_base_line: false,
+        }));
+
+        self.write(
+            Packet::Message(MessagePacket {
+                tick: DemoTick::default(),
+                messages,
+                meta: self.last_meta.clone(),
+            }),
+            out,
+        )
+    }
+
+    fn write(&mut self, packet: Packet<'a>, out: &mut BitWriteStream<LittleEndian>) -> Result<()> {
+        if let Packet::Message(_) = &packet {
+            self.frames += 1;
+        }
+        packet.encode(out, &self.encode_handler.state_handler)?;
+        self.encode_handler.handle_packet(packet)
+    }
+
+    fn finish(&mut self, out: &mut BitWriteStream<LittleEndian>) -> Result<()> {
+        if self.signon_length.is_none() {
+            return Err(ParseError::InvalidDemo("No sync tick found"));
+        }
+        self.write_snapshot(out)?;
+        if !self.stopped {
+            self.write(
+                Packet::Stop(StopPacket {
+                    tick: self.last_tick.into(),
+                }),
+                out,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Include the baseline props of entities entering the pvs, the baselines of the client playing
+/// the cut demo don't necessarily match the baselines of the original demo
+pub(crate) fn expand_entering_entities(packet: &mut MessagePacket, state: &ParserState) {
+    for message in packet.messages.iter_mut() {
+        if let Message::PacketEntities(message) = message {
+            for entity in message.entities.iter_mut() {
+                if entity.update_type == UpdateType::Enter {
+                    let mut props: Vec<_> = entity.props(state).collect();
+                    props.sort_by_key(|prop| prop.index);
+                    entity.props = props;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use crate::demo::data::DemoTick;
+use crate::demo::edit::cut::expand_entering_entities;
+use crate::demo::header::Header;
+use crate::demo::message::stringtable::{CreateStringTableMessage, UpdateStringTableMessage};
+use crate::demo::message::Message;
+use crate::demo::packet::datatable::DataTablePacket;
+use crate::demo::packet::message::{MessagePacket, MessagePacketMeta};
+use crate::demo::packet::stop::StopPacket;
+use crate::demo::packet::stringtable::StringTable;
+use crate::demo::packet::Packet;
+use crate::demo::parser::{DemoHandler, Encode, NullHandler, RawPacketStream};
+use crate::{Demo, ParseError, Result, Stream};
+use bitbuffer::{BitRead, BitWrite, BitWriteStream, LittleEndian};
+
+/// Concatenate consecutive recordings of the same match into a single demo
+///
+/// The signon of the first demo is kept as is, the signons of the following demos are replaced by
+/// updates to the existing string tables, and their entities are sent with all props when they enter.
+/// The ticks of every demo continue after the last tick of the previous demo.
+///
+/// All demos have to be recorded on the same map with the same data tables.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::edit::merge_demos;
+/// let first = std::fs::read("match_1.dem").unwrap();
+/// let second = std::fs::read("match_2.dem").unwrap();
+/// let merged = merge_demos(&[Demo::new(&first), Demo::new(&second)]).unwrap();
+/// std::fs::write("match.dem", merged).unwrap();
+/// ```
+pub fn merge_demos(demos: &[Demo]) -> Result<Vec<u8>> {
+    let Some((first, rest)) = demos.split_first() else {
+        return Err(ParseError::InvalidDemo("No demos to merge"));
+    };
+    let mut stream = first.get_stream();
+    let mut header = Header::read(&mut stream)?;
+
+    let mut merger = DemoMerger::default();
+    merger.encode_handler.handle_header(&header);
+
+    let mut body = Vec::new();
+    {
+        let mut out = BitWriteStream::new(&mut body, LittleEndian);
+        merger.merge(&header, stream, &mut out)?;
+        for demo in rest {
+            let mut stream = demo.get_stream();
+            let demo_header = Header::read(&mut stream)?;
+            if demo_header.map != header.map {
+                return Err(ParseError::InvalidDemo(
+                    "The demos are recorded on different maps",
+                ));
+            }
+            merger.merge(&demo_header, stream, &mut out)?;
+        }
+        merger.write(
+            Packet::Stop(StopPacket {
+                tick: merger.last_tick.into(),
+            }),
+            &mut out,
+        )?;
+    }
+
+    header.ticks = merger.last_tick;
+    header.frames = merger.frames;
+    header.signon = merger.signon_length.unwrap_or_default();
+    header.duration = merger.last_tick as f32
+        * merger
+            .encode_handler
+            .state_handler
+            .demo_meta
+            .interval_per_tick;
+
+    let mut out = Vec::with_capacity(body.len() + 1072);
+    {
+        let mut stream = BitWriteStream::new(&mut out, LittleEndian);
+        header.write(&mut stream)?;
+    }
+    out.extend_from_slice(&body);
+    Ok(out)
+}
+
+#[derive(Default)]
+struct DemoMerger<'a> {
+    encode_handler: DemoHandler<'a, NullHandler>,
+    /// The data tables of the first demo
+    data_tables: Option<DataTablePacket>,
+    /// Names of the string tables in the merged demo, indexed by table id
+    string_table_names: Vec<String>,
+    /// Byte length of the packets before the sync tick
+    signon_length: Option<u32>,
+    frames: u32,
+    last_tick: u32,
+}
+
+impl<'a> DemoMerger<'a> {
+    fn merge(
+        &mut self,
+        header: &Header,
+        stream: Stream<'a>,
+        out: &mut BitWriteStream<LittleEndian>,
+    ) -> Result<()> {
+        let first = self.signon_length.is_none();
+        let offset = if first { 0 } else { self.last_tick + 1 };
+
+        let mut handler = DemoHandler::default();
+        handler.handle_header(header);
+        let mut packets = RawPacketStream::new(stream);
+
+        let mut in_signon = true;
+        // names of the string tables in the demo being merged, indexed by table id
+        let mut demo_table_names = Vec::new();
+        let mut signon_messages = Vec::new();
+
+        while let Some(packet) = packets.next(&handler.state_handler)? {
+            if let Packet::Signon(message_packet) | Packet::Message(message_packet) = &packet {
+                for message in &message_packet.messages {
+                    if let Message::CreateStringTable(message) = message {
+                        demo_table_names.push(message.table.name.to_string());
+                    }
+                }
+            }
+
+            match &packet {
+                // a single stop packet is added after the last demo
+                Packet::Stop(_) => {}
+                Packet::SyncTick(_) if first => {
+                    in_signon = false;
+                    self.signon_length = Some(out.byte_len() as u32);
+                    self.write(packet.clone(), out)?;
+                }
+                Packet::SyncTick(_) => {
+                    in_signon = false;
+                    if !signon_messages.is_empty() {
+                        self.write(
+                            Packet::Message(MessagePacket {
+                                tick: offset.into(),
+                                messages: std::mem::take(&mut signon_messages),
+                                meta: MessagePacketMeta::default(),
+                            }),
+                            out,
+                        )?;
+                    }
+                }
+                Packet::DataTables(tables) if first => {
+                    self.data_tables = Some(tables.clone());
+                    self.write(packet.clone(), out)?;
+                }
+                _ if first => self.write(packet.clone(), out)?,
+                _ if in_signon => {
+                    self.convert_signon(&packet, &demo_table_names, &mut signon_messages)?
+                }
+                _ => {
+                    let mut merged = packet.clone();
+                    merged.set_tick(DemoTick::from(u32::from(packet.tick()) + offset));
+                    // the baselines of the merged demo don't match the baselines of this demo
+                    if let Packet::Message(message_packet) = &mut merged {
+                        expand_entering_entities(message_packet, &handler.state_handler);
+                    }
+                    self.write(merged, out)?;
+                }
+            }
+            handler.handle_packet(packet)?;
+        }
+
+        if in_signon {
+            return Err(ParseError::InvalidDemo("No sync tick found"));
+        }
+        Ok(())
+    }
+
+    /// Convert the signon of a following demo into updates of the state of the merged demo
+    fn convert_signon(
+        &self,
+        packet: &Packet<'a>,
+        demo_table_names: &[String],
+        messages: &mut Vec<Message<'a>>,
+    ) -> Result<()> {
+        match packet {
+            Packet::DataTables(tables) => match &self.data_tables {
+                Some(first)
+                    if first.tables == tables.tables
+                        && first.server_classes == tables.server_classes => {}
+                _ => {
+                    return Err(ParseError::InvalidDemo(
+                        "The data tables of the demos differ",
+                    ))
+                }
+            },
+            Packet::StringTables(tables_packet) => {
+                for table in &tables_packet.tables {
+                    if let Some(table_id) = self.table_id(&table.name) {
+                        messages.extend(update_table(table_id, table));
+                    }
+                }
+            }
+            Packet::Signon(message_packet) => {
+                for message in &message_packet.messages {
+                    match message {
+                        Message::CreateStringTable(create) => {
+                            match self.table_id(&create.table.name) {
+                                Some(table_id) => {
+                                    messages.extend(update_table(table_id, &create.table))
+                                }
+                                None => messages.push(Message::CreateStringTable(
+                                    CreateStringTableMessage {
+                                        table: create.table.clone(),
+                                    },
+                                )),
+                            }
+                        }
+                        Message::UpdateStringTable(update) => {
+                            let table_id = demo_table_names
+                                .get(update.table_id as usize)
+                                .and_then(|name| self.table_id(name))
+                                .ok_or(ParseError::StringTableNotFound(update.table_id))?;
+                            messages.push(Message::UpdateStringTable(UpdateStringTableMessage {
+                                entries: update.entries.clone(),
+                                table_id,
+                            }));
+                        }
+                        Message::SetConVar(_) => messages.push(message.clone()),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn table_id(&self, name: &str) -> Option<u8> {
+        self.string_table_names
+            .iter()
+            .position(|table_name| table_name == name)
+            .map(|id| id as u8)
+    }
+
+    fn write(&mut self, packet: Packet<'a>, out: &mut BitWriteStream<LittleEndian>) -> Result<()> {
+        match &packet {
+            Packet::Signon(message_packet) | Packet::Message(message_packet) => {
+                for message in &message_packet.messages {
+                    if let Message::CreateStringTable(message) = message {
+                        self.string_table_names.push(message.table.name.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+        if let Packet::Message(_) = &packet {
+            self.frames += 1;
+        }
+        // the signon packets can have a higher tick than the packets after the sync tick
+        if self.signon_length.is_some() {
+            self.last_tick = self.last_tick.max(packet.tick().into());
+        }
+        packet.encode(out, &self.encode_handler.state_handler)?;
+        self.encode_handler.handle_packet(packet)
+    }
+}
+
+fn update_table<'a>(table_id: u8, table: &StringTable<'a>) -> Option<Message<'a>> {
+    (!table.entries.is_empty()).then(|| {
+        Message::UpdateStringTable(UpdateStringTableMessage {
+            entries: table.entries.clone(),
+            table_id,
+        })
+    })
+}
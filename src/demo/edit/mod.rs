@@ -0,0 +1,19 @@
+//! Tools for creating new demos, from scratch or from existing ones
+
+pub mod annotate;
+pub mod anonymize;
+pub mod builder;
+pub mod cut;
+pub mod merge;
+pub mod pov;
+pub mod repair;
+pub mod rewrite;
+
+pub use self::annotate::{Annotation, Annotator};
+pub use self::anonymize::{AnonymizeOptions, Anonymizer};
+pub use self::builder::{DemoBuilder, PropDefinition};
+pub use self::cut::cut_demo;
+pub use self::merge::merge_demos;
+pub use self::pov::PovConverter;
+pub use self::repair::{repair_demo, RepairChange, RepairedDemo};
+pub use self::rewrite::{DemoRewriter, Rewrite};
@@ -0,0 +1,290 @@
+use crate::demo::data::UserInfo;
+use crate::demo::header::Header;
+use crate::demo::message::packetentities::{EntityId, PacketEntity};
+use crate::demo::message::{Message, MessageType, SetViewMessage};
+use crate::demo::packet::message::ViewAngles;
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::packet::Packet;
+use crate::demo::parser::analyser::UserId;
+use crate::demo::parser::handler::MessageHandler;
+use crate::demo::sendprop::SendPropIdentifier;
+use crate::demo::vector::{Vector, VectorXY};
+use crate::{Demo, DemoParser, ParseError, ParserState, Result};
+
+const LOCAL_ORIGIN: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin");
+const NON_LOCAL_ORIGIN: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin");
+const LOCAL_ORIGIN_Z: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin[2]");
+const NON_LOCAL_ORIGIN_Z: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin[2]");
+const LOCAL_EYE_ANGLES: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[1]");
+const NON_LOCAL_EYE_ANGLES: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[1]");
+const LOCAL_PITCH_ANGLES: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[0]");
+const NON_LOCAL_PITCH_ANGLES: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[0]");
+
+/// Convert an stv demo into a demo from the point of view of a single player
+///
+/// The demo is marked as a pov demo recorded by the player, the view is locked to the player and
+/// the view angles of every packet are set from the position and eye angles of the player.
+///
+/// The packets have to be passed to the converter in order, before they are encoded.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::edit::{DemoRewriter, PovConverter};
+/// let file = std::fs::read("stv.dem").unwrap();
+/// let demo = Demo::new(&file);
+/// let converter = PovConverter::new(&demo, 3u32.into()).unwrap();
+/// let output = DemoRewriter::new()
+///     .with_pov_converter(converter)
+///     .rewrite(&demo)
+///     .unwrap();
+/// std::fs::write("pov.dem", output).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PovConverter {
+    user_id: UserId,
+    name: String,
+    entity: EntityId,
+    /// The entity the view is currently locked to
+    view_entity: Option<EntityId>,
+    /// The position of the player's feet, demos recorded by the client store this as the view
+    /// origin instead of the eye position, the view offset is added by the client during playback
+    origin: Vector,
+    pitch: f32,
+    yaw: f32,
+    // indexed by table id
+    string_table_names: Vec<String>,
+}
+
+impl PovConverter {
+    /// Create a converter for the player with the given user id
+    ///
+    /// The demo is parsed once to find the player, since the server info at the start of the demo
+    /// needs the player slot before the player is listed in the demo
+    pub fn new(demo: &Demo, user_id: UserId) -> Result<Self> {
+        let (_, user) =
+            DemoParser::new_with_analyser(demo.get_stream(), FirstUserInfo::new(user_id))
+                .parse()?;
+        let user = user.ok_or(ParseError::UnknownUser(user_id))?;
+        Ok(PovConverter {
+            user_id,
+            name: user.player_info.name,
+            entity: user.entity_id,
+            view_entity: None,
+            origin: Vector::default(),
+            pitch: 0.0,
+            yaw: 0.0,
+            string_table_names: Vec::new(),
+        })
+    }
+
+    /// Set the player as the recorder of the demo
+    pub fn convert_header(&self, header: &mut Header) {
+        header.nick.clone_from(&self.name);
+    }
+
+    pub fn convert_packet(&mut self, packet: &mut Packet, state: &ParserState) -> Result<()> {
+        match packet {
+            Packet::StringTables(packet) => {
+                for table in packet.tables.iter() {
+                    if table.name == "userinfo" {
+                        self.handle_user_info(&table.entries)?;
+                    }
+                }
+            }
+            Packet::Signon(packet) | Packet::Message(packet) => {
+                packet
+                    .messages
+                    .retain(|message| !matches!(message, Message::SetView(_)));
+                for message in packet.messages.iter_mut() {
+                    self.convert_message(message, state)?;
+                }
+            }
+            _ => {}
+        }
+
+        if let Packet::Message(packet) = packet {
+            if self.view_entity != Some(self.entity) {
+                self.view_entity = Some(self.entity);
+                packet.messages.push(Message::SetView(SetViewMessage {
+                    index: u32::from(self.entity) as u16,
+                }));
+            }
+            let angles = Vector {
+                x: self.pitch,
+                y: self.yaw,
+                z: 0.0,
+            };
+            packet.meta.view_angles = [
+                ViewAngles {
+                    origin: self.origin,
+                    angles,
+                    local_angles: angles,
+                },
+                ViewAngles::default(),
+            ];
+        }
+        Ok(())
+    }
+
+    fn convert_message(&mut self, message: &mut Message, state: &ParserState) -> Result<()> {
+        match message {
+            Message::ServerInfo(info) => {
+                info.stv = false;
+                info.player_slot = u32::from(self.entity).saturating_sub(1) as u8;
+            }
+            Message::CreateStringTable(message) => {
+                self.string_table_names.push(message.table.name.to_string());
+                if message.table.name == "userinfo" {
+                    self.handle_user_info(&message.table.entries)?;
+                }
+            }
+            Message::UpdateStringTable(message)
+                if self
+                    .string_table_names
+                    .get(message.table_id as usize)
+                    .is_some_and(|name| name == "userinfo") =>
+            {
+                self.handle_user_info(&message.entries)?;
+            }
+            Message::PacketEntities(message) => {
+                if let Some(entity) = message
+                    .entities
+                    .iter()
+                    .find(|entity| entity.entity_index == self.entity)
+                {
+                    self.handle_entity(entity, state);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Follow the player to a new entity if they reconnect
+    fn handle_user_info(&mut self, entries: &[(u16, StringTableEntry)]) -> Result<()> {
+        for (index, entry) in entries {
+            if let Some(user_info) = UserInfo::parse_from_string_table(
+                *index,
+                entry.text.as_deref(),
+                entry.extra_data.as_ref().map(|extra| extra.data.clone()),
+            )? {
+                if user_info.player_info.user_id == self.user_id {
+                    self.entity = user_info.entity_id;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_entity(&mut self, entity: &PacketEntity, state: &ParserState) {
+        for prop in entity.props(state) {
+            match prop.identifier {
+                LOCAL_ORIGIN | NON_LOCAL_ORIGIN => {
+                    let origin = VectorXY::try_from(&prop.value).unwrap_or_default();
+                    self.origin.x = origin.x;
+                    self.origin.y = origin.y;
+                }
+                LOCAL_ORIGIN_Z | NON_LOCAL_ORIGIN_Z => {
+                    self.origin.z = f32::try_from(&prop.value).unwrap_or_default();
+                }
+                LOCAL_EYE_ANGLES | NON_LOCAL_EYE_ANGLES => {
+                    self.yaw = f32::try_from(&prop.value).unwrap_or_default();
+                }
+                LOCAL_PITCH_ANGLES | NON_LOCAL_PITCH_ANGLES => {
+                    self.pitch = f32::try_from(&prop.value).unwrap_or_default();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Finds the first user info entry for a user
+///
+/// The player can reconnect in a different slot, the slot at the start of the demo is the one
+/// from the first entry.
+struct FirstUserInfo {
+    user_id: UserId,
+    user: Option<UserInfo>,
+}
+
+impl FirstUserInfo {
+    fn new(user_id: UserId) -> Self {
+        FirstUserInfo {
+            user_id,
+            user: None,
+        }
+    }
+}
+
+impl MessageHandler for FirstUserInfo {
+    type Output = Option<UserInfo>;
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        false
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        _parser_state: &ParserState,
+    ) {
+        if table != "userinfo" || self.user.is_some() {
+            return;
+        }
+        if let Ok(Some(user_info)) = UserInfo::parse_from_string_table(
+            index as u16,
+            entry.text.as_deref(),
+            entry.extra_data.as_ref().map(|extra| extra.data.clone()),
+        ) {
+            if user_info.player_info.user_id == self.user_id {
+                self.user = Some(user_info);
+            }
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.user
+    }
+}
+
+#[test]
+fn test_first_user_info() {
+    use crate::demo::data::userinfo::PlayerInfo;
+
+    let entry = |entity: u32, user_id: u16| StringTableEntry {
+        text: Some((entity - 1).to_string().into()),
+        extra_data: UserInfo {
+            entity_id: EntityId::from(entity),
+            player_info: PlayerInfo {
+                name: "player".into(),
+                user_id: UserId::from(user_id),
+                steam_id: "[U:1:1]".into(),
+                ..PlayerInfo::default()
+            },
+        }
+        .encode_to_string_table()
+        .unwrap()
+        .extra_data,
+    };
+
+    let state = ParserState::new(24, |_| false, false);
+    let mut handler = FirstUserInfo::new(UserId::from(3u16));
+    handler.handle_string_entry("userinfo", 0, &entry(1, 2), &state);
+    handler.handle_string_entry("userinfo", 4, &entry(5, 3), &state);
+    // reconnected in a different slot
+    handler.handle_string_entry("userinfo", 7, &entry(8, 3), &state);
+
+    let user = handler.into_output(&state).unwrap();
+    assert_eq!(EntityId::from(5u32), user.entity_id);
+}
@@ -0,0 +1,193 @@
+use crate::demo::header::Header;
+use crate::demo::packet::stop::StopPacket;
+use crate::demo::packet::Packet;
+use crate::demo::parser::{DemoHandler, Encode, RawPacketStream};
+use crate::{Demo, ParseError, ParserState, Result};
+use bitbuffer::{BitRead, BitWrite, BitWriteStream, LittleEndian};
+
+/// A change made while repairing a demo
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepairChange {
+    /// An incomplete packet at the end of the demo was removed
+    DroppedPartialPacket { bytes: usize },
+    /// A stop packet was added at the end of the demo
+    AddedStop,
+    /// The tick count in the header was corrected
+    Ticks { old: u32, new: u32 },
+    /// The frame count in the header was corrected
+    Frames { old: u32, new: u32 },
+    /// The duration in the header was corrected
+    Duration { old: f32, new: f32 },
+    /// The signon length in the header was corrected
+    SignonLength { old: u32, new: u32 },
+}
+
+/// The result of [`repair_demo`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepairedDemo {
+    pub data: Vec<u8>,
+    pub header: Header,
+    pub changes: Vec<RepairChange>,
+}
+
+impl RepairedDemo {
+    /// Whether the demo was already intact
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// The header fields of a demo as calculated from its packets
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct DemoLength {
+    ticks: u32,
+    frames: u32,
+    duration: f32,
+    signon: Option<u32>,
+}
+
+/// Repair a demo that was closed unexpectedly
+///
+/// An incomplete packet at the end of the demo is removed, a stop packet is added if the demo
+/// has none and the length fields of the header are recalculated from the packets of the demo.
+///
+/// The packets themselves are copied from the original demo without re-encoding.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::edit::repair_demo;
+/// let file = std::fs::read("demo.dem").unwrap();
+/// let demo = Demo::new(&file);
+/// let repaired = repair_demo(&demo).unwrap();
+/// for change in &repaired.changes {
+///     println!("{:?}", change);
+/// }
+/// std::fs::write("repaired.dem", repaired.data).unwrap();
+/// ```
+pub fn repair_demo(demo: &Demo) -> Result<RepairedDemo> {
+    let mut stream = demo.get_stream();
+    let mut header = Header::read(&mut stream)?;
+    let mut body_stream = stream.clone();
+    let scan = scan_packets(&header, RawPacketStream::new(stream))?;
+    if scan.length.signon.is_none() {
+        return Err(ParseError::InvalidDemo("No sync tick found"));
+    }
+
+    let mut changes = Vec::new();
+    let remaining = (body_stream.bit_len() / 8).saturating_sub(scan.body_length);
+    if scan.incomplete && remaining > 0 {
+        changes.push(RepairChange::DroppedPartialPacket { bytes: remaining });
+    }
+    if !scan.has_stop {
+        changes.push(RepairChange::AddedStop);
+    }
+    changes.extend(update_header(&mut header, scan.length));
+
+    let body = body_stream.read_bytes(scan.body_length)?;
+    let mut data = Vec::with_capacity(body.len() + 1072);
+    {
+        let mut out = BitWriteStream::new(&mut data, LittleEndian);
+        header.write(&mut out)?;
+        out.write_bytes(&body)?;
+        if !scan.has_stop {
+            Packet::Stop(StopPacket {
+                tick: scan.length.ticks.into(),
+            })
+            .encode(
+                &mut out,
+                &ParserState::new(header.protocol, |_| false, false),
+            )?;
+        }
+    }
+
+    Ok(RepairedDemo {
+        data,
+        header,
+        changes,
+    })
+}
+
+/// Recalculate the length fields of the header from the packets of the demo
+pub(crate) fn fix_header(header: &mut Header, packets: RawPacketStream) -> Result<()> {
+    let scan = scan_packets(header, packets)?;
+    update_header(header, scan.length);
+    Ok(())
+}
+
+struct PacketScan {
+    length: DemoLength,
+    /// Byte length of the complete packets
+    body_length: usize,
+    has_stop: bool,
+    incomplete: bool,
+}
+
+fn scan_packets(header: &Header, mut packets: RawPacketStream) -> Result<PacketScan> {
+    let mut handler = DemoHandler::default();
+    handler.handle_header(header);
+
+    let start = packets.pos();
+    let mut end = start;
+    let mut length = DemoLength::default();
+    let mut has_stop = false;
+
+    while let Some(packet) = packets.next(&handler.state_handler)? {
+        match &packet {
+            // the signon length doesn't include the sync tick itself
+            Packet::SyncTick(_) if length.signon.is_none() => {
+                length.signon = Some(((end - start) / 8) as u32);
+            }
+            Packet::Message(_) => length.frames += 1,
+            Packet::Stop(_) => has_stop = true,
+            _ => {}
+        }
+        end = packets.pos();
+        length.ticks = packet.tick().into();
+        handler.handle_packet(packet)?;
+    }
+    length.duration = length.ticks as f32 * handler.state_handler.demo_meta.interval_per_tick;
+
+    Ok(PacketScan {
+        length,
+        body_length: (end - start) / 8,
+        has_stop,
+        incomplete: packets.incomplete,
+    })
+}
+
+fn update_header(header: &mut Header, length: DemoLength) -> Vec<RepairChange> {
+    let mut changes = Vec::new();
+    if header.ticks != length.ticks {
+        changes.push(RepairChange::Ticks {
+            old: header.ticks,
+            new: length.ticks,
+        });
+        header.ticks = length.ticks;
+    }
+    if header.frames != length.frames {
+        changes.push(RepairChange::Frames {
+            old: header.frames,
+            new: length.frames,
+        });
+        header.frames = length.frames;
+    }
+    // the duration is recorded as a float, allow for rounding differences
+    if (header.duration - length.duration).abs() > 0.1 {
+        changes.push(RepairChange::Duration {
+            old: header.duration,
+            new: length.duration,
+        });
+        header.duration = length.duration;
+    }
+    match length.signon {
+        Some(signon) if header.signon != signon => {
+            changes.push(RepairChange::SignonLength {
+                old: header.signon,
+                new: signon,
+            });
+            header.signon = signon;
+        }
+        _ => {}
+    }
+    changes
+}
@@ -0,0 +1,269 @@
+use crate::demo::data::DemoTick;
+use crate::demo::edit::repair::fix_header;
+use crate::demo::edit::{Annotator, Anonymizer, PovConverter};
+use crate::demo::header::Header;
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::stop::StopPacket;
+use crate::demo::packet::{Packet, PacketType};
+use crate::demo::parser::{DemoHandler, Encode, RawPacketStream};
+use crate::{Demo, ParserState, Result};
+use bitbuffer::{BitRead, BitWrite, BitWriteStream, LittleEndian};
+
+/// The result of a rewrite hook
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rewrite<T> {
+    /// Keep the, possibly modified, item
+    Keep(T),
+    /// Remove the item from the demo
+    Drop,
+    /// Replace the item with any number of items, this can be used to insert items before or after it
+    Replace(Vec<T>),
+}
+
+impl<T> Rewrite<T> {
+    fn into_vec(self) -> Vec<T> {
+        match self {
+            Rewrite::Keep(item) => vec![item],
+            Rewrite::Drop => Vec::new(),
+            Rewrite::Replace(items) => items,
+        }
+    }
+}
+
+pub type HeaderHook<'h> = Box<dyn FnMut(&mut Header) + 'h>;
+pub type PacketHook<'h> =
+    Box<dyn for<'p> FnMut(Packet<'p>, &ParserState) -> Result<Rewrite<Packet<'p>>> + 'h>;
+pub type MessageHook<'h> = Box<
+    dyn for<'p> FnMut(Message<'p>, DemoTick, &ParserState) -> Result<Rewrite<Message<'p>>> + 'h,
+>;
+
+/// Re-encode a demo, passing every packet and message through a set of hooks
+///
+/// Packet hooks are called for every packet in the order they are added, message hooks are called
+/// for every message of the packets that remain after the packet hooks.
+/// The parser state passed to the hooks is the state of the original demo before the packet is handled.
+///
+/// Demos that are closed unexpectedly get their length fixed and a stop packet added.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::edit::rewrite::{DemoRewriter, Rewrite, drop_packets};
+/// # use tf_demo_parser::demo::message::Message;
+/// # use tf_demo_parser::demo::packet::PacketType;
+/// let file = std::fs::read("demo.dem").unwrap();
+/// let demo = Demo::new(&file);
+/// let output = DemoRewriter::new()
+///     .with_packet_hook(drop_packets(PacketType::ConsoleCmd))
+///     .with_message_hook(|message, _tick, _state| {
+///         Ok(match message {
+///             Message::VoiceData(_) => Rewrite::Drop,
+///             message => Rewrite::Keep(message),
+///         })
+///     })
+///     .rewrite(&demo)
+///     .unwrap();
+/// std::fs::write("out.dem", output).unwrap();
+/// ```
+#[derive(Default)]
+pub struct DemoRewriter<'h> {
+    header_hooks: Vec<HeaderHook<'h>>,
+    packet_hooks: Vec<PacketHook<'h>>,
+    message_hooks: Vec<MessageHook<'h>>,
+}
+
+impl<'h> DemoRewriter<'h> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_header_hook<F: FnMut(&mut Header) + 'h>(mut self, hook: F) -> Self {
+        self.header_hooks.push(Box::new(hook));
+        self
+    }
+
+    pub fn with_packet_hook<F>(mut self, hook: F) -> Self
+    where
+        F: for<'p> FnMut(Packet<'p>, &ParserState) -> Result<Rewrite<Packet<'p>>> + 'h,
+    {
+        self.packet_hooks.push(Box::new(hook));
+        self
+    }
+
+    pub fn with_message_hook<F>(mut self, hook: F) -> Self
+    where
+        F: for<'p> FnMut(Message<'p>, DemoTick, &ParserState) -> Result<Rewrite<Message<'p>>> + 'h,
+    {
+        self.message_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Replace player names and steam ids with pseudonyms
+    pub fn with_anonymizer(self, mut anonymizer: Anonymizer) -> Self {
+        let header_anonymizer = anonymizer.clone();
+        self.with_header_hook(move |header| header_anonymizer.anonymize_header(header))
+            .with_packet_hook(move |mut packet, _state| {
+                anonymizer.anonymize_packet(&mut packet)?;
+                Ok(Rewrite::Keep(packet))
+            })
+    }
+
+    /// Insert markers and notes at chosen ticks
+    pub fn with_annotator(self, mut annotator: Annotator) -> Self {
+        self.with_packet_hook(move |packet, _state| {
+            Ok(Rewrite::Replace(annotator.annotate_packet(packet)))
+        })
+    }
+
+    /// Convert an stv demo into a demo from the point of view of a player
+    pub fn with_pov_converter(self, mut converter: PovConverter) -> Self {
+        let header_converter = converter.clone();
+        self.with_header_hook(move |header| header_converter.convert_header(header))
+            .with_packet_hook(move |mut packet, state| {
+                converter.convert_packet(&mut packet, state)?;
+                Ok(Rewrite::Keep(packet))
+            })
+    }
+
+    pub fn rewrite(&mut self, demo: &Demo) -> Result<Vec<u8>> {
+        let mut stream = demo.get_stream();
+        let mut header = Header::read(&mut stream)?;
+        let mut packets = RawPacketStream::new(stream.clone());
+
+        // demos that are closed unexpectedly have no length set
+        if header.ticks == 0 {
+            fix_header(&mut header, packets.clone())?;
+        }
+        for hook in self.header_hooks.iter_mut() {
+            hook(&mut header);
+        }
+
+        let mut out = Vec::with_capacity(stream.bit_len() / 8 + 1072);
+        {
+            let mut out_stream = BitWriteStream::new(&mut out, LittleEndian);
+            header.write(&mut out_stream)?;
+
+            let mut handler = DemoHandler::default();
+            let mut encode_handler = DemoHandler::default();
+            handler.handle_header(&header);
+            encode_handler.handle_header(&header);
+
+            let mut has_stop = false;
+            let mut last_tick = DemoTick::default();
+
+            while let Some(packet) = packets.next(&handler.state_handler)? {
+                last_tick = packet.tick();
+                let rewritten = self.rewrite_packet(packet.clone(), &handler.state_handler)?;
+                handler.handle_packet(packet)?;
+
+                for packet in rewritten {
+                    has_stop |= packet.packet_type() == PacketType::Stop;
+                    packet.encode(&mut out_stream, &encode_handler.state_handler)?;
+                    encode_handler.handle_packet(packet)?;
+                }
+            }
+
+            if !has_stop {
+                Packet::Stop(StopPacket { tick: last_tick })
+                    .encode(&mut out_stream, &encode_handler.state_handler)?;
+            }
+        }
+        Ok(out)
+    }
+
+    fn rewrite_packet<'p>(
+        &mut self,
+        packet: Packet<'p>,
+        state: &ParserState,
+    ) -> Result<Vec<Packet<'p>>> {
+        let mut packets = vec![packet];
+        for hook in self.packet_hooks.iter_mut() {
+            let mut rewritten = Vec::with_capacity(packets.len());
+            for packet in packets {
+                rewritten.extend(hook(packet, state)?.into_vec());
+            }
+            packets = rewritten;
+        }
+
+        if !self.message_hooks.is_empty() {
+            for packet in packets.iter_mut() {
+                if let Packet::Signon(message_packet) | Packet::Message(message_packet) = packet {
+                    for hook in self.message_hooks.iter_mut() {
+                        let messages = std::mem::take(&mut message_packet.messages);
+                        for message in messages {
+                            message_packet
+                                .messages
+                                .extend(hook(message, message_packet.tick, state)?.into_vec());
+                        }
+                    }
+                }
+            }
+        }
+        Ok(packets)
+    }
+}
+
+/// Remove all packets of a type
+pub fn drop_packets(
+    packet_type: PacketType,
+) -> impl for<'p> FnMut(Packet<'p>, &ParserState) -> Result<Rewrite<Packet<'p>>> {
+    move |packet, _state| {
+        Ok(if packet.packet_type() == packet_type {
+            Rewrite::Drop
+        } else {
+            Rewrite::Keep(packet)
+        })
+    }
+}
+
+/// Remove all messages of a type
+pub fn drop_messages(
+    message_type: MessageType,
+) -> impl for<'p> FnMut(Message<'p>, DemoTick, &ParserState) -> Result<Rewrite<Message<'p>>> {
+    move |message, _tick, _state| {
+        Ok(if message.get_message_type() == message_type {
+            Rewrite::Drop
+        } else {
+            Rewrite::Keep(message)
+        })
+    }
+}
+
+/// Remove the view angles of the recording player and mark the demo as an stv demo
+pub fn strip_pov<'p>(mut packet: Packet<'p>, _state: &ParserState) -> Result<Rewrite<Packet<'p>>> {
+    if let Packet::Signon(message_packet) | Packet::Message(message_packet) = &mut packet {
+        message_packet.meta.view_angles = Default::default();
+        for message in message_packet.messages.iter_mut() {
+            if let Message::ServerInfo(info) = message {
+                info.stv = true;
+            }
+        }
+    }
+    Ok(Rewrite::Keep(packet))
+}
+
+/// Increase the size of the player and building arrays in the data tables to allow servers with
+/// up to 100 players
+pub fn patch_array_sizes<'p>(
+    mut packet: Packet<'p>,
+    _state: &ParserState,
+) -> Result<Rewrite<Packet<'p>>> {
+    if let Packet::DataTables(tables_packet) = &mut packet {
+        for table in tables_packet.tables.iter_mut() {
+            for prop in table.props.iter_mut() {
+                match (table.name.as_str(), prop.name.as_str()) {
+                    ("DT_ObjectDispenser", "\"healing_array\"") => {
+                        prop.element_count = Some(101);
+                    }
+                    ("DT_Team", "\"player_array\"") => {
+                        prop.element_count = Some(101);
+                    }
+                    ("DT_TFTeam", "\"team_object_array\"") => {
+                        prop.element_count = Some(606);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(Rewrite::Keep(packet))
+}
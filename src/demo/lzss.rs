@@ -44,3 +44,183 @@ fn decompress_(input: &[u8], output: &mut Vec<u8>) -> Option<()> {
         }
     }
 }
+
+/// Largest distance a back reference can point to
+#[cfg(feature = "write")]
+const WINDOW_SIZE: usize = 4096;
+/// Back references are 2 bytes, so shorter matches aren't worth it
+#[cfg(feature = "write")]
+const MIN_MATCH: usize = 3;
+#[cfg(feature = "write")]
+const MAX_MATCH: usize = 16;
+#[cfg(feature = "write")]
+const HASH_SIZE: usize = 1 << 12;
+/// Limit the number of candidates checked for every position to keep large inputs fast
+#[cfg(feature = "write")]
+const MAX_CHAIN: usize = 256;
+
+/// Compress the input in the format read by [`decompress`]
+#[cfg(feature = "write")]
+pub fn compress(input: &[u8], output: &mut Vec<u8>) {
+    output.extend_from_slice(&(input.len() as u32).to_le_bytes());
+
+    let mut chains = HashChains::new(input);
+    let mut commands = Commands::new(output);
+    let mut pos = 0;
+    while let Some(&byte) = input.get(pos) {
+        let mut best = (0, 0);
+        let mut candidate = chains.head(pos);
+        let mut checked = 0;
+        while candidate != usize::MAX && pos - candidate <= WINDOW_SIZE && checked < MAX_CHAIN {
+            let length = input
+                .get(candidate..)
+                .unwrap_or_default()
+                .iter()
+                .zip(input.get(pos..).unwrap_or_default())
+                .take(MAX_MATCH)
+                .take_while(|(a, b)| a == b)
+                .count();
+            if length > best.1 {
+                best = (pos - candidate, length);
+            }
+            candidate = chains.prev(candidate);
+            checked += 1;
+        }
+
+        let (distance, length) = best;
+        if length >= MIN_MATCH {
+            let offset = distance - 1;
+            commands.push_reference([
+                (offset >> 4) as u8,
+                ((offset & 0x0F) << 4 | (length - 1)) as u8,
+            ]);
+            for pos in pos..pos + length {
+                chains.insert(pos);
+            }
+            pos += length;
+        } else {
+            commands.push_literal(byte);
+            chains.insert(pos);
+            pos += 1;
+        }
+    }
+
+    // a reference with a length of 1 marks the end of the data
+    commands.push_reference([0, 0]);
+}
+
+/// Earlier positions in the input that start with the same bytes
+#[cfg(feature = "write")]
+struct HashChains<'a> {
+    input: &'a [u8],
+    /// Most recent position for every hash
+    head: Vec<usize>,
+    /// Previous position with the same hash, for every position
+    prev: Vec<usize>,
+}
+
+#[cfg(feature = "write")]
+impl<'a> HashChains<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        HashChains {
+            input,
+            head: vec![usize::MAX; HASH_SIZE],
+            prev: vec![usize::MAX; input.len()],
+        }
+    }
+
+    fn hash(&self, pos: usize) -> Option<usize> {
+        match self.input.get(pos..pos + MIN_MATCH) {
+            Some(&[a, b, c]) => {
+                Some(((a as usize) << 8 ^ (b as usize) << 4 ^ c as usize) & (HASH_SIZE - 1))
+            }
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, pos: usize) {
+        if let Some(hash) = self.hash(pos) {
+            if let (Some(head), Some(prev)) = (self.head.get_mut(hash), self.prev.get_mut(pos)) {
+                *prev = *head;
+                *head = pos;
+            }
+        }
+    }
+
+    /// The most recent position with the same hash as `pos`, or `usize::MAX`
+    fn head(&self, pos: usize) -> usize {
+        self.hash(pos)
+            .and_then(|hash| self.head.get(hash).copied())
+            .unwrap_or(usize::MAX)
+    }
+
+    /// The position before `pos` with the same hash, or `usize::MAX`
+    fn prev(&self, pos: usize) -> usize {
+        self.prev.get(pos).copied().unwrap_or(usize::MAX)
+    }
+}
+
+/// Groups literals and back references by 8, prefixed by a byte with a flag for every back reference
+#[cfg(feature = "write")]
+struct Commands<'a> {
+    output: &'a mut Vec<u8>,
+    command_pos: usize,
+    command_bit: u8,
+}
+
+#[cfg(feature = "write")]
+impl<'a> Commands<'a> {
+    fn new(output: &'a mut Vec<u8>) -> Self {
+        Commands {
+            command_pos: output.len(),
+            output,
+            command_bit: 8,
+        }
+    }
+
+    fn next_command(&mut self) {
+        if self.command_bit == 8 {
+            self.command_pos = self.output.len();
+            self.output.push(0);
+            self.command_bit = 0;
+        }
+    }
+
+    fn push_literal(&mut self, byte: u8) {
+        self.next_command();
+        self.command_bit += 1;
+        self.output.push(byte);
+    }
+
+    fn push_reference(&mut self, reference: [u8; 2]) {
+        self.next_command();
+        if let Some(command) = self.output.get_mut(self.command_pos) {
+            *command |= 1 << self.command_bit;
+        }
+        self.command_bit += 1;
+        self.output.extend_from_slice(&reference);
+    }
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn test_lzss_roundtrip() {
+    let inputs: [&[u8]; 4] = [
+        b"",
+        b"a",
+        b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        b"models/player/scout.mdl\0models/player/soldier.mdl\0models/player/pyro.mdl\0",
+    ];
+    let long: Vec<u8> = (0..20_000u32).map(|i| (i % 7 + i / 1000) as u8).collect();
+    for input in inputs.into_iter().chain([long.as_slice()]) {
+        let mut compressed = Vec::new();
+        compress(input, &mut compressed);
+        let mut decompressed = Vec::new();
+        decompress(&compressed, &mut decompressed);
+        assert_eq!(input, decompressed.as_slice());
+    }
+
+    let mut compressed = Vec::new();
+    compress(&long, &mut compressed);
+    assert!(compressed.len() < long.len() / 2);
+}
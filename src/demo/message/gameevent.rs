@@ -146,6 +146,12 @@ impl ParseBitSkip<'_> for GameEventMessage {
 #[cfg_attr(feature = "write", derive(BitWrite))]
 pub struct GameEventTypeId(#[size = 9] u16);
 
+impl From<u16> for GameEventTypeId {
+    fn from(id: u16) -> Self {
+        GameEventTypeId(id)
+    }
+}
+
 impl From<GameEventTypeId> for usize {
     fn from(id: GameEventTypeId) -> Self {
         id.0 as usize
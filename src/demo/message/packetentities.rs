@@ -9,7 +9,7 @@ use crate::demo::packet::datatable::{ClassId, SendTable};
 #[cfg(feature = "write")]
 use crate::demo::parser::Encode;
 use crate::demo::parser::ParseBitSkip;
-use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::demo::sendprop::{SendProp, SendPropDefinition, SendPropIdentifier, SendPropValue};
 use crate::{Parse, ParseError, ParserState, ReadResult, Result, Stream};
 use parse_display::{Display, FromStr};
 use std::cmp::{min, Ordering};
@@ -18,6 +18,7 @@ use std::collections::HashSet;
 use crate::demo::data::ServerTick;
 use itertools::Either;
 use std::fmt;
+use std::ops::Range;
 #[cfg(feature = "trace")]
 use tracing::trace;
 
@@ -144,6 +145,10 @@ impl<E: Endianness> BitWrite<E> for BaselineIndex {
 pub struct PacketEntity {
     pub server_class: ClassId,
     pub entity_index: EntityId,
+    /// The props of the entity, ordered by their index in the flattened send table
+    ///
+    /// Props read from a demo are always ordered, use [`PacketEntity::apply_update`] to change the
+    /// props while keeping them ordered.
     pub props: Vec<SendProp>,
     pub in_pvs: bool,
     pub update_type: UpdateType,
@@ -164,17 +169,28 @@ impl fmt::Display for PacketEntity {
 }
 
 impl PacketEntity {
-    fn mut_prop_by_identifier(&mut self, index: &SendPropIdentifier) -> Option<&mut SendProp> {
-        self.props.iter_mut().find(|prop| prop.identifier == *index)
-    }
-
+    /// Get a prop by identifier, including props from the baseline for entities entering the pvs
+    ///
+    /// For props that occur multiple times in the send table, the first occurrence is returned.
     pub fn get_prop_by_identifier(
         &self,
         index: &SendPropIdentifier,
         parser_state: &ParserState,
     ) -> Option<SendProp> {
-        self.props(parser_state)
-            .find(|prop| prop.identifier == *index)
+        let index = parser_state.index_for_prop(self.server_class, *index)?;
+        self.get_prop_by_index(index, parser_state)
+    }
+
+    /// Get a prop by its index in the flattened send table, including props from the baseline for entities entering the pvs
+    pub fn get_prop_by_index(&self, index: u32, parser_state: &ParserState) -> Option<SendProp> {
+        if let Some(prop) = find_prop(&self.props, index) {
+            return Some(prop.clone());
+        }
+        if self.update_type == UpdateType::Enter {
+            find_prop(&self.get_baseline_props(parser_state), index).cloned()
+        } else {
+            None
+        }
     }
 
     pub fn get_own_prop_value_by_identifier<T: for<'a> TryFrom<&'a SendPropValue>>(
@@ -189,12 +205,7 @@ impl PacketEntity {
     }
 
     pub fn apply_update(&mut self, props: &[SendProp]) {
-        for prop in props {
-            match self.mut_prop_by_identifier(&prop.identifier) {
-                Some(existing_prop) => existing_prop.value = prop.value.clone(),
-                None => self.props.push(prop.clone()),
-            }
-        }
+        apply_prop_updates(&mut self.props, props);
     }
 
     pub fn get_prop_by_name(
@@ -227,24 +238,308 @@ impl PacketEntity {
         parser_state: &'a ParserState,
     ) -> impl Iterator<Item = SendProp> + 'a {
         if self.update_type == UpdateType::Enter {
-            let mut found_props = HashSet::<SendPropIdentifier>::new();
+            // dedup by index instead of identifier since arrays of datatables can contain the same identifier multiple times
+            let mut found_props = HashSet::<u32>::new();
             let props = self.props.iter().cloned();
             #[allow(clippy::unnecessary_to_owned)]
             let baseline_props = self
                 .get_baseline_props(parser_state)
                 .into_owned()
                 .into_iter();
-            Either::Left(props.chain(baseline_props).filter(move |prop| {
-                let found = found_props.contains(&prop.identifier);
-                found_props.insert(prop.identifier);
-                !found
-            }))
+            Either::Left(
+                props
+                    .chain(baseline_props)
+                    .filter(move |prop| found_props.insert(prop.index)),
+            )
         } else {
             Either::Right(self.props.iter().cloned())
         }
     }
 }
 
+/// Reusable storage for decoding entity updates
+///
+/// Decoding packet entities into a buffer with [`PacketEntitiesMessage::parse_into`] prevents having
+/// to allocate the props for every entity update, the props from previously decoded messages are re-used
+/// when the buffer is cleared.
+#[derive(Debug, Clone, Default)]
+pub struct EntityBuffer {
+    messages: Vec<BufferedMessage>,
+    entities: Vec<BufferedEntity>,
+    removed_entities: Vec<EntityId>,
+    /// Props past `prop_count` are left over from previous messages and only kept for their allocations
+    props: Vec<SendProp>,
+    prop_count: usize,
+}
+
+#[derive(Debug, Clone)]
+struct BufferedMessage {
+    max_entries: u16,
+    delta: Option<ServerTick>,
+    base_line: BaselineIndex,
+    updated_base_line: bool,
+    entities: Range<usize>,
+    removed_entities: Range<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct BufferedEntity {
+    /// Entity without props
+    entity: PacketEntity,
+    props: Range<usize>,
+}
+
+impl EntityBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The messages decoded into the buffer since it was last cleared
+    pub fn messages(&self) -> impl Iterator<Item = PacketEntitiesView<'_>> {
+        self.messages.iter().map(move |message| PacketEntitiesView {
+            max_entries: message.max_entries,
+            delta: message.delta,
+            base_line: message.base_line,
+            updated_base_line: message.updated_base_line,
+            entities: self
+                .entities
+                .get(message.entities.clone())
+                .unwrap_or_default(),
+            removed_entities: self
+                .removed_entities
+                .get(message.removed_entities.clone())
+                .unwrap_or_default(),
+            props: &self.props,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Remove all messages from the buffer while keeping the allocations
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.entities.clear();
+        self.removed_entities.clear();
+        self.prop_count = 0;
+    }
+
+    fn read_prop(
+        &mut self,
+        stream: &mut Stream,
+        index: u32,
+        definition: &SendPropDefinition,
+    ) -> Result<()> {
+        match self.props.get_mut(self.prop_count) {
+            Some(prop) => {
+                prop.value
+                    .parse_into(stream, &definition.parse_definition)?;
+                prop.index = index;
+                prop.identifier = definition.identifier;
+            }
+            None => self.props.push(SendProp {
+                index,
+                identifier: definition.identifier,
+                value: SendPropValue::parse(stream, &definition.parse_definition)?,
+            }),
+        }
+        self.prop_count += 1;
+        Ok(())
+    }
+}
+
+/// A packet entities message borrowed from an [`EntityBuffer`]
+#[derive(Debug, Clone, Copy)]
+pub struct PacketEntitiesView<'a> {
+    pub max_entries: u16,
+    pub delta: Option<ServerTick>,
+    pub base_line: BaselineIndex,
+    pub updated_base_line: bool,
+    entities: &'a [BufferedEntity],
+    removed_entities: &'a [EntityId],
+    props: &'a [SendProp],
+}
+
+impl<'a> PacketEntitiesView<'a> {
+    pub fn entities(&self) -> impl ExactSizeIterator<Item = PacketEntityView<'a>> + 'a {
+        let props = self.props;
+        self.entities.iter().map(move |buffered| {
+            let entity = &buffered.entity;
+            PacketEntityView {
+                server_class: entity.server_class,
+                entity_index: entity.entity_index,
+                props: props.get(buffered.props.clone()).unwrap_or_default(),
+                in_pvs: entity.in_pvs,
+                update_type: entity.update_type,
+                serial_number: entity.serial_number,
+                delay: entity.delay,
+                delta: entity.delta,
+                baseline_index: entity.baseline_index,
+            }
+        })
+    }
+
+    pub fn removed_entities(&self) -> &'a [EntityId] {
+        self.removed_entities
+    }
+
+    pub fn to_owned(&self) -> PacketEntitiesMessage {
+        PacketEntitiesMessage {
+            entities: self.entities().map(|entity| entity.to_owned()).collect(),
+            removed_entities: self.removed_entities.to_vec(),
+            max_entries: self.max_entries,
+            delta: self.delta,
+            base_line: self.base_line,
+            updated_base_line: self.updated_base_line,
+        }
+    }
+}
+
+/// A [`PacketEntity`] with the props borrowed from an [`EntityBuffer`]
+#[derive(Debug, Clone, Copy)]
+pub struct PacketEntityView<'a> {
+    pub server_class: ClassId,
+    pub entity_index: EntityId,
+    pub props: &'a [SendProp],
+    pub in_pvs: bool,
+    pub update_type: UpdateType,
+    pub serial_number: u32,
+    pub delay: Option<f32>,
+    pub delta: Option<ServerTick>,
+    pub baseline_index: BaselineIndex,
+}
+
+impl<'a> PacketEntityView<'a> {
+    /// Get a prop by identifier, including props from the baseline for entities entering the pvs
+    ///
+    /// For props that occur multiple times in the send table, the first occurrence is returned.
+    pub fn get_prop_by_identifier(
+        &self,
+        identifier: &SendPropIdentifier,
+        parser_state: &'a ParserState,
+    ) -> Option<Cow<'a, SendProp>> {
+        let index = parser_state.index_for_prop(self.server_class, *identifier)?;
+        if let Some(prop) = find_prop(self.props, index) {
+            return Some(Cow::Borrowed(prop));
+        }
+        if self.update_type != UpdateType::Enter {
+            return None;
+        }
+        let send_table = parser_state
+            .send_tables
+            .get(usize::from(self.server_class))?;
+        match parser_state
+            .get_baseline(
+                self.baseline_index,
+                self.entity_index,
+                self.server_class,
+                send_table,
+                self.delta.is_some(),
+            )
+            .ok()?
+        {
+            Cow::Borrowed(props) => find_prop(props, index).map(Cow::Borrowed),
+            Cow::Owned(props) => find_prop(&props, index).cloned().map(Cow::Owned),
+        }
+    }
+
+    pub fn to_owned(&self) -> PacketEntity {
+        PacketEntity {
+            server_class: self.server_class,
+            entity_index: self.entity_index,
+            props: self.props.to_vec(),
+            in_pvs: self.in_pvs,
+            update_type: self.update_type,
+            serial_number: self.serial_number,
+            delay: self.delay,
+            delta: self.delta,
+            baseline_index: self.baseline_index,
+        }
+    }
+}
+
+/// Read the indexes of the updated props, `read` is called for every prop to read its value
+///
+/// Props excluded by the mask of the reader are skipped.
+fn read_prop_updates<'a>(
+    stream: &mut Stream<'a>,
+    reader: &PropReader,
+    entity_index: EntityId,
+    mut read: impl FnMut(&mut Stream<'a>, u32, &SendPropDefinition) -> Result<()>,
+) -> Result<()> {
+    let send_table = reader.send_table;
+    let mut index: i32 = -1;
+
+    #[cfg(feature = "trace")]
+    trace!(entity_index = display(entity_index), "reading update");
+    #[cfg(not(feature = "trace"))]
+    let _ = entity_index;
+
+    while stream.read()? {
+        let diff: u32 = read_bit_var(stream)?;
+        index = index.saturating_add(diff as i32).saturating_add(1);
+
+        match send_table.flattened_props().get(index as usize) {
+            Some(definition) => {
+                #[cfg(feature = "trace")]
+                trace!(
+                    entity_index = display(entity_index),
+                    index = display(index),
+                    definition = display(definition.identifier),
+                    "reading prop"
+                );
+                let decode = reader.mask.map_or(true, |mask| {
+                    mask.get(index as usize).copied().unwrap_or(true)
+                });
+                if decode {
+                    read(stream, index as u32, definition)?;
+                } else {
+                    SendPropValue::skip(stream, &definition.parse_definition)?;
+                }
+            }
+            None => {
+                return Err(ParseError::PropIndexOutOfBounds {
+                    index,
+                    prop_count: send_table.flattened_props().len(),
+                    table: send_table.name.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Find a prop by index, the props have to be ordered by index
+fn find_prop(props: &[SendProp], index: u32) -> Option<&SendProp> {
+    let position = props.binary_search_by_key(&index, |prop| prop.index).ok()?;
+    props.get(position)
+}
+
+fn props_sorted(props: &[SendProp]) -> bool {
+    props
+        .windows(2)
+        .all(|pair| matches!(pair, [a, b] if a.index < b.index))
+}
+
+/// Update or insert the props, keeping the props ordered by index
+pub(crate) fn apply_prop_updates(props: &mut Vec<SendProp>, updates: &[SendProp]) {
+    if !props_sorted(props) {
+        props.sort_by_key(|prop| prop.index);
+    }
+    for update in updates {
+        match props.binary_search_by_key(&update.index, |prop| prop.index) {
+            Ok(position) => {
+                if let Some(prop) = props.get_mut(position) {
+                    prop.value = update.value.clone();
+                }
+            }
+            Err(position) => props.insert(position, update.clone()),
+        }
+    }
+}
+
 fn read_bit_var<'a, T: BitReadSized<'a, LittleEndian>>(stream: &mut Stream<'a>) -> ReadResult<T> {
     let ty: u8 = stream.read_sized(2)?;
 
@@ -273,6 +568,20 @@ fn write_bit_var(var: u32, stream: &mut BitWriteStream<LittleEndian>) -> ReadRes
     var.write_sized(stream, bits)
 }
 
+#[test]
+fn test_apply_prop_updates() {
+    let prop = |index: u32, value: i64| SendProp {
+        index,
+        identifier: SendPropIdentifier::new("table", &index.to_string()),
+        value: SendPropValue::Integer(value),
+    };
+    let mut props = vec![prop(5, 1), prop(1, 1)];
+    apply_prop_updates(&mut props, &[prop(3, 2), prop(5, 2), prop(0, 2)]);
+    assert_eq!(vec![prop(0, 2), prop(1, 1), prop(3, 2), prop(5, 2)], props);
+    assert_eq!(Some(&prop(3, 2)), find_prop(&props, 3));
+    assert_eq!(None, find_prop(&props, 4));
+}
+
 #[test]
 #[cfg(feature = "write")]
 fn test_bit_var_roundtrip() {
@@ -340,7 +649,7 @@ fn get_entity_for_update(
     Ok(PacketEntity {
         server_class: class_id,
         entity_index,
-        props: Vec::with_capacity(8),
+        props: Vec::new(),
         in_pvs: false,
         update_type,
         serial_number: 0,
@@ -350,35 +659,38 @@ fn get_entity_for_update(
     })
 }
 
-impl Parse<'_> for PacketEntitiesMessage {
-    fn parse(stream: &mut Stream, state: &ParserState) -> Result<Self> {
-        let max_entries = stream.read_sized(11)?;
-        let delta: Option<ServerTick> = stream.read()?;
-        #[derive(BitRead)]
-        struct Header {
-            base_line: BaselineIndex,
-            #[bitbuffer(size = 11)]
-            updated_entries: u16,
-            #[bitbuffer(size = 20)]
-            length: u32,
-            updated_base_line: bool,
-        }
-        let Header {
-            base_line,
-            updated_entries,
-            length,
-            updated_base_line,
-        } = stream.read()?;
-
-        let mut data = stream.read_bits(length as usize)?;
+#[derive(BitRead)]
+struct PacketEntitiesHeader {
+    #[bitbuffer(size = 11)]
+    max_entries: u16,
+    delta: Option<ServerTick>,
+    base_line: BaselineIndex,
+    #[bitbuffer(size = 11)]
+    updated_entries: u16,
+    #[bitbuffer(size = 20)]
+    length: u32,
+    updated_base_line: bool,
+}
 
-        let mut entities = Vec::with_capacity(min(updated_entries, 128) as usize);
-        let mut removed_entities = Vec::new();
+/// The send table to read the props of an entity with, and optionally which of the props to decode
+struct PropReader<'a> {
+    send_table: &'a SendTable,
+    mask: Option<&'a [bool]>,
+}
 
+impl PacketEntitiesHeader {
+    /// Read the entity updates, `handle` is called with every entity and the send table to read
+    /// the props of the entity with, if the update contains props
+    fn read_entities<'a>(
+        &self,
+        data: &mut Stream<'a>,
+        state: &ParserState,
+        mut handle: impl FnMut(&mut Stream<'a>, PacketEntity, Option<PropReader>) -> Result<()>,
+    ) -> Result<()> {
         let mut last_index: i32 = -1;
 
-        for _ in 0..updated_entries {
-            let diff: u32 = read_bit_var(&mut data)?;
+        for _ in 0..self.updated_entries {
+            let diff: u32 = read_bit_var(data)?;
             let index = last_index.saturating_add(diff as i32).saturating_add(1);
             if index >= 2048 {
                 return Err(ParseError::InvalidDemo("invalid entity index"));
@@ -388,25 +700,38 @@ impl Parse<'_> for PacketEntitiesMessage {
             let update_type = data.read()?;
 
             if update_type == UpdateType::Enter {
-                let mut entity =
-                    Self::read_enter(&mut data, entity_index, state, base_line, delta)?;
-                let send_table = get_send_table(state, entity.server_class)?;
-                Self::read_update(&mut data, send_table, &mut entity.props, entity_index)?;
-
-                entities.push(entity);
+                let entity = PacketEntitiesMessage::read_enter(
+                    data,
+                    entity_index,
+                    state,
+                    self.base_line,
+                    self.delta,
+                )?;
+                // entities that update the baseline need all their props for the baseline to stay correct
+                let mask = if self.updated_base_line {
+                    None
+                } else {
+                    state.prop_mask(entity.server_class)
+                };
+                let reader = PropReader {
+                    send_table: get_send_table(state, entity.server_class)?,
+                    mask,
+                };
+                handle(data, entity, Some(reader))?;
             } else if update_type == UpdateType::Delta {
-                let mut entity = get_entity_for_update(state, entity_index, update_type, delta)?;
-                let send_table = get_send_table(state, entity.server_class)?;
-
-                Self::read_update(&mut data, send_table, &mut entity.props, entity_index)?;
+                let mut entity =
+                    get_entity_for_update(state, entity_index, update_type, self.delta)?;
+                let reader = PropReader {
+                    send_table: get_send_table(state, entity.server_class)?,
+                    mask: state.prop_mask(entity.server_class),
+                };
                 entity.in_pvs = true;
-
-                entities.push(entity);
+                handle(data, entity, Some(reader))?;
             } else if state.entity_classes.contains_key(&entity_index) {
-                let entity = get_entity_for_update(state, entity_index, update_type, delta)?;
-                entities.push(entity);
+                let entity = get_entity_for_update(state, entity_index, update_type, self.delta)?;
+                handle(data, entity, None)?;
             } else {
-                entities.push(PacketEntity {
+                let entity = PacketEntity {
                     server_class: 0.into(),
                     entity_index,
                     props: vec![],
@@ -414,27 +739,64 @@ impl Parse<'_> for PacketEntitiesMessage {
                     update_type,
                     serial_number: 0,
                     delay: None,
-                    delta,
+                    delta: self.delta,
                     baseline_index: BaselineIndex::First,
-                });
+                };
+                handle(data, entity, None)?;
             }
 
             last_index = index;
         }
+        Ok(())
+    }
 
-        if delta.is_some() {
+    fn read_removed_entities(&self, data: &mut Stream, removed: &mut Vec<EntityId>) -> Result<()> {
+        if self.delta.is_some() {
             while data.read()? {
-                removed_entities.push(data.read_sized::<u32>(11)?.into())
+                removed.push(data.read_sized::<u32>(11)?.into())
             }
         }
+        Ok(())
+    }
+}
+
+impl Parse<'_> for PacketEntitiesMessage {
+    fn parse(stream: &mut Stream, state: &ParserState) -> Result<Self> {
+        let header: PacketEntitiesHeader = stream.read()?;
+        let mut data = stream.read_bits(header.length as usize)?;
+
+        let mut entities = Vec::with_capacity(min(header.updated_entries, 128) as usize);
+        let mut removed_entities = Vec::new();
+
+        header.read_entities(&mut data, state, |data, mut entity, reader| {
+            if let Some(reader) = reader {
+                entity.props.reserve(8);
+                read_prop_updates(
+                    data,
+                    &reader,
+                    entity.entity_index,
+                    |stream, index, definition| {
+                        entity.props.push(SendProp {
+                            index,
+                            identifier: definition.identifier,
+                            value: SendPropValue::parse(stream, &definition.parse_definition)?,
+                        });
+                        Ok(())
+                    },
+                )?;
+            }
+            entities.push(entity);
+            Ok(())
+        })?;
+        header.read_removed_entities(&mut data, &mut removed_entities)?;
 
         Ok(PacketEntitiesMessage {
             entities,
             removed_entities,
-            max_entries,
-            delta,
-            base_line,
-            updated_base_line,
+            max_entries: header.max_entries,
+            delta: header.delta,
+            base_line: header.base_line,
+            updated_base_line: header.updated_base_line,
         })
     }
 }
@@ -542,45 +904,65 @@ impl PacketEntitiesMessage {
         props: &mut Vec<SendProp>,
         entity_index: EntityId,
     ) -> Result<()> {
-        let mut index: i32 = -1;
-
-        #[cfg(feature = "trace")]
-        trace!(entity_index = display(entity_index), "reading update");
-        #[cfg(not(feature = "trace"))]
-        let _ = entity_index;
-
-        while stream.read()? {
-            let diff: u32 = read_bit_var(stream)?;
-            index = index.saturating_add(diff as i32).saturating_add(1);
-
-            match send_table.flattened_props.get(index as usize) {
-                Some(definition) => {
-                    let value = SendPropValue::parse(stream, &definition.parse_definition)?;
-
-                    #[cfg(feature = "trace")]
-                    trace!(
-                        entity_index = display(entity_index),
-                        index = display(index),
-                        value = debug(&value),
-                        definition = display(definition.identifier),
-                        "reading prop"
-                    );
-                    props.push(SendProp {
-                        index: index as u32,
-                        identifier: definition.identifier,
-                        value,
-                    });
-                }
-                None => {
-                    return Err(ParseError::PropIndexOutOfBounds {
-                        index,
-                        prop_count: send_table.flattened_props.len(),
-                        table: send_table.name.to_string(),
-                    });
-                }
-            }
-        }
+        let reader = PropReader {
+            send_table,
+            mask: None,
+        };
+        read_prop_updates(
+            stream,
+            &reader,
+            entity_index,
+            |stream, index, definition| {
+                props.push(SendProp {
+                    index,
+                    identifier: definition.identifier,
+                    value: SendPropValue::parse(stream, &definition.parse_definition)?,
+                });
+                Ok(())
+            },
+        )
+    }
 
+    /// Parse the message into a reusable buffer instead of allocating new props for every entity
+    ///
+    /// The message is appended to the messages already in the buffer.
+    pub fn parse_into(
+        stream: &mut Stream,
+        state: &ParserState,
+        buffer: &mut EntityBuffer,
+    ) -> Result<()> {
+        let header: PacketEntitiesHeader = stream.read()?;
+        let mut data = stream.read_bits(header.length as usize)?;
+
+        let entities_start = buffer.entities.len();
+        let removed_start = buffer.removed_entities.len();
+
+        header.read_entities(&mut data, state, |data, entity, reader| {
+            let props_start = buffer.prop_count;
+            if let Some(reader) = reader {
+                read_prop_updates(
+                    data,
+                    &reader,
+                    entity.entity_index,
+                    |stream, index, definition| buffer.read_prop(stream, index, definition),
+                )?;
+            }
+            buffer.entities.push(BufferedEntity {
+                entity,
+                props: props_start..buffer.prop_count,
+            });
+            Ok(())
+        })?;
+        header.read_removed_entities(&mut data, &mut buffer.removed_entities)?;
+
+        buffer.messages.push(BufferedMessage {
+            max_entries: header.max_entries,
+            delta: header.delta,
+            base_line: header.base_line,
+            updated_base_line: header.updated_base_line,
+            entities: entities_start..buffer.entities.len(),
+            removed_entities: removed_start..buffer.removed_entities.len(),
+        });
         Ok(())
     }
 
@@ -601,15 +983,13 @@ impl PacketEntitiesMessage {
 
             let index = prop.index as usize;
 
-            let definition =
-                send_table
-                    .flattened_props
-                    .get(index)
-                    .ok_or(ParseError::PropIndexOutOfBounds {
-                        index: index as i32,
-                        prop_count: send_table.flattened_props.len(),
-                        table: send_table.name.to_string(),
-                    })?;
+            let definition = send_table.flattened_props().get(index).ok_or(
+                ParseError::PropIndexOutOfBounds {
+                    index: index as i32,
+                    prop_count: send_table.flattened_props().len(),
+                    table: send_table.name.to_string(),
+                },
+            )?;
             write_bit_var((index as i32 - last_index - 1) as u32, stream)?;
             last_index = index as i32;
             prop.value.encode(stream, &definition.parse_definition)?;
@@ -638,6 +1018,7 @@ impl ParseBitSkip<'_> for PacketEntitiesMessage {
 fn test_packet_entity_message_roundtrip() {
     use crate::demo::packet::datatable::{SendTable, SendTableName, ServerClass, ServerClassName};
     use crate::demo::sendprop::{FloatDefinition, SendPropDefinition, SendPropParseDefinition};
+    use std::sync::Arc;
 
     let mut state = ParserState::new(24, |_| false, false);
     state.set_server_classes(vec![
@@ -652,16 +1033,12 @@ fn test_packet_entity_message_roundtrip() {
             data_table: SendTableName::from("table2"),
         },
     ]);
-    state.send_tables = vec![
-        SendTable {
-            name: SendTableName::from("table1"),
-            needs_decoder: false,
-            flattened_props: vec![],
-        },
-        SendTable {
-            name: SendTableName::from("table2"),
-            needs_decoder: false,
-            flattened_props: vec![
+    state.send_tables = Arc::new(vec![
+        SendTable::new(SendTableName::from("table1"), false, vec![]),
+        SendTable::new(
+            SendTableName::from("table2"),
+            false,
+            vec![
                 SendPropDefinition {
                     identifier: SendPropIdentifier::new("table2", "prop1"),
                     parse_definition: SendPropParseDefinition::Int {
@@ -683,8 +1060,8 @@ fn test_packet_entity_message_roundtrip() {
                     },
                 },
             ],
-        },
-    ];
+        ),
+    ]);
     state
         .entity_classes
         .insert(EntityId::from(4u32), ClassId::from(1));
@@ -788,3 +1165,62 @@ fn test_packet_entity_message_roundtrip() {
         &state,
     );
 }
+
+#[test]
+fn test_enter_props_keep_duplicate_identifiers() {
+    // arrays of data tables contain the same prop identifier at different indexes
+    let state = ParserState::new(24, |_| false, false);
+    let identifier = SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCaptures");
+    let entity = PacketEntity {
+        server_class: ClassId::from(0),
+        entity_index: EntityId::from(1u32),
+        props: vec![
+            SendProp {
+                index: 3,
+                identifier,
+                value: SendPropValue::Integer(1),
+            },
+            SendProp {
+                index: 9,
+                identifier,
+                value: SendPropValue::Integer(2),
+            },
+        ],
+        in_pvs: true,
+        update_type: UpdateType::Enter,
+        serial_number: 0,
+        delay: None,
+        delta: None,
+        baseline_index: BaselineIndex::First,
+    };
+    let props: Vec<_> = entity
+        .props(&state)
+        .map(|prop| (prop.index, prop.value))
+        .collect();
+    assert_eq!(
+        vec![
+            (3, SendPropValue::Integer(1)),
+            (9, SendPropValue::Integer(2))
+        ],
+        props
+    );
+}
+
+#[test]
+fn test_apply_unsorted_props() {
+    let prop = |index: u32| SendProp {
+        index,
+        identifier: SendPropIdentifier::new("DT_Test", &format!("{index}")),
+        value: SendPropValue::Integer(index as i64),
+    };
+    let sorted = [prop(1), prop(4), prop(7)];
+    assert_eq!(Some(4), find_prop(&sorted, 4).map(|prop| prop.index));
+    assert_eq!(None, find_prop(&sorted, 5));
+
+    let mut props = vec![prop(7), prop(1), prop(4), prop(3)];
+    apply_prop_updates(&mut props, &[prop(5), prop(3)]);
+    assert_eq!(
+        vec![1, 3, 4, 5, 7],
+        props.iter().map(|prop| prop.index).collect::<Vec<_>>()
+    );
+}
@@ -5,9 +5,11 @@ use num_traits::{PrimInt, Unsigned};
 use serde::{Deserialize, Serialize};
 use snap::raw::{decompress_len, Decoder};
 
+#[cfg(feature = "write")]
+use crate::demo::lzss::compress;
 use crate::demo::lzss::decompress;
 use crate::demo::packet::stringtable::{
-    ExtraData, FixedUserDataSize, StringTable, StringTableEntry,
+    ExtraData, FixedUserDataSize, StringTable, StringTableCompression, StringTableEntry,
 };
 #[cfg(feature = "write")]
 use crate::demo::parser::Encode;
@@ -57,6 +59,7 @@ impl<'a> Parse<'a> for CreateStringTableMessage<'a> {
 
         let mut table_data = stream.read_bits(length as usize)?;
 
+        let mut compression = StringTableCompression::default();
         if compressed {
             let decompressed_size: u32 = table_data.read()?;
             let compressed_size: u32 = table_data.read()?;
@@ -102,6 +105,7 @@ impl<'a> Parse<'a> for CreateStringTableMessage<'a> {
                     let compressed_data = table_data.read_bytes(compressed_size as usize - 4)?;
                     let mut decompressed_data = Vec::with_capacity(decompressed_size as usize);
                     decompress(&compressed_data, &mut decompressed_data);
+                    compression = StringTableCompression::Lzss;
 
                     if decompressed_data.len() != decompressed_size as usize {
                         return Err(ParseError::UnexpectedDecompressedSize {
@@ -133,6 +137,7 @@ impl<'a> Parse<'a> for CreateStringTableMessage<'a> {
             fixed_user_data_size: fixed_userdata_size,
             client_entries: None,
             compressed,
+            compression,
             name,
         };
         Ok(CreateStringTableMessage { table })
@@ -178,14 +183,35 @@ impl Encode for CreateStringTableMessage<'_> {
                 fixed_size.write(stream)?;
             }
 
-            // no compression for now
-            false.write(stream)?;
+            table.compressed.write(stream)?;
 
             let start = stream.bit_len();
 
             let table_meta = table.get_table_meta();
 
-            write_string_table_update(&table.entries, stream, &table_meta)?;
+            if table.compressed {
+                let mut data = Vec::new();
+                {
+                    let mut data_stream = BitWriteStream::new(&mut data, LittleEndian);
+                    write_string_table_update(&table.entries, &mut data_stream, &table_meta)?;
+                }
+                let (magic, compressed_data) = match table.compression {
+                    StringTableCompression::Snappy => {
+                        (b"SNAP", snap::raw::Encoder::new().compress_vec(&data)?)
+                    }
+                    StringTableCompression::Lzss => {
+                        let mut compressed_data = Vec::with_capacity(data.len() + 8);
+                        compress(&data, &mut compressed_data);
+                        (b"LZSS", compressed_data)
+                    }
+                };
+                (data.len() as u32).write(stream)?;
+                (compressed_data.len() as u32 + 4).write(stream)?;
+                stream.write_bytes(magic)?;
+                stream.write_bytes(&compressed_data)?;
+            } else {
+                write_string_table_update(&table.entries, stream, &table_meta)?;
+            }
 
             let end = stream.bit_len();
             Ok(encode_var_int_fixed((end - start) as u32))
@@ -208,6 +234,7 @@ fn test_create_string_table_roundtrip() {
                 fixed_user_data_size: None,
                 client_entries: None,
                 compressed: false,
+                compression: StringTableCompression::default(),
             },
         },
         &state,
@@ -236,10 +263,38 @@ fn test_create_string_table_roundtrip() {
                 fixed_user_data_size: Some(FixedUserDataSize { size: 12, bits: 4 }),
                 client_entries: None,
                 compressed: false,
+                compression: StringTableCompression::default(),
             },
         },
         &state,
     );
+
+    for compression in [StringTableCompression::Snappy, StringTableCompression::Lzss] {
+        crate::test_roundtrip_encode(
+            CreateStringTableMessage {
+                table: StringTable {
+                    name: "table1".into(),
+                    entries: (0..8)
+                        .map(|index| {
+                            (
+                                index,
+                                StringTableEntry {
+                                    text: Some(format!("entry_{index}").into()),
+                                    extra_data: None,
+                                },
+                            )
+                        })
+                        .collect(),
+                    max_entries: 16,
+                    fixed_user_data_size: None,
+                    client_entries: None,
+                    compressed: true,
+                    compression,
+                },
+            },
+            &state,
+        );
+    }
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -1,6 +1,9 @@
 use bitbuffer::{BitReadBuffer, BitReadStream, LittleEndian};
 
 pub mod data;
+pub mod diff;
+#[cfg(feature = "write")]
+pub mod edit;
 pub mod gameevent_gen;
 pub mod gamevent;
 pub mod header;
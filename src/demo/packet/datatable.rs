@@ -7,11 +7,13 @@ use crate::{Parse, ParseError, ParserState, Result, Stream};
 use bitbuffer::{BitRead, BitReadStream, Endianness};
 #[cfg(feature = "write")]
 use bitbuffer::{BitWrite, BitWriteSized, BitWriteStream, LittleEndian};
+use fnv::{FnvHashMap, FnvHashSet, FnvHasher};
 use parse_display::{Display, FromStr};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::cmp::min;
 use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
 #[cfg(feature = "write")]
 use std::iter::once;
 use std::ops::Deref;
@@ -103,7 +105,7 @@ impl Deref for ServerClassName {
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(BitRead, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(BitRead, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "write", derive(BitWrite))]
 pub struct ServerClass {
     pub id: ClassId,
@@ -313,13 +315,113 @@ fn test_parse_send_table_roundtrip() {
 
 impl ParseSendTable {
     pub fn flatten_props(&self, tables: &[ParseSendTable]) -> Result<Vec<SendPropDefinition>> {
+        TableLookup::new(tables).flatten(self)
+    }
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.needs_decoder.hash(state);
+        self.props.len().hash(state);
+        for prop in &self.props {
+            hash_prop_definition(prop, state);
+        }
+    }
+}
+
+fn hash_prop_definition<H: Hasher>(prop: &RawSendPropDefinition, state: &mut H) {
+    (prop.prop_type as u8).hash(state);
+    prop.identifier.hash(state);
+    prop.flags.bits().hash(state);
+    prop.table_name.hash(state);
+    prop.low_value.map(f32::to_bits).hash(state);
+    prop.high_value.map(f32::to_bits).hash(state);
+    prop.bit_count.hash(state);
+    prop.element_count.hash(state);
+    prop.original_bit_count.hash(state);
+    match &prop.array_property {
+        Some(array_property) => {
+            true.hash(state);
+            hash_prop_definition(array_property, state);
+        }
+        None => false.hash(state),
+    }
+}
+
+/// Get a hash that uniquely identifies the layout of a set of data tables and server classes
+pub fn hash_data_tables(tables: &[ParseSendTable], server_classes: &[ServerClass]) -> u64 {
+    let mut hasher = FnvHasher::default();
+    tables.len().hash(&mut hasher);
+    for table in tables {
+        table.hash(&mut hasher);
+    }
+    server_classes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Flatten the send tables for every server class, indexed by class id
+pub fn flatten_send_tables(
+    tables: &[ParseSendTable],
+    server_classes: &[ServerClass],
+) -> Result<Vec<SendTable>> {
+    let lookup = TableLookup::new(tables);
+    let mut send_tables = Vec::with_capacity(server_classes.len());
+    for class in server_classes {
+        if let Some((_, table)) = lookup.get(&class.data_table) {
+            send_tables.push(SendTable::new(
+                table.name.clone(),
+                table.needs_decoder,
+                lookup.flatten(table)?,
+            ));
+        } else {
+            #[cfg(feature = "trace")]
+            tracing::warn!(class = debug(class), "class without table");
+        }
+    }
+    Ok(send_tables)
+}
+
+/// Indexes the send tables by name so data tables can be resolved without scanning all tables
+struct TableLookup<'a> {
+    tables: &'a [ParseSendTable],
+    indices: FnvHashMap<&'a str, usize>,
+}
+
+impl<'a> TableLookup<'a> {
+    fn new(tables: &'a [ParseSendTable]) -> Self {
+        let mut indices = FnvHashMap::with_capacity_and_hasher(tables.len(), Default::default());
+        for (index, table) in tables.iter().enumerate() {
+            // keep the first table when names are duplicated, consistent with a linear search
+            indices.entry(table.name.as_str()).or_insert(index);
+        }
+        TableLookup { tables, indices }
+    }
+
+    fn get(&self, name: &SendTableName) -> Option<(usize, &'a ParseSendTable)> {
+        let index = *self.indices.get(name.as_str())?;
+        Some((index, self.tables.get(index)?))
+    }
+
+    fn data_table(&self, prop: &RawSendPropDefinition) -> Option<(usize, &'a ParseSendTable)> {
+        if prop.prop_type == SendPropType::DataTable {
+            self.get(prop.table_name.as_ref()?)
+        } else {
+            None
+        }
+    }
+
+    fn flatten(&self, table: &'a ParseSendTable) -> Result<Vec<SendPropDefinition>> {
+        let mut excludes = FnvHashSet::default();
+        let mut processed = vec![false; self.tables.len()];
+        if let Some((index, _)) = self.get(&table.name) {
+            if let Some(processed) = processed.get_mut(index) {
+                *processed = true;
+            }
+        }
+        self.build_excludes(table, &mut processed, &mut excludes);
+
         let mut flat = Vec::with_capacity(32);
-        self.push_props_end(
-            tables,
-            &self.get_excludes(tables),
-            &mut flat,
-            &mut Vec::with_capacity(16),
-        )?;
+        let mut table_stack = vec![false; self.tables.len()];
+        self.push_props_end(table, &excludes, &mut flat, &mut table_stack)?;
 
         // sort often changed props before the others
         let mut start = 0;
@@ -336,79 +438,70 @@ impl ParseSendTable {
         Ok(flat)
     }
 
-    fn get_excludes<'a>(&'a self, tables: &'a [ParseSendTable]) -> Vec<SendPropIdentifier> {
-        let mut excludes = Vec::with_capacity(8);
-
-        self.build_excludes(tables, &mut Vec::with_capacity(8), &mut excludes);
-
-        excludes
-    }
-
-    fn build_excludes<'a>(
-        &'a self,
-        tables: &'a [ParseSendTable],
-        processed_tables: &mut Vec<&'a SendTableName>,
-        excludes: &mut Vec<SendPropIdentifier>,
+    fn build_excludes(
+        &self,
+        table: &'a ParseSendTable,
+        processed: &mut [bool],
+        excludes: &mut FnvHashSet<SendPropIdentifier>,
     ) {
-        processed_tables.push(&self.name);
-
-        for prop in self.props.iter() {
+        for prop in table.props.iter() {
             if let Some(exclude_table) = prop.get_exclude_table() {
-                excludes.push(SendPropIdentifier::new(
+                excludes.insert(SendPropIdentifier::new(
                     exclude_table.as_str(),
                     prop.name.as_str(),
-                ))
-            } else if let Some(table) = prop.get_data_table(tables) {
-                if !processed_tables.contains(&&table.name) {
-                    table.build_excludes(tables, processed_tables, excludes);
+                ));
+            } else if let Some((index, data_table)) = self.data_table(prop) {
+                if let Some(seen @ false) = processed.get_mut(index) {
+                    *seen = true;
+                    self.build_excludes(data_table, processed, excludes);
                 }
             }
         }
     }
 
-    // TODO: below is a direct port from the js which is a direct port from C++ and not very optimal
-    fn push_props_end<'a>(
-        &'a self,
-        tables: &'a [ParseSendTable],
-        excludes: &[SendPropIdentifier],
+    fn push_props_end(
+        &self,
+        table: &'a ParseSendTable,
+        excludes: &FnvHashSet<SendPropIdentifier>,
         props: &mut Vec<SendPropDefinition>,
-        table_stack: &mut Vec<&'a SendTableName>,
+        table_stack: &mut [bool],
     ) -> Result<()> {
         let mut local_props = Vec::new();
 
-        self.push_props_collapse(tables, excludes, &mut local_props, props, table_stack)?;
+        self.push_props_collapse(table, excludes, &mut local_props, props, table_stack)?;
         props.extend_from_slice(&local_props);
         Ok(())
     }
 
-    fn push_props_collapse<'a>(
-        &'a self,
-        tables: &'a [ParseSendTable],
-        excludes: &[SendPropIdentifier],
+    fn push_props_collapse(
+        &self,
+        table: &'a ParseSendTable,
+        excludes: &FnvHashSet<SendPropIdentifier>,
         local_props: &mut Vec<SendPropDefinition>,
         props: &mut Vec<SendPropDefinition>,
-        table_stack: &mut Vec<&'a SendTableName>,
+        table_stack: &mut [bool],
     ) -> Result<()> {
-        table_stack.push(&self.name);
+        let index = self.get(&table.name).map(|(index, _)| index);
+        Self::set_in_stack(table_stack, index, true);
 
-        let result = self
+        let result = table
             .props
             .iter()
             .filter(|prop| !prop.is_exclude())
-            .filter(|prop| !excludes.iter().any(|exclude| *exclude == prop.identifier()))
+            .filter(|prop| !excludes.contains(&prop.identifier()))
             .try_for_each(|prop| {
-                if let Some(table) = prop.get_data_table(tables) {
-                    if !table_stack.contains(&&table.name) {
+                if let Some((index, data_table)) = self.data_table(prop) {
+                    if table_stack.get(index) == Some(&false) {
                         if prop.flags.contains(SendPropFlag::Collapsible) {
-                            table.push_props_collapse(
-                                tables,
+                            self.push_props_collapse(
+                                data_table,
                                 excludes,
                                 local_props,
                                 props,
                                 table_stack,
                             )?;
                         } else {
-                            table.push_props_end(tables, excludes, props, table_stack)?;
+                            self.push_props_end(data_table, excludes, props, table_stack)?;
                         }
                     }
                 } else {
@@ -417,18 +510,74 @@ impl ParseSendTable {
                 Ok(())
             });
 
-        table_stack.pop();
+        Self::set_in_stack(table_stack, index, false);
 
         result
     }
+
+    fn set_in_stack(table_stack: &mut [bool], index: Option<usize>, value: bool) {
+        if let Some(in_stack) = index.and_then(|index| table_stack.get_mut(index)) {
+            *in_stack = value;
+        }
+    }
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "SendTableData")]
 pub struct SendTable {
     pub name: SendTableName,
     pub needs_decoder: bool,
-    pub flattened_props: Vec<SendPropDefinition>,
+    flattened_props: Vec<SendPropDefinition>,
+    /// The index of the first flattened prop for every identifier
+    #[serde(skip)]
+    prop_indexes: FnvHashMap<SendPropIdentifier, u32>,
+}
+
+impl SendTable {
+    pub fn new(
+        name: SendTableName,
+        needs_decoder: bool,
+        flattened_props: Vec<SendPropDefinition>,
+    ) -> Self {
+        let mut prop_indexes =
+            FnvHashMap::with_capacity_and_hasher(flattened_props.len(), Default::default());
+        for (index, prop) in flattened_props.iter().enumerate() {
+            prop_indexes.entry(prop.identifier).or_insert(index as u32);
+        }
+        SendTable {
+            name,
+            needs_decoder,
+            flattened_props,
+            prop_indexes,
+        }
+    }
+
+    /// The props of all tables for the class, indexed by the prop index used in entity updates
+    pub fn flattened_props(&self) -> &[SendPropDefinition] {
+        &self.flattened_props
+    }
+
+    /// The flattened index of a prop
+    ///
+    /// For props that occur multiple times in the table, the index of the first occurrence is returned.
+    pub fn prop_index(&self, identifier: SendPropIdentifier) -> Option<u32> {
+        self.prop_indexes.get(&identifier).copied()
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Deserialize)]
+struct SendTableData {
+    name: SendTableName,
+    needs_decoder: bool,
+    flattened_props: Vec<SendPropDefinition>,
+}
+
+impl From<SendTableData> for SendTable {
+    fn from(data: SendTableData) -> Self {
+        SendTable::new(data.name, data.needs_decoder, data.flattened_props)
+    }
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -4,6 +4,7 @@ use bitbuffer::{BitWrite, BitWriteStream, LittleEndian};
 use serde::{Deserialize, Serialize};
 
 use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::PacketEntitiesMessage;
 use crate::demo::message::{Message, MessageType};
 #[cfg(feature = "write")]
 use crate::demo::parser::Encode;
@@ -80,7 +81,13 @@ impl<'a> Parse<'a> for MessagePacket<'a> {
                 span!(Level::DEBUG, "reading message", message_type = ?message_type, tick = ?tick)
                     .entered();
 
-            if state.should_parse_message(message_type) && message_type != MessageType::Empty {
+            if message_type == MessageType::PacketEntities && state.borrows_entities() {
+                #[cfg(feature = "trace")]
+                event!(Level::TRACE, "parsing message into entity buffer");
+                let mut buffer = state.entity_buffer.borrow_mut();
+                PacketEntitiesMessage::parse_into(&mut packet_data, state, &mut buffer)?;
+            } else if state.should_parse_message(message_type) && message_type != MessageType::Empty
+            {
                 #[cfg(feature = "trace")]
                 event!(Level::TRACE, "parsing message");
                 messages.push(Message::from_type(message_type, &mut packet_data, state)?);
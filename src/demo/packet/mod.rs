@@ -105,8 +105,10 @@ impl<'a> Parse<'a> for Packet<'a> {
         let packet_type = PacketType::read(stream)?;
         #[cfg(feature = "trace")]
         {
-            let tick: u32 = stream.read()?;
-            stream.set_pos(stream.pos() - 32)?;
+            // the tick of a stop packet is only 24 bits and can be at the end of the demo
+            let tick_bits = stream.bits_left().min(32);
+            let tick: u32 = stream.read_int(tick_bits)?;
+            stream.set_pos(stream.pos() - tick_bits)?;
             let _span =
                 span!(Level::INFO, "reading packet", packet_type = ?packet_type, tick = tick)
                     .entered();
@@ -32,16 +32,30 @@ pub struct StringTable<'a> {
     pub fixed_user_data_size: Option<FixedUserDataSize>,
     pub client_entries: Option<Vec<StringTableEntry<'a>>>,
     pub compressed: bool,
+    /// The compression used when `compressed` is set
+    #[serde(default)]
+    pub compression: StringTableCompression,
+}
+
+/// The algorithm used to compress the data of a string table
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StringTableCompression {
+    #[default]
+    Snappy,
+    Lzss,
 }
 
 impl PartialEq for StringTable<'_> {
     fn eq(&self, other: &Self) -> bool {
-        // ignore `compresses` until we encode compressed
+        // the compression type is only relevant for compressed tables
         self.name.eq(&other.name)
             && (self.entries.eq(&other.entries))
             && (self.max_entries.eq(&other.max_entries))
             && (self.fixed_user_data_size.eq(&other.fixed_user_data_size))
             && (self.client_entries.eq(&other.client_entries))
+            && (self.compressed.eq(&other.compressed))
+            && (!self.compressed || self.compression.eq(&other.compression))
     }
 }
 
@@ -78,6 +92,7 @@ impl<'a> BitRead<'a, LittleEndian> for StringTable<'a> {
             fixed_user_data_size: None,
             client_entries,
             compressed: false,
+            compression: StringTableCompression::default(),
         })
     }
 }
@@ -111,6 +126,7 @@ fn test_string_table_roundtrip() {
         fixed_user_data_size: None,
         client_entries: None,
         compressed: false,
+        compression: StringTableCompression::default(),
     });
     crate::test_roundtrip_write(StringTable {
         name: "foo".into(),
@@ -125,6 +141,7 @@ fn test_string_table_roundtrip() {
         fixed_user_data_size: None,
         client_entries: None,
         compressed: false,
+        compression: StringTableCompression::default(),
     });
     crate::test_roundtrip_write(StringTable {
         name: "foo".into(),
@@ -151,6 +168,7 @@ fn test_string_table_roundtrip() {
             extra_data: None,
         }]),
         compressed: false,
+        compression: StringTableCompression::default(),
     });
 }
 
@@ -300,6 +318,7 @@ fn test_string_table_packet_roundtrip() {
                 fixed_user_data_size: None,
                 client_entries: None,
                 compressed: false,
+                compression: StringTableCompression::default(),
             }],
         },
         &state,
@@ -321,6 +340,7 @@ fn test_string_table_packet_roundtrip() {
                     fixed_user_data_size: None,
                     client_entries: None,
                     compressed: false,
+                    compression: StringTableCompression::default(),
                 },
                 StringTable {
                     name: "table2".into(),
@@ -347,6 +367,7 @@ fn test_string_table_packet_roundtrip() {
                         extra_data: None,
                     }]),
                     compressed: false,
+                    compression: StringTableCompression::default(),
                 },
             ],
         },
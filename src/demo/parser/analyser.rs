@@ -1,3 +1,4 @@
+use crate::demo::data::items::{get_item_schema_by_log_name, ItemSchema};
 use crate::demo::data::{DemoTick, ServerTick};
 use crate::demo::gameevent_gen::{
     GameEvent, PlayerDeathEvent, PlayerSpawnEvent, TeamPlayRoundWinEvent,
@@ -366,6 +367,11 @@ impl Death {
             victim: UserId::from(event.user_id),
         }
     }
+
+    /// Look up the weapon used in the bundled item table
+    pub fn weapon_schema(&self) -> Option<&'static ItemSchema> {
+        get_item_schema_by_log_name(&self.weapon)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
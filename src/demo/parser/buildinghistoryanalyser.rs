@@ -0,0 +1,575 @@
+use crate::demo::data::game_state::{Building, BuildingClass, GameState, Handle};
+use crate::demo::data::DemoTick;
+use crate::demo::gameevent_gen::{ObjectDestroyedEvent, PlayerDeathEvent, PlayerTeleportedEvent};
+use crate::demo::gamevent::GameEvent;
+use crate::demo::message::gameevent::GameEventMessage;
+use crate::demo::message::packetentities::{EntityId, PacketEntitiesMessage, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::packet::message::MessagePacketMeta;
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::parser::analyser::{Team, UserId};
+use crate::demo::parser::gamestateanalyser::GameStateAnalyser;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::vector::Vector;
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickRange {
+    pub start: DemoTick,
+    /// `None` if the range was still open at the end of the demo
+    pub end: Option<DemoTick>,
+}
+
+impl TickRange {
+    fn open(start: DemoTick) -> Self {
+        TickRange { start, end: None }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelUp {
+    pub tick: DemoTick,
+    pub level: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeleporterUse {
+    pub tick: DemoTick,
+    pub user: UserId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildingKill {
+    pub tick: DemoTick,
+    pub victim: UserId,
+    pub weapon: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum BuildingEnd {
+    /// Destroyed by an enemy
+    Destroyed {
+        attacker: UserId,
+        assister: Option<UserId>,
+        weapon: String,
+    },
+    /// Destroyed by the builder
+    Detonated,
+    /// Removed without being destroyed, e.g. by a round restart
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildingDestruction {
+    pub tick: DemoTick,
+    pub cause: BuildingEnd,
+}
+
+/// The full history of a single building
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildingLifecycle {
+    pub entity: EntityId,
+    pub class: BuildingClass,
+    pub builder: UserId,
+    pub team: Team,
+    pub position: Vector,
+    pub is_mini: bool,
+    /// The first tick the building was seen
+    pub build_start: DemoTick,
+    /// The tick construction finished
+    pub built: Option<DemoTick>,
+    pub level_ups: Vec<LevelUp>,
+    /// Periods where the sentry was controlled by the wrangler
+    pub wrangled: Vec<TickRange>,
+    pub sapped: Vec<TickRange>,
+    /// Periods where the building was picked up by the builder
+    pub carried: Vec<TickRange>,
+    pub teleports: Vec<TeleporterUse>,
+    pub kills: Vec<BuildingKill>,
+    pub destroyed: Option<BuildingDestruction>,
+}
+
+impl BuildingLifecycle {
+    fn new(building: &Building, tick: DemoTick) -> Self {
+        BuildingLifecycle {
+            entity: building.entity_id(),
+            class: building.class(),
+            builder: building.builder(),
+            team: building.team(),
+            position: building.position(),
+            is_mini: matches!(building, Building::Sentry(sentry) if sentry.is_mini),
+            build_start: tick,
+            built: None,
+            level_ups: Vec::new(),
+            wrangled: Vec::new(),
+            sapped: Vec::new(),
+            carried: Vec::new(),
+            teleports: Vec::new(),
+            kills: Vec::new(),
+            destroyed: None,
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.destroyed.is_none()
+    }
+}
+
+/// Update a list of ranges from the current state, opening or closing the last range as needed
+fn update_ranges(ranges: &mut Vec<TickRange>, active: bool, tick: DemoTick) {
+    let open = ranges.last_mut().filter(|range| range.end.is_none());
+    match (open, active) {
+        (None, true) => ranges.push(TickRange::open(tick)),
+        (Some(range), false) => range.end = Some(tick),
+        _ => {}
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildingHistory {
+    /// All buildings in the order they were built
+    pub buildings: Vec<BuildingLifecycle>,
+}
+
+impl BuildingHistory {
+    pub fn for_builder(&self, builder: UserId) -> impl Iterator<Item = &BuildingLifecycle> {
+        self.buildings
+            .iter()
+            .filter(move |building| building.builder == builder)
+    }
+
+    pub fn by_builder(&self) -> BTreeMap<UserId, Vec<&BuildingLifecycle>> {
+        let mut result: BTreeMap<UserId, Vec<&BuildingLifecycle>> = BTreeMap::new();
+        for building in &self.buildings {
+            result.entry(building.builder).or_default().push(building);
+        }
+        result
+    }
+}
+
+/// Records the lifecycle of every sentry, dispenser and teleporter in the demo
+#[derive(Default, Debug)]
+pub struct BuildingHistoryAnalyser {
+    game_state: GameStateAnalyser,
+    state: BuildingHistory,
+    // lifecycle of the live buildings, by building entity
+    alive: BTreeMap<EntityId, usize>,
+    // raw builder handle, by lifecycle index
+    builder_handles: BTreeMap<usize, Handle>,
+    // serial number of every entity, from the last time it entered
+    serials: BTreeMap<EntityId, u32>,
+    // serial number of buildings that ended while their entity still exists, by building entity
+    ended: BTreeMap<EntityId, u32>,
+}
+
+impl MessageHandler for BuildingHistoryAnalyser {
+    type Output = BuildingHistory;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        GameStateAnalyser::does_handle(message_type)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        if let Message::GameEvent(GameEventMessage { event, .. }) = message {
+            self.handle_event(event, tick);
+        }
+
+        self.game_state.handle_message(message, tick, parser_state);
+
+        if let Message::PacketEntities(entities) = message {
+            self.update_entities(entities);
+            self.update_buildings(tick);
+            self.resolve_builders();
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .handle_string_entry(table, index, entry, parser_state)
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .handle_data_tables(parse_tables, server_classes, parser_state)
+    }
+
+    fn handle_packet_meta(
+        &mut self,
+        tick: DemoTick,
+        meta: &MessagePacketMeta,
+        parser_state: &ParserState,
+    ) {
+        self.game_state.handle_packet_meta(tick, meta, parser_state)
+    }
+
+    fn into_output(mut self, _state: &ParserState) -> Self::Output {
+        self.resolve_builders();
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for BuildingHistoryAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl BuildingHistoryAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The game state the building history is derived from
+    pub fn game_state(&self) -> &GameState {
+        &self.game_state.state
+    }
+
+    fn alive_mut(&mut self, entity: EntityId) -> Option<&mut BuildingLifecycle> {
+        let index = *self.alive.get(&entity)?;
+        self.state.buildings.get_mut(index)
+    }
+
+    fn end_building(&mut self, entity: EntityId, tick: DemoTick, cause: BuildingEnd) {
+        if self.alive.contains_key(&entity) && self.game_state.state.buildings.contains_key(&entity)
+        {
+            // the entity can stay around for a bit, it shouldn't be seen as a new building
+            let serial = self.serials.get(&entity).copied().unwrap_or_default();
+            self.ended.insert(entity, serial);
+        }
+        if let Some(building) = self.alive_mut(entity) {
+            building.destroyed = Some(BuildingDestruction { tick, cause });
+            for ranges in [
+                &mut building.wrangled,
+                &mut building.sapped,
+                &mut building.carried,
+            ] {
+                update_ranges(ranges, false, tick);
+            }
+        }
+        self.alive.remove(&entity);
+    }
+
+    fn end_all(&mut self, tick: DemoTick) {
+        let alive: Vec<EntityId> = self.alive.keys().copied().collect();
+        for entity in alive {
+            self.end_building(entity, tick, BuildingEnd::Removed);
+        }
+    }
+
+    fn handle_event(&mut self, event: &GameEvent, tick: DemoTick) {
+        match event {
+            GameEvent::ObjectDestroyed(destroyed) => self.handle_destroyed(destroyed, tick),
+            GameEvent::ObjectDetonated(detonated) => {
+                self.end_building(
+                    EntityId::from(detonated.index as u32),
+                    tick,
+                    BuildingEnd::Detonated,
+                );
+            }
+            GameEvent::ObjectRemoved(removed) => {
+                self.end_building(
+                    EntityId::from(removed.index as u32),
+                    tick,
+                    BuildingEnd::Removed,
+                );
+            }
+            GameEvent::PlayerCarryObject(carry) => {
+                if let Some(building) = self.alive_mut(EntityId::from(carry.index as u32)) {
+                    update_ranges(&mut building.carried, true, tick);
+                }
+            }
+            GameEvent::PlayerDropObject(drop) => {
+                if let Some(building) = self.alive_mut(EntityId::from(drop.index as u32)) {
+                    update_ranges(&mut building.carried, false, tick);
+                }
+            }
+            GameEvent::PlayerTeleported(teleported) => self.handle_teleported(teleported, tick),
+            GameEvent::PlayerDeath(death) => self.handle_death(death, tick),
+            GameEvent::RoundStart(_) | GameEvent::TeamPlayRoundStart(_) => self.end_all(tick),
+            _ => {}
+        }
+    }
+
+    fn handle_destroyed(&mut self, destroyed: &ObjectDestroyedEvent, tick: DemoTick) {
+        let cause = BuildingEnd::Destroyed {
+            attacker: UserId::from(destroyed.attacker),
+            assister: (destroyed.assister > 0).then(|| UserId::from(destroyed.assister)),
+            weapon: destroyed.weapon.to_string(),
+        };
+        self.end_building(EntityId::from(destroyed.index as u32), tick, cause);
+    }
+
+    fn handle_teleported(&mut self, teleported: &PlayerTeleportedEvent, tick: DemoTick) {
+        let builder = UserId::from(teleported.builder_id);
+        let state = &self.game_state.state;
+        let entrance = state
+            .buildings
+            .values()
+            .find_map(|building| match building {
+                Building::Teleporter(teleporter)
+                    if teleporter.builder == builder && teleporter.is_entrance =>
+                {
+                    Some(teleporter.entity)
+                }
+                _ => None,
+            });
+        if let Some(building) = entrance.and_then(|entrance| self.alive_mut(entrance)) {
+            building.teleports.push(TeleporterUse {
+                tick,
+                user: UserId::from(teleported.user_id),
+            });
+        }
+    }
+
+    fn handle_death(&mut self, death: &PlayerDeathEvent, tick: DemoTick) {
+        let weapon = death.weapon.to_string();
+        let inflictor = EntityId::from(death.inflictor_ent_index);
+        // sentry rockets have the rocket as inflictor, so we fall back to the sentry of the attacker
+        let entity = if self.alive.contains_key(&inflictor) {
+            Some(inflictor)
+        } else if weapon.starts_with("obj_sentrygun") || weapon.starts_with("obj_minisentry") {
+            let attacker = UserId::from(death.attacker);
+            self.alive
+                .iter()
+                .filter_map(|(entity, index)| Some((*entity, self.state.buildings.get(*index)?)))
+                .find(|(_, building)| {
+                    building.class == BuildingClass::Sentry && building.builder == attacker
+                })
+                .map(|(entity, _)| entity)
+        } else {
+            None
+        };
+
+        if let Some(building) = entity.and_then(|entity| self.alive_mut(entity)) {
+            building.kills.push(BuildingKill {
+                tick,
+                victim: UserId::from(death.user_id),
+                weapon,
+            });
+        }
+    }
+
+    /// Forget ended buildings once their entity is deleted or re-used
+    fn update_entities(&mut self, message: &PacketEntitiesMessage) {
+        for entity in &message.entities {
+            match entity.update_type {
+                UpdateType::Enter => self.entity_entered(entity.entity_index, entity.serial_number),
+                UpdateType::Delete => self.entity_deleted(entity.entity_index),
+                _ => {}
+            }
+        }
+        for entity in &message.removed_entities {
+            self.entity_deleted(*entity);
+        }
+    }
+
+    fn entity_entered(&mut self, entity: EntityId, serial: u32) {
+        self.serials.insert(entity, serial);
+        if self
+            .ended
+            .get(&entity)
+            .is_some_and(|ended| *ended != serial)
+        {
+            self.ended.remove(&entity);
+        }
+    }
+
+    fn entity_deleted(&mut self, entity: EntityId) {
+        self.serials.remove(&entity);
+        self.ended.remove(&entity);
+    }
+
+    fn update_buildings(&mut self, tick: DemoTick) {
+        let state = &self.game_state.state;
+
+        for (entity, building) in state.buildings.iter() {
+            if self.ended.contains_key(entity) {
+                continue;
+            }
+            let index = match self.alive.get(entity) {
+                Some(index) => *index,
+                None => {
+                    let index = self.state.buildings.len();
+                    self.state
+                        .buildings
+                        .push(BuildingLifecycle::new(building, tick));
+                    self.alive.insert(*entity, index);
+                    index
+                }
+            };
+            let Some(lifecycle) = self.state.buildings.get_mut(index) else {
+                continue;
+            };
+            if lifecycle.builder == UserId::default() {
+                lifecycle.builder = building.builder();
+            }
+            if building.builder_handle() != Handle::default() {
+                self.builder_handles
+                    .insert(index, building.builder_handle());
+            }
+
+            let (constructing, level) = match building {
+                Building::Sentry(sentry) => (sentry.building, sentry.level),
+                Building::Dispenser(dispenser) => (dispenser.building, dispenser.level),
+                Building::Teleporter(teleporter) => (teleporter.building, teleporter.level),
+            };
+
+            // the position isn't always known in the first update
+            if lifecycle.built.is_none() {
+                lifecycle.position = building.position();
+                if !constructing && level > 0 {
+                    lifecycle.built = Some(tick);
+                }
+            }
+
+            let last_level = lifecycle
+                .level_ups
+                .last()
+                .map(|level_up| level_up.level)
+                .unwrap_or(1);
+            if lifecycle.built.is_some() && level > last_level {
+                lifecycle.level_ups.push(LevelUp { tick, level });
+            }
+
+            if let Building::Sentry(sentry) = building {
+                lifecycle.is_mini = sentry.is_mini;
+                update_ranges(&mut lifecycle.wrangled, sentry.player_controlled, tick);
+            }
+            update_ranges(&mut lifecycle.sapped, building.sapped(), tick);
+        }
+
+        // buildings removed without an event
+        let removed: Vec<EntityId> = self
+            .alive
+            .keys()
+            .filter(|entity| !state.buildings.contains_key(entity))
+            .copied()
+            .collect();
+        for entity in removed {
+            self.end_building(entity, tick, BuildingEnd::Removed);
+        }
+    }
+
+    /// Resolve the builder handles against the player list for buildings without a known builder
+    ///
+    /// The builder handle is often sent before the user info of the builder is known.
+    fn resolve_builders(&mut self) {
+        let state = &self.game_state.state;
+        for (index, handle) in self.builder_handles.iter() {
+            let Some(lifecycle) = self.state.buildings.get_mut(*index) else {
+                continue;
+            };
+            if lifecycle.builder != UserId::default() {
+                continue;
+            }
+            if let Some(user_id) = handle
+                .entity_id()
+                .and_then(|entity| state.get_player(entity))
+                .and_then(|player| player.info.as_ref())
+                .map(|info| info.user_id)
+            {
+                lifecycle.builder = user_id;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_update_ranges() {
+    let mut ranges = Vec::new();
+    update_ranges(&mut ranges, false, DemoTick::from(1u32));
+    update_ranges(&mut ranges, true, DemoTick::from(2u32));
+    update_ranges(&mut ranges, true, DemoTick::from(3u32));
+    update_ranges(&mut ranges, false, DemoTick::from(4u32));
+    update_ranges(&mut ranges, true, DemoTick::from(5u32));
+    assert_eq!(
+        vec![
+            TickRange {
+                start: DemoTick::from(2u32),
+                end: Some(DemoTick::from(4u32))
+            },
+            TickRange {
+                start: DemoTick::from(5u32),
+                end: None
+            }
+        ],
+        ranges
+    );
+}
+
+#[test]
+fn test_resolve_late_builder() {
+    use crate::demo::parser::analyser::{ClassList, UserInfo};
+
+    let mut analyser = BuildingHistoryAnalyser::new();
+    analyser
+        .game_state
+        .state
+        .get_or_create_building(EntityId::from(30u32), BuildingClass::Dispenser);
+    analyser.update_buildings(DemoTick::from(1u32));
+    analyser.builder_handles.insert(0, Handle(2));
+
+    // the user info of the builder isn't known yet
+    analyser.resolve_builders();
+    assert_eq!(UserId::default(), analyser.state.buildings[0].builder);
+
+    let player = analyser
+        .game_state
+        .state
+        .get_or_create_player(EntityId::from(2u32));
+    player.info = Some(UserInfo {
+        classes: ClassList::default(),
+        name: "engineer".into(),
+        user_id: UserId::from(4u16),
+        steam_id: String::new(),
+        entity_id: EntityId::from(2u32),
+        team: Team::Blue,
+    });
+
+    let history = analyser.into_output(&ParserState::new(24, |_| false, false));
+    assert_eq!(UserId::from(4u16), history.buildings[0].builder);
+}
+
+#[test]
+fn test_ended_building_stays_ended() {
+    let entity = EntityId::from(30u32);
+    let mut analyser = BuildingHistoryAnalyser::new();
+    analyser.entity_entered(entity, 5);
+    analyser
+        .game_state
+        .state
+        .get_or_create_building(entity, BuildingClass::Sentry);
+    analyser.update_buildings(DemoTick::from(1u32));
+    analyser.end_building(entity, DemoTick::from(2u32), BuildingEnd::Detonated);
+
+    // the entity is still in the game state after the event
+    analyser.update_buildings(DemoTick::from(3u32));
+    analyser.entity_entered(entity, 5);
+    analyser.update_buildings(DemoTick::from(4u32));
+    assert_eq!(1, analyser.state.buildings.len());
+
+    // re-used for a new building
+    analyser.entity_entered(entity, 6);
+    analyser.update_buildings(DemoTick::from(5u32));
+    assert_eq!(2, analyser.state.buildings.len());
+    assert_eq!(
+        DemoTick::from(5u32),
+        analyser.state.buildings[1].build_start
+    );
+    assert!(analyser.state.buildings[1].is_alive());
+}
@@ -0,0 +1,216 @@
+use crate::demo::data::game_state::Handle;
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{EntityId, PacketEntitiesMessage, PacketEntity};
+use crate::demo::message::{Message, MessageType, UpdateType};
+use crate::demo::packet::datatable::{ClassId, ParseSendTable, ServerClass, ServerClassName};
+use crate::demo::parser::handler::BorrowMessageHandler;
+use crate::demo::parser::MessageHandler;
+use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The full current state of an entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackedEntity {
+    pub id: EntityId,
+    pub class: ClassId,
+    pub class_name: ServerClassName,
+    pub serial: u32,
+    /// Whether the entity is in the PVS of the recording player
+    ///
+    /// Entities outside the PVS don't receive updates, so their props can be out of date
+    pub in_pvs: bool,
+    /// All props of the entity, including those from the baseline, by flattened prop index
+    pub props: BTreeMap<u32, SendProp>,
+}
+
+impl TrackedEntity {
+    fn new(entity: &PacketEntity, class_name: ServerClassName, parser_state: &ParserState) -> Self {
+        TrackedEntity {
+            id: entity.entity_index,
+            class: entity.server_class,
+            class_name,
+            serial: entity.serial_number,
+            in_pvs: entity.in_pvs,
+            props: entity
+                .props(parser_state)
+                .map(|prop| (prop.index, prop))
+                .collect(),
+        }
+    }
+
+    fn apply_update(&mut self, props: &[SendProp]) {
+        for prop in props {
+            self.props.insert(prop.index, prop.clone());
+        }
+    }
+
+    /// The handle other entities use to refer to this entity
+    pub fn handle(&self) -> Handle {
+        Handle(i64::from(u32::from(self.id)) | (i64::from(self.serial) << 11))
+    }
+
+    pub fn props(&self) -> impl Iterator<Item = &SendProp> {
+        self.props.values()
+    }
+
+    /// Get a prop by identifier
+    ///
+    /// For props that occur multiple times in the flattened props, the first one is returned
+    pub fn get_prop(&self, identifier: SendPropIdentifier) -> Option<&SendProp> {
+        self.props
+            .values()
+            .find(|prop| prop.identifier == identifier)
+    }
+
+    pub fn get_prop_value<T: for<'a> TryFrom<&'a SendPropValue>>(
+        &self,
+        identifier: SendPropIdentifier,
+    ) -> Option<T> {
+        self.get_prop(identifier)
+            .and_then(|prop| T::try_from(&prop.value).ok())
+    }
+}
+
+/// All live entities, by entity id
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackedEntities {
+    pub entities: BTreeMap<EntityId, TrackedEntity>,
+}
+
+impl TrackedEntities {
+    pub fn get(&self, id: EntityId) -> Option<&TrackedEntity> {
+        self.entities.get(&id)
+    }
+
+    /// Get the entity a handle refers to
+    ///
+    /// Returns `None` if the entity slot has been re-used by a different entity since the handle was created
+    pub fn get_by_handle(&self, handle: Handle) -> Option<&TrackedEntity> {
+        self.get(handle.entity_id()?)
+            .filter(|entity| entity.serial == handle.serial())
+    }
+
+    pub fn by_class<'a>(
+        &'a self,
+        class_name: &'a str,
+    ) -> impl Iterator<Item = &'a TrackedEntity> + 'a {
+        self.entities
+            .values()
+            .filter(move |entity| entity.class_name.as_str() == class_name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TrackedEntity> {
+        self.entities.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+/// Keeps track of the full prop state of every entity
+///
+/// Unlike the entities in [`PacketEntitiesMessage`], which only contain the props changed in that packet,
+/// the tracked entities contain every prop, with the props not send by the server resolved from the baselines.
+///
+/// The tracker can be embedded in other analysers by forwarding `handle_data_tables` and calling
+/// [`EntityTracker::handle_packet_entities`] for every `PacketEntities` message.
+#[derive(Default, Debug)]
+pub struct EntityTracker {
+    pub state: TrackedEntities,
+    class_names: Vec<ServerClassName>, // indexed by ClassId
+}
+
+impl MessageHandler for EntityTracker {
+    type Output = TrackedEntities;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn handle_message(&mut self, message: &Message, _tick: DemoTick, parser_state: &ParserState) {
+        if let Message::PacketEntities(message) = message {
+            self.handle_packet_entities(message, parser_state);
+        }
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        _parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _parser_state: &ParserState,
+    ) {
+        self.class_names = server_classes
+            .iter()
+            .map(|class| &class.name)
+            .cloned()
+            .collect();
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for EntityTracker {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl EntityTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle_packet_entities(
+        &mut self,
+        message: &PacketEntitiesMessage,
+        parser_state: &ParserState,
+    ) {
+        if message.delta.is_none() {
+            // full update, every live entity is included in the packet
+            self.state.entities.clear();
+        }
+
+        for entity in &message.entities {
+            self.handle_entity(entity, parser_state);
+        }
+        for id in &message.removed_entities {
+            self.state.entities.remove(id);
+        }
+    }
+
+    fn handle_entity(&mut self, entity: &PacketEntity, parser_state: &ParserState) {
+        match entity.update_type {
+            UpdateType::Enter => {
+                let Some(class_name) = self.class_names.get(usize::from(entity.server_class))
+                else {
+                    return;
+                };
+                let tracked = TrackedEntity::new(entity, class_name.clone(), parser_state);
+                self.state.entities.insert(entity.entity_index, tracked);
+            }
+            UpdateType::Delta => {
+                if let Some(tracked) = self.state.entities.get_mut(&entity.entity_index) {
+                    tracked.in_pvs = true;
+                    tracked.apply_update(&entity.props);
+                }
+            }
+            UpdateType::Leave => {
+                if let Some(tracked) = self.state.entities.get_mut(&entity.entity_index) {
+                    tracked.in_pvs = false;
+                }
+            }
+            UpdateType::Delete => {
+                self.state.entities.remove(&entity.entity_index);
+            }
+        }
+    }
+}
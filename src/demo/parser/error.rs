@@ -2,6 +2,7 @@ use crate::demo::gamevent::GameEventValueType;
 use crate::demo::message::gameevent::GameEventTypeId;
 use crate::demo::message::packetentities::EntityId;
 use crate::demo::packet::datatable::{ClassId, SendTableName};
+use crate::demo::parser::analyser::UserId;
 use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
 use bitbuffer::BitError;
 use std::str::Utf8Error;
@@ -71,6 +72,8 @@ pub enum ParseError {
     UnknownEntity(EntityId),
     #[error("No sendprop definition found for property")]
     UnknownDefinition(SendPropIdentifier),
+    #[error("No player with user id {0} found in the demo")]
+    UnknownUser(UserId),
 }
 
 #[non_exhaustive]
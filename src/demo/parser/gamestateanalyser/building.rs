@@ -163,8 +163,6 @@ fn handle_building(
     parser_state: &ParserState,
     class: BuildingClass,
 ) {
-    let building = state.get_or_create_building(entity.entity_index, class);
-
     const LOCAL_ORIGIN: SendPropIdentifier =
         SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
     const TEAM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
@@ -178,6 +176,8 @@ fn handle_building(
     const PROGRESS: SendPropIdentifier =
         SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed");
 
+    let building = state.get_or_create_building(entity.entity_index, class);
+
     match building {
         Building::Sentry(Sentry {
             position,
@@ -185,6 +185,7 @@ fn handle_building(
             angle,
             sapped,
             builder,
+            builder_handle,
             level,
             building,
             max_health,
@@ -198,6 +199,7 @@ fn handle_building(
             angle,
             sapped,
             builder,
+            builder_handle,
             level,
             building,
             max_health,
@@ -211,6 +213,7 @@ fn handle_building(
             angle,
             sapped,
             builder,
+            builder_handle,
             level,
             building,
             max_health,
@@ -234,9 +237,11 @@ fn handle_building(
                     SAPPED => *sapped = i64::try_from(&prop.value).unwrap_or_default() > 0,
                     BUILDING => *building = i64::try_from(&prop.value).unwrap_or_default() > 0,
                     LEVEL => *level = i64::try_from(&prop.value).unwrap_or_default() as u8,
+                    // the builder is networked as a handle to the player entity, the user info
+                    // of the player might not be known yet, so the builder is resolved afterwards
                     BUILDER => {
-                        *builder =
-                            UserId::from(i64::try_from(&prop.value).unwrap_or_default() as u16)
+                        *builder_handle = Handle::try_from(&prop.value).unwrap_or_default();
+                        *builder = UserId::default();
                     }
                     MAX_HEALTH => {
                         *max_health = i64::try_from(&prop.value).unwrap_or_default() as u16
@@ -64,6 +64,7 @@ impl MessageHandler for GameStateAnalyser {
                     self.state.projectile_destroy(*id);
                     self.state.remove_building(*id);
                 }
+                self.state.resolve_builders();
             }
             Message::ServerInfo(message) => {
                 self.state.interval_per_tick = message.interval_per_tick
@@ -270,6 +271,7 @@ impl GameStateAnalyser {
         {
             let id = user_info.entity_id;
             self.state.get_or_create_player(id).info = Some(user_info.into());
+            self.state.resolve_builders();
         }
 
         Ok(())
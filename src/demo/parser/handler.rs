@@ -1,7 +1,9 @@
+use crate::demo::message::packetentities::PacketEntitiesView;
 use crate::demo::message::{Message, MessageType};
 use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
 use crate::demo::packet::stringtable::{StringTable, StringTableEntry};
 use crate::demo::packet::Packet;
+use crate::demo::parser::propfilter::PropFilter;
 use crate::Result;
 
 use crate::demo::data::{DemoTick, ServerTick};
@@ -9,6 +11,7 @@ use crate::demo::header::Header;
 use crate::demo::packet::message::MessagePacketMeta;
 use crate::ParserState;
 use std::borrow::Cow;
+use std::mem::take;
 
 pub trait MessageHandler {
     type Output;
@@ -45,6 +48,32 @@ pub trait MessageHandler {
     ) {
     }
 
+    /// The classes and props used by the handler, other props are skipped while decoding entity updates
+    ///
+    /// By default all props are decoded.
+    fn prop_filter(&self) -> Option<PropFilter> {
+        None
+    }
+
+    /// Whether to receive entity updates as views borrowed from a buffer owned by the parser,
+    /// instead of as `Message::PacketEntities`
+    ///
+    /// Re-using the buffer prevents having to allocate the props of every entity update.
+    fn does_handle_borrowed_entities() -> bool {
+        false
+    }
+
+    /// Handle entity updates when [`does_handle_borrowed_entities`](Self::does_handle_borrowed_entities) returns true
+    ///
+    /// Entity updates are handled after the other messages of the packet they're part of.
+    fn handle_entities(
+        &mut self,
+        _entities: &PacketEntitiesView,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+    }
+
     fn into_output(self, state: &ParserState) -> Self::Output;
 }
 
@@ -87,7 +116,9 @@ impl Default for DemoHandler<'_, NullHandler> {
 
 impl<'a, T: MessageHandler> DemoHandler<'a, T> {
     pub fn with_analyser(analyser: T) -> Self {
-        let state_handler = ParserState::new(24, T::does_handle, false);
+        let mut state_handler = ParserState::new(24, T::does_handle, false);
+        state_handler.set_borrow_entities(T::does_handle_borrowed_entities());
+        state_handler.set_prop_filter(analyser.prop_filter());
 
         DemoHandler {
             server_tick: ServerTick::default(),
@@ -145,6 +176,9 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
                         message => self.handle_message(message, packet.tick),
                     }
                 }
+                if !self.state_handler.entity_buffer.get_mut().is_empty() {
+                    self.handle_entity_buffer(packet.tick);
+                }
             }
             _ => {}
         };
@@ -201,6 +235,17 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
         self.state_handler.handle_message(message, tick);
     }
 
+    fn handle_entity_buffer(&mut self, tick: DemoTick) {
+        let mut buffer = take(self.state_handler.entity_buffer.get_mut());
+        for message in buffer.messages() {
+            self.analyser
+                .handle_entities(&message, tick, &self.state_handler);
+            self.state_handler.handle_entities_view(&message);
+        }
+        buffer.clear();
+        *self.state_handler.entity_buffer.get_mut() = buffer;
+    }
+
     pub fn into_output(self) -> T::Output {
         self.analyser.into_output(&self.state_handler)
     }
@@ -0,0 +1,613 @@
+use crate::demo::data::attributes::{get_attributes, AttributeValue};
+use crate::demo::data::game_state::Handle;
+use crate::demo::data::items::{get_item_schema, ItemSchema, ItemSlot};
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{EntityId, PacketEntity, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass, ServerClassName};
+use crate::demo::parser::analyser::Class;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::sendprop::{SendProp, SendPropIdentifier};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+const MAX_WEAPONS: usize = 48;
+
+const OUTER: SendPropIdentifier = SendPropIdentifier::new("DT_AttributeContainer", "m_hOuter");
+const OUTER2: SendPropIdentifier = SendPropIdentifier::new("DT_AttributeManager", "m_hOuter");
+const OWNER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+const PLAYER_CLASS: SendPropIdentifier =
+    SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iClass");
+const ACTIVE_WEAPON: SendPropIdentifier =
+    SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+const ITEM_DEFINITION: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iItemDefinitionIndex");
+const ITEM_QUALITY: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iEntityQuality");
+const ITEM_LEVEL: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iEntityLevel");
+const ATTRIBUTE_INDEX: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+const ATTRIBUTE_VALUE: SendPropIdentifier =
+    SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+
+/// The loadout slot of a weapon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadoutSlot {
+    Primary,
+    Secondary,
+    Melee,
+    /// Weapons in other slots or of an unknown type, by their position in the players `m_hMyWeapons`
+    Other(u8),
+}
+
+impl LoadoutSlot {
+    /// Get the slot of a weapon for the class of the player holding it
+    ///
+    /// The slot is taken from the item table, weapons missing from the table use the slot
+    /// of their server class.
+    ///
+    /// The position of the weapon in `m_hMyWeapons` is the order the weapons were equipped in,
+    /// not the slot, so it's only used to distinguish weapons outside of the main slots.
+    pub fn for_weapon(
+        definition_index: u16,
+        weapon_class: &str,
+        player_class: Class,
+        weapon_index: usize,
+    ) -> Self {
+        let slot = match get_item_schema(definition_index) {
+            Some(item) => match item.slot_for(player_class) {
+                ItemSlot::Primary => Some(LoadoutSlot::Primary),
+                ItemSlot::Secondary => Some(LoadoutSlot::Secondary),
+                ItemSlot::Melee => Some(LoadoutSlot::Melee),
+                _ => None,
+            },
+            None => Self::for_weapon_class(weapon_class),
+        };
+        slot.unwrap_or(LoadoutSlot::Other(weapon_index as u8))
+    }
+
+    /// Get the slot of a weapon from its server class
+    fn for_weapon_class(class: &str) -> Option<Self> {
+        Some(match class {
+            "CTFShotgun"
+            | "CTFShotgun_Revenge"
+            | "CTFShotgunBuildingRescue"
+            | "CTFPistol_ScoutPrimary"
+            | "CTFParachute_Primary"
+            | "CTFScatterGun"
+            | "CTFSodaPopper"
+            | "CTFPEPBrawlerBlaster"
+            | "CTFRocketLauncher"
+            | "CTFRocketLauncher_DirectHit"
+            | "CTFRocketLauncher_AirStrike"
+            | "CTFParticleCannon"
+            | "CTFFlameThrower"
+            | "CTFGrenadeLauncher"
+            | "CTFCannon"
+            | "CTFMinigun"
+            | "CTFDRGPomson"
+            | "CTFSyringeGun"
+            | "CTFCrossbow"
+            | "CTFSniperRifle"
+            | "CTFSniperRifleDecap"
+            | "CTFSniperRifleClassic"
+            | "CTFCompoundBow"
+            | "CTFRevolver" => LoadoutSlot::Primary,
+            "CTFShotgun_Soldier"
+            | "CTFShotgun_HWG"
+            | "CTFShotgun_Pyro"
+            | "CTFPistol"
+            | "CTFPistol_Scout"
+            | "CTFPistol_ScoutSecondary"
+            | "CTFParachute_Secondary"
+            | "CTFCleaver"
+            | "CTFJar"
+            | "CTFJarMilk"
+            | "CTFJarGas"
+            | "CTFLunchBox"
+            | "CTFLunchBox_Drink"
+            | "CTFBuffItem"
+            | "CTFRaygun"
+            | "CTFFlareGun"
+            | "CTFFlareGun_Revenge"
+            | "CTFRocketPack"
+            | "CTFPipebombLauncher"
+            | "CTFMechanicalArm"
+            | "CTFLaserPointer"
+            | "CTFMedigun"
+            | "CTFSMG"
+            | "CTFChargedSMG" => LoadoutSlot::Secondary,
+            "CTFBat" | "CTFBat_Wood" | "CTFBat_Fish" | "CTFBat_Giftwrap" | "CTFShovel"
+            | "CTFFireAxe" | "CTFBreakableSign" | "CTFSlap" | "CTFBottle" | "CTFStickBomb"
+            | "CTFSword" | "CTFKatana" | "CTFFists" | "CTFWrench" | "CTFRobotArm"
+            | "CTFBonesaw" | "CTFClub" | "CTFKnife" => LoadoutSlot::Melee,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemKind {
+    Weapon,
+    Wearable,
+}
+
+impl ItemKind {
+    pub fn for_class(class: &ServerClassName) -> Self {
+        if class.starts_with("CTFWearable") || class.as_str() == "CTFPowerupBottle" {
+            ItemKind::Wearable
+        } else {
+            ItemKind::Weapon
+        }
+    }
+}
+
+/// A weapon or wearable entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    pub entity: EntityId,
+    pub class: ServerClassName,
+    pub kind: ItemKind,
+    pub definition_index: u16,
+    pub quality: u8,
+    pub level: u8,
+    pub attributes: Vec<AttributeValue>,
+}
+
+impl Item {
+    /// Look up the item in the bundled item table
+    pub fn schema(&self) -> Option<&'static ItemSchema> {
+        get_item_schema(self.definition_index)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Loadout {
+    pub primary: Option<Item>,
+    pub secondary: Option<Item>,
+    pub melee: Option<Item>,
+    /// Weapons in other slots, such as pda's and the spy's sapper
+    pub other: Vec<(LoadoutSlot, Item)>,
+    pub cosmetics: Vec<Item>,
+    pub active_weapon: Option<LoadoutSlot>,
+}
+
+impl Loadout {
+    pub fn get(&self, slot: LoadoutSlot) -> Option<&Item> {
+        match slot {
+            LoadoutSlot::Primary => self.primary.as_ref(),
+            LoadoutSlot::Secondary => self.secondary.as_ref(),
+            LoadoutSlot::Melee => self.melee.as_ref(),
+            LoadoutSlot::Other(_) => self
+                .other
+                .iter()
+                .find(|(other_slot, _)| *other_slot == slot)
+                .map(|(_, item)| item),
+        }
+    }
+
+    /// Get the currently held weapon
+    pub fn active(&self) -> Option<&Item> {
+        self.active_weapon.and_then(|slot| self.get(slot))
+    }
+
+    pub fn weapons(&self) -> impl Iterator<Item = (LoadoutSlot, &Item)> {
+        [
+            (LoadoutSlot::Primary, self.primary.as_ref()),
+            (LoadoutSlot::Secondary, self.secondary.as_ref()),
+            (LoadoutSlot::Melee, self.melee.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(slot, item)| Some((slot, item?)))
+        .chain(self.other.iter().map(|(slot, item)| (*slot, item)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LoadoutChangeKind {
+    Equipped { slot: LoadoutSlot, item: Item },
+    Unequipped { slot: LoadoutSlot, item: Item },
+    CosmeticEquipped { item: Item },
+    CosmeticUnequipped { item: Item },
+    ActiveWeapon { slot: Option<LoadoutSlot> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoadoutChange {
+    pub tick: DemoTick,
+    pub player: EntityId,
+    pub change: LoadoutChangeKind,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LoadoutState {
+    /// The current loadout for each player, by player entity
+    pub loadouts: BTreeMap<EntityId, Loadout>,
+    /// Every change made to the player loadouts, in order
+    pub changes: Vec<LoadoutChange>,
+}
+
+#[derive(Debug)]
+struct TrackedItem {
+    handle: Handle,
+    owner: Handle,
+    class: ServerClassName,
+    definition_index: u16,
+    quality: u8,
+    level: u8,
+    // attribute list props, by prop index
+    attribute_props: BTreeMap<u32, SendProp>,
+}
+
+impl TrackedItem {
+    fn new(class: ServerClassName) -> Self {
+        TrackedItem {
+            handle: Handle::default(),
+            owner: Handle::default(),
+            class,
+            definition_index: 0,
+            quality: 0,
+            level: 0,
+            attribute_props: BTreeMap::new(),
+        }
+    }
+
+    fn item(&self, entity: EntityId) -> Item {
+        Item {
+            entity,
+            class: self.class.clone(),
+            kind: ItemKind::for_class(&self.class),
+            definition_index: self.definition_index,
+            quality: self.quality,
+            level: self.level,
+            attributes: get_attributes(self.attribute_props.values()),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct TrackedPlayer {
+    handle: Handle,
+    class: Class,
+    weapons: Vec<Handle>,
+    active_weapon: Handle,
+}
+
+/// Call `f` for every prop of the entity without cloning them
+///
+/// For entering entities the baseline props are passed first, so the props sent with the entity take precedence
+fn for_each_prop(entity: &PacketEntity, parser_state: &ParserState, mut f: impl FnMut(&SendProp)) {
+    if entity.update_type == UpdateType::Enter {
+        entity
+            .get_baseline_props(parser_state)
+            .iter()
+            .for_each(&mut f);
+    }
+    entity.props.iter().for_each(f);
+}
+
+fn is_item_definition(prop: &SendProp) -> bool {
+    prop.identifier == ITEM_DEFINITION
+}
+
+/// Tracks the weapons and wearables of all players trough the demo
+#[derive(Debug)]
+pub struct LoadoutAnalyser {
+    state: LoadoutState,
+    class_names: Vec<ServerClassName>, // indexed by ClassId
+    weapon_props: [SendPropIdentifier; MAX_WEAPONS],
+    players: BTreeMap<EntityId, TrackedPlayer>,
+    items: BTreeMap<EntityId, TrackedItem>,
+    dirty: BTreeSet<EntityId>,
+}
+
+impl Default for LoadoutAnalyser {
+    fn default() -> Self {
+        LoadoutAnalyser {
+            state: LoadoutState::default(),
+            class_names: Vec::new(),
+            weapon_props: std::array::from_fn(|index| {
+                SendPropIdentifier::new("m_hMyWeapons", &format!("{index:03}"))
+            }),
+            players: BTreeMap::new(),
+            items: BTreeMap::new(),
+            dirty: BTreeSet::new(),
+        }
+    }
+}
+
+impl MessageHandler for LoadoutAnalyser {
+    type Output = LoadoutState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        if let Message::PacketEntities(message) = message {
+            for entity in &message.entities {
+                self.handle_entity(entity, parser_state);
+            }
+            for id in &message.removed_entities {
+                self.remove_entity(*id);
+            }
+            self.update_loadouts(tick);
+        }
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        _parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _parser_state: &ParserState,
+    ) {
+        self.class_names = server_classes
+            .iter()
+            .map(|class| &class.name)
+            .cloned()
+            .collect();
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for LoadoutAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+impl LoadoutAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle_entity(&mut self, entity: &PacketEntity, parser_state: &ParserState) {
+        let Some(class_name) = self.class_names.get(usize::from(entity.server_class)) else {
+            return;
+        };
+
+        if entity.update_type == UpdateType::Delete {
+            self.remove_entity(entity.entity_index);
+            return;
+        }
+
+        if class_name.as_str() == "CTFPlayer" {
+            self.handle_player_entity(entity, parser_state);
+        } else if self.items.contains_key(&entity.entity_index)
+            || (entity.update_type == UpdateType::Enter
+                && (entity.props.iter().any(is_item_definition)
+                    || entity
+                        .get_baseline_props(parser_state)
+                        .iter()
+                        .any(is_item_definition)))
+        {
+            let class_name = class_name.clone();
+            self.handle_item_entity(entity, class_name, parser_state);
+        }
+    }
+
+    fn handle_player_entity(&mut self, entity: &PacketEntity, parser_state: &ParserState) {
+        let player = self.players.entry(entity.entity_index).or_default();
+        let weapon_props = &self.weapon_props;
+
+        for_each_prop(entity, parser_state, |prop| match prop.identifier {
+            OUTER | OUTER2 => player.handle = Handle::try_from(&prop.value).unwrap_or_default(),
+            PLAYER_CLASS => {
+                player.class = Class::new(i64::try_from(&prop.value).unwrap_or_default())
+            }
+            ACTIVE_WEAPON => {
+                player.active_weapon = Handle::try_from(&prop.value).unwrap_or_default()
+            }
+            identifier => {
+                if let Some(index) = weapon_props
+                    .iter()
+                    .position(|weapon_prop| *weapon_prop == identifier)
+                {
+                    if player.weapons.len() <= index {
+                        player.weapons.resize(index + 1, Handle::default());
+                    }
+                    if let Some(weapon) = player.weapons.get_mut(index) {
+                        *weapon = Handle::try_from(&prop.value).unwrap_or_default();
+                    }
+                }
+            }
+        });
+
+        self.dirty.insert(entity.entity_index);
+    }
+
+    fn handle_item_entity(
+        &mut self,
+        entity: &PacketEntity,
+        class: ServerClassName,
+        parser_state: &ParserState,
+    ) {
+        let item = self
+            .items
+            .entry(entity.entity_index)
+            .or_insert_with(|| TrackedItem::new(class));
+        let old_owner = item.owner;
+
+        // attribute lists contain multiple props with the same identifier, so they are stored by index
+        for_each_prop(entity, parser_state, |prop| match prop.identifier {
+            OUTER => item.handle = Handle::try_from(&prop.value).unwrap_or_default(),
+            OWNER => item.owner = Handle::try_from(&prop.value).unwrap_or_default(),
+            ITEM_DEFINITION => {
+                item.definition_index = i64::try_from(&prop.value).unwrap_or_default() as u16
+            }
+            ITEM_QUALITY => item.quality = i64::try_from(&prop.value).unwrap_or_default() as u8,
+            ITEM_LEVEL => item.level = i64::try_from(&prop.value).unwrap_or_default() as u8,
+            ATTRIBUTE_INDEX | ATTRIBUTE_VALUE => {
+                item.attribute_props.insert(prop.index, prop.clone());
+            }
+            _ => {}
+        });
+
+        let new_owner = item.owner;
+        self.mark_owner_dirty(old_owner);
+        self.mark_owner_dirty(new_owner);
+    }
+
+    fn remove_entity(&mut self, entity: EntityId) {
+        if let Some(item) = self.items.remove(&entity) {
+            self.mark_owner_dirty(item.owner);
+        }
+        if self.players.remove(&entity).is_some() {
+            self.state.loadouts.remove(&entity);
+            self.dirty.remove(&entity);
+        }
+    }
+
+    fn mark_owner_dirty(&mut self, owner: Handle) {
+        if let Some(owner) = owner.entity_id() {
+            if self.players.contains_key(&owner) {
+                self.dirty.insert(owner);
+            }
+        }
+    }
+
+    fn get_item(&self, handle: Handle) -> Option<(EntityId, &TrackedItem)> {
+        let entity = handle.entity_id()?;
+        self.items
+            .get(&entity)
+            .filter(|item| item.handle == handle)
+            .map(|item| (entity, item))
+    }
+
+    fn build_loadout(&self, player: &TrackedPlayer) -> Loadout {
+        let mut loadout = Loadout::default();
+
+        for (index, handle) in player.weapons.iter().enumerate() {
+            if let Some((entity, weapon)) = self.get_item(*handle) {
+                let slot = LoadoutSlot::for_weapon(
+                    weapon.definition_index,
+                    weapon.class.as_str(),
+                    player.class,
+                    index,
+                );
+                let item = weapon.item(entity);
+                match slot {
+                    LoadoutSlot::Primary => loadout.primary = Some(item),
+                    LoadoutSlot::Secondary => loadout.secondary = Some(item),
+                    LoadoutSlot::Melee => loadout.melee = Some(item),
+                    LoadoutSlot::Other(_) => loadout.other.push((slot, item)),
+                }
+                if *handle == player.active_weapon {
+                    loadout.active_weapon = Some(slot);
+                }
+            }
+        }
+
+        loadout.cosmetics = self
+            .items
+            .iter()
+            .filter(|(_, item)| item.owner == player.handle)
+            .filter(|(_, item)| ItemKind::for_class(&item.class) == ItemKind::Wearable)
+            .map(|(entity, item)| item.item(*entity))
+            .collect();
+
+        loadout
+    }
+
+    fn update_loadouts(&mut self, tick: DemoTick) {
+        for player_id in std::mem::take(&mut self.dirty) {
+            let Some(player) = self.players.get(&player_id) else {
+                continue;
+            };
+            let loadout = self.build_loadout(player);
+            let old = self
+                .state
+                .loadouts
+                .get(&player_id)
+                .cloned()
+                .unwrap_or_default();
+
+            let mut push = |change| {
+                self.state.changes.push(LoadoutChange {
+                    tick,
+                    player: player_id,
+                    change,
+                })
+            };
+
+            let old_weapons: Vec<(LoadoutSlot, &Item)> = old.weapons().collect();
+            let new_weapons: Vec<(LoadoutSlot, &Item)> = loadout.weapons().collect();
+            for (slot, item) in old_weapons.iter() {
+                if loadout.get(*slot) != Some(item) {
+                    push(LoadoutChangeKind::Unequipped {
+                        slot: *slot,
+                        item: (*item).clone(),
+                    });
+                }
+            }
+            for (slot, item) in new_weapons.iter() {
+                if old.get(*slot) != Some(item) {
+                    push(LoadoutChangeKind::Equipped {
+                        slot: *slot,
+                        item: (*item).clone(),
+                    });
+                }
+            }
+            for item in old.cosmetics.iter() {
+                if !loadout.cosmetics.contains(item) {
+                    push(LoadoutChangeKind::CosmeticUnequipped { item: item.clone() });
+                }
+            }
+            for item in loadout.cosmetics.iter() {
+                if !old.cosmetics.contains(item) {
+                    push(LoadoutChangeKind::CosmeticEquipped { item: item.clone() });
+                }
+            }
+            if old.active_weapon != loadout.active_weapon {
+                push(LoadoutChangeKind::ActiveWeapon {
+                    slot: loadout.active_weapon,
+                });
+            }
+
+            self.state.loadouts.insert(player_id, loadout);
+        }
+    }
+}
+
+#[test]
+fn test_weapon_slot() {
+    // the eyelander is a melee weapon, even when it's the first weapon of a demoknight
+    assert_eq!(
+        LoadoutSlot::Melee,
+        LoadoutSlot::for_weapon(132, "CTFSword", Class::Demoman, 0)
+    );
+    assert_eq!(
+        LoadoutSlot::Primary,
+        LoadoutSlot::for_weapon(200, "CTFScatterGun", Class::Scout, 2)
+    );
+    // the shotgun is the engineer's primary
+    assert_eq!(
+        LoadoutSlot::Secondary,
+        LoadoutSlot::for_weapon(199, "CTFShotgun_Soldier", Class::Soldier, 1)
+    );
+    assert_eq!(
+        LoadoutSlot::Primary,
+        LoadoutSlot::for_weapon(199, "CTFShotgun", Class::Engineer, 1)
+    );
+    // engineer build pda
+    assert_eq!(
+        LoadoutSlot::Other(1),
+        LoadoutSlot::for_weapon(25, "CTFWeaponPDA_Engineer_Build", Class::Engineer, 1)
+    );
+    // items missing from the table use the slot of their server class
+    assert_eq!(
+        LoadoutSlot::Secondary,
+        LoadoutSlot::for_weapon(u16::MAX, "CTFShotgun_HWG", Class::Heavy, 3)
+    );
+    assert_eq!(
+        LoadoutSlot::Other(4),
+        LoadoutSlot::for_weapon(u16::MAX, "CTFWeaponBuilder", Class::Spy, 4)
+    );
+}
@@ -9,15 +9,25 @@ use crate::demo::packet::Packet;
 use crate::demo::parser::analyser::Analyser;
 pub use crate::demo::parser::analyser::MatchState;
 pub use crate::demo::parser::handler::{DemoHandler, MessageHandler, NullHandler};
+pub use crate::demo::parser::sendtablecache::SendTableCache;
 pub use crate::demo::parser::state::ParserState;
 use crate::Stream;
 
 pub mod analyser;
+pub mod buildinghistoryanalyser;
+pub mod entitytracker;
 pub mod error;
 pub mod gamestateanalyser;
 pub mod handler;
+pub mod loadoutanalyser;
 pub mod messagetypeanalyser;
 pub mod player_summary_analyzer;
+pub mod propfilter;
+pub mod prophistory;
+pub mod propwatcher;
+pub mod query;
+pub mod sendtablecache;
+pub mod spyanalyser;
 pub mod state;
 
 pub use self::error::*;
@@ -87,6 +97,16 @@ impl<'a, A: MessageHandler> DemoParser<'a, A> {
         }
     }
 
+    /// Use a shared cache for the flattened send tables
+    ///
+    /// When parsing a large number of demos, sharing a cache between the parsers prevents having to
+    /// re-process the same data tables for every demo. Without a cache the send tables are flattened
+    /// for every demo.
+    pub fn with_send_table_cache(mut self, cache: SendTableCache) -> Self {
+        self.handler.state_handler.set_send_table_cache(cache);
+        self
+    }
+
     pub fn parse(self) -> Result<(Header, A::Output)> {
         let (header, mut ticker) = self.ticker()?;
         while ticker.tick()? {
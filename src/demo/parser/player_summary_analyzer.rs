@@ -1,13 +1,15 @@
 use crate::demo::data::DemoTick;
+use crate::demo::gamevent::GameEvent;
+use crate::demo::message::gameevent::GameEventMessage;
 use crate::demo::message::packetentities::EntityId;
 use crate::demo::message::packetentities::PacketEntity;
 use crate::demo::message::{Message, MessageType};
-use crate::demo::packet::datatable::ClassId;
+use crate::demo::packet::datatable::{ClassId, SendTable};
 use crate::demo::packet::stringtable::StringTableEntry;
-use crate::demo::parser::analyser::UserInfo;
+use crate::demo::parser::analyser::{Team, UserInfo};
 use crate::demo::parser::gamestateanalyser::UserId;
 use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
-use crate::demo::sendprop::SendProp;
+use crate::demo::sendprop::SendPropIdentifier;
 use crate::{ParserState, ReadResult, Stream};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
@@ -17,19 +19,29 @@ use std::collections::{BTreeMap, HashMap};
  * end of the demo.  Essentially, this will capture all the information that would appear on the
  * scoreboard for every player if they took a snapshot at the time the demo finishes (such as the end
  * of a match or round).
+ *
+ * Next to the scoreboard for the entire match, a scoreboard is kept for every round in the demo.
  */
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct PlayerSummaryAnalyzer {
     state: PlayerSummaryState,
     user_id_map: HashMap<EntityId, UserId>,
+    /// Scoring props for the player class, by flattened prop index
+    #[serde(skip)]
+    score_props: Option<HashMap<u32, (ScoreScope, ScoreField)>>,
+    /// Support values of every player at the start of the current round
+    #[serde(skip)]
+    round_start_support: HashMap<UserId, (u32, u32)>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
 pub struct PlayerSummary {
     pub points: u32,
     pub kills: u32,
     pub assists: u32,
     pub deaths: u32,
+    pub suicides: u32,
+    pub buildings_built: u32,
     pub buildings_destroyed: u32,
     pub captures: u32,
     pub defenses: u32,
@@ -41,28 +53,188 @@ pub struct PlayerSummary {
     pub healing: u32,
     pub backstabs: u32,
     pub bonus_points: u32,
+    /// Sum of `healing_assist` and `damage_assist`
     pub support: u32,
+    pub healing_assist: u32,
+    pub damage_assist: u32,
     pub damage_dealt: u32,
+    pub crits: u32,
+    pub resupply_points: u32,
+}
+
+impl PlayerSummary {
+    fn set_support(&mut self, healing_assist: u32, damage_assist: u32) {
+        self.healing_assist = healing_assist;
+        self.damage_assist = damage_assist;
+        self.support = healing_assist + damage_assist;
+    }
+}
+
+/// The scoreboard for a single round
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct RoundSummary {
+    pub start_tick: DemoTick,
+    /// `None` if the round didn't finish within the demo
+    pub end_tick: Option<DemoTick>,
+    pub winner: Option<Team>,
+    pub player_summaries: HashMap<UserId, PlayerSummary>,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct PlayerSummaryState {
+    /// Scoreboard for the entire match
     pub player_summaries: HashMap<UserId, PlayerSummary>,
     pub users: BTreeMap<UserId, UserInfo>,
+    /// Scoreboard for every round, in order
+    pub rounds: Vec<RoundSummary>,
+}
+
+/// The player has separate copies of the scoring data for the entire match and the current round
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScoreScope {
+    Match,
+    Round,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScoreField {
+    Captures,
+    Defenses,
+    Kills,
+    Deaths,
+    Suicides,
+    Dominations,
+    Revenges,
+    BuildingsBuilt,
+    BuildingsDestroyed,
+    Headshots,
+    Backstabs,
+    Healing,
+    Ubercharges,
+    Teleports,
+    DamageDealt,
+    Crits,
+    ResupplyPoints,
+    Assists,
+    BonusPoints,
+    Points,
+}
+
+impl ScoreField {
+    fn get_mut(self, summary: &mut PlayerSummary) -> &mut u32 {
+        match self {
+            ScoreField::Captures => &mut summary.captures,
+            ScoreField::Defenses => &mut summary.defenses,
+            ScoreField::Kills => &mut summary.kills,
+            ScoreField::Deaths => &mut summary.deaths,
+            ScoreField::Suicides => &mut summary.suicides,
+            ScoreField::Dominations => &mut summary.dominations,
+            ScoreField::Revenges => &mut summary.revenges,
+            ScoreField::BuildingsBuilt => &mut summary.buildings_built,
+            ScoreField::BuildingsDestroyed => &mut summary.buildings_destroyed,
+            ScoreField::Headshots => &mut summary.headshots,
+            ScoreField::Backstabs => &mut summary.backstabs,
+            ScoreField::Healing => &mut summary.healing,
+            ScoreField::Ubercharges => &mut summary.ubercharges,
+            ScoreField::Teleports => &mut summary.teleports,
+            ScoreField::DamageDealt => &mut summary.damage_dealt,
+            ScoreField::Crits => &mut summary.crits,
+            ScoreField::ResupplyPoints => &mut summary.resupply_points,
+            ScoreField::Assists => &mut summary.assists,
+            ScoreField::BonusPoints => &mut summary.bonus_points,
+            ScoreField::Points => &mut summary.points,
+        }
+    }
 }
 
+/*
+ * Member: m_iCaptures (offset 4) (type integer) (bits 10) (Unsigned)
+ * Member: m_iDefenses (offset 8) (type integer) (bits 10) (Unsigned)
+ * Member: m_iKills (offset 12) (type integer) (bits 10) (Unsigned)
+ * Member: m_iDeaths (offset 16) (type integer) (bits 10) (Unsigned)
+ * Member: m_iSuicides (offset 20) (type integer) (bits 10) (Unsigned)
+ * Member: m_iDominations (offset 24) (type integer) (bits 10) (Unsigned)
+ * Member: m_iRevenge (offset 28) (type integer) (bits 10) (Unsigned)
+ * Member: m_iBuildingsBuilt (offset 32) (type integer) (bits 10) (Unsigned)
+ * Member: m_iBuildingsDestroyed (offset 36) (type integer) (bits 10) (Unsigned)
+ * Member: m_iHeadshots (offset 40) (type integer) (bits 10) (Unsigned)
+ * Member: m_iBackstabs (offset 44) (type integer) (bits 10) (Unsigned)
+ * Member: m_iHealPoints (offset 48) (type integer) (bits 20) (Unsigned)
+ * Member: m_iInvulns (offset 52) (type integer) (bits 10) (Unsigned)
+ * Member: m_iTeleports (offset 56) (type integer) (bits 10) (Unsigned)
+ * Member: m_iDamageDone (offset 60) (type integer) (bits 20) (Unsigned)
+ * Member: m_iCrits (offset 64) (type integer) (bits 10) (Unsigned)
+ * Member: m_iResupplyPoints (offset 68) (type integer) (bits 10) (Unsigned)
+ * Member: m_iKillAssists (offset 72) (type integer) (bits 12) (Unsigned)
+ * Member: m_iBonusPoints (offset 76) (type integer) (bits 10) (Unsigned)
+ * Member: m_iPoints (offset 80) (type integer) (bits 10) (Unsigned)
+ *
+ * NOTE: support points aren't included here, but are equal to the sum of m_iHealingAssist and m_iDamageAssist
+ *       from the player resource
+ */
+const SCORE_PROPS: [(&str, ScoreField); 20] = [
+    ("m_iCaptures", ScoreField::Captures),
+    ("m_iDefenses", ScoreField::Defenses),
+    ("m_iKills", ScoreField::Kills),
+    ("m_iDeaths", ScoreField::Deaths),
+    ("m_iSuicides", ScoreField::Suicides),
+    ("m_iDominations", ScoreField::Dominations),
+    ("m_iRevenge", ScoreField::Revenges),
+    ("m_iBuildingsBuilt", ScoreField::BuildingsBuilt),
+    ("m_iBuildingsDestroyed", ScoreField::BuildingsDestroyed),
+    ("m_iHeadshots", ScoreField::Headshots),
+    ("m_iBackstabs", ScoreField::Backstabs),
+    ("m_iHealPoints", ScoreField::Healing),
+    ("m_iInvulns", ScoreField::Ubercharges),
+    ("m_iTeleports", ScoreField::Teleports),
+    ("m_iDamageDone", ScoreField::DamageDealt),
+    ("m_iCrits", ScoreField::Crits),
+    ("m_iResupplyPoints", ScoreField::ResupplyPoints),
+    ("m_iKillAssists", ScoreField::Assists),
+    ("m_iBonusPoints", ScoreField::BonusPoints),
+    ("m_iPoints", ScoreField::Points),
+];
+
 impl MessageHandler for PlayerSummaryAnalyzer {
     type Output = PlayerSummaryState;
 
     fn does_handle(message_type: MessageType) -> bool {
-        matches!(message_type, MessageType::PacketEntities)
+        matches!(
+            message_type,
+            MessageType::PacketEntities | MessageType::GameEvent
+        )
     }
 
-    fn handle_message(&mut self, message: &Message, _tick: DemoTick, parser_state: &ParserState) {
-        if let Message::PacketEntities(message) = message {
-            for entity in message.entities.iter() {
-                self.handle_packet_entity(entity, parser_state);
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        match message {
+            Message::PacketEntities(message) => {
+                let is_resource = |entity: &&PacketEntity| {
+                    parser_state
+                        .server_classes
+                        .get(<ClassId as Into<usize>>::into(entity.server_class))
+                        .map(|class| class.name.as_str() == "CTFPlayerResource")
+                        .unwrap_or_default()
+                };
+                // the player resource maps the player entities to user ids, so handle it first
+                let (resources, others): (Vec<_>, Vec<_>) =
+                    message.entities.iter().partition(is_resource);
+                for entity in resources.into_iter().chain(others) {
+                    self.handle_packet_entity(entity, tick, parser_state);
+                }
             }
+            Message::GameEvent(GameEventMessage { event, .. }) => match event {
+                GameEvent::TeamPlayRoundStart(_) => self.start_round(tick),
+                GameEvent::TeamPlayRoundWin(win) => {
+                    if let Some(round) = self.state.rounds.last_mut() {
+                        if round.end_tick.is_none() {
+                            round.end_tick = Some(tick);
+                            round.winner = Some(Team::new(win.team));
+                        }
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
         }
     }
 
@@ -93,29 +265,28 @@ impl BorrowMessageHandler for PlayerSummaryAnalyzer {
     }
 }
 
-/**
- * Helper function to make processing integer properties easier.
- *
- * parse_integer_prop(packet, "DT_TFPlayerScoringDataExclusive", "m_iPoints", |points| { println!("Scored {} points", points) });
- */
-fn parse_integer_prop<F>(
-    packet: &PacketEntity,
-    table: &str,
-    name: &str,
-    parser_state: &ParserState,
-    handler: F,
-) where
-    F: FnOnce(u32),
-{
-    use crate::demo::sendprop::SendPropValue;
-
-    if let Some(SendProp {
-        value: SendPropValue::Integer(val),
-        ..
-    }) = packet.get_prop_by_name(table, name, parser_state)
-    {
-        handler(val as u32);
+/// Find the scoring props in the flattened props of the player class
+///
+/// `m_ScoreData` is defined before `m_RoundScoreData` so the first copy of every prop contains the
+/// data for the entire match.
+fn find_score_props(send_table: &SendTable) -> HashMap<u32, (ScoreScope, ScoreField)> {
+    let mut props = HashMap::new();
+    for (name, field) in SCORE_PROPS {
+        let identifier = SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", name);
+        let mut indexes = send_table
+            .flattened_props()
+            .iter()
+            .enumerate()
+            .filter(|(_, prop)| prop.identifier == identifier)
+            .map(|(index, _)| index as u32);
+        if let Some(index) = indexes.next() {
+            props.insert(index, (ScoreScope::Match, field));
+        }
+        for index in indexes {
+            props.insert(index, (ScoreScope::Round, field));
+        }
     }
+    props
 }
 
 impl PlayerSummaryAnalyzer {
@@ -123,254 +294,110 @@ impl PlayerSummaryAnalyzer {
         Self::default()
     }
 
-    fn handle_packet_entity(&mut self, packet: &PacketEntity, parser_state: &ParserState) {
-        use crate::demo::sendprop::SendPropValue;
+    fn start_round(&mut self, tick: DemoTick) {
+        if let Some(round) = self.state.rounds.last_mut() {
+            if round.end_tick.is_none() {
+                round.end_tick = Some(tick);
+            }
+        }
+        self.round_start_support = self
+            .state
+            .player_summaries
+            .iter()
+            .map(|(user_id, summary)| (*user_id, (summary.healing_assist, summary.damage_assist)))
+            .collect();
+        self.state.rounds.push(RoundSummary {
+            start_tick: tick,
+            ..RoundSummary::default()
+        });
+    }
 
-        // println!("Known server classes: {:?}", parser_state.server_classes);
+    fn current_round(&mut self, tick: DemoTick) -> Option<&mut RoundSummary> {
+        if self.state.rounds.is_empty() {
+            // demo started in the middle of a round
+            self.start_round(tick);
+        }
+        self.state.rounds.last_mut()
+    }
 
-        if let Some(class) = parser_state
-            .server_classes
-            .get(<ClassId as Into<usize>>::into(packet.server_class))
-        {
-            // println!("Got a {} data packet: {:?}", class.name, packet);
+    fn handle_packet_entity(
+        &mut self,
+        packet: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        let class_index = <ClassId as Into<usize>>::into(packet.server_class);
+        if let Some(class) = parser_state.server_classes.get(class_index) {
             match class.name.as_str() {
                 "CTFPlayer" => {
-                    if let Some(user_id) = self.user_id_map.get(&packet.entity_index) {
-                        let summaries = &mut self.state.player_summaries;
-                        let player_summary = summaries.entry(*user_id).or_default();
-
-                        // Extract scoreboard information, if present, and update the player's summary accordingly
-                        // NOTE: Multiple DT_TFPlayerScoringDataExclusive structures may be present - one for the entire match,
-                        //       and one for just the current round.  Since we're only interested in the overall match scores,
-                        //       we need to ignore the round-specific values.  Fortunately, this is easy - just ignore the
-                        //       lesser value (if multiple values are present), since none of these scores are able to decrement.
+                    let Some(user_id) = self.user_id_map.get(&packet.entity_index).copied() else {
+                        return;
+                    };
+                    if self.score_props.is_none() {
+                        self.score_props = parser_state
+                            .send_tables
+                            .get(class_index)
+                            .map(find_score_props);
+                    }
 
-                        /*
-                         * Member: m_iCaptures (offset 4) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iDefenses (offset 8) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iKills (offset 12) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iDeaths (offset 16) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iSuicides (offset 20) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iDominations (offset 24) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iRevenge (offset 28) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iBuildingsBuilt (offset 32) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iBuildingsDestroyed (offset 36) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iHeadshots (offset 40) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iBackstabs (offset 44) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iHealPoints (offset 48) (type integer) (bits 20) (Unsigned)
-                         * Member: m_iInvulns (offset 52) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iTeleports (offset 56) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iDamageDone (offset 60) (type integer) (bits 20) (Unsigned)
-                         * Member: m_iCrits (offset 64) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iResupplyPoints (offset 68) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iKillAssists (offset 72) (type integer) (bits 12) (Unsigned)
-                         * Member: m_iBonusPoints (offset 76) (type integer) (bits 10) (Unsigned)
-                         * Member: m_iPoints (offset 80) (type integer) (bits 10) (Unsigned)
-                         *
-                         * NOTE: support points aren't included here, but is equal to the sum of m_iHealingAssist and m_iDamageAssist
-                         * TODO: pull data for support points
-                         */
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iCaptures",
-                            parser_state,
-                            |captures| {
-                                if captures > player_summary.captures {
-                                    player_summary.captures = captures;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iDefenses",
-                            parser_state,
-                            |defenses| {
-                                if defenses > player_summary.defenses {
-                                    player_summary.defenses = defenses;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iKills",
-                            parser_state,
-                            |kills| {
-                                if kills > player_summary.kills {
-                                    // TODO: This might not be accruate.  Tested with a demo file with 89 kills (88 on the scoreboard),
-                                    // but only a 83 were reported in the scoring data.
-                                    player_summary.kills = kills;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iDeaths",
-                            parser_state,
-                            |deaths| {
-                                if deaths > player_summary.deaths {
-                                    player_summary.deaths = deaths;
-                                }
-                            },
-                        );
-                        // ignore m_iSuicides
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iDominations",
-                            parser_state,
-                            |dominations| {
-                                if dominations > player_summary.dominations {
-                                    player_summary.dominations = dominations;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iRevenge",
-                            parser_state,
-                            |revenges| {
-                                if revenges > player_summary.revenges {
-                                    player_summary.revenges = revenges;
-                                }
-                            },
-                        );
-                        // ignore m_iBuildingsBuilt
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iBuildingsDestroyed",
-                            parser_state,
-                            |buildings_destroyed| {
-                                if buildings_destroyed > player_summary.buildings_destroyed {
-                                    player_summary.buildings_destroyed = buildings_destroyed;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iHeadshots",
-                            parser_state,
-                            |headshots| {
-                                if headshots > player_summary.headshots {
-                                    player_summary.headshots = headshots;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iBackstabs",
-                            parser_state,
-                            |backstabs| {
-                                if backstabs > player_summary.backstabs {
-                                    player_summary.backstabs = backstabs;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iHealPoints",
-                            parser_state,
-                            |healing| {
-                                if healing > player_summary.healing {
-                                    player_summary.healing = healing;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iInvulns",
-                            parser_state,
-                            |ubercharges| {
-                                if ubercharges > player_summary.ubercharges {
-                                    player_summary.ubercharges = ubercharges;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iTeleports",
-                            parser_state,
-                            |teleports| {
-                                if teleports > player_summary.teleports {
-                                    player_summary.teleports = teleports;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iDamageDone",
-                            parser_state,
-                            |damage_dealt| {
-                                if damage_dealt > player_summary.damage_dealt {
-                                    player_summary.damage_dealt = damage_dealt;
-                                }
-                            },
-                        );
-                        // ignore m_iCrits
-                        // ignore m_iResupplyPoints
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iKillAssists",
-                            parser_state,
-                            |assists| {
-                                if assists > player_summary.assists {
-                                    player_summary.assists = assists;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iBonusPoints",
-                            parser_state,
-                            |bonus_points| {
-                                if bonus_points > player_summary.bonus_points {
-                                    player_summary.bonus_points = bonus_points;
-                                }
-                            },
-                        );
-                        parse_integer_prop(
-                            packet,
-                            "DT_TFPlayerScoringDataExclusive",
-                            "m_iPoints",
-                            parser_state,
-                            |points| {
-                                if points > player_summary.points {
-                                    player_summary.points = points;
-                                }
-                            },
-                        );
+                    for prop in packet.props(parser_state) {
+                        let Some((scope, field)) = self
+                            .score_props
+                            .as_ref()
+                            .and_then(|props| props.get(&prop.index))
+                            .copied()
+                        else {
+                            continue;
+                        };
+                        let value = i64::try_from(&prop.value).unwrap_or_default() as u32;
+                        let summary = match scope {
+                            ScoreScope::Match => {
+                                Some(self.state.player_summaries.entry(user_id).or_default())
+                            }
+                            ScoreScope::Round => self
+                                .current_round(tick)
+                                .map(|round| round.player_summaries.entry(user_id).or_default()),
+                        };
+                        if let Some(summary) = summary {
+                            *field.get_mut(summary) = value;
+                        }
                     }
                 }
                 "CTFPlayerResource" => {
                     // Player summaries - including entity IDs!
                     // look for props like m_iUserID.<entity_id> = <user_id>
                     // for example, `m_iUserID.024 = 2523` means entity 24 is user 2523
-                    for i in 0..33 {
-                        // 0 to 32, inclusive (1..33 might also work, not sure if there's a user 0 or not).  Not exhaustive and doesn't work for servers with > 32 players
-                        if let Some(SendProp {
-                            value: SendPropValue::Integer(x),
-                            ..
-                        }) = packet.get_prop_by_name(
-                            "m_iUserID",
-                            format!("{i:0>3}").as_str(),
-                            parser_state,
-                        ) {
-                            let entity_id = EntityId::from(i as u32);
-                            let user_id = UserId::from(x as u32);
-                            self.user_id_map.insert(entity_id, user_id);
+                    let mut support: HashMap<EntityId, (Option<u32>, Option<u32>)> = HashMap::new();
+                    for prop in packet.props(parser_state) {
+                        let Some((table_name, prop_name)) = prop.identifier.names() else {
+                            continue;
+                        };
+                        let Ok(entity_id) = prop_name.as_str().parse::<u32>() else {
+                            continue;
+                        };
+                        let entity_id = EntityId::from(entity_id);
+                        let value = i64::try_from(&prop.value).unwrap_or_default();
+                        match table_name.as_str() {
+                            // empty player slots have a user id of 0
+                            "m_iUserID" if value > 0 => {
+                                self.user_id_map
+                                    .insert(entity_id, UserId::from(value as u32));
+                            }
+                            "m_iUserID" => {
+                                self.user_id_map.remove(&entity_id);
+                            }
+                            "m_iHealingAssist" => {
+                                support.entry(entity_id).or_default().0 = Some(value as u32)
+                            }
+                            "m_iDamageAssist" => {
+                                support.entry(entity_id).or_default().1 = Some(value as u32)
+                            }
+                            _ => {}
+                        }
+                    }
+                    for (entity_id, (healing_assist, damage_assist)) in support {
+                        if let Some(user_id) = self.user_id_map.get(&entity_id).copied() {
+                            self.update_support(user_id, healing_assist, damage_assist, tick);
                         }
                     }
                 }
@@ -381,6 +408,42 @@ impl PlayerSummaryAnalyzer {
         }
     }
 
+    /// Support is only available for the entire match, the round values are calculated from
+    /// the values at the start of the round
+    fn update_support(
+        &mut self,
+        user_id: UserId,
+        healing_assist: Option<u32>,
+        damage_assist: Option<u32>,
+        tick: DemoTick,
+    ) {
+        // make sure the round is started before the new values are stored, so a round that started
+        // before the demo uses the same start values for every update
+        if self.current_round(tick).is_none() {
+            return;
+        }
+        let summary = self.state.player_summaries.entry(user_id).or_default();
+        let healing_assist = healing_assist.unwrap_or(summary.healing_assist);
+        let damage_assist = damage_assist.unwrap_or(summary.damage_assist);
+        summary.set_support(healing_assist, damage_assist);
+
+        let (start_healing, start_damage) = self
+            .round_start_support
+            .get(&user_id)
+            .copied()
+            .unwrap_or_default();
+        if let Some(round) = self.current_round(tick) {
+            round
+                .player_summaries
+                .entry(user_id)
+                .or_default()
+                .set_support(
+                    healing_assist.saturating_sub(start_healing),
+                    damage_assist.saturating_sub(start_damage),
+                );
+        }
+    }
+
     fn parse_user_info(
         &mut self,
         index: usize,
@@ -402,3 +465,23 @@ impl PlayerSummaryAnalyzer {
         Ok(())
     }
 }
+
+#[test]
+fn test_round_support() {
+    let mut analyzer = PlayerSummaryAnalyzer::new();
+    let user = UserId::from(2u16);
+    analyzer.update_support(user, Some(10), Some(5), DemoTick::from(10u32));
+    analyzer.start_round(DemoTick::from(100u32));
+    analyzer.update_support(user, Some(12), None, DemoTick::from(150u32));
+
+    let state = analyzer.state;
+    assert_eq!(2, state.rounds.len());
+    assert_eq!(Some(DemoTick::from(100u32)), state.rounds[0].end_tick);
+    assert_eq!(15, state.rounds[0].player_summaries[&user].support);
+    assert_eq!(17, state.player_summaries[&user].support);
+    let round = &state.rounds[1].player_summaries[&user];
+    assert_eq!(
+        (2, 0, 2),
+        (round.healing_assist, round.damage_assist, round.support)
+    );
+}
@@ -0,0 +1,73 @@
+use crate::demo::packet::datatable::{SendTable, ServerClass};
+use crate::demo::sendprop::SendPropIdentifier;
+use std::collections::HashSet;
+
+/// The entity classes and props used by a handler
+///
+/// Props that aren't included in the filter are skipped while decoding entity updates instead of being decoded.
+/// Entities of classes that aren't included are still reported, but without any props.
+///
+/// ```
+/// # use tf_demo_parser::demo::parser::propfilter::PropFilter;
+/// # use tf_demo_parser::demo::sendprop::SendPropIdentifier;
+/// let filter = PropFilter::new()
+///     .with_class("CTFPlayer")
+///     .with_prop(SendPropIdentifier::new("DT_BasePlayer", "m_iHealth"));
+/// assert!(filter.includes_class("CTFPlayer"));
+/// assert!(!filter.includes_class("CTFPlayerResource"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropFilter {
+    classes: Option<HashSet<String>>,
+    props: HashSet<SendPropIdentifier>,
+}
+
+impl PropFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode the prop
+    pub fn with_prop(mut self, identifier: SendPropIdentifier) -> Self {
+        self.props.insert(identifier);
+        self
+    }
+
+    /// Only decode props for entities of the class, by default props are decoded for every class
+    pub fn with_class(mut self, class_name: &str) -> Self {
+        self.classes
+            .get_or_insert_with(HashSet::new)
+            .insert(class_name.into());
+        self
+    }
+
+    pub fn includes_class(&self, class_name: &str) -> bool {
+        self.classes
+            .as_ref()
+            .map_or(true, |classes| classes.contains(class_name))
+    }
+
+    pub fn includes_prop(&self, identifier: SendPropIdentifier) -> bool {
+        self.props.contains(&identifier)
+    }
+
+    /// For every class, whether each of the flattened props should be decoded
+    pub(crate) fn prop_masks(
+        &self,
+        send_tables: &[SendTable],
+        server_classes: &[ServerClass],
+    ) -> Vec<Vec<bool>> {
+        send_tables
+            .iter()
+            .zip(server_classes)
+            .map(|(send_table, class)| {
+                let include_class = self.includes_class(class.name.as_str());
+                send_table
+                    .flattened_props()
+                    .iter()
+                    .map(|prop| include_class && self.includes_prop(prop.identifier))
+                    .collect()
+            })
+            .collect()
+    }
+}
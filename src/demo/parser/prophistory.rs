@@ -0,0 +1,278 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{EntityId, PacketEntity, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::ClassId;
+use crate::demo::parser::handler::BorrowMessageHandler;
+use crate::demo::parser::propfilter::PropFilter;
+use crate::demo::parser::MessageHandler;
+use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropValueChange {
+    pub tick: DemoTick,
+    /// `None` if the entity was removed
+    pub value: Option<SendPropValue>,
+}
+
+/// All changes of a single prop of an entity, ordered by tick
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PropTimeline {
+    pub changes: Vec<PropValueChange>,
+}
+
+impl PropTimeline {
+    fn push(&mut self, tick: DemoTick, value: Option<SendPropValue>) {
+        match self.changes.as_mut_slice() {
+            [.., last] if last.value == value => {}
+            // multiple updates within the same tick that restore the value from before the tick
+            [.., previous, last] if last.tick == tick && previous.value == value => {
+                self.changes.pop();
+            }
+            // multiple updates within the same tick
+            [.., last] if last.tick == tick => last.value = value,
+            _ => self.changes.push(PropValueChange { tick, value }),
+        }
+    }
+
+    /// The value of the prop at the specified tick
+    pub fn value_at(&self, tick: DemoTick) -> Option<&SendPropValue> {
+        let index = self.changes.partition_point(|change| change.tick <= tick);
+        self.changes
+            .get(index.checked_sub(1)?)
+            .and_then(|change| change.value.as_ref())
+    }
+
+    /// All changes from `start` up to and including `end`
+    pub fn changes_between(&self, start: DemoTick, end: DemoTick) -> &[PropValueChange] {
+        let start = self.changes.partition_point(|change| change.tick < start);
+        let end = self.changes.partition_point(|change| change.tick <= end);
+        self.changes.get(start..end.max(start)).unwrap_or_default()
+    }
+}
+
+/// Recorded prop values, by entity and prop
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PropHistory {
+    pub entities: BTreeMap<EntityId, BTreeMap<SendPropIdentifier, PropTimeline>>,
+}
+
+impl PropHistory {
+    pub fn get(&self, entity: EntityId, identifier: SendPropIdentifier) -> Option<&PropTimeline> {
+        self.entities.get(&entity)?.get(&identifier)
+    }
+
+    pub fn value_at(
+        &self,
+        entity: EntityId,
+        identifier: SendPropIdentifier,
+        tick: DemoTick,
+    ) -> Option<&SendPropValue> {
+        self.get(entity, identifier)?.value_at(tick)
+    }
+
+    /// The timelines for a prop of every entity
+    pub fn for_prop(
+        &self,
+        identifier: SendPropIdentifier,
+    ) -> impl Iterator<Item = (EntityId, &PropTimeline)> {
+        self.entities
+            .iter()
+            .filter_map(move |(entity, props)| Some((*entity, props.get(&identifier)?)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, SendPropIdentifier, &PropTimeline)> {
+        self.entities.iter().flat_map(|(entity, props)| {
+            props
+                .iter()
+                .map(move |(identifier, timeline)| (*entity, *identifier, timeline))
+        })
+    }
+}
+
+/// Record the value history of selected props
+///
+/// Only changes to the selected props are stored, so the history stays small even for long demos.
+/// For props that occur multiple times in a send table, only the first occurrence is recorded.
+#[derive(Default, Debug)]
+pub struct PropHistoryAnalyser {
+    props: HashSet<SendPropIdentifier>,
+    classes: Option<HashSet<String>>,
+    history: PropHistory,
+    /// The flattened prop indexes to record for every class
+    indexes: HashMap<ClassId, HashMap<u32, SendPropIdentifier>>,
+}
+
+impl PropHistoryAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the history of a prop
+    pub fn with_prop(mut self, identifier: SendPropIdentifier) -> Self {
+        self.props.insert(identifier);
+        self
+    }
+
+    /// Only record props for entities of the class, by default props are recorded for every class
+    pub fn with_class(mut self, class_name: &str) -> Self {
+        self.classes
+            .get_or_insert_with(HashSet::new)
+            .insert(class_name.into());
+        self
+    }
+
+    fn class_indexes(
+        &mut self,
+        class: ClassId,
+        parser_state: &ParserState,
+    ) -> &HashMap<u32, SendPropIdentifier> {
+        let props = &self.props;
+        let classes = &self.classes;
+        self.indexes.entry(class).or_insert_with(|| {
+            let class_name = parser_state
+                .server_classes
+                .get(usize::from(class))
+                .map(|class| class.name.as_str())
+                .unwrap_or_default();
+            if let Some(classes) = classes {
+                if !classes.contains(class_name) {
+                    return HashMap::new();
+                }
+            }
+            let mut indexes = HashMap::new();
+            let mut found = HashSet::new();
+            if let Some(send_table) = parser_state.send_tables.get(usize::from(class)) {
+                for (index, prop) in send_table.flattened_props().iter().enumerate() {
+                    if props.contains(&prop.identifier) && found.insert(prop.identifier) {
+                        indexes.insert(index as u32, prop.identifier);
+                    }
+                }
+            }
+            indexes
+        })
+    }
+
+    fn handle_entity(&mut self, entity: &PacketEntity, tick: DemoTick, parser_state: &ParserState) {
+        match entity.update_type {
+            UpdateType::Enter | UpdateType::Delta => {
+                let indexes = self.class_indexes(entity.server_class, parser_state);
+                if indexes.is_empty() {
+                    return;
+                }
+                let values: Vec<_> = entity
+                    .props(parser_state)
+                    .filter_map(|prop| Some((*indexes.get(&prop.index)?, prop.value)))
+                    .collect();
+                let timelines = self
+                    .history
+                    .entities
+                    .entry(entity.entity_index)
+                    .or_default();
+                for (identifier, value) in values {
+                    timelines
+                        .entry(identifier)
+                        .or_default()
+                        .push(tick, Some(value));
+                }
+            }
+            UpdateType::Delete => self.remove_entity(entity.entity_index, tick),
+            UpdateType::Leave => {}
+        }
+    }
+
+    fn remove_entity(&mut self, entity: EntityId, tick: DemoTick) {
+        if let Some(timelines) = self.history.entities.get_mut(&entity) {
+            for timeline in timelines.values_mut() {
+                timeline.push(tick, None);
+            }
+        }
+    }
+}
+
+impl MessageHandler for PropHistoryAnalyser {
+    type Output = PropHistory;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        if let Message::PacketEntities(message) = message {
+            for entity in &message.entities {
+                self.handle_entity(entity, tick, parser_state);
+            }
+            for entity in &message.removed_entities {
+                self.remove_entity(*entity, tick);
+            }
+        }
+    }
+
+    fn prop_filter(&self) -> Option<PropFilter> {
+        let filter = self
+            .props
+            .iter()
+            .fold(PropFilter::new(), |filter, prop| filter.with_prop(*prop));
+        Some(match &self.classes {
+            Some(classes) => classes
+                .iter()
+                .fold(filter, |filter, class| filter.with_class(class)),
+            None => filter,
+        })
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.history
+    }
+}
+
+impl BorrowMessageHandler for PropHistoryAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.history
+    }
+}
+
+#[test]
+fn test_timeline() {
+    let mut timeline = PropTimeline::default();
+    timeline.push(10u32.into(), Some(SendPropValue::Integer(1)));
+    timeline.push(20u32.into(), Some(SendPropValue::Integer(1)));
+    timeline.push(30u32.into(), Some(SendPropValue::Integer(2)));
+    timeline.push(30u32.into(), Some(SendPropValue::Integer(3)));
+    timeline.push(40u32.into(), None);
+
+    assert_eq!(3, timeline.changes.len());
+    assert_eq!(None, timeline.value_at(5u32.into()));
+    assert_eq!(
+        Some(&SendPropValue::Integer(1)),
+        timeline.value_at(29u32.into())
+    );
+    assert_eq!(
+        Some(&SendPropValue::Integer(3)),
+        timeline.value_at(30u32.into())
+    );
+    assert_eq!(None, timeline.value_at(100u32.into()));
+    assert_eq!(
+        2,
+        timeline.changes_between(10u32.into(), 30u32.into()).len()
+    );
+    assert_eq!(
+        0,
+        timeline.changes_between(11u32.into(), 29u32.into()).len()
+    );
+
+    // an update that is reverted within the same tick leaves no change
+    let mut timeline = PropTimeline::default();
+    timeline.push(20u32.into(), Some(SendPropValue::Integer(1)));
+    timeline.push(30u32.into(), Some(SendPropValue::Integer(2)));
+    timeline.push(30u32.into(), Some(SendPropValue::Integer(1)));
+    assert_eq!(
+        vec![PropValueChange {
+            tick: 20u32.into(),
+            value: Some(SendPropValue::Integer(1))
+        }],
+        timeline.changes
+    );
+}
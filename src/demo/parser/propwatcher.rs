@@ -0,0 +1,238 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{
+    EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
+};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::ServerClassName;
+use crate::demo::parser::MessageHandler;
+use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::ParserState;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::mpsc::{channel, Receiver};
+
+/// A change in the value of a watched prop
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropChange {
+    pub entity: EntityId,
+    pub class_name: ServerClassName,
+    pub identifier: SendPropIdentifier,
+    /// `None` if the entity was just created
+    pub old_value: Option<SendPropValue>,
+    pub new_value: SendPropValue,
+    pub tick: DemoTick,
+}
+
+struct Subscription<'a> {
+    identifier: SendPropIdentifier,
+    class_name: Option<String>,
+    callback: Box<dyn FnMut(&PropChange) + 'a>,
+}
+
+impl Subscription<'_> {
+    fn matches(&self, change: &PropChange) -> bool {
+        self.identifier == change.identifier
+            && self
+                .class_name
+                .as_deref()
+                .map_or(true, |class_name| class_name == change.class_name.as_str())
+    }
+}
+
+/// Notify subscribers when the value of a prop changes
+///
+/// ```no_run
+/// # use tf_demo_parser::{Demo, DemoParser};
+/// # use tf_demo_parser::demo::parser::propwatcher::PropWatcher;
+/// # use tf_demo_parser::demo::sendprop::SendPropIdentifier;
+/// # let file = std::fs::read("demo.dem").unwrap();
+/// let mut watcher = PropWatcher::new();
+/// watcher.subscribe(
+///     SendPropIdentifier::new("DT_BasePlayer", "m_iHealth"),
+///     Some("CTFPlayer"),
+///     |change| println!("{}: {:?} -> {}", change.entity, change.old_value, change.new_value),
+/// );
+/// let demo = Demo::new(&file);
+/// DemoParser::new_with_analyser(demo.get_stream(), watcher).parse().unwrap();
+/// ```
+#[derive(Default)]
+pub struct PropWatcher<'a> {
+    subscriptions: Vec<Subscription<'a>>,
+    watched: HashSet<SendPropIdentifier>,
+    entities: HashMap<EntityId, WatchedEntity>,
+    tick: DemoTick,
+}
+
+/// The last value of the watched props of an entity
+struct WatchedEntity {
+    serial: u32,
+    class_name: ServerClassName,
+    /// By prop index, since arrays can contain the same prop identifier multiple times
+    values: HashMap<u32, SendPropValue>,
+}
+
+impl<'a> PropWatcher<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `callback` every time the prop changes, optionally only for entities of a specific server class
+    pub fn subscribe<F: FnMut(&PropChange) + 'a>(
+        &mut self,
+        identifier: SendPropIdentifier,
+        class_name: Option<&str>,
+        callback: F,
+    ) {
+        self.watched.insert(identifier);
+        self.subscriptions.push(Subscription {
+            identifier,
+            class_name: class_name.map(String::from),
+            callback: Box::new(callback),
+        });
+    }
+
+    /// Receive every change of the prop over a channel, optionally only for entities of a specific server class
+    pub fn subscribe_channel(
+        &mut self,
+        identifier: SendPropIdentifier,
+        class_name: Option<&str>,
+    ) -> Receiver<PropChange> {
+        let (sender, receiver) = channel();
+        self.subscribe(identifier, class_name, move |change| {
+            // the receiver being dropped only means nobody is interested anymore
+            let _ = sender.send(change.clone());
+        });
+        receiver
+    }
+
+    fn handle_packet_entities(
+        &mut self,
+        message: &PacketEntitiesMessage,
+        parser_state: &ParserState,
+    ) {
+        let mut changes = Vec::new();
+        for entity in &message.entities {
+            match entity.update_type {
+                UpdateType::Enter => self.handle_enter(entity, parser_state, &mut changes),
+                UpdateType::Delta => self.handle_delta(entity, &mut changes),
+                UpdateType::Leave => {}
+                UpdateType::Delete => {
+                    self.entities.remove(&entity.entity_index);
+                }
+            }
+        }
+        for id in &message.removed_entities {
+            self.entities.remove(id);
+        }
+        if message.delta.is_none() {
+            // full update, every live entity is included in the packet
+            let live: HashSet<EntityId> = message
+                .entities
+                .iter()
+                .map(|entity| entity.entity_index)
+                .collect();
+            self.entities.retain(|id, _| live.contains(id));
+        }
+
+        for change in &changes {
+            for subscription in self.subscriptions.iter_mut() {
+                if subscription.matches(change) {
+                    (subscription.callback)(change);
+                }
+            }
+        }
+    }
+
+    fn handle_enter(
+        &mut self,
+        entity: &PacketEntity,
+        parser_state: &ParserState,
+        changes: &mut Vec<PropChange>,
+    ) {
+        let Some(class) = parser_state
+            .server_classes
+            .get(usize::from(entity.server_class))
+        else {
+            return;
+        };
+        // only entities entering the pvs have their serial number set
+        let old_values = self
+            .entities
+            .remove(&entity.entity_index)
+            .filter(|existing| existing.serial == entity.serial_number)
+            .map(|existing| existing.values)
+            .unwrap_or_default();
+
+        // the props sent with the entity take precedence over the baseline
+        let baseline = entity.get_baseline_props(parser_state);
+        let props: BTreeMap<u32, &SendProp> = baseline
+            .iter()
+            .chain(entity.props.iter())
+            .filter(|prop| self.watched.contains(&prop.identifier))
+            .map(|prop| (prop.index, prop))
+            .collect();
+
+        let mut values = HashMap::with_capacity(props.len());
+        for (index, prop) in props {
+            let old_value = old_values.get(&index);
+            if old_value != Some(&prop.value) {
+                changes.push(PropChange {
+                    entity: entity.entity_index,
+                    class_name: class.name.clone(),
+                    identifier: prop.identifier,
+                    old_value: old_value.cloned(),
+                    new_value: prop.value.clone(),
+                    tick: self.tick,
+                });
+            }
+            values.insert(index, prop.value.clone());
+        }
+
+        self.entities.insert(
+            entity.entity_index,
+            WatchedEntity {
+                serial: entity.serial_number,
+                class_name: class.name.clone(),
+                values,
+            },
+        );
+    }
+
+    fn handle_delta(&mut self, entity: &PacketEntity, changes: &mut Vec<PropChange>) {
+        let Some(existing) = self.entities.get_mut(&entity.entity_index) else {
+            return;
+        };
+        for prop in entity.props.iter() {
+            if !self.watched.contains(&prop.identifier) {
+                continue;
+            }
+            let old_value = existing.values.insert(prop.index, prop.value.clone());
+            if old_value.as_ref() != Some(&prop.value) {
+                changes.push(PropChange {
+                    entity: entity.entity_index,
+                    class_name: existing.class_name.clone(),
+                    identifier: prop.identifier,
+                    old_value,
+                    new_value: prop.value.clone(),
+                    tick: self.tick,
+                });
+            }
+        }
+    }
+}
+
+impl MessageHandler for PropWatcher<'_> {
+    type Output = ();
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        self.tick = tick;
+        if let Message::PacketEntities(message) = message {
+            self.handle_packet_entities(message, parser_state);
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {}
+}
@@ -0,0 +1,621 @@
+//! A small expression language to filter entity updates
+//!
+//! ```text
+//! class == "CTFPlayer" && prop("DT_BasePlayer.m_iHealth") < 50
+//! ```
+//!
+//! The following operands are available
+//!
+//! - `class`: the server class name of the entity
+//! - `entity`: the entity id
+//! - `tick`: the tick of the update
+//! - `prop("DT_Table.m_name")`: the current value of a prop
+//! - string, number and boolean literals
+//!
+//! Operands can be compared using `==`, `!=`, `<`, `<=`, `>` and `>=` and conditions can be combined
+//! using `&&`, `||`, `!` and parentheses. Props that the entity doesn't have never match a comparison.
+
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{EntityId, UpdateType};
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::parser::entitytracker::{EntityTracker, TrackedEntity};
+use crate::demo::parser::handler::BorrowMessageHandler;
+use crate::demo::parser::MessageHandler;
+use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum QueryError {
+    #[error("Unexpected character '{character}' at position {position}")]
+    UnexpectedCharacter { character: char, position: usize },
+    #[error("Unterminated string starting at position {0}")]
+    UnterminatedString(usize),
+    #[error("Unexpected {found}, expected {expected}")]
+    UnexpectedToken {
+        found: String,
+        expected: &'static str,
+    },
+    #[error("Invalid prop name \"{0}\", expected \"DT_Table.m_name\"")]
+    InvalidPropName(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    String(String),
+    Number(f64),
+    LeftParen,
+    RightParen,
+    And,
+    Or,
+    Not,
+    Comparison(Comparison),
+    End,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Ident(ident) => format!("'{ident}'"),
+            Token::String(string) => format!("\"{string}\""),
+            Token::Number(number) => number.to_string(),
+            Token::LeftParen => "'('".into(),
+            Token::RightParen => "')'".into(),
+            Token::And => "'&&'".into(),
+            Token::Or => "'||'".into(),
+            Token::Not => "'!'".into(),
+            Token::Comparison(comparison) => format!("'{}'", comparison.as_str()),
+            Token::End => "end of query".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    while let Some((position, character)) = chars.next() {
+        let mut followed_by = |expected: char| chars.next_if(|(_, c)| *c == expected).is_some();
+        let token = match character {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '&' if followed_by('&') => Token::And,
+            '|' if followed_by('|') => Token::Or,
+            '=' if followed_by('=') => Token::Comparison(Comparison::Equal),
+            '!' if followed_by('=') => Token::Comparison(Comparison::NotEqual),
+            '!' => Token::Not,
+            '<' if followed_by('=') => Token::Comparison(Comparison::LessOrEqual),
+            '<' => Token::Comparison(Comparison::Less),
+            '>' if followed_by('=') => Token::Comparison(Comparison::GreaterOrEqual),
+            '>' => Token::Comparison(Comparison::Greater),
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, c)) => string.push(c),
+                        None => return Err(QueryError::UnterminatedString(position)),
+                    }
+                }
+                Token::String(string)
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                let mut number = String::from(c);
+                while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit() || *c == '.') {
+                    number.push(c);
+                }
+                Token::Number(
+                    number
+                        .parse()
+                        .map_err(|_| QueryError::UnexpectedCharacter {
+                            character: c,
+                            position,
+                        })?,
+                )
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let mut ident = String::from(c);
+                while let Some((_, c)) =
+                    chars.next_if(|(_, c)| c.is_ascii_alphanumeric() || *c == '_')
+                {
+                    ident.push(c);
+                }
+                Token::Ident(ident)
+            }
+            character => {
+                return Err(QueryError::UnexpectedCharacter {
+                    character,
+                    position,
+                })
+            }
+        };
+        tokens.push(token);
+    }
+    tokens.push(Token::End);
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Class,
+    Entity,
+    Tick,
+    Prop(SendPropIdentifier, String),
+    String(String),
+    Number(f64),
+    Bool(bool),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    And(Box<Expression>, Box<Expression>),
+    Or(Box<Expression>, Box<Expression>),
+    Not(Box<Expression>),
+    Compare(Operand, Comparison, Operand),
+    /// A single operand, true if the operand exists and isn't zero, empty or false
+    Operand(Operand),
+}
+
+struct QueryParser {
+    tokens: std::vec::IntoIter<Token>,
+    current: Token,
+}
+
+impl QueryParser {
+    fn new(tokens: Vec<Token>) -> Self {
+        let mut tokens = tokens.into_iter();
+        let current = tokens.next().unwrap_or(Token::End);
+        QueryParser { tokens, current }
+    }
+
+    fn advance(&mut self) -> Token {
+        let next = self.tokens.next().unwrap_or(Token::End);
+        std::mem::replace(&mut self.current, next)
+    }
+
+    fn expect(&mut self, expected_token: Token, expected: &'static str) -> Result<(), QueryError> {
+        if self.current == expected_token {
+            self.advance();
+            Ok(())
+        } else {
+            Err(QueryError::UnexpectedToken {
+                found: self.current.describe(),
+                expected,
+            })
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expression, QueryError> {
+        let mut expression = self.parse_and()?;
+        while self.current == Token::Or {
+            self.advance();
+            expression = Expression::Or(Box::new(expression), Box::new(self.parse_and()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<Expression, QueryError> {
+        let mut expression = self.parse_unary()?;
+        while self.current == Token::And {
+            self.advance();
+            expression = Expression::And(Box::new(expression), Box::new(self.parse_unary()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, QueryError> {
+        match self.current {
+            Token::Not => {
+                self.advance();
+                Ok(Expression::Not(Box::new(self.parse_unary()?)))
+            }
+            Token::LeftParen => {
+                self.advance();
+                let expression = self.parse_or()?;
+                self.expect(Token::RightParen, "')'")?;
+                Ok(expression)
+            }
+            _ => {
+                let left = self.parse_operand()?;
+                if let Token::Comparison(comparison) = self.current {
+                    self.advance();
+                    let right = self.parse_operand()?;
+                    Ok(Expression::Compare(left, comparison, right))
+                } else {
+                    Ok(Expression::Operand(left))
+                }
+            }
+        }
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, QueryError> {
+        match self.advance() {
+            Token::String(string) => Ok(Operand::String(string)),
+            Token::Number(number) => Ok(Operand::Number(number)),
+            Token::Ident(ident) => match ident.as_str() {
+                "class" => Ok(Operand::Class),
+                "entity" => Ok(Operand::Entity),
+                "tick" => Ok(Operand::Tick),
+                "true" => Ok(Operand::Bool(true)),
+                "false" => Ok(Operand::Bool(false)),
+                "prop" => {
+                    self.expect(Token::LeftParen, "'('")?;
+                    let name = match self.advance() {
+                        Token::String(name) => name,
+                        token => {
+                            return Err(QueryError::UnexpectedToken {
+                                found: token.describe(),
+                                expected: "prop name",
+                            })
+                        }
+                    };
+                    self.expect(Token::RightParen, "')'")?;
+                    // table names never contain a '.' but prop names can
+                    let (table, prop) = name
+                        .split_once('.')
+                        .ok_or_else(|| QueryError::InvalidPropName(name.clone()))?;
+                    Ok(Operand::Prop(SendPropIdentifier::new(table, prop), name))
+                }
+                _ => Err(QueryError::UnexpectedToken {
+                    found: format!("'{ident}'"),
+                    expected: "operand",
+                }),
+            },
+            token => Err(QueryError::UnexpectedToken {
+                found: token.describe(),
+                expected: "operand",
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value<'a> {
+    Number(f64),
+    String(&'a str),
+    Bool(bool),
+    /// Other prop values are compared by their string representation
+    Other(String),
+}
+
+impl Value<'_> {
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Number(number) => *number != 0.0,
+            Value::String(string) => !string.is_empty(),
+            Value::Bool(bool) => *bool,
+            Value::Other(_) => true,
+        }
+    }
+
+    fn compare(&self, comparison: Comparison, other: &Value) -> bool {
+        let ordering = match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+            (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+            (Value::Bool(a), Value::Number(b)) | (Value::Number(b), Value::Bool(a)) => {
+                return match comparison {
+                    Comparison::Equal => *a == (*b != 0.0),
+                    Comparison::NotEqual => *a != (*b != 0.0),
+                    _ => false,
+                }
+            }
+            (a, b) => Some(a.as_string().cmp(&b.as_string())),
+        };
+        let Some(ordering) = ordering else {
+            return false;
+        };
+        match comparison {
+            Comparison::Equal => ordering.is_eq(),
+            Comparison::NotEqual => ordering.is_ne(),
+            Comparison::Less => ordering.is_lt(),
+            Comparison::LessOrEqual => ordering.is_le(),
+            Comparison::Greater => ordering.is_gt(),
+            Comparison::GreaterOrEqual => ordering.is_ge(),
+        }
+    }
+
+    fn as_string(&self) -> String {
+        match self {
+            Value::Number(number) => number.to_string(),
+            Value::String(string) => string.to_string(),
+            Value::Bool(bool) => bool.to_string(),
+            Value::Other(other) => other.clone(),
+        }
+    }
+}
+
+impl<'a> From<&'a SendPropValue> for Value<'a> {
+    fn from(value: &'a SendPropValue) -> Self {
+        match value {
+            SendPropValue::Integer(int) => Value::Number(*int as f64),
+            SendPropValue::Float(float) => Value::Number(*float as f64),
+            SendPropValue::String(string) => Value::String(string.as_str()),
+            value => Value::Other(value.to_string()),
+        }
+    }
+}
+
+/// A parsed query
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    pub expression: Expression,
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parser = QueryParser::new(tokenize(input)?);
+        let expression = parser.parse_or()?;
+        parser.expect(Token::End, "end of query")?;
+        Ok(Query { expression })
+    }
+}
+
+impl Query {
+    pub fn matches(&self, entity: &TrackedEntity, tick: DemoTick) -> bool {
+        Self::evaluate(&self.expression, entity, tick)
+    }
+
+    fn evaluate(expression: &Expression, entity: &TrackedEntity, tick: DemoTick) -> bool {
+        match expression {
+            Expression::And(a, b) => {
+                Self::evaluate(a, entity, tick) && Self::evaluate(b, entity, tick)
+            }
+            Expression::Or(a, b) => {
+                Self::evaluate(a, entity, tick) || Self::evaluate(b, entity, tick)
+            }
+            Expression::Not(expression) => !Self::evaluate(expression, entity, tick),
+            Expression::Compare(a, comparison, b) => {
+                match (Self::value(a, entity, tick), Self::value(b, entity, tick)) {
+                    (Some(a), Some(b)) => a.compare(*comparison, &b),
+                    _ => false,
+                }
+            }
+            Expression::Operand(operand) => Self::value(operand, entity, tick)
+                .map(|value| value.truthy())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn value<'a>(
+        operand: &'a Operand,
+        entity: &'a TrackedEntity,
+        tick: DemoTick,
+    ) -> Option<Value<'a>> {
+        Some(match operand {
+            Operand::Class => Value::String(entity.class_name.as_str()),
+            Operand::Entity => Value::Number(u32::from(entity.id) as f64),
+            Operand::Tick => Value::Number(u32::from(tick) as f64),
+            Operand::Prop(identifier, _) => Value::from(&entity.get_prop(*identifier)?.value),
+            Operand::String(string) => Value::String(string.as_str()),
+            Operand::Number(number) => Value::Number(*number),
+            Operand::Bool(bool) => Value::Bool(*bool),
+        })
+    }
+
+    /// All props used in the query, with their names
+    pub fn props(&self) -> Vec<(SendPropIdentifier, &str)> {
+        fn collect<'a>(expression: &'a Expression, props: &mut Vec<(SendPropIdentifier, &'a str)>) {
+            let mut add = |operand: &'a Operand| {
+                if let Operand::Prop(identifier, name) = operand {
+                    if !props.iter().any(|(existing, _)| existing == identifier) {
+                        props.push((*identifier, name.as_str()));
+                    }
+                }
+            };
+            match expression {
+                Expression::And(a, b) | Expression::Or(a, b) => {
+                    collect(a, props);
+                    collect(b, props);
+                }
+                Expression::Not(expression) => collect(expression, props),
+                Expression::Compare(a, _, b) => {
+                    add(a);
+                    add(b);
+                }
+                Expression::Operand(operand) => add(operand),
+            }
+        }
+        let mut props = Vec::new();
+        collect(&self.expression, &mut props);
+        props
+    }
+}
+
+/// An entity update that matched the query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryMatch {
+    pub tick: DemoTick,
+    pub entity: EntityId,
+    pub class: String,
+    /// The values of the props used in the query, formatted using their `Display` implementation
+    pub values: BTreeMap<String, String>,
+}
+
+/// Evaluate a query against every entity update
+pub struct QueryAnalyser<'a> {
+    query: Query,
+    /// The props used in the query, with their names
+    props: Vec<(SendPropIdentifier, String)>,
+    tracker: EntityTracker,
+    matches: Vec<QueryMatch>,
+    callback: Option<Box<dyn FnMut(QueryMatch) + 'a>>,
+}
+
+impl<'a> QueryAnalyser<'a> {
+    pub fn new(query: Query) -> Self {
+        let props = query
+            .props()
+            .into_iter()
+            .map(|(identifier, name)| (identifier, name.to_string()))
+            .collect();
+        QueryAnalyser {
+            query,
+            props,
+            tracker: EntityTracker::new(),
+            matches: Vec::new(),
+            callback: None,
+        }
+    }
+
+    /// Pass every match to `callback` as soon as it's found, instead of collecting them in the output
+    pub fn with_callback<F: FnMut(QueryMatch) + 'a>(query: Query, callback: F) -> Self {
+        QueryAnalyser {
+            callback: Some(Box::new(callback)),
+            ..Self::new(query)
+        }
+    }
+}
+
+impl MessageHandler for QueryAnalyser<'_> {
+    type Output = Vec<QueryMatch>;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::PacketEntities)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        let Message::PacketEntities(message) = message else {
+            return;
+        };
+        self.tracker.handle_packet_entities(message, parser_state);
+
+        for update in &message.entities {
+            if !matches!(update.update_type, UpdateType::Enter | UpdateType::Delta) {
+                continue;
+            }
+            let Some(entity) = self.tracker.state.get(update.entity_index) else {
+                continue;
+            };
+            if self.query.matches(entity, tick) {
+                let query_match = QueryMatch {
+                    tick,
+                    entity: entity.id,
+                    class: entity.class_name.to_string(),
+                    values: self
+                        .props
+                        .iter()
+                        .filter_map(|(identifier, name)| {
+                            let prop = entity.get_prop(*identifier)?;
+                            Some((name.clone(), prop.value.to_string()))
+                        })
+                        .collect(),
+                };
+                match &mut self.callback {
+                    Some(callback) => callback(query_match),
+                    None => self.matches.push(query_match),
+                }
+            }
+        }
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        parser_state: &ParserState,
+    ) {
+        self.tracker
+            .handle_data_tables(parse_tables, server_classes, parser_state);
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.matches
+    }
+}
+
+impl BorrowMessageHandler for QueryAnalyser<'_> {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.matches
+    }
+}
+
+#[test]
+fn test_parse_query() {
+    let query: Query =
+        r#"class == "CTFPlayer" && !(prop("DT_BasePlayer.m_iHealth") >= 50 || tick < 10)"#
+            .parse()
+            .unwrap();
+    assert_eq!(
+        Expression::And(
+            Box::new(Expression::Compare(
+                Operand::Class,
+                Comparison::Equal,
+                Operand::String("CTFPlayer".into())
+            )),
+            Box::new(Expression::Not(Box::new(Expression::Or(
+                Box::new(Expression::Compare(
+                    Operand::Prop(
+                        SendPropIdentifier::new("DT_BasePlayer", "m_iHealth"),
+                        "DT_BasePlayer.m_iHealth".into()
+                    ),
+                    Comparison::GreaterOrEqual,
+                    Operand::Number(50.0)
+                )),
+                Box::new(Expression::Compare(
+                    Operand::Tick,
+                    Comparison::Less,
+                    Operand::Number(10.0)
+                ))
+            ))))
+        ),
+        query.expression
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Err(QueryError::UnterminatedString(9)),
+        "class == \"CTFPlayer".parse::<Query>()
+    );
+    assert_eq!(
+        Err(QueryError::InvalidPropName("m_iHealth".into())),
+        "prop(\"m_iHealth\") > 1".parse::<Query>()
+    );
+    assert!(matches!(
+        "class ==".parse::<Query>(),
+        Err(QueryError::UnexpectedToken { .. })
+    ));
+    assert!(matches!(
+        "class == 1 1".parse::<Query>(),
+        Err(QueryError::UnexpectedToken { .. })
+    ));
+}
+
+#[test]
+fn test_compare_values() {
+    assert!(Value::Number(1.0).compare(Comparison::Less, &Value::Number(2.0)));
+    assert!(Value::String("a").compare(Comparison::Equal, &Value::String("a")));
+    assert!(Value::Bool(true).compare(Comparison::Equal, &Value::Number(1.0)));
+    assert!(!Value::Number(f64::NAN).compare(Comparison::Equal, &Value::Number(f64::NAN)));
+}
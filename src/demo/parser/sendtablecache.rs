@@ -0,0 +1,229 @@
+use crate::demo::packet::datatable::{
+    flatten_send_tables, hash_data_tables, ParseSendTable, SendTable, ServerClass,
+};
+use crate::Result;
+use std::sync::{Arc, Mutex};
+
+const DEFAULT_CAPACITY: usize = 4;
+
+/// Flattened send tables, by the hash of the data tables they are created from
+///
+/// Demos recorded on the same version of the game share the same data tables, by sharing a cache
+/// between parsers the send tables only have to be flattened once for every version.
+///
+/// The cache is cheap to clone, all clones share the same cached tables.
+///
+/// ```no_run
+/// # use tf_demo_parser::{Demo, DemoParser};
+/// # use tf_demo_parser::demo::parser::sendtablecache::SendTableCache;
+/// let cache = SendTableCache::new();
+/// for path in ["a.dem", "b.dem"] {
+///     let file = std::fs::read(path).unwrap();
+///     let demo = Demo::new(&file);
+///     let parser = DemoParser::new(demo.get_stream()).with_send_table_cache(cache.clone());
+///     parser.parse().unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SendTableCache {
+    inner: Arc<Mutex<CacheEntries>>,
+}
+
+#[derive(Debug)]
+struct CacheEntries {
+    capacity: usize,
+    /// Least recently used first
+    entries: Vec<CacheEntry>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    hash: u64,
+    // the source tables are kept to verify cache hits, since different tables can have the same hash
+    tables: Vec<ParseSendTable>,
+    server_classes: Vec<ServerClass>,
+    send_tables: Arc<Vec<SendTable>>,
+}
+
+impl CacheEntry {
+    fn matches(
+        &self,
+        hash: u64,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+    ) -> bool {
+        self.hash == hash && self.tables == tables && self.server_classes == server_classes
+    }
+}
+
+impl Default for SendTableCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SendTableCache {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// Create a cache that keeps the send tables for up to `capacity` different data table layouts
+    pub fn with_capacity(capacity: usize) -> Self {
+        SendTableCache {
+            inner: Arc::new(Mutex::new(CacheEntries {
+                capacity: capacity.max(1),
+                entries: Vec::with_capacity(capacity),
+            })),
+        }
+    }
+
+    /// Get the cached send tables for the data tables
+    pub fn get(
+        &self,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+    ) -> Option<Arc<Vec<SendTable>>> {
+        self.get_by_hash(
+            hash_data_tables(tables, server_classes),
+            tables,
+            server_classes,
+        )
+    }
+
+    fn get_by_hash(
+        &self,
+        hash: u64,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+    ) -> Option<Arc<Vec<SendTable>>> {
+        let mut cache = self.inner.lock().ok()?;
+        let index = cache
+            .entries
+            .iter()
+            .position(|entry| entry.matches(hash, tables, server_classes))?;
+        let entry = cache.entries.remove(index);
+        let send_tables = entry.send_tables.clone();
+        cache.entries.push(entry);
+        Some(send_tables)
+    }
+
+    /// Store the flattened send tables for the data tables
+    pub fn insert(
+        &self,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        send_tables: Arc<Vec<SendTable>>,
+    ) {
+        self.insert_by_hash(
+            hash_data_tables(tables, server_classes),
+            tables,
+            server_classes,
+            send_tables,
+        )
+    }
+
+    fn insert_by_hash(
+        &self,
+        hash: u64,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        send_tables: Arc<Vec<SendTable>>,
+    ) {
+        if let Ok(mut cache) = self.inner.lock() {
+            cache
+                .entries
+                .retain(|entry| !entry.matches(hash, tables, server_classes));
+            if cache.entries.len() >= cache.capacity {
+                cache.entries.remove(0);
+            }
+            cache.entries.push(CacheEntry {
+                hash,
+                tables: tables.to_vec(),
+                server_classes: server_classes.to_vec(),
+                send_tables,
+            });
+        }
+    }
+
+    /// Get the flattened send tables for the data tables, flattening them if they aren't cached yet
+    pub fn get_or_flatten(
+        &self,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+    ) -> Result<Arc<Vec<SendTable>>> {
+        let hash = hash_data_tables(tables, server_classes);
+        if let Some(send_tables) = self.get_by_hash(hash, tables, server_classes) {
+            return Ok(send_tables);
+        }
+        let send_tables = Arc::new(flatten_send_tables(tables, server_classes)?);
+        self.insert_by_hash(hash, tables, server_classes, send_tables.clone());
+        Ok(send_tables)
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner
+            .lock()
+            .map(|cache| cache.entries.len())
+            .unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut cache) = self.inner.lock() {
+            cache.entries.clear();
+        }
+    }
+}
+
+#[test]
+fn test_cache_eviction() {
+    use crate::demo::packet::datatable::SendTableName;
+
+    let test_tables = |name: &'static str| {
+        vec![ParseSendTable {
+            name: SendTableName::from(name),
+            props: Vec::new(),
+            needs_decoder: false,
+        }]
+    };
+
+    let cache = SendTableCache::with_capacity(2);
+    cache.insert(&test_tables("1"), &[], Arc::default());
+    cache.insert(&test_tables("2"), &[], Arc::default());
+    assert!(cache.get(&test_tables("1"), &[]).is_some());
+    cache.insert(&test_tables("3"), &[], Arc::default());
+    assert_eq!(2, cache.len());
+    assert!(cache.get(&test_tables("1"), &[]).is_some());
+    assert!(cache.get(&test_tables("2"), &[]).is_none());
+    assert!(cache.get(&test_tables("3"), &[]).is_some());
+
+    let shared = cache.clone();
+    shared.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_cache_hash_collision() {
+    use crate::demo::packet::datatable::SendTableName;
+
+    let test_tables = |name: &'static str| {
+        vec![ParseSendTable {
+            name: SendTableName::from(name),
+            props: Vec::new(),
+            needs_decoder: false,
+        }]
+    };
+
+    let cache = SendTableCache::new();
+    let tables = test_tables("1");
+    let hash = hash_data_tables(&tables, &[]);
+    cache.insert_by_hash(hash, &tables, &[], Arc::default());
+
+    // different tables that would end up with the same hash don't get the cached send tables
+    let other = test_tables("2");
+    assert!(cache.get_by_hash(hash, &other, &[]).is_none());
+    assert!(cache.get_by_hash(hash, &tables, &[]).is_some());
+}
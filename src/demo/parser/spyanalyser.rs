@@ -0,0 +1,541 @@
+use crate::demo::data::game_state::{
+    BuildingClass, GameState, Player, PlayerClassData, PlayerCondition, PlayerState,
+};
+use crate::demo::data::DemoTick;
+use crate::demo::gameevent_gen::{
+    PlayerDeathEvent, PlayerHurtEvent, PlayerSappedObjectEvent, PlayerShieldBlockedEvent,
+};
+use crate::demo::gamevent::GameEvent;
+use crate::demo::message::gameevent::GameEventMessage;
+use crate::demo::message::packetentities::EntityId;
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::packet::message::MessagePacketMeta;
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::parser::analyser::{Class, Team, UserId};
+use crate::demo::parser::gamestateanalyser::GameStateAnalyser;
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::demo::vector::Vector;
+use crate::ParserState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// `TF_DMG_CUSTOM_BACKSTAB`
+const CUSTOM_BACKSTAB: u16 = 2;
+/// `TF_DEATH_FEIGN_DEATH`
+const DEATH_FLAG_FEIGN: u16 = 0x20;
+/// Number of ticks a `player_sapped_object` event is used to find the spy for a newly sapped building
+const SAPPER_EVENT_WINDOW: u32 = 66;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisguiseSession {
+    pub spy: UserId,
+    pub team: Team,
+    pub class: Class,
+    pub start: DemoTick,
+    pub end: Option<DemoTick>,
+    pub start_position: Vector,
+    pub end_position: Option<Vector>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloakWindow {
+    pub spy: UserId,
+    pub start: DemoTick,
+    pub end: Option<DemoTick>,
+    pub start_position: Vector,
+    pub end_position: Option<Vector>,
+    /// Cloak meter when cloaking
+    pub start_cloak: f32,
+    /// Cloak meter when uncloaking
+    pub end_cloak: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeignDeath {
+    pub spy: UserId,
+    pub attacker: UserId,
+    pub tick: DemoTick,
+    /// The tick the feign death condition ended
+    pub end: Option<DemoTick>,
+    pub position: Vector,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackstabOutcome {
+    Kill,
+    /// The backstab did damage but didn't kill the victim
+    Damage,
+    /// The backstab was blocked by a razorback
+    Blocked,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Backstab {
+    pub spy: UserId,
+    pub victim: UserId,
+    pub tick: DemoTick,
+    pub outcome: BackstabOutcome,
+    pub position: Vector,
+    pub victim_position: Vector,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SapperPlacement {
+    /// The spy placing the sapper, if it could be determined
+    pub spy: Option<UserId>,
+    pub building: EntityId,
+    pub building_class: BuildingClass,
+    pub builder: UserId,
+    pub start: DemoTick,
+    /// The tick the sapper was removed or the building destroyed
+    pub end: Option<DemoTick>,
+    pub position: Vector,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpyState {
+    pub disguises: Vec<DisguiseSession>,
+    pub cloaks: Vec<CloakWindow>,
+    pub feigns: Vec<FeignDeath>,
+    pub backstabs: Vec<Backstab>,
+    pub sappers: Vec<SapperPlacement>,
+}
+
+impl SpyState {
+    pub fn backstab_kills(&self) -> impl Iterator<Item = &Backstab> {
+        self.backstabs
+            .iter()
+            .filter(|backstab| backstab.outcome == BackstabOutcome::Kill)
+    }
+}
+
+/// Open sessions for a single spy, as indexes into the `SpyState` lists
+#[derive(Debug, Default)]
+struct SpyTracking {
+    disguise: Option<usize>,
+    cloak: Option<usize>,
+    feign: Option<usize>,
+    feign_active: bool,
+}
+
+/// Records disguise, cloak, feign death, backstab and sapper usage of all spies
+#[derive(Default, Debug)]
+pub struct SpyAnalyser {
+    game_state: GameStateAnalyser,
+    state: SpyState,
+    spies: BTreeMap<UserId, SpyTracking>,
+    // open sapper placements by building
+    sapped: BTreeMap<EntityId, usize>,
+    sap_events: Vec<(DemoTick, PlayerSappedObjectEvent)>,
+}
+
+impl MessageHandler for SpyAnalyser {
+    type Output = SpyState;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        GameStateAnalyser::does_handle(message_type)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        // handle the event first so we can get the state for the killed player before it's updated
+        if let Message::GameEvent(GameEventMessage { event, .. }) = message {
+            self.handle_event(event, tick);
+        }
+
+        self.game_state.handle_message(message, tick, parser_state);
+
+        if let Message::PacketEntities(_) = message {
+            self.update_spies(tick);
+            self.update_sappers(tick);
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .handle_string_entry(table, index, entry, parser_state)
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        parser_state: &ParserState,
+    ) {
+        self.game_state
+            .handle_data_tables(parse_tables, server_classes, parser_state)
+    }
+
+    fn handle_packet_meta(
+        &mut self,
+        tick: DemoTick,
+        meta: &MessagePacketMeta,
+        parser_state: &ParserState,
+    ) {
+        self.game_state.handle_packet_meta(tick, meta, parser_state)
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.state
+    }
+}
+
+impl BorrowMessageHandler for SpyAnalyser {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.state
+    }
+}
+
+fn user_id(player: &Player) -> Option<UserId> {
+    player.info.as_ref().map(|info| info.user_id)
+}
+
+fn position_of(state: &GameState, user: UserId) -> Vector {
+    state
+        .get_player_by_user_id(user)
+        .map(|player| player.position)
+        .unwrap_or_default()
+}
+
+impl SpyAnalyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The game state the spy data is derived from
+    pub fn game_state(&self) -> &GameState {
+        &self.game_state.state
+    }
+
+    fn handle_event(&mut self, event: &GameEvent, tick: DemoTick) {
+        match event {
+            GameEvent::PlayerDeath(death) => self.handle_death(death, tick),
+            GameEvent::PlayerHurt(hurt) => self.handle_hurt(hurt, tick),
+            GameEvent::PlayerShieldBlocked(blocked) => self.handle_shield_blocked(blocked, tick),
+            GameEvent::PlayerSappedObject(sapped) => {
+                self.sap_events.push((tick, sapped.clone()));
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_death(&mut self, death: &PlayerDeathEvent, tick: DemoTick) {
+        let victim = UserId::from(death.user_id);
+        let attacker = UserId::from(death.attacker);
+        let state = &self.game_state.state;
+
+        if death.death_flags & DEATH_FLAG_FEIGN != 0 {
+            let index = self.state.feigns.len();
+            self.state.feigns.push(FeignDeath {
+                spy: victim,
+                attacker,
+                tick,
+                end: None,
+                position: position_of(state, victim),
+            });
+            let tracking = self.spies.entry(victim).or_default();
+            tracking.feign = Some(index);
+            tracking.feign_active = false;
+        } else if death.custom_kill == CUSTOM_BACKSTAB {
+            self.state.backstabs.push(Backstab {
+                spy: attacker,
+                victim,
+                tick,
+                outcome: BackstabOutcome::Kill,
+                position: position_of(state, attacker),
+                victim_position: position_of(state, victim),
+            });
+        }
+    }
+
+    fn handle_hurt(&mut self, hurt: &PlayerHurtEvent, tick: DemoTick) {
+        if hurt.custom == CUSTOM_BACKSTAB && hurt.health > 0 {
+            let victim = UserId::from(hurt.user_id);
+            let spy = UserId::from(hurt.attacker);
+            let state = &self.game_state.state;
+            self.state.backstabs.push(Backstab {
+                spy,
+                victim,
+                tick,
+                outcome: BackstabOutcome::Damage,
+                position: position_of(state, spy),
+                victim_position: position_of(state, victim),
+            });
+        }
+    }
+
+    fn handle_shield_blocked(&mut self, blocked: &PlayerShieldBlockedEvent, tick: DemoTick) {
+        let state = &self.game_state.state;
+        let spy = state.get_player(EntityId::from(blocked.attacker_ent_index as u32));
+        let victim = state.get_player(EntityId::from(blocked.blocker_ent_index as u32));
+        if let (Some(spy), Some(victim)) = (spy, victim) {
+            if let (Some(spy_id), Some(victim_id)) = (user_id(spy), user_id(victim)) {
+                self.state.backstabs.push(Backstab {
+                    spy: spy_id,
+                    victim: victim_id,
+                    tick,
+                    outcome: BackstabOutcome::Blocked,
+                    position: spy.position,
+                    victim_position: victim.position,
+                });
+            }
+        }
+    }
+
+    fn update_spies(&mut self, tick: DemoTick) {
+        let state = &self.game_state.state;
+        let mut seen = BTreeSet::new();
+
+        for player in &state.players {
+            let Some(spy) = user_id(player) else {
+                continue;
+            };
+            let alive = player.state == PlayerState::Alive;
+            let (disguise, cloaked) = match player.class_data {
+                PlayerClassData::Spy {
+                    disguise_team,
+                    disguise_class,
+                    cloak,
+                } => {
+                    let disguise = (alive && disguise_class != Class::Other)
+                        .then_some((disguise_team, disguise_class));
+                    let cloaked = (alive && player.has_condition(PlayerCondition::Stealthed))
+                        .then_some(cloak);
+                    (disguise, cloaked)
+                }
+                _ => (None, None),
+            };
+            let feigning = player.has_condition(PlayerCondition::FeignDeath);
+            seen.insert(spy);
+
+            let tracking = self.spies.entry(spy).or_default();
+
+            // disguise sessions
+            let current = tracking
+                .disguise
+                .and_then(|index| self.state.disguises.get_mut(index));
+            match (current, disguise) {
+                (Some(session), Some((team, class)))
+                    if session.team == team && session.class == class => {}
+                (current, new) => {
+                    if let Some(session) = current {
+                        session.end = Some(tick);
+                        session.end_position = Some(player.position);
+                        tracking.disguise = None;
+                    }
+                    if let Some((team, class)) = new {
+                        tracking.disguise = Some(self.state.disguises.len());
+                        self.state.disguises.push(DisguiseSession {
+                            spy,
+                            team,
+                            class,
+                            start: tick,
+                            end: None,
+                            start_position: player.position,
+                            end_position: None,
+                        });
+                    }
+                }
+            }
+
+            // cloak windows
+            let current = tracking
+                .cloak
+                .and_then(|index| self.state.cloaks.get_mut(index));
+            match (current, cloaked) {
+                (Some(_), Some(_)) => {}
+                (Some(window), None) => {
+                    window.end = Some(tick);
+                    window.end_position = Some(player.position);
+                    if let PlayerClassData::Spy { cloak, .. } = player.class_data {
+                        window.end_cloak = Some(cloak);
+                    }
+                    tracking.cloak = None;
+                }
+                (None, Some(cloak)) => {
+                    tracking.cloak = Some(self.state.cloaks.len());
+                    self.state.cloaks.push(CloakWindow {
+                        spy,
+                        start: tick,
+                        end: None,
+                        start_position: player.position,
+                        end_position: None,
+                        start_cloak: cloak,
+                        end_cloak: None,
+                    });
+                }
+                (None, None) => {}
+            }
+
+            // feign deaths are started from the death event, the condition tells us when it ends
+            if feigning {
+                tracking.feign_active = tracking.feign.is_some();
+            } else if tracking.feign_active {
+                if let Some(feign) = tracking
+                    .feign
+                    .take()
+                    .and_then(|index| self.state.feigns.get_mut(index))
+                {
+                    feign.end = Some(tick);
+                }
+                tracking.feign_active = false;
+            }
+        }
+
+        // close everything for players that left
+        for (spy, tracking) in self.spies.iter_mut() {
+            if seen.contains(spy) {
+                continue;
+            }
+            if let Some(session) = tracking
+                .disguise
+                .take()
+                .and_then(|index| self.state.disguises.get_mut(index))
+            {
+                session.end = Some(tick);
+            }
+            if let Some(window) = tracking
+                .cloak
+                .take()
+                .and_then(|index| self.state.cloaks.get_mut(index))
+            {
+                window.end = Some(tick);
+            }
+        }
+    }
+
+    fn update_sappers(&mut self, tick: DemoTick) {
+        let state = &self.game_state.state;
+
+        self.sap_events
+            .retain(|(event_tick, _)| *event_tick + SAPPER_EVENT_WINDOW >= tick);
+
+        for (id, building) in state.buildings.iter() {
+            let open = self.sapped.get(id).copied();
+            match (open, building.sapped()) {
+                (None, true) => {
+                    let builder = building.builder();
+                    let building_class = building.class();
+                    let spy = self
+                        .sap_events
+                        .iter()
+                        .rev()
+                        .find(|(_, event)| {
+                            UserId::from(event.owner_id) == builder
+                                && object_class(event.object) == Some(building_class)
+                        })
+                        .map(|(_, event)| UserId::from(event.user_id));
+                    self.sapped.insert(*id, self.state.sappers.len());
+                    self.state.sappers.push(SapperPlacement {
+                        spy,
+                        building: *id,
+                        building_class,
+                        builder,
+                        start: tick,
+                        end: None,
+                        position: building.position(),
+                    });
+                }
+                (Some(index), false) => {
+                    if let Some(placement) = self.state.sappers.get_mut(index) {
+                        placement.end = Some(tick);
+                    }
+                    self.sapped.remove(id);
+                }
+                _ => {}
+            }
+        }
+
+        // destroyed buildings
+        let sappers = &mut self.state.sappers;
+        self.sapped.retain(|id, index| {
+            let alive = state.buildings.contains_key(id);
+            if !alive {
+                if let Some(placement) = sappers.get_mut(*index) {
+                    placement.end = Some(tick);
+                }
+            }
+            alive
+        });
+    }
+}
+
+/// Convert the `object` field from `player_sapped_object` into the building class
+fn object_class(object: u8) -> Option<BuildingClass> {
+    match object {
+        0 => Some(BuildingClass::Dispenser),
+        1 => Some(BuildingClass::Teleporter),
+        2 => Some(BuildingClass::Sentry),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_spy_sessions() {
+    use crate::demo::parser::analyser::{ClassList, UserInfo};
+
+    let mut analyser = SpyAnalyser::new();
+    let spy_id = UserId::from(3u16);
+    let player = analyser
+        .game_state
+        .state
+        .get_or_create_player(EntityId::from(1u32));
+    player.class = Class::Spy;
+    player.info = Some(UserInfo {
+        classes: ClassList::default(),
+        name: "spy".into(),
+        user_id: spy_id,
+        steam_id: String::new(),
+        entity_id: EntityId::from(1u32),
+        team: Team::Red,
+    });
+    player.class_data = PlayerClassData::Spy {
+        disguise_team: Team::Blue,
+        disguise_class: Class::Medic,
+        cloak: 100.0,
+    };
+    analyser.update_spies(DemoTick::from(10u32));
+
+    let player = analyser
+        .game_state
+        .state
+        .get_or_create_player(EntityId::from(1u32));
+    player.conditions[0] = 1 << PlayerCondition::Stealthed as u8;
+    player.class_data = PlayerClassData::Spy {
+        disguise_team: Team::Blue,
+        disguise_class: Class::Medic,
+        cloak: 80.0,
+    };
+    analyser.update_spies(DemoTick::from(20u32));
+
+    let player = analyser
+        .game_state
+        .state
+        .get_or_create_player(EntityId::from(1u32));
+    player.conditions[0] = 0;
+    player.class_data = PlayerClassData::Spy {
+        disguise_team: Team::Other,
+        disguise_class: Class::Other,
+        cloak: 40.0,
+    };
+    analyser.update_spies(DemoTick::from(30u32));
+
+    let state = analyser.state;
+    assert_eq!(1, state.disguises.len());
+    assert_eq!(Class::Medic, state.disguises[0].class);
+    assert_eq!(DemoTick::from(10u32), state.disguises[0].start);
+    assert_eq!(Some(DemoTick::from(30u32)), state.disguises[0].end);
+
+    assert_eq!(1, state.cloaks.len());
+    assert_eq!(DemoTick::from(20u32), state.cloaks[0].start);
+    assert_eq!(Some(DemoTick::from(30u32)), state.cloaks[0].end);
+    assert_eq!(80.0, state.cloaks[0].start_cloak);
+    assert_eq!(Some(40.0), state.cloaks[0].end_cloak);
+}
@@ -1,25 +1,28 @@
-use fnv::FnvHashMap;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
 use crate::demo::gamevent::GameEventDefinition;
 
 use crate::demo::message::packetentities::{
-    BaselineIndex, EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
+    apply_prop_updates, BaselineIndex, EntityBuffer, EntityId, PacketEntitiesMessage,
+    PacketEntitiesView, PacketEntity, UpdateType,
 };
 use crate::demo::message::stringtable::StringTableMeta;
 use crate::demo::message::{log_base2, Message, MessageType};
 use crate::demo::packet::datatable::{
-    ClassId, ParseSendTable, SendTable, SendTableName, ServerClass,
+    flatten_send_tables, ClassId, ParseSendTable, SendTable, ServerClass,
 };
 use crate::demo::packet::stringtable::StringTableEntry;
 
 use crate::demo::data::DemoTick;
+use crate::demo::parser::propfilter::PropFilter;
+use crate::demo::parser::sendtablecache::SendTableCache;
 use crate::demo::sendprop::{SendProp, SendPropIdentifier};
 use crate::nullhasher::NullHasherBuilder;
 use crate::{Result, Stream};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
+use std::sync::Arc;
 #[cfg(feature = "trace")]
 use tracing::warn;
 
@@ -38,15 +41,21 @@ pub struct ParserState {
     pub string_tables: Vec<StringTableMeta>,
     pub entity_classes: HashMap<EntityId, ClassId, NullHasherBuilder>,
     // indexed by ClassId
-    pub send_tables: Vec<SendTable>,
+    pub send_tables: Arc<Vec<SendTable>>,
     pub server_classes: Vec<ServerClass>,
     pub server_class_bits: usize,
     pub instance_baselines: [Baseline; 2],
     pub demo_meta: DemoMeta,
     analyser_handles: fn(message_type: MessageType) -> bool,
     handle_entities: bool,
+    borrow_entities: bool,
     parse_all: bool,
     pub protocol_version: u32,
+    send_table_cache: Option<SendTableCache>,
+    pub(crate) entity_buffer: RefCell<EntityBuffer>,
+    prop_filter: Option<PropFilter>,
+    /// Which props to decode, by class
+    prop_masks: Vec<Vec<bool>>,
 }
 
 #[derive(Clone)]
@@ -84,18 +93,65 @@ impl ParserState {
             event_definitions: Vec::new(),
             string_tables: Vec::new(),
             entity_classes: HashMap::with_hasher(NullHasherBuilder),
-            send_tables: Vec::new(),
+            send_tables: Arc::default(),
             server_classes: Vec::new(),
             server_class_bits: 0,
             instance_baselines: [Baseline::default(), Baseline::default()],
             demo_meta: DemoMeta::default(),
             analyser_handles,
             handle_entities: analyser_handles(MessageType::PacketEntities) || parse_all,
+            borrow_entities: false,
             parse_all,
             protocol_version,
+            send_table_cache: None,
+            entity_buffer: RefCell::default(),
+            prop_filter: None,
+            prop_masks: Vec::new(),
         }
     }
 
+    /// Only decode the props included in the filter for entity updates
+    ///
+    /// Entities entering the pvs are always fully decoded when they update the baseline.
+    /// The filter is ignored when parsing all messages.
+    pub fn set_prop_filter(&mut self, prop_filter: Option<PropFilter>) {
+        self.prop_filter = prop_filter;
+        self.update_prop_masks();
+    }
+
+    fn update_prop_masks(&mut self) {
+        self.prop_masks = match &self.prop_filter {
+            Some(filter) if !self.parse_all => {
+                filter.prop_masks(&self.send_tables, &self.server_classes)
+            }
+            _ => Vec::new(),
+        };
+    }
+
+    /// Which of the flattened props of the class should be decoded, `None` if all props should be decoded
+    pub fn prop_mask(&self, class: ClassId) -> Option<&[bool]> {
+        self.prop_masks.get(usize::from(class)).map(Vec::as_slice)
+    }
+
+    /// Decode entity updates into the entity buffer instead of as `Message::PacketEntities`
+    ///
+    /// Entity updates are always decoded as messages when parsing all messages.
+    pub fn set_borrow_entities(&mut self, borrow_entities: bool) {
+        self.borrow_entities = borrow_entities;
+        self.handle_entities = borrow_entities
+            || (self.analyser_handles)(MessageType::PacketEntities)
+            || self.parse_all;
+    }
+
+    pub fn borrows_entities(&self) -> bool {
+        self.borrow_entities && !self.parse_all
+    }
+
+    /// Set the cache used for the flattened send tables, by default the send tables aren't cached
+    pub fn set_send_table_cache(&mut self, cache: SendTableCache) {
+        self.send_table_cache = Some(cache);
+    }
+
     pub fn set_server_classes(&mut self, server_classes: Vec<ServerClass>) {
         self.server_class_bits = log_base2(server_classes.len()) as usize + 1;
         self.server_classes = server_classes;
@@ -185,33 +241,12 @@ impl ParserState {
         server_classes: Vec<ServerClass>,
     ) -> Result<()> {
         if self.handle_entities {
-            let mut send_tables: FnvHashMap<SendTableName, SendTable> = parse_tables
-                .iter()
-                .map(|parse_table| {
-                    let flat = parse_table.flatten_props(parse_tables);
-                    Ok((
-                        parse_table.name.clone(),
-                        SendTable {
-                            name: parse_table.name.clone(),
-                            needs_decoder: parse_table.needs_decoder,
-                            flattened_props: flat?,
-                        },
-                    ))
-                })
-                .collect::<Result<_>>()?;
-
+            self.send_tables = match &self.send_table_cache {
+                Some(cache) => cache.get_or_flatten(parse_tables, &server_classes)?,
+                None => Arc::new(flatten_send_tables(parse_tables, &server_classes)?),
+            };
             self.set_server_classes(server_classes);
-
-            self.send_tables.reserve(self.server_classes.len());
-
-            for class in self.server_classes.iter() {
-                if let Some(table) = send_tables.remove(&class.data_table) {
-                    self.send_tables.push(table);
-                } else {
-                    #[cfg(feature = "trace")]
-                    warn!(class = debug(class), "class without table");
-                }
-            }
+            self.update_prop_masks();
         }
 
         Ok(())
@@ -253,51 +288,95 @@ impl ParserState {
                 self.event_definitions = message.event_list;
             }
             Message::PacketEntities(ent_message) => {
-                for removed in ent_message.removed_entities.iter() {
-                    self.entity_classes.remove(removed);
-                }
+                self.update_entity_classes(
+                    &ent_message.removed_entities,
+                    ent_message.entities.iter().map(|entity| {
+                        (entity.entity_index, entity.server_class, entity.update_type)
+                    }),
+                );
 
-                for entity in ent_message.entities.iter() {
-                    if entity.update_type == UpdateType::Delete {
-                        self.entity_classes.remove(&entity.entity_index);
-                    }
-                    self.entity_classes
-                        .insert(entity.entity_index, entity.server_class);
+                if ent_message.updated_base_line {
+                    self.update_instance_baselines(
+                        ent_message.base_line,
+                        ent_message.delta.is_some(),
+                        ent_message.entities,
+                    );
                 }
+            }
+            _ => {}
+        }
+    }
 
-                if ent_message.updated_base_line {
-                    let old_index = ent_message.base_line;
-                    let new_index = old_index.other();
-                    let [baseline1, baseline2] = &mut self.instance_baselines;
-                    if old_index == BaselineIndex::First {
-                        baseline2.copy_from(baseline1);
-                    } else {
-                        baseline1.copy_from(baseline2);
-                    }
+    /// Handle entity updates that were decoded into the entity buffer
+    pub fn handle_entities_view(&mut self, message: &PacketEntitiesView) {
+        self.update_entity_classes(
+            message.removed_entities(),
+            message
+                .entities()
+                .map(|entity| (entity.entity_index, entity.server_class, entity.update_type)),
+        );
+
+        if message.updated_base_line {
+            self.update_instance_baselines(
+                message.base_line,
+                message.delta.is_some(),
+                message
+                    .entities()
+                    .filter(|entity| entity.update_type == UpdateType::Enter)
+                    .map(|entity| entity.to_owned()),
+            );
+        }
+    }
 
-                    for entity in ent_message.entities {
-                        if entity.update_type == UpdateType::Enter {
-                            let updated_baseline = match self
-                                .get_instance_baseline(old_index)
-                                .get(entity.entity_index)
-                            {
-                                Some(baseline_entity)
-                                    if baseline_entity.server_class == entity.server_class
-                                        && ent_message.delta.is_some() =>
-                                {
-                                    let mut updated_baseline = baseline_entity.clone();
-                                    updated_baseline.apply_update(&entity.props);
-                                    updated_baseline
-                                }
-                                _ => entity.into(),
-                            };
-                            self.get_instance_baseline_mut(new_index)
-                                .set(updated_baseline);
-                        }
+    fn update_entity_classes(
+        &mut self,
+        removed_entities: &[EntityId],
+        entities: impl Iterator<Item = (EntityId, ClassId, UpdateType)>,
+    ) {
+        for removed in removed_entities {
+            self.entity_classes.remove(removed);
+        }
+
+        for (entity_index, server_class, update_type) in entities {
+            if update_type == UpdateType::Delete {
+                self.entity_classes.remove(&entity_index);
+            }
+            self.entity_classes.insert(entity_index, server_class);
+        }
+    }
+
+    fn update_instance_baselines(
+        &mut self,
+        old_index: BaselineIndex,
+        is_delta: bool,
+        entities: impl IntoIterator<Item = PacketEntity>,
+    ) {
+        let new_index = old_index.other();
+        let [baseline1, baseline2] = &mut self.instance_baselines;
+        if old_index == BaselineIndex::First {
+            baseline2.copy_from(baseline1);
+        } else {
+            baseline1.copy_from(baseline2);
+        }
+
+        for entity in entities {
+            if entity.update_type == UpdateType::Enter {
+                let updated_baseline = match self
+                    .get_instance_baseline(old_index)
+                    .get(entity.entity_index)
+                {
+                    Some(baseline_entity)
+                        if baseline_entity.server_class == entity.server_class && is_delta =>
+                    {
+                        let mut updated_baseline = baseline_entity.clone();
+                        updated_baseline.apply_update(&entity.props);
+                        updated_baseline
                     }
-                }
+                    _ => entity.into(),
+                };
+                self.get_instance_baseline_mut(new_index)
+                    .set(updated_baseline);
             }
-            _ => {}
         }
     }
 
@@ -312,13 +391,7 @@ impl ParserState {
     }
 
     pub fn index_for_prop(&self, class: ClassId, prop: SendPropIdentifier) -> Option<u32> {
-        let send_table = self.send_tables.get(usize::from(class))?;
-        send_table
-            .flattened_props
-            .iter()
-            .enumerate()
-            .find(|(_i, def)| def.identifier == prop)
-            .map(|(index, _)| index as u32)
+        self.send_tables.get(usize::from(class))?.prop_index(prop)
     }
 }
 
@@ -385,17 +458,8 @@ pub struct BaselineEntity {
 }
 
 impl BaselineEntity {
-    fn mut_prop_by_identifier(&mut self, index: &SendPropIdentifier) -> Option<&mut SendProp> {
-        self.props.iter_mut().find(|prop| prop.identifier == *index)
-    }
-
     pub fn apply_update(&mut self, props: &[SendProp]) {
-        for prop in props {
-            match self.mut_prop_by_identifier(&prop.identifier) {
-                Some(existing_prop) => existing_prop.value = prop.value.clone(),
-                None => self.props.push(prop.clone()),
-            }
-        }
+        apply_prop_updates(&mut self.props, props);
     }
 }
 
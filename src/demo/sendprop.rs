@@ -737,6 +737,93 @@ impl SendPropValue {
         }
     }
 
+    /// Skip over a value without decoding it
+    pub fn skip(stream: &mut Stream, definition: &SendPropParseDefinition) -> Result<()> {
+        match definition {
+            SendPropParseDefinition::NormalVarInt { .. } => {
+                read_var_int(stream, false)?;
+            }
+            SendPropParseDefinition::UnsignedInt { bit_count, .. }
+            | SendPropParseDefinition::Int { bit_count, .. } => {
+                stream.skip_bits(*bit_count as usize)?;
+            }
+            SendPropParseDefinition::Float {
+                definition: float_definition,
+                ..
+            } => Self::skip_float(stream, float_definition)?,
+            SendPropParseDefinition::String { .. } => {
+                let length: usize = stream.read_int(9)?;
+                stream.skip_bits(length * 8)?;
+            }
+            SendPropParseDefinition::Vector {
+                definition: float_definition,
+                ..
+            } => {
+                Self::skip_float(stream, float_definition)?;
+                Self::skip_float(stream, float_definition)?;
+                match float_definition {
+                    // only the sign of z is stored
+                    FloatDefinition::NormalVarFloat => stream.skip_bits(1)?,
+                    _ => Self::skip_float(stream, float_definition)?,
+                }
+            }
+            SendPropParseDefinition::VectorXY {
+                definition: float_definition,
+                ..
+            } => {
+                Self::skip_float(stream, float_definition)?;
+                Self::skip_float(stream, float_definition)?;
+            }
+            SendPropParseDefinition::Array {
+                count_bit_count,
+                inner_definition,
+                ..
+            } => {
+                let count: usize = stream.read_int(*count_bit_count as usize)?;
+                for _ in 0..count {
+                    Self::skip(stream, inner_definition)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse a value in place, re-using the allocation of strings and arrays when the existing value
+    /// has the same type
+    pub fn parse_into(
+        &mut self,
+        stream: &mut Stream,
+        definition: &SendPropParseDefinition,
+    ) -> Result<()> {
+        match (definition, self) {
+            (SendPropParseDefinition::String { .. }, SendPropValue::String(value)) => {
+                let length = stream.read_int(9)?;
+                let string = stream.read_string(Some(length))?;
+                value.clear();
+                value.push_str(&string);
+            }
+            (
+                SendPropParseDefinition::Array {
+                    count_bit_count,
+                    inner_definition,
+                    ..
+                },
+                SendPropValue::Array(values),
+            ) => {
+                let count = stream.read_int(*count_bit_count as usize)?;
+                values.truncate(count);
+                for value in values.iter_mut() {
+                    value.parse_into(stream, inner_definition)?;
+                }
+                for _ in values.len()..count {
+                    values.push(Self::parse(stream, inner_definition)?);
+                }
+            }
+            (definition, value) => *value = Self::parse(stream, definition)?,
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "write")]
     pub fn encode(
         &self,
@@ -835,6 +922,18 @@ impl SendPropValue {
         }
     }
 
+    fn skip_float(stream: &mut Stream, definition: &FloatDefinition) -> Result<()> {
+        match definition {
+            FloatDefinition::FloatNoScale => stream.skip_bits(32)?,
+            FloatDefinition::Scaled { bit_count, .. } => stream.skip_bits(*bit_count as usize)?,
+            // coords and normals don't have a fixed size
+            _ => {
+                Self::read_float(stream, definition)?;
+            }
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "write")]
     fn write_float(
         val: f32,
@@ -887,6 +986,9 @@ fn test_send_prop_value_roundtrip() {
         let mut read = BitReadStream::new(BitReadBuffer::new(&data, LittleEndian));
         assert_eq!(val, SendPropValue::parse(&mut read, &def).unwrap());
         assert_eq!(pos, read.pos());
+        let mut skip = BitReadStream::new(BitReadBuffer::new(&data, LittleEndian));
+        SendPropValue::skip(&mut skip, &def).unwrap();
+        assert_eq!(pos, skip.pos());
     }
     send_prop_value_roundtrip(
         SendPropValue::Integer(0),
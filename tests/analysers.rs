@@ -0,0 +1,654 @@
+mod common;
+
+use tf_demo_parser::{Demo, DemoParser};
+
+#[test]
+fn loadout_test() {
+    use tf_demo_parser::demo::message::packetentities::EntityId;
+    use tf_demo_parser::demo::parser::loadoutanalyser::{LoadoutAnalyser, LoadoutSlot};
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let (_, state) = DemoParser::new_with_analyser(demo.get_stream(), LoadoutAnalyser::new())
+        .parse()
+        .unwrap();
+
+    let loadout = state
+        .loadouts
+        .get(&EntityId::from(1u32))
+        .expect("no loadout for player");
+    assert_eq!(
+        Some(200),
+        loadout.primary.as_ref().map(|item| item.definition_index)
+    );
+    assert_eq!(
+        Some(222),
+        loadout.secondary.as_ref().map(|item| item.definition_index)
+    );
+    assert_eq!(
+        Some(355),
+        loadout.melee.as_ref().map(|item| item.definition_index)
+    );
+    let mut cosmetics: Vec<_> = loadout
+        .cosmetics
+        .iter()
+        .map(|item| item.definition_index)
+        .collect();
+    cosmetics.sort();
+    assert_eq!(vec![1011, 30394, 30395], cosmetics);
+    assert!(state
+        .changes
+        .iter()
+        .any(|change| change.player == EntityId::from(1u32)));
+    assert_eq!(Some(LoadoutSlot::Primary), loadout.active_weapon);
+}
+
+#[test]
+fn entity_tracker_test() {
+    use tf_demo_parser::demo::data::game_state::Handle;
+    use tf_demo_parser::demo::message::packetentities::EntityId;
+    use tf_demo_parser::demo::parser::entitytracker::EntityTracker;
+    use tf_demo_parser::demo::sendprop::SendPropIdentifier;
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let (_, state) = DemoParser::new_with_analyser(demo.get_stream(), EntityTracker::new())
+        .parse()
+        .unwrap();
+
+    let player = state.get(EntityId::from(1u32)).expect("no player entity");
+    assert_eq!("CTFPlayer", player.class_name.as_str());
+    assert_eq!(
+        Some(player.id),
+        state.get_by_handle(player.handle()).map(|entity| entity.id)
+    );
+    assert!(
+        player
+            .get_prop_value::<i64>(SendPropIdentifier::new("DT_BasePlayer", "m_iHealth"))
+            .unwrap_or_default()
+            > 0
+    );
+
+    let active_weapon = player
+        .get_prop_value::<Handle>(SendPropIdentifier::new(
+            "DT_BaseCombatCharacter",
+            "m_hActiveWeapon",
+        ))
+        .expect("no active weapon");
+    let weapon = state
+        .get_by_handle(active_weapon)
+        .expect("active weapon not tracked");
+    assert_eq!("CTFScatterGun", weapon.class_name.as_str());
+    assert_eq!(1, state.by_class("CTFPlayerResource").count());
+}
+
+#[test]
+fn entity_view_test() {
+    use tf_demo_parser::demo::data::entityviews::CTFPlayerView;
+    use tf_demo_parser::demo::message::packetentities::EntityId;
+    use tf_demo_parser::demo::parser::entitytracker::EntityTracker;
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let (_, state) = DemoParser::new_with_analyser(demo.get_stream(), EntityTracker::new())
+        .parse()
+        .unwrap();
+
+    let entity = state.get(EntityId::from(1u32)).expect("no player entity");
+    let player = CTFPlayerView::try_new(entity).expect("not a player");
+    assert_eq!(Some(125), player.health());
+    assert_eq!(Some(1), player.class());
+
+    let weapon = player
+        .active_weapon()
+        .and_then(|handle| state.get_by_handle(handle))
+        .expect("no active weapon");
+    assert!(CTFPlayerView::try_new(weapon).is_none());
+}
+
+#[test]
+fn prop_watcher_test() {
+    use tf_demo_parser::demo::message::packetentities::EntityId;
+    use tf_demo_parser::demo::parser::propwatcher::PropWatcher;
+    use tf_demo_parser::demo::sendprop::{SendPropIdentifier, SendPropValue};
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let mut changes = Vec::new();
+    let mut watcher = PropWatcher::new();
+    watcher.subscribe(
+        SendPropIdentifier::new("DT_BasePlayer", "m_iHealth"),
+        Some("CTFPlayer"),
+        |change| changes.push(change.clone()),
+    );
+    let teams = watcher.subscribe_channel(
+        SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum"),
+        Some("CTFPlayer"),
+    );
+    DemoParser::new_with_analyser(demo.get_stream(), watcher)
+        .parse()
+        .unwrap();
+
+    let [health] = changes.as_slice() else {
+        panic!("expected a single health change, got {changes:?}");
+    };
+    assert_eq!(EntityId::from(1u32), health.entity);
+    assert_eq!("CTFPlayer", health.class_name.as_str());
+    assert_eq!(None, health.old_value);
+    assert_eq!(SendPropValue::Integer(125), health.new_value);
+
+    // other entities with a team are filtered out by the class name
+    let teams: Vec<_> = teams.try_iter().collect();
+    let [team] = teams.as_slice() else {
+        panic!("expected a single team change, got {teams:?}");
+    };
+    assert_eq!(EntityId::from(1u32), team.entity);
+    assert_eq!(None, team.old_value);
+    assert_eq!(SendPropValue::Integer(2), team.new_value);
+}
+
+#[test]
+fn prop_history_test() {
+    use tf_demo_parser::demo::message::packetentities::EntityId;
+    use tf_demo_parser::demo::parser::prophistory::PropHistoryAnalyser;
+    use tf_demo_parser::demo::sendprop::{SendPropIdentifier, SendPropValue};
+
+    const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let analyser = PropHistoryAnalyser::new()
+        .with_prop(HEALTH)
+        .with_class("CTFPlayer");
+    let (_, history) = DemoParser::new_with_analyser(demo.get_stream(), analyser)
+        .parse()
+        .unwrap();
+
+    assert_eq!(1, history.for_prop(HEALTH).count());
+    let timeline = history
+        .get(EntityId::from(1u32), HEALTH)
+        .expect("no health history");
+    let first = timeline.changes.first().expect("no health changes");
+    assert_eq!(
+        Some(&SendPropValue::Integer(125)),
+        history.value_at(EntityId::from(1u32), HEALTH, first.tick)
+    );
+    assert_eq!(
+        timeline.changes.len(),
+        timeline.changes_between(first.tick, u32::MAX.into()).len()
+    );
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn spy_analyser_test() {
+    use tf_demo_parser::demo::data::game_state::BuildingClass;
+    use tf_demo_parser::demo::data::userinfo::PlayerInfo;
+    use tf_demo_parser::demo::edit::builder::{DemoBuilder, PropDefinition};
+    use tf_demo_parser::demo::parser::analyser::{Class, Team, UserId};
+    use tf_demo_parser::demo::parser::spyanalyser::SpyAnalyser;
+    use tf_demo_parser::demo::sendprop::{SendPropIdentifier, SendPropValue};
+
+    const LIFE_STATE: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_lifeState");
+    const COND: SendPropIdentifier = SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCond");
+    const DISGUISE_TEAM: SendPropIdentifier =
+        SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseTeam");
+    const DISGUISE_CLASS: SendPropIdentifier =
+        SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseClass");
+    const CLOAK: SendPropIdentifier =
+        SendPropIdentifier::new("DT_TFPlayerShared", "m_flCloakMeter");
+    const SPY_CLASS: SendPropIdentifier = SendPropIdentifier::new("m_iPlayerClass", "001");
+    const ENGINEER_CLASS: SendPropIdentifier = SendPropIdentifier::new("m_iPlayerClass", "002");
+    const SAPPED: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper");
+    const BUILDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_hBuilder");
+
+    const DISGUISED: i64 = 1 << 3;
+    const STEALTHED: i64 = 1 << 4;
+
+    let mut builder = DemoBuilder::new("cp_test");
+    let player = builder.add_server_class(
+        "CTFPlayer",
+        [
+            PropDefinition::unsigned_int("DT_BasePlayer", "m_lifeState", 3),
+            PropDefinition::unsigned_int("DT_TFPlayerShared", "m_nPlayerCond", 32),
+            PropDefinition::unsigned_int("DT_TFPlayerShared", "m_nDisguiseTeam", 3),
+            PropDefinition::unsigned_int("DT_TFPlayerShared", "m_nDisguiseClass", 4),
+            PropDefinition::float("DT_TFPlayerShared", "m_flCloakMeter"),
+        ],
+    );
+    let resource = builder.add_server_class(
+        "CTFPlayerResource",
+        [
+            PropDefinition::unsigned_int("m_iPlayerClass", "001", 4),
+            PropDefinition::unsigned_int("m_iPlayerClass", "002", 4),
+        ],
+    );
+    let dispenser = builder.add_server_class(
+        "CObjectDispenser",
+        [
+            PropDefinition::unsigned_int("DT_BaseObject", "m_bHasSapper", 1),
+            PropDefinition::unsigned_int("DT_BaseObject", "m_hBuilder", 21),
+        ],
+    );
+    builder.add_player(
+        1u32.into(),
+        PlayerInfo {
+            name: "spy".into(),
+            user_id: 3u16.into(),
+            steam_id: "[U:1:3]".into(),
+            ..PlayerInfo::default()
+        },
+    );
+    builder.add_player(
+        2u32.into(),
+        PlayerInfo {
+            name: "engineer".into(),
+            user_id: 4u16.into(),
+            steam_id: "[U:1:4]".into(),
+            ..PlayerInfo::default()
+        },
+    );
+
+    let int = SendPropValue::Integer;
+    builder.enter_entity(
+        1u32.into(),
+        40u32.into(),
+        resource,
+        [(SPY_CLASS, int(8)), (ENGINEER_CLASS, int(9))],
+    );
+    builder.enter_entity(
+        2u32.into(),
+        1u32.into(),
+        player,
+        [
+            (LIFE_STATE, int(0)),
+            (COND, int(0)),
+            (DISGUISE_TEAM, int(0)),
+            (DISGUISE_CLASS, int(0)),
+            (CLOAK, SendPropValue::Float(100.0)),
+        ],
+    );
+    builder.enter_entity(
+        2u32.into(),
+        30u32.into(),
+        dispenser,
+        [(SAPPED, int(0)), (BUILDER, int(2))],
+    );
+    builder.update_entity(
+        3u32.into(),
+        1u32.into(),
+        [
+            (COND, int(DISGUISED)),
+            (DISGUISE_TEAM, int(3)),
+            (DISGUISE_CLASS, int(5)),
+        ],
+    );
+    // both conditions are stored in the same byte of the condition bits
+    builder.update_entity(
+        4u32.into(),
+        1u32.into(),
+        [
+            (COND, int(DISGUISED | STEALTHED)),
+            (CLOAK, SendPropValue::Float(90.0)),
+        ],
+    );
+    builder.update_entity(
+        5u32.into(),
+        1u32.into(),
+        [(COND, int(DISGUISED)), (CLOAK, SendPropValue::Float(60.0))],
+    );
+    builder.update_entity(5u32.into(), 30u32.into(), [(SAPPED, int(1))]);
+    builder.update_entity(
+        6u32.into(),
+        1u32.into(),
+        [(COND, int(0)), (DISGUISE_CLASS, int(0))],
+    );
+    builder.update_entity(6u32.into(), 30u32.into(), [(SAPPED, int(0))]);
+
+    let data = builder.build().unwrap();
+    let (_, spies) =
+        DemoParser::new_with_analyser(Demo::new(&data).get_stream(), SpyAnalyser::new())
+            .parse()
+            .unwrap();
+
+    let spy = UserId::from(3u16);
+    let [disguise] = spies.disguises.as_slice() else {
+        panic!("expected a single disguise, got {:?}", spies.disguises);
+    };
+    assert_eq!(spy, disguise.spy);
+    assert_eq!(Team::Blue, disguise.team);
+    assert_eq!(Class::Medic, disguise.class);
+    assert_eq!(
+        (3u32.into(), Some(6u32.into())),
+        (disguise.start, disguise.end)
+    );
+
+    let [cloak] = spies.cloaks.as_slice() else {
+        panic!("expected a single cloak, got {:?}", spies.cloaks);
+    };
+    assert_eq!(spy, cloak.spy);
+    assert_eq!((4u32.into(), Some(5u32.into())), (cloak.start, cloak.end));
+    assert_eq!((90.0, Some(60.0)), (cloak.start_cloak, cloak.end_cloak));
+
+    let [sapper] = spies.sappers.as_slice() else {
+        panic!("expected a single sapper, got {:?}", spies.sappers);
+    };
+    assert_eq!(BuildingClass::Dispenser, sapper.building_class);
+    assert_eq!(UserId::from(4u16), sapper.builder);
+    assert_eq!((5u32.into(), Some(6u32.into())), (sapper.start, sapper.end));
+
+    assert!(spies.feigns.is_empty());
+    assert!(spies.backstabs.is_empty());
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn building_history_test() {
+    use tf_demo_parser::demo::data::game_state::BuildingClass;
+    use tf_demo_parser::demo::data::userinfo::PlayerInfo;
+    use tf_demo_parser::demo::data::DemoTick;
+    use tf_demo_parser::demo::edit::builder::{DemoBuilder, PropDefinition};
+    use tf_demo_parser::demo::parser::analyser::{Team, UserId};
+    use tf_demo_parser::demo::parser::buildinghistoryanalyser::{
+        BuildingEnd, BuildingHistoryAnalyser, LevelUp, TickRange,
+    };
+    use tf_demo_parser::demo::sendprop::{SendPropIdentifier, SendPropValue};
+    use tf_demo_parser::demo::vector::Vector;
+
+    const ORIGIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
+    const TEAM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+    const BUILDING: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bBuilding");
+    const LEVEL: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel");
+    const SAPPED: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper");
+    const BUILDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_hBuilder");
+
+    let mut builder = DemoBuilder::new("cp_test");
+    let dispenser = builder.add_server_class(
+        "CObjectDispenser",
+        [
+            PropDefinition::vector("DT_BaseEntity", "m_vecOrigin"),
+            PropDefinition::unsigned_int("DT_BaseEntity", "m_iTeamNum", 3),
+            PropDefinition::unsigned_int("DT_BaseObject", "m_bBuilding", 1),
+            PropDefinition::unsigned_int("DT_BaseObject", "m_iUpgradeLevel", 2),
+            PropDefinition::unsigned_int("DT_BaseObject", "m_bHasSapper", 1),
+            PropDefinition::unsigned_int("DT_BaseObject", "m_hBuilder", 21),
+        ],
+    );
+    builder.add_player(
+        2u32.into(),
+        PlayerInfo {
+            name: "engineer".into(),
+            user_id: 4u16.into(),
+            steam_id: "[U:1:4]".into(),
+            ..PlayerInfo::default()
+        },
+    );
+
+    let int = SendPropValue::Integer;
+    let position = Vector {
+        x: 100.0,
+        y: 200.0,
+        z: 10.0,
+    };
+    builder.enter_entity(
+        2u32.into(),
+        30u32.into(),
+        dispenser,
+        [
+            (ORIGIN, SendPropValue::Vector(position)),
+            (TEAM, int(3)),
+            (BUILDING, int(1)),
+            (LEVEL, int(1)),
+            (SAPPED, int(0)),
+            (BUILDER, int(2)),
+        ],
+    );
+    builder.update_entity(3u32.into(), 30u32.into(), [(BUILDING, int(0))]);
+    builder.update_entity(4u32.into(), 30u32.into(), [(LEVEL, int(2))]);
+    builder.update_entity(5u32.into(), 30u32.into(), [(SAPPED, int(1))]);
+    builder.update_entity(6u32.into(), 30u32.into(), [(SAPPED, int(0))]);
+    builder.delete_entity(8u32.into(), 30u32.into());
+
+    let data = builder.build().unwrap();
+    let (_, history) = DemoParser::new_with_analyser(
+        Demo::new(&data).get_stream(),
+        BuildingHistoryAnalyser::new(),
+    )
+    .parse()
+    .unwrap();
+
+    let [building] = history.buildings.as_slice() else {
+        panic!("expected a single building, got {:?}", history.buildings);
+    };
+    assert_eq!(BuildingClass::Dispenser, building.class);
+    assert_eq!(UserId::from(4u16), building.builder);
+    assert_eq!(Team::Blue, building.team);
+    assert_eq!(position, building.position);
+    assert_eq!(DemoTick::from(2u32), building.build_start);
+    assert_eq!(Some(DemoTick::from(3u32)), building.built);
+    assert_eq!(
+        vec![LevelUp {
+            tick: DemoTick::from(4u32),
+            level: 2
+        }],
+        building.level_ups
+    );
+    assert_eq!(
+        vec![TickRange {
+            start: DemoTick::from(5u32),
+            end: Some(DemoTick::from(6u32))
+        }],
+        building.sapped
+    );
+    let destroyed = building.destroyed.as_ref().unwrap();
+    assert_eq!(DemoTick::from(8u32), destroyed.tick);
+    assert_eq!(BuildingEnd::Removed, destroyed.cause);
+}
+
+#[test]
+fn query_test() {
+    use tf_demo_parser::demo::parser::query::{Query, QueryAnalyser};
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let query: Query = r#"class == "CTFPlayer" && prop("DT_BasePlayer.m_iHealth") > 0"#
+        .parse()
+        .unwrap();
+
+    let (_, matches) =
+        DemoParser::new_with_analyser(demo.get_stream(), QueryAnalyser::new(query.clone()))
+            .parse()
+            .unwrap();
+    assert!(!matches.is_empty());
+    assert!(matches
+        .iter()
+        .all(|query_match| query_match.class == "CTFPlayer"));
+
+    let mut streamed = Vec::new();
+    let (_, collected) = DemoParser::new_with_analyser(
+        demo.get_stream(),
+        QueryAnalyser::with_callback(query, |query_match| streamed.push(query_match)),
+    )
+    .parse()
+    .unwrap();
+    assert!(collected.is_empty());
+    assert_eq!(matches, streamed);
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn player_summary_test() {
+    use tf_demo_parser::demo::data::userinfo::PlayerInfo;
+    use tf_demo_parser::demo::data::DemoTick;
+    use tf_demo_parser::demo::edit::builder::{DemoBuilder, PropDefinition};
+    use tf_demo_parser::demo::gameevent_gen::{TeamPlayRoundStartEvent, TeamPlayRoundWinEvent};
+    use tf_demo_parser::demo::gamevent::{
+        GameEvent, GameEventEntry, GameEventType, GameEventValueType,
+    };
+    use tf_demo_parser::demo::parser::analyser::{Team, UserId};
+    use tf_demo_parser::demo::parser::player_summary_analyzer::PlayerSummaryAnalyzer;
+    use tf_demo_parser::demo::sendprop::{SendPropIdentifier, SendPropValue};
+
+    const USER_ID: SendPropIdentifier = SendPropIdentifier::new("m_iUserID", "001");
+    const HEALING_ASSIST: SendPropIdentifier = SendPropIdentifier::new("m_iHealingAssist", "001");
+    const DAMAGE_ASSIST: SendPropIdentifier = SendPropIdentifier::new("m_iDamageAssist", "001");
+    const KILLS: SendPropIdentifier =
+        SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKills");
+    const POINTS: SendPropIdentifier =
+        SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iPoints");
+
+    let mut builder = DemoBuilder::new("cp_test");
+    let resource = builder.add_server_class(
+        "CTFPlayerResource",
+        [
+            PropDefinition::unsigned_int("m_iUserID", "001", 10),
+            PropDefinition::unsigned_int("m_iHealingAssist", "001", 16),
+            PropDefinition::unsigned_int("m_iDamageAssist", "001", 16),
+        ],
+    );
+    // the scoring data is included once for the match and once for the current round
+    let player = builder.add_server_class(
+        "CTFPlayer",
+        [
+            PropDefinition::data_table(
+                "DT_CTFPlayer",
+                "m_ScoreData",
+                "DT_TFPlayerScoringDataExclusive",
+            ),
+            PropDefinition::data_table(
+                "DT_CTFPlayer",
+                "m_RoundScoreData",
+                "DT_TFPlayerScoringDataExclusive",
+            ),
+            PropDefinition::unsigned_int("DT_TFPlayerScoringDataExclusive", "m_iKills", 10),
+            PropDefinition::unsigned_int("DT_TFPlayerScoringDataExclusive", "m_iPoints", 10),
+        ],
+    );
+    builder.add_player(
+        1u32.into(),
+        PlayerInfo {
+            name: "medic".into(),
+            user_id: 5u16.into(),
+            steam_id: "[U:1:5]".into(),
+            ..PlayerInfo::default()
+        },
+    );
+    builder.add_event_definition(
+        GameEventType::TeamPlayRoundStart,
+        vec![GameEventEntry::new(
+            "full_reset",
+            GameEventValueType::Boolean,
+        )],
+    );
+    builder.add_event_definition(
+        GameEventType::TeamPlayRoundWin,
+        vec![GameEventEntry::new("team", GameEventValueType::Byte)],
+    );
+
+    let int = SendPropValue::Integer;
+    let round_start =
+        || GameEvent::TeamPlayRoundStart(TeamPlayRoundStartEvent { full_reset: false });
+    let round_win = |team| {
+        GameEvent::TeamPlayRoundWin(TeamPlayRoundWinEvent {
+            team,
+            win_reason: 0,
+            flag_cap_limit: 0,
+            full_round: 1,
+            round_time: 0.0,
+            losing_team_num_caps: 0,
+            was_sudden_death: 0,
+        })
+    };
+
+    // the demo starts in the middle of the first round
+    builder.enter_entity(
+        1u32.into(),
+        40u32.into(),
+        resource,
+        [
+            (USER_ID, int(5)),
+            (HEALING_ASSIST, int(10)),
+            (DAMAGE_ASSIST, int(4)),
+        ],
+    );
+    builder.enter_entity(
+        1u32.into(),
+        1u32.into(),
+        player,
+        [
+            (KILLS, int(2)),
+            (KILLS, int(2)),
+            (POINTS, int(3)),
+            (POINTS, int(3)),
+        ],
+    );
+    builder.add_game_event(2u32.into(), round_win(2));
+
+    builder.add_game_event(3u32.into(), round_start());
+    builder.update_entity(4u32.into(), 40u32.into(), [(HEALING_ASSIST, int(16))]);
+    builder.update_entity(
+        4u32.into(),
+        1u32.into(),
+        [
+            (KILLS, int(5)),
+            (KILLS, int(3)),
+            (POINTS, int(7)),
+            (POINTS, int(4)),
+        ],
+    );
+    builder.add_game_event(5u32.into(), round_win(3));
+
+    builder.add_game_event(6u32.into(), round_start());
+    builder.update_entity(7u32.into(), 40u32.into(), [(DAMAGE_ASSIST, int(9))]);
+
+    let data = builder.build().unwrap();
+    let (_, state) =
+        DemoParser::new_with_analyser(Demo::new(&data).get_stream(), PlayerSummaryAnalyzer::new())
+            .parse()
+            .unwrap();
+
+    let user = UserId::from(5u16);
+    assert_eq!("medic", state.users[&user].name);
+
+    let total = &state.player_summaries[&user];
+    assert_eq!((5, 7), (total.kills, total.points));
+    assert_eq!(
+        (16, 9, 25),
+        (total.healing_assist, total.damage_assist, total.support)
+    );
+
+    let [first, second, third] = state.rounds.as_slice() else {
+        panic!("expected three rounds, got {:?}", state.rounds);
+    };
+    assert_eq!(DemoTick::from(1u32), first.start_tick);
+    assert_eq!(Some(DemoTick::from(2u32)), first.end_tick);
+    assert_eq!(Some(Team::Red), first.winner);
+    let summary = &first.player_summaries[&user];
+    assert_eq!((2, 3, 14), (summary.kills, summary.points, summary.support));
+
+    assert_eq!(DemoTick::from(3u32), second.start_tick);
+    assert_eq!(Some(DemoTick::from(5u32)), second.end_tick);
+    assert_eq!(Some(Team::Blue), second.winner);
+    let summary = &second.player_summaries[&user];
+    assert_eq!((3, 4), (summary.kills, summary.points));
+    assert_eq!(
+        (6, 0, 6),
+        (
+            summary.healing_assist,
+            summary.damage_assist,
+            summary.support
+        )
+    );
+
+    assert_eq!(DemoTick::from(6u32), third.start_tick);
+    assert_eq!(None, third.end_tick);
+    assert_eq!(None, third.winner);
+    let summary = &third.player_summaries[&user];
+    assert_eq!(
+        (0, 5, 5),
+        (
+            summary.healing_assist,
+            summary.damage_assist,
+            summary.support
+        )
+    );
+}
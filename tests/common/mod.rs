@@ -0,0 +1,29 @@
+//! Fixtures shared between the integration tests
+#![allow(dead_code)]
+
+use bitbuffer::BitRead;
+use std::fs;
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::packet::Packet;
+use tf_demo_parser::demo::parser::{DemoHandler, RawPacketStream};
+use tf_demo_parser::Demo;
+
+/// Read a demo file from the `test_data` directory
+pub fn read_demo(name: &str) -> Vec<u8> {
+    fs::read(format!("test_data/{name}")).expect("Unable to read file")
+}
+
+/// Read the header and all packets from an encoded demo
+pub fn read_packets(data: &[u8]) -> (Header, Vec<Packet<'_>>) {
+    let mut stream = Demo::new(data).get_stream();
+    let header = Header::read(&mut stream).unwrap();
+    let mut handler = DemoHandler::default();
+    handler.handle_header(&header);
+    let mut packets = RawPacketStream::new(stream);
+    let mut result = Vec::new();
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        result.push(packet.clone());
+        handler.handle_packet(packet).unwrap();
+    }
+    (header, result)
+}
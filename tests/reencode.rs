@@ -1,3 +1,6 @@
+#[cfg(feature = "write")]
+mod common;
+
 #[cfg(feature = "write")]
 use std::fs;
 #[cfg(feature = "write")]
@@ -16,11 +19,13 @@ use tf_demo_parser::demo::packet::datatable::SendTableName;
 #[cfg(feature = "write")]
 use tf_demo_parser::demo::packet::Packet;
 #[cfg(feature = "write")]
+use tf_demo_parser::demo::parser::gamestateanalyser::GameStateAnalyser;
+#[cfg(feature = "write")]
 use tf_demo_parser::demo::parser::{DemoHandler, Encode, NullHandler, RawPacketStream};
 #[cfg(feature = "write")]
 use tf_demo_parser::demo::sendprop::{RawSendPropDefinition, SendPropIdentifier, SendPropName};
 #[cfg(feature = "write")]
-use tf_demo_parser::{Demo, Parse};
+use tf_demo_parser::{Demo, DemoParser, Parse};
 
 #[cfg(feature = "write")]
 #[test_case("test_data/small.dem"; "small.dem")]
@@ -151,3 +156,497 @@ fn re_encode_test(input_file: &str) {
         handler.handle_packet(packet).unwrap();
     }
 }
+
+#[test]
+#[cfg(feature = "write")]
+fn cut_demo_test() {
+    use tf_demo_parser::demo::edit::cut_demo;
+    use tf_demo_parser::demo::parser::entitytracker::{EntityTracker, TrackedEntity};
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+
+    let tracked = |data: &[u8]| {
+        let demo = Demo::new(data);
+        let (header, state) =
+            DemoParser::new_with_analyser(demo.get_stream(), EntityTracker::new())
+                .parse()
+                .unwrap();
+        let entities: Vec<TrackedEntity> = state
+            .iter()
+            .filter(|entity| entity.in_pvs)
+            .cloned()
+            .collect();
+        (header, entities)
+    };
+
+    let full = cut_demo(&demo, 0u32.into(), 80u32.into()).unwrap();
+    let clip = cut_demo(&demo, 40u32.into(), 80u32.into()).unwrap();
+    assert!(clip.len() < full.len());
+
+    let (full_header, full_entities) = tracked(&full);
+    let (clip_header, clip_entities) = tracked(&clip);
+    assert_eq!(80, full_header.ticks);
+    assert_eq!(40, clip_header.ticks);
+    assert!(clip_header.frames < full_header.frames);
+    assert!(!clip_entities.is_empty());
+    assert_eq!(full_entities, clip_entities);
+
+    let (_, state) =
+        DemoParser::new_with_analyser(Demo::new(&clip).get_stream(), GameStateAnalyser::new())
+            .parse()
+            .unwrap();
+    assert!(!state.players.is_empty());
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn repair_demo_test() {
+    use tf_demo_parser::demo::edit::{repair_demo, RepairChange};
+
+    let file = common::read_demo("small.dem");
+    let truncated = &file[..file.len() - 100];
+    let mut broken = truncated.to_vec();
+    // clear the header length fields like a demo that was closed unexpectedly
+    broken[1060..1072].fill(0);
+
+    let repaired = repair_demo(&Demo::new(&broken)).unwrap();
+    assert!(matches!(
+        repaired.changes.first(),
+        Some(RepairChange::DroppedPartialPacket { bytes }) if *bytes > 0
+    ));
+    assert!(repaired.changes.contains(&RepairChange::AddedStop));
+    assert!(repaired
+        .changes
+        .contains(&RepairChange::Ticks { old: 0, new: 115 }));
+    assert!(repaired
+        .changes
+        .contains(&RepairChange::Frames { old: 0, new: 110 }));
+    assert!(repaired.changes.contains(&RepairChange::SignonLength {
+        old: 0,
+        new: 218908
+    }));
+
+    let (header, _) = DemoParser::new(Demo::new(&repaired.data).get_stream())
+        .parse()
+        .unwrap();
+    assert_eq!(repaired.header, header);
+
+    let again = repair_demo(&Demo::new(&repaired.data)).unwrap();
+    assert!(again.is_unchanged());
+    assert_eq!(repaired.data, again.data);
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn diff_demos_test() {
+    use tf_demo_parser::demo::diff::{diff_demos, DiffOptions};
+    use tf_demo_parser::demo::edit::{DemoRewriter, Rewrite};
+    use tf_demo_parser::demo::packet::Packet;
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    assert!(diff_demos(&demo, &demo, &DiffOptions::default())
+        .unwrap()
+        .is_empty());
+
+    let edited = DemoRewriter::new()
+        .with_packet_hook(|mut packet, _state| {
+            if let Packet::Message(message) = &mut packet {
+                message.meta.view_angles = Default::default();
+            }
+            Ok(Rewrite::Keep(packet))
+        })
+        .rewrite(&demo)
+        .unwrap();
+    let edited = Demo::new(&edited);
+
+    let differences = diff_demos(&demo, &edited, &DiffOptions::default()).unwrap();
+    assert!(!differences.is_empty());
+    assert!(differences
+        .iter()
+        .all(|difference| difference.path.contains(".meta.view_angles")));
+
+    let options = DiffOptions {
+        ignore_view_angles: true,
+        ..DiffOptions::default()
+    };
+    assert!(diff_demos(&demo, &edited, &options).unwrap().is_empty());
+
+    let limited = DiffOptions {
+        max_differences: Some(5),
+        ..DiffOptions::default()
+    };
+    assert_eq!(diff_demos(&demo, &edited, &limited).unwrap().len(), 5);
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn pov_converter_test() {
+    use tf_demo_parser::demo::edit::rewrite::strip_pov;
+    use tf_demo_parser::demo::edit::{DemoRewriter, PovConverter};
+    use tf_demo_parser::demo::message::Message;
+    use tf_demo_parser::demo::packet::message::ViewAngles;
+    use tf_demo_parser::demo::packet::Packet;
+    use tf_demo_parser::ParseError;
+
+    let file = common::read_demo("small.dem");
+    let stv = DemoRewriter::new()
+        .with_header_hook(|header| header.nick = "SourceTV Demo".into())
+        .with_packet_hook(strip_pov)
+        .rewrite(&Demo::new(&file))
+        .unwrap();
+    let stv = Demo::new(&stv);
+
+    assert!(matches!(
+        PovConverter::new(&stv, 99u32.into()),
+        Err(ParseError::UnknownUser(_))
+    ));
+
+    let converter = PovConverter::new(&stv, 2u32.into()).unwrap();
+    let pov = DemoRewriter::new()
+        .with_pov_converter(converter)
+        .rewrite(&stv)
+        .unwrap();
+
+    let (_, original_packets) = common::read_packets(&file);
+    let (header, packets) = common::read_packets(&pov);
+    assert_eq!("Icewind | demos.tf", header.nick);
+
+    let mut set_views = Vec::new();
+    for packet in &packets {
+        if let Packet::Signon(packet) | Packet::Message(packet) = packet {
+            for message in &packet.messages {
+                match message {
+                    Message::ServerInfo(info) => {
+                        assert!(!info.stv);
+                        assert_eq!(0, info.player_slot);
+                    }
+                    Message::SetView(view) => set_views.push(view.index),
+                    _ => {}
+                }
+            }
+        }
+    }
+    assert_eq!(vec![1], set_views);
+
+    let origins = |packets: &[Packet]| -> Vec<_> {
+        packets
+            .iter()
+            .filter_map(|packet| match packet {
+                Packet::Message(packet) => Some(packet.meta.view_angles[0].origin),
+                _ => None,
+            })
+            .collect()
+    };
+    assert_eq!(origins(&original_packets), origins(&packets));
+    // the client records the position of the player's feet as the view origin, the player
+    // starts at a z of 298 with a view offset of 65
+    assert_eq!(298.0, origins(&original_packets)[0].z);
+    assert!(packets.iter().any(|packet| matches!(
+        packet,
+        Packet::Message(packet) if packet.meta.view_angles[0] != ViewAngles::default()
+    )));
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn annotate_test() {
+    use tf_demo_parser::demo::edit::{Annotation, Annotator, DemoRewriter};
+    use tf_demo_parser::demo::message::Message;
+    use tf_demo_parser::demo::packet::Packet;
+
+    let file = common::read_demo("small.dem");
+    let mut annotator = Annotator::new();
+    annotator.add(60u32.into(), Annotation::Chat("second note".into()));
+    annotator.add(0u32.into(), Annotation::echo("start"));
+    annotator.add(50u32.into(), Annotation::Print("first note".into()));
+    annotator.add(60u32.into(), Annotation::echo("marker"));
+    // after the end of the demo
+    annotator.add(500u32.into(), Annotation::echo("end"));
+    let out = DemoRewriter::new()
+        .with_annotator(annotator)
+        .rewrite(&Demo::new(&file))
+        .unwrap();
+
+    let (_, packets) = common::read_packets(&out);
+    let mut last_tick = 0u32.into();
+    let mut commands = Vec::new();
+    let mut prints = Vec::new();
+    for packet in packets {
+        // signon packets can have a higher tick than the packets after the sync tick
+        if !matches!(packet, Packet::Signon(_) | Packet::SyncTick(_)) {
+            assert!(packet.tick() >= last_tick);
+        }
+        last_tick = packet.tick();
+        match &packet {
+            // the demo already contains the console commands of the player
+            Packet::ConsoleCmd(command) if command.command.starts_with("echo") => {
+                commands.push((command.tick, command.command.clone()))
+            }
+            Packet::Message(message_packet) => {
+                for message in &message_packet.messages {
+                    if let Message::Print(print) = message {
+                        prints.push((message_packet.tick, print.value.to_string()));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    assert_eq!(2, commands.len());
+    assert_eq!(0u32, commands[0].0);
+    assert_eq!("echo \"start\"", commands[0].1);
+    assert_eq!("echo \"marker\"", commands[1].1);
+    assert_eq!(60u32, commands[1].0);
+    assert!(prints
+        .iter()
+        .any(|(tick, text)| *tick >= 50u32 && text == "first note\n"));
+
+    let (_, state) = DemoParser::new(Demo::new(&out).get_stream())
+        .parse()
+        .unwrap();
+    let chat = state
+        .chat
+        .iter()
+        .find(|message| message.text.contains("second note"))
+        .unwrap();
+    assert!(chat.tick >= 60u32);
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn merge_demos_test() {
+    use tf_demo_parser::demo::edit::{merge_demos, DemoRewriter, Rewrite};
+    use tf_demo_parser::demo::packet::Packet;
+    use tf_demo_parser::ParseError;
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let merged = merge_demos(&[Demo::new(&file), Demo::new(&file)]).unwrap();
+
+    let (original_header, original_state) = DemoParser::new(demo.get_stream()).parse().unwrap();
+    let (header, state) = DemoParser::new(Demo::new(&merged).get_stream())
+        .parse()
+        .unwrap();
+    // the ticks of the second demo continue after the first
+    assert_eq!(original_header.ticks * 2 + 1, header.ticks);
+    assert_eq!(original_state.chat.len() * 2, state.chat.len());
+    let second_half: Vec<_> = state
+        .chat
+        .iter()
+        .skip(original_state.chat.len())
+        .map(|message| u32::from(message.tick) - original_header.ticks - 1)
+        .collect();
+    let original_ticks: Vec<_> = original_state
+        .chat
+        .iter()
+        .map(|message| u32::from(message.tick))
+        .collect();
+    assert_eq!(original_ticks, second_half);
+
+    let game_state = |data: &[u8]| {
+        DemoParser::new_all_with_analyser(Demo::new(data).get_stream(), GameStateAnalyser::new())
+            .parse()
+            .unwrap()
+            .1
+    };
+    let merged_game_state = game_state(&merged);
+    let original_game_state = game_state(&file);
+    assert_eq!(
+        original_game_state.players.len(),
+        merged_game_state.players.len()
+    );
+    for (merged, original) in merged_game_state
+        .players
+        .iter()
+        .zip(original_game_state.players.iter())
+    {
+        assert_eq!(original.position, merged.position);
+        assert_eq!(original.health, merged.health);
+    }
+
+    let patched = DemoRewriter::new()
+        .with_packet_hook(|mut packet, _state| {
+            if let Packet::DataTables(tables) = &mut packet {
+                if let Some(class) = tables.server_classes.last_mut() {
+                    class.name = "CPatched".into();
+                }
+            }
+            Ok(Rewrite::Keep(packet))
+        })
+        .rewrite(&demo)
+        .unwrap();
+    assert!(matches!(
+        merge_demos(&[Demo::new(&file), Demo::new(&patched)]),
+        Err(ParseError::InvalidDemo(_))
+    ));
+    let other_map = common::read_demo("short-2024.dem");
+    assert!(matches!(
+        merge_demos(&[Demo::new(&file), Demo::new(&other_map)]),
+        Err(ParseError::InvalidDemo(_))
+    ));
+    assert!(merge_demos(&[]).is_err());
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn anonymize_test() {
+    use tf_demo_parser::demo::data::userinfo::PlayerInfo;
+    use tf_demo_parser::demo::data::UserInfo;
+    use tf_demo_parser::demo::edit::{AnonymizeOptions, Anonymizer, DemoRewriter};
+    use tf_demo_parser::demo::packet::stringtable::StringTableEntry;
+    use tf_demo_parser::demo::parser::MessageHandler;
+    use tf_demo_parser::{MessageType, ParserState};
+
+    #[derive(Default)]
+    struct PlayerInfos(Vec<PlayerInfo>);
+
+    impl MessageHandler for PlayerInfos {
+        type Output = Vec<PlayerInfo>;
+
+        fn does_handle(_message_type: MessageType) -> bool {
+            false
+        }
+
+        fn handle_string_entry(
+            &mut self,
+            table: &str,
+            index: usize,
+            entry: &StringTableEntry,
+            _state: &ParserState,
+        ) {
+            if table == "userinfo" {
+                if let Some(info) = UserInfo::parse_from_string_table(
+                    index as u16,
+                    entry.text.as_deref(),
+                    entry.extra_data.as_ref().map(|extra| extra.data.clone()),
+                )
+                .unwrap()
+                {
+                    self.0.push(info.player_info);
+                }
+            }
+        }
+
+        fn into_output(self, _state: &ParserState) -> Self::Output {
+            self.0
+        }
+    }
+
+    let anonymize = |options: AnonymizeOptions| {
+        let file = common::read_demo("small.dem");
+        DemoRewriter::new()
+            .with_anonymizer(Anonymizer::new(options))
+            .rewrite(&Demo::new(&file))
+            .unwrap()
+    };
+
+    let file = common::read_demo("small.dem");
+    let (_, original) = DemoParser::new(Demo::new(&file).get_stream())
+        .parse()
+        .unwrap();
+    let out = anonymize(AnonymizeOptions::default());
+    let (header, state) = DemoParser::new(Demo::new(&out).get_stream())
+        .parse()
+        .unwrap();
+
+    assert!(header.nick.is_empty());
+    assert!(header.server.is_empty());
+    assert_eq!(
+        original.users.keys().collect::<Vec<_>>(),
+        state.users.keys().collect::<Vec<_>>()
+    );
+    for (user_id, user) in &state.users {
+        let original_user = &original.users[user_id];
+        assert!(user.name.starts_with("Player "));
+        assert_ne!(original_user.name, user.name);
+        if original_user.steam_id != "BOT" {
+            assert!(user.steam_id.starts_with("[I:0:"));
+        }
+    }
+    assert_eq!(original.chat.len(), state.chat.len());
+    for message in &state.chat {
+        assert!(message.from.is_empty() || message.from.starts_with("Player "));
+    }
+
+    let (_, player_infos) =
+        DemoParser::new_with_analyser(Demo::new(&out).get_stream(), PlayerInfos::default())
+            .parse()
+            .unwrap();
+    assert!(!player_infos.is_empty());
+    for info in &player_infos {
+        assert_eq!(0, info.friends_id);
+        assert_eq!([0; 32], info.friends_name_bytes);
+        assert_eq!([0; 4], info.custom_file);
+    }
+
+    let out = anonymize(AnonymizeOptions {
+        drop_chat: true,
+        drop_voice: true,
+    });
+    let (_, state) = DemoParser::new(Demo::new(&out).get_stream())
+        .parse()
+        .unwrap();
+    // server messages are kept
+    assert!(state.chat.iter().all(|message| message.client.is_none()));
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn rewrite_test() {
+    use tf_demo_parser::demo::edit::rewrite::{drop_packets, DemoRewriter, Rewrite};
+    use tf_demo_parser::demo::message::generated::PrintMessage;
+    use tf_demo_parser::demo::message::Message;
+    use tf_demo_parser::demo::packet::{Packet, PacketType};
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+
+    let count_packets = |data: &[u8]| {
+        let (mut console_commands, mut prints) = (0, 0);
+        for packet in common::read_packets(data).1 {
+            match &packet {
+                Packet::ConsoleCmd(_) => console_commands += 1,
+                Packet::Message(packet) => {
+                    prints += packet
+                        .messages
+                        .iter()
+                        .filter(|message| matches!(message, Message::Print(_)))
+                        .count()
+                }
+                _ => {}
+            }
+        }
+        (console_commands, prints)
+    };
+
+    let unchanged = DemoRewriter::new().rewrite(&demo).unwrap();
+    let (_, original_state) = DemoParser::new(demo.get_stream()).parse().unwrap();
+    let (_, state) = DemoParser::new(Demo::new(&unchanged).get_stream())
+        .parse()
+        .unwrap();
+    assert_eq!(original_state, state);
+    let (console_commands, prints) = count_packets(&unchanged);
+    assert!(console_commands > 0);
+
+    let rewritten = DemoRewriter::new()
+        .with_packet_hook(drop_packets(PacketType::ConsoleCmd))
+        .with_message_hook(|message, _tick, _state| {
+            Ok(match message {
+                Message::NetTick(_) => Rewrite::Replace(vec![
+                    message,
+                    Message::Print(PrintMessage {
+                        value: "marker".into(),
+                    }),
+                ]),
+                message => Rewrite::Keep(message),
+            })
+        })
+        .rewrite(&demo)
+        .unwrap();
+    let (rewritten_console_commands, rewritten_prints) = count_packets(&rewritten);
+    assert_eq!(0, rewritten_console_commands);
+    assert!(rewritten_prints > prints);
+}
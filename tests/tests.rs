@@ -1,4 +1,5 @@
-use std::fs;
+mod common;
+
 use test_case::test_case;
 
 use tf_demo_parser::demo::parser::gamestateanalyser::GameStateAnalyser;
@@ -15,7 +16,7 @@ use tf_demo_parser::{Demo, DemoParser};
 #[test_case("emptysaytext.dem")]
 #[test_case("protocol23.dem")]
 fn snapshot_test(input_file: &str) {
-    let file = fs::read(format!("test_data/{input_file}")).expect("Unable to read file");
+    let file = common::read_demo(input_file);
     let demo = Demo::new(&file);
     let (_, state) = DemoParser::new(demo.get_stream()).parse().unwrap();
 
@@ -28,7 +29,7 @@ fn snapshot_test(input_file: &str) {
 #[test_case("small.dem")]
 #[test_case("gully.dem")]
 fn game_state_test(input_file: &str) {
-    let file = fs::read(format!("test_data/{input_file}")).expect("Unable to read file");
+    let file = common::read_demo(input_file);
     let demo = Demo::new(&file);
     let (_, mut state) = DemoParser::new_with_analyser(demo.get_stream(), GameStateAnalyser::new())
         .parse()
@@ -39,3 +40,171 @@ fn game_state_test(input_file: &str) {
         insta::assert_json_snapshot!(input_file, state);
     });
 }
+
+#[test]
+fn send_table_cache_test() {
+    use tf_demo_parser::demo::parser::SendTableCache;
+
+    let file = common::read_demo("small.dem");
+    let cache = SendTableCache::new();
+    let parse = || {
+        let demo = Demo::new(&file);
+        DemoParser::new_with_analyser(demo.get_stream(), GameStateAnalyser::new())
+            .with_send_table_cache(cache.clone())
+            .parse()
+            .unwrap()
+            .1
+    };
+    let first = parse();
+    assert_eq!(1, cache.len());
+    let second = parse();
+    assert_eq!(1, cache.len());
+    assert_eq!(first.players, second.players);
+}
+
+#[test]
+fn borrowed_entities_test() {
+    use tf_demo_parser::demo::data::DemoTick;
+    use tf_demo_parser::demo::message::packetentities::{
+        PacketEntitiesMessage, PacketEntitiesView,
+    };
+    use tf_demo_parser::demo::message::Message;
+    use tf_demo_parser::demo::parser::MessageHandler;
+    use tf_demo_parser::{MessageType, ParserState};
+
+    #[derive(Default)]
+    struct Owned(Vec<PacketEntitiesMessage>);
+
+    impl MessageHandler for Owned {
+        type Output = Vec<PacketEntitiesMessage>;
+
+        fn does_handle(message_type: MessageType) -> bool {
+            message_type == MessageType::PacketEntities
+        }
+
+        fn handle_message(&mut self, message: &Message, _tick: DemoTick, _state: &ParserState) {
+            if let Message::PacketEntities(message) = message {
+                self.0.push(message.clone());
+            }
+        }
+
+        fn into_output(self, _state: &ParserState) -> Self::Output {
+            self.0
+        }
+    }
+
+    #[derive(Default)]
+    struct Borrowed(Vec<PacketEntitiesMessage>);
+
+    impl MessageHandler for Borrowed {
+        type Output = Vec<PacketEntitiesMessage>;
+
+        fn does_handle(_message_type: MessageType) -> bool {
+            false
+        }
+
+        fn does_handle_borrowed_entities() -> bool {
+            true
+        }
+
+        fn handle_entities(
+            &mut self,
+            entities: &PacketEntitiesView,
+            _tick: DemoTick,
+            _state: &ParserState,
+        ) {
+            self.0.push(entities.to_owned());
+        }
+
+        fn into_output(self, _state: &ParserState) -> Self::Output {
+            self.0
+        }
+    }
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let (_, owned) = DemoParser::new_with_analyser(demo.get_stream(), Owned::default())
+        .parse()
+        .unwrap();
+    let (_, borrowed) = DemoParser::new_with_analyser(demo.get_stream(), Borrowed::default())
+        .parse()
+        .unwrap();
+    assert!(!owned.is_empty());
+    assert_eq!(owned, borrowed);
+}
+
+#[test]
+fn prop_filter_test() {
+    use tf_demo_parser::demo::data::DemoTick;
+    use tf_demo_parser::demo::message::packetentities::{EntityId, UpdateType};
+    use tf_demo_parser::demo::message::Message;
+    use tf_demo_parser::demo::parser::propfilter::PropFilter;
+    use tf_demo_parser::demo::parser::MessageHandler;
+    use tf_demo_parser::demo::sendprop::{SendPropIdentifier, SendPropValue};
+    use tf_demo_parser::{MessageType, ParserState};
+
+    const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+
+    struct Health {
+        filter: Option<PropFilter>,
+        values: Vec<(DemoTick, EntityId, SendPropValue)>,
+    }
+
+    impl MessageHandler for Health {
+        type Output = Vec<(DemoTick, EntityId, SendPropValue)>;
+
+        fn does_handle(message_type: MessageType) -> bool {
+            message_type == MessageType::PacketEntities
+        }
+
+        fn handle_message(&mut self, message: &Message, tick: DemoTick, state: &ParserState) {
+            let Message::PacketEntities(message) = message else {
+                return;
+            };
+            for entity in &message.entities {
+                for prop in &entity.props {
+                    if prop.identifier == HEALTH {
+                        self.values
+                            .push((tick, entity.entity_index, prop.value.clone()));
+                    } else if self.filter.is_some() {
+                        // only entities updating the baseline are fully decoded
+                        assert!(message.updated_base_line);
+                        assert_eq!(UpdateType::Enter, entity.update_type);
+                    }
+                }
+                if let Some(class) = state.server_classes.get(usize::from(entity.server_class)) {
+                    if self.filter.is_some() && class.name.as_str() != "CTFPlayer" {
+                        assert!(entity.props.is_empty() || message.updated_base_line);
+                    }
+                }
+            }
+        }
+
+        fn prop_filter(&self) -> Option<PropFilter> {
+            self.filter.clone()
+        }
+
+        fn into_output(self, _state: &ParserState) -> Self::Output {
+            self.values
+        }
+    }
+
+    let file = common::read_demo("small.dem");
+    let demo = Demo::new(&file);
+    let parse = |filter| {
+        let handler = Health {
+            filter,
+            values: Vec::new(),
+        };
+        DemoParser::new_with_analyser(demo.get_stream(), handler)
+            .parse()
+            .unwrap()
+            .1
+    };
+    let full = parse(None);
+    let filtered = parse(Some(
+        PropFilter::new().with_class("CTFPlayer").with_prop(HEALTH),
+    ));
+    assert!(!full.is_empty());
+    assert_eq!(full, filtered);
+}